@@ -78,12 +78,12 @@ async fn main() -> binance_api_client::Result<()> {
 
     let oco = OcoOrderBuilder::new(
         symbol,
-        OrderSide::Sell,                      // Selling BTC
-        "0.001",                              // Quantity
-        &format!("{:.2}", take_profit_price), // Take profit (limit price)
-        &format!("{:.2}", stop_price),        // Stop price
+        OrderSide::Sell,        // Selling BTC
+        "0.001",                // Quantity
+        (take_profit_price, 2), // Take profit (limit price)
+        (stop_price, 2),        // Stop price
     )
-    .stop_limit_price(&format!("{:.2}", stop_limit_price))
+    .stop_limit_price((stop_limit_price, 2))
     .stop_limit_time_in_force(TimeInForce::GTC)
     .build();
 
@@ -116,16 +116,16 @@ async fn main() -> binance_api_client::Result<()> {
 
     let oto = OtoOrderBuilder::new(
         symbol,
-        OrderType::Limit,               // Working order type
-        OrderSide::Buy,                 // Working order side
-        &format!("{:.2}", entry_price), // Working order price
-        "0.001",                        // Working order quantity
-        OrderType::Limit,               // Pending order type
-        OrderSide::Sell,                // Pending order side
-        "0.001",                        // Pending order quantity
+        OrderType::Limit, // Working order type
+        OrderSide::Buy,   // Working order side
+        (entry_price, 2), // Working order price
+        "0.001",          // Working order quantity
+        OrderType::Limit, // Pending order type
+        OrderSide::Sell,  // Pending order side
+        "0.001",          // Pending order quantity
     )
     .working_time_in_force(TimeInForce::GTC)
-    .pending_price(&format!("{:.2}", take_profit_price))
+    .pending_price((take_profit_price, 2))
     .pending_time_in_force(TimeInForce::GTC)
     .build();
 
@@ -157,19 +157,19 @@ async fn main() -> binance_api_client::Result<()> {
 
     let otoco = OtocoOrderBuilder::new(
         symbol,
-        OrderType::Limit,               // Working order type
-        OrderSide::Buy,                 // Working order side
-        &format!("{:.2}", entry_price), // Working order price
-        "0.001",                        // Working order quantity
-        OrderSide::Sell,                // Pending orders side (exit side)
-        "0.001",                        // Pending orders quantity
-        OrderType::LimitMaker,          // Take profit type (above price)
+        OrderType::Limit,      // Working order type
+        OrderSide::Buy,        // Working order side
+        (entry_price, 2),      // Working order price
+        "0.001",               // Working order quantity
+        OrderSide::Sell,       // Pending orders side (exit side)
+        "0.001",               // Pending orders quantity
+        OrderType::LimitMaker, // Take profit type (above price)
     )
     .working_time_in_force(TimeInForce::GTC)
-    .pending_above_price(&format!("{:.2}", take_profit_price)) // Take profit price
+    .pending_above_price((take_profit_price, 2)) // Take profit price
     .pending_below_type(OrderType::StopLossLimit)
-    .pending_below_stop_price(&format!("{:.2}", stop_price))
-    .pending_below_price(&format!("{:.2}", stop_limit_price))
+    .pending_below_stop_price((stop_price, 2))
+    .pending_below_price((stop_limit_price, 2))
     .pending_below_time_in_force(TimeInForce::GTC)
     .build();
 
@@ -204,7 +204,7 @@ async fn main() -> binance_api_client::Result<()> {
     // First, let's place a test order to modify
     let initial_order = OrderBuilder::new(symbol, OrderSide::Buy, OrderType::Limit)
         .quantity("0.001")
-        .price(&format!("{:.2}", entry_price))
+        .price((entry_price, 2))
         .time_in_force(TimeInForce::GTC)
         .build();
 
@@ -226,7 +226,7 @@ async fn main() -> binance_api_client::Result<()> {
             )
             .cancel_order_id(result.order_id)
             .quantity("0.001")
-            .price(&format!("{:.2}", new_price))
+            .price((new_price, 2))
             .time_in_force(TimeInForce::GTC)
             .build();
 
@@ -25,6 +25,7 @@ async fn main() -> binance_api_client::Result<()> {
         depth_limit: 100,       // Number of levels to fetch in snapshot
         fast_updates: true,     // Use 100ms update speed (vs 1000ms)
         refresh_interval: None, // Optional: periodically re-fetch snapshot
+        send_updates: true,     // Send a copy of the book to next() on each update
     };
 
     let symbol = "BTCUSDT";
@@ -20,7 +20,7 @@
 //!
 //! Run with: cargo run --example margin_trading
 
-use binance_api_client::Binance;
+use binance_api_client::{Binance, BorrowRepayType};
 
 #[tokio::main]
 async fn main() -> binance_api_client::Result<()> {
@@ -216,7 +216,16 @@ async fn main() -> binance_api_client::Result<()> {
     println!("=== Recent Loan Records ===\n");
     match client
         .margin()
-        .loan_records("USDT", None, None, None, None, Some(5))
+        .borrow_repay_records(
+            BorrowRepayType::Borrow,
+            Some("USDT"),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(5),
+        )
         .await
     {
         Ok(result) => {
@@ -246,12 +255,14 @@ async fn main() -> binance_api_client::Result<()> {
 
     println!("// Borrow funds");
     println!("let result = client.margin()");
-    println!("    .loan(\"USDT\", \"50.0\", false, None)  // false = cross margin");
+    println!(
+        "    .borrow_repay(\"USDT\", \"50.0\", BorrowRepayType::Borrow, false, None)  // false = cross margin"
+    );
     println!("    .await?;\n");
 
     println!("// Repay loan");
     println!("let result = client.margin()");
-    println!("    .repay(\"USDT\", \"50.0\", false, None)");
+    println!("    .borrow_repay(\"USDT\", \"50.0\", BorrowRepayType::Repay, false, None)");
     println!("    .await?;\n");
 
     println!("// Create margin order with auto-borrow");
@@ -300,15 +311,21 @@ fn show_example_code() {
     println!("    .await?;\n");
 
     println!("// Borrow funds (cross margin)");
-    println!("let tx = client.margin().loan(\"USDT\", \"50.0\", false, None).await?;\n");
+    println!("let tx = client.margin()");
+    println!("    .borrow_repay(\"USDT\", \"50.0\", BorrowRepayType::Borrow, false, None)");
+    println!("    .await?;\n");
 
     println!("// Borrow funds (isolated margin)");
+    println!("let tx = client.margin()");
     println!(
-        "let tx = client.margin().loan(\"USDT\", \"50.0\", true, Some(\"BTCUSDT\")).await?;\n"
+        "    .borrow_repay(\"USDT\", \"50.0\", BorrowRepayType::Borrow, true, Some(\"BTCUSDT\"))"
     );
+    println!("    .await?;\n");
 
     println!("// Repay loan");
-    println!("let tx = client.margin().repay(\"USDT\", \"50.0\", false, None).await?;\n");
+    println!("let tx = client.margin()");
+    println!("    .borrow_repay(\"USDT\", \"50.0\", BorrowRepayType::Repay, false, None)");
+    println!("    .await?;\n");
 
     println!("// Create margin order with side effect (auto-borrow)");
     println!("use binance_api_client::{{OrderSide, OrderType, TimeInForce, SideEffectType}};");
@@ -334,7 +351,9 @@ fn show_example_code() {
 
     println!("// Get loan/repay records");
     println!("let loans = client.margin()");
-    println!("    .loan_records(\"USDT\", None, None, None, None, Some(10))");
+    println!(
+        "    .borrow_repay_records(BorrowRepayType::Borrow, Some(\"USDT\"), None, None, None, None, None, Some(10))"
+    );
     println!("    .await?;\n");
 
     println!("// Get interest history");
@@ -105,6 +105,9 @@ async fn main() -> binance_api_client::Result<()> {
     println!("    base_delay: Duration::from_millis(500),");
     println!("    health_check_enabled: true,");
     println!("    health_check_interval: Duration::from_secs(60),");
+    println!("    max_idle: Duration::from_secs(120), // reconnect when silent this long");
+    println!("    retry_mode: RetryMode::Infinite, // never give up");
+    println!("    ..Default::default()");
     println!("}};");
     println!();
     println!("let conn = ReconnectingWebSocket::new(url, config).await?;");
@@ -215,12 +215,12 @@ fn print_event(event: &WebSocketEvent) {
             println!();
         }
         WebSocketEvent::ListStatus(status) => {
-            println!("=== List Status (OCO) ===");
+            println!("=== List Status ===");
             println!("  Symbol: {}", status.symbol);
             println!("  Order List ID: {}", status.order_list_id);
-            println!("  Contingency Type: {}", status.contingency_type);
-            println!("  List Status Type: {}", status.list_status_type);
-            println!("  List Order Status: {}", status.list_order_status);
+            println!("  Contingency Type: {:?}", status.contingency_type);
+            println!("  List Status Type: {:?}", status.list_status_type);
+            println!("  List Order Status: {:?}", status.list_order_status);
             println!("  Orders:");
             for order in &status.orders {
                 println!(
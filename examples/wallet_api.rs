@@ -281,9 +281,11 @@ fn show_example_code() {
     println!("use binance_api_client::UniversalTransferType;");
     println!("let result = client.wallet()");
     println!("    .universal_transfer(");
-    println!("        UniversalTransferType::MainToFunding,");
+    println!("        UniversalTransferType::MainFunding,");
     println!("        \"USDT\",");
     println!("        \"50.0\",");
+    println!("        None,");
+    println!("        None,");
     println!("    )");
     println!("    .await?;\n");
 
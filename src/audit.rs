@@ -0,0 +1,358 @@
+//! Order action audit log.
+//!
+//! When an [`AuditLog`] is attached to a client, every request that creates,
+//! cancels or amends an order is recorded together with the response (or the
+//! transport error) it produced. Signatures, keys and listen keys are
+//! redacted before the record is handed to the sink, so audit files never
+//! contain material that could be replayed.
+//!
+//! Records can be appended to a JSONL file with [`JsonlAuditSink`] or passed
+//! to any user-provided [`AuditSink`], including a plain closure.
+
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use crate::Result;
+use crate::credentials::get_timestamp;
+use crate::error::REDACTED_PARAMS;
+
+/// Placeholder written in place of redacted parameter values.
+pub const REDACTED: &str = "<redacted>";
+
+/// A single audited order action.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    /// Local time the record was created, in milliseconds.
+    pub timestamp: u64,
+    /// HTTP method (`POST`, `PUT` or `DELETE`), or `WS` for WebSocket API
+    /// requests.
+    pub method: String,
+    /// Endpoint path, e.g. `/api/v3/order`, or WebSocket API method, e.g.
+    /// `order.place`.
+    pub endpoint: String,
+    /// Request parameters, with signatures and keys redacted.
+    pub params: BTreeMap<String, String>,
+    /// HTTP status code, if a response was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Response body, if a response was received.
+    ///
+    /// Bodies that are not valid JSON are stored as a JSON string.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response: Option<serde_json::Value>,
+    /// Transport error, if no response was received.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Destination for audit records.
+///
+/// Sinks are called inline on the request path and should not block for
+/// long. Closures taking an `&AuditRecord` implement this trait.
+pub trait AuditSink: Send + Sync {
+    /// Record an order action.
+    fn record(&self, record: &AuditRecord);
+}
+
+impl<F> AuditSink for F
+where
+    F: Fn(&AuditRecord) + Send + Sync,
+{
+    fn record(&self, record: &AuditRecord) {
+        self(record)
+    }
+}
+
+/// Audit sink that appends one JSON object per line to a file.
+#[derive(Debug)]
+pub struct JsonlAuditSink {
+    file: Mutex<File>,
+}
+
+impl JsonlAuditSink {
+    /// Open (or create) an append-only audit file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl AuditSink for JsonlAuditSink {
+    fn record(&self, record: &AuditRecord) {
+        let line = match serde_json::to_string(record) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!("Failed to serialize audit record: {}", e);
+                return;
+            }
+        };
+
+        let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+        if let Err(e) = writeln!(file, "{}", line).and_then(|_| file.flush()) {
+            tracing::warn!("Failed to write audit record: {}", e);
+        }
+    }
+}
+
+/// Opt-in audit log for order create, cancel and amend requests.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{AuditLog, Binance};
+///
+/// let client = Binance::from_env()?
+///     .with_audit_log(AuditLog::jsonl("orders.jsonl")?);
+///
+/// // Recorded to orders.jsonl with the signature redacted.
+/// client.account().market_buy("BTCUSDT", "0.001").await?;
+/// ```
+#[derive(Clone)]
+pub struct AuditLog {
+    sink: Arc<dyn AuditSink>,
+}
+
+impl AuditLog {
+    /// Create an audit log that writes to a custom sink.
+    pub fn new(sink: impl AuditSink + 'static) -> Self {
+        Self {
+            sink: Arc::new(sink),
+        }
+    }
+
+    /// Create an audit log that appends to a JSONL file.
+    pub fn jsonl(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(JsonlAuditSink::open(path)?))
+    }
+
+    /// Check if a request is an order action that should be audited.
+    ///
+    /// Order queries (`GET`) are not audited.
+    pub fn is_audited(method: &str, endpoint: &str) -> bool {
+        crate::client::is_order_action(method, endpoint)
+    }
+
+    /// Record a request that received a response.
+    pub(crate) fn record_response(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query: &str,
+        status: u16,
+        body: &[u8],
+    ) {
+        let response = serde_json::from_slice(body).unwrap_or_else(|_| {
+            serde_json::Value::String(String::from_utf8_lossy(body).into_owned())
+        });
+        let params = redact_query(query);
+        self.record(method, endpoint, params, Some(status), Some(response), None);
+    }
+
+    /// Record a request that failed before a response was received.
+    pub(crate) fn record_error(&self, method: &str, endpoint: &str, query: &str, error: String) {
+        self.record(
+            method,
+            endpoint,
+            redact_query(query),
+            None,
+            None,
+            Some(error),
+        );
+    }
+
+    /// Record a WebSocket API request (e.g. `order.place`) and its result.
+    ///
+    /// Requests rejected by the exchange are recorded with the error
+    /// response, other failures with the error.
+    pub(crate) fn record_ws_api(
+        &self,
+        ws_method: &str,
+        params: &serde_json::Value,
+        result: &Result<serde_json::Value>,
+    ) {
+        let params = params
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(key, value)| {
+                let value = match value {
+                    _ if REDACTED_PARAMS.contains(&key.as_str()) => REDACTED.to_string(),
+                    serde_json::Value::String(value) => value.clone(),
+                    value => value.to_string(),
+                };
+                (key.clone(), value)
+            })
+            .collect();
+
+        let (response, error) = match result {
+            Ok(response) => (Some(response.clone()), None),
+            Err(e) => match e.inner() {
+                crate::Error::Api { code, message } => (
+                    Some(serde_json::json!({ "code": code, "msg": message })),
+                    None,
+                ),
+                e => (None, Some(e.to_string())),
+            },
+        };
+        self.record("WS", ws_method, params, None, response, error);
+    }
+
+    fn record(
+        &self,
+        method: &str,
+        endpoint: &str,
+        params: BTreeMap<String, String>,
+        status: Option<u16>,
+        response: Option<serde_json::Value>,
+        error: Option<String>,
+    ) {
+        let record = AuditRecord {
+            timestamp: get_timestamp().unwrap_or_default(),
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            params,
+            status,
+            response,
+            error,
+        };
+        self.sink.record(&record);
+    }
+}
+
+impl std::fmt::Debug for AuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuditLog").finish_non_exhaustive()
+    }
+}
+
+/// Split a query string into parameters, redacting sensitive values.
+fn redact_query(query: &str) -> BTreeMap<String, String> {
+    query
+        .split('&')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let (key, value) = part.split_once('=').unwrap_or((part, ""));
+            let value = if REDACTED_PARAMS.contains(&key) {
+                REDACTED.to_string()
+            } else {
+                urlencoding::decode(value)
+                    .map(|v| v.into_owned())
+                    .unwrap_or_else(|_| value.to_string())
+            };
+            (key.to_string(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_audited() {
+        assert!(AuditLog::is_audited("POST", "/api/v3/order"));
+        assert!(AuditLog::is_audited("DELETE", "/api/v3/openOrders"));
+        assert!(AuditLog::is_audited(
+            "PUT",
+            "/api/v3/order/amend/keepPriority"
+        ));
+        assert!(AuditLog::is_audited("POST", "/sapi/v1/margin/order"));
+        assert!(!AuditLog::is_audited("GET", "/api/v3/order"));
+        assert!(!AuditLog::is_audited(
+            "POST",
+            "/sapi/v1/capital/withdraw/apply"
+        ));
+    }
+
+    #[test]
+    fn test_record_redacts_signature() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let captured = records.clone();
+        let log = AuditLog::new(move |r: &AuditRecord| captured.lock().unwrap().push(r.clone()));
+
+        log.record_response(
+            "POST",
+            "/api/v3/order",
+            "timestamp=1&symbol=BTCUSDT&side=BUY&signature=abcdef",
+            200,
+            br#"{"orderId":42}"#,
+        );
+        log.record_error("DELETE", "/api/v3/order", "signature=abc", "timeout".into());
+
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].params["symbol"], "BTCUSDT");
+        assert_eq!(records[0].params["signature"], REDACTED);
+        assert_eq!(records[0].status, Some(200));
+        assert_eq!(records[0].response.as_ref().unwrap()["orderId"], 42);
+        assert_eq!(records[1].error.as_deref(), Some("timeout"));
+
+        let line = serde_json::to_string(&records[0]).unwrap();
+        assert!(!line.contains("abcdef"));
+        assert!(!line.contains("\"error\""));
+    }
+
+    #[test]
+    fn test_record_ws_api() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let captured = records.clone();
+        let log = AuditLog::new(move |r: &AuditRecord| captured.lock().unwrap().push(r.clone()));
+
+        let params = serde_json::json!({
+            "symbol": "BTCUSDT",
+            "orderId": 42,
+            "apiKey": "key",
+            "signature": "abc"
+        });
+        log.record_ws_api(
+            "order.cancel",
+            &params,
+            &Ok(serde_json::json!({"orderId": 42})),
+        );
+        let rejected = Err(crate::Error::Api {
+            code: -2011,
+            message: "Unknown order sent.".to_string(),
+        });
+        log.record_ws_api("order.cancel", &params, &rejected);
+
+        let records = records.lock().unwrap();
+        assert_eq!(records[0].method, "WS");
+        assert_eq!(records[0].endpoint, "order.cancel");
+        assert_eq!(records[0].params["orderId"], "42");
+        assert_eq!(records[0].params["apiKey"], REDACTED);
+        assert_eq!(records[0].params["signature"], REDACTED);
+        assert_eq!(records[0].response.as_ref().unwrap()["orderId"], 42);
+        assert_eq!(records[1].response.as_ref().unwrap()["code"], -2011);
+        assert!(records[1].error.is_none());
+    }
+
+    #[test]
+    fn test_jsonl_sink_appends() {
+        let path = std::env::temp_dir().join(format!(
+            "binance-audit-{}-{}.jsonl",
+            std::process::id(),
+            get_timestamp().unwrap()
+        ));
+
+        let log = AuditLog::jsonl(&path).unwrap();
+        log.record_response("POST", "/api/v3/order", "signature=x", 400, b"not json");
+        log.record_response("POST", "/api/v3/order", "signature=y", 200, b"{}");
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["response"], "not json");
+        assert_eq!(first["params"]["signature"], REDACTED);
+    }
+}
@@ -1,13 +1,26 @@
-use reqwest::StatusCode;
-use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, USER_AGENT};
-use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use reqwest_middleware::{ClientBuilder, ClientWithMiddleware, RequestBuilder};
 use reqwest_retry::{RetryTransientMiddleware, policies::ExponentialBackoff};
 use reqwest_tracing::TracingMiddleware;
 use serde::de::DeserializeOwned;
 
-use crate::config::Config;
-use crate::credentials::{Credentials, build_signed_query_string};
-use crate::error::{BinanceApiError, Error, Result};
+use std::sync::Arc;
+
+use crate::audit::AuditLog;
+use crate::config::{CLIENT_INFO_HEADER, Config};
+use crate::credentials::{Credentials, build_signed_query_string_at, get_timestamp};
+use crate::error::{BinanceApiError, Error, RequestContext, Result};
+use crate::models::ServerTime;
+use crate::rate_limit::{RateLimitStatus, RateLimitTracker};
+use crate::schema_drift::{SchemaDriftDetector, SchemaDriftReport};
+use crate::shutdown::{InFlightOrder, ShutdownHandle};
+use crate::supervisor::RestartPolicy;
+use crate::time_sync::{TIME_SYNC_RETRY_DELAY, TimeSync};
+use crate::withdraw_policy::WithdrawPolicy;
+
+// API endpoints.
+const API_V3_TIME: &str = "/api/v3/time";
 
 /// HTTP client for Binance REST API.
 #[derive(Clone)]
@@ -15,6 +28,15 @@ pub struct Client {
     http: ClientWithMiddleware,
     config: Config,
     credentials: Option<Credentials>,
+    api_key: Option<String>,
+    time_sync: Arc<TimeSync>,
+    // Held while synchronizing the server time automatically
+    time_sync_lock: Arc<tokio::sync::Mutex<()>>,
+    rate_limits: Arc<RateLimitTracker>,
+    withdraw_policy: Option<Arc<WithdrawPolicy>>,
+    audit_log: Option<AuditLog>,
+    schema_drift: Option<Arc<SchemaDriftDetector>>,
+    shutdown: ShutdownHandle,
 }
 
 impl Client {
@@ -28,13 +50,38 @@ impl Client {
         Self::build(config, None)
     }
 
+    /// Create a client with an API key but no secret, for endpoints that
+    /// need the API key header but no signature.
+    pub fn new_with_api_key(config: Config, api_key: impl Into<String>) -> Result<Self> {
+        let mut client = Self::build(config, None)?;
+        client.api_key = Some(api_key.into());
+        Ok(client)
+    }
+
     fn build(config: Config, credentials: Option<Credentials>) -> Result<Self> {
-        let mut builder = reqwest::Client::builder();
+        let mut builder = reqwest::Client::builder().user_agent(config.user_agent.as_str());
+
+        if let Some(client_info) = &config.client_info {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static(CLIENT_INFO_HEADER),
+                HeaderValue::from_str(client_info)?,
+            );
+            builder = builder.default_headers(headers);
+        }
 
         if let Some(timeout) = config.timeout {
             builder = builder.timeout(timeout);
         }
 
+        if let Some(timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(reqwest::Proxy::all(proxy.as_str())?);
+        }
+
         let reqwest_client = builder.build()?;
 
         // Set up retry policy for transient errors
@@ -45,10 +92,27 @@ impl Client {
             .with(RetryTransientMiddleware::new_with_policy(retry_policy))
             .build();
 
+        let time_sync = Arc::new(TimeSync::new(
+            config.auto_time_sync,
+            config.time_sync_interval,
+        ));
+
+        let schema_drift = config
+            .detect_schema_drift
+            .then(|| Arc::new(SchemaDriftDetector::new()));
+
         Ok(Self {
             http,
             config,
             credentials,
+            api_key: None,
+            time_sync,
+            time_sync_lock: Arc::new(tokio::sync::Mutex::new(())),
+            rate_limits: Arc::new(RateLimitTracker::new()),
+            withdraw_policy: None,
+            audit_log: None,
+            schema_drift,
+            shutdown: ShutdownHandle::new(),
         })
     }
 
@@ -62,15 +126,219 @@ impl Client {
         self.credentials.is_some()
     }
 
-    /// Make an unsigned GET request (for public endpoints).
-    pub async fn get<T: DeserializeOwned>(&self, endpoint: &str, query: Option<&str>) -> Result<T> {
-        let url = match query {
-            Some(q) => format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, q),
-            None => format!("{}{}", self.config.rest_api_endpoint, endpoint),
+    /// Get the credentials, if any.
+    pub(crate) fn credentials(&self) -> Option<&Credentials> {
+        self.credentials.as_ref()
+    }
+
+    /// Get the server time synchronization state.
+    pub fn time_sync(&self) -> &TimeSync {
+        &self.time_sync
+    }
+
+    /// Get the rate limit usage reported by the most recent responses.
+    pub fn rate_limit_status(&self) -> RateLimitStatus {
+        self.rate_limits.status()
+    }
+
+    /// Get the schema drift detected so far, if detection is enabled.
+    pub fn schema_drift(&self) -> Option<SchemaDriftReport> {
+        self.schema_drift.as_ref().map(|detector| detector.report())
+    }
+
+    /// Log the detected schema drift, if detection is enabled.
+    pub(crate) fn log_schema_drift(&self) {
+        if let Some(detector) = &self.schema_drift {
+            detector.log();
+        }
+    }
+
+    /// Attach a withdrawal policy that is consulted before every withdrawal.
+    pub fn with_withdraw_policy(mut self, policy: WithdrawPolicy) -> Self {
+        self.withdraw_policy = Some(Arc::new(policy));
+        self
+    }
+
+    /// Get the withdrawal policy, if one is attached.
+    pub fn withdraw_policy(&self) -> Option<&WithdrawPolicy> {
+        self.withdraw_policy.as_deref()
+    }
+
+    /// Attach an audit log that records every order create, cancel and
+    /// amend request together with its response.
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    /// Get the audit log, if one is attached.
+    pub fn audit_log(&self) -> Option<&AuditLog> {
+        self.audit_log.as_ref()
+    }
+
+    /// Get the shutdown handle shared by this client and its clones.
+    pub fn shutdown_handle(&self) -> &ShutdownHandle {
+        &self.shutdown
+    }
+
+    /// Synchronize with the server time.
+    ///
+    /// Calls `/api/v3/time` and stores the offset between the server clock
+    /// and the local clock. The offset is applied to the `timestamp` of all
+    /// subsequent signed requests.
+    ///
+    /// Returns the measured offset in milliseconds.
+    pub async fn sync_time(&self) -> Result<i64> {
+        let request_ms = get_timestamp()?;
+        let server_time: ServerTime = self.get(API_V3_TIME, None).await?;
+        let response_ms = get_timestamp()?;
+
+        Ok(self
+            .time_sync
+            .record(server_time.server_time, request_ms, response_ms))
+    }
+
+    /// Build a signed query string.
+    ///
+    /// With automatic synchronization, the first signed request waits for
+    /// the server time to be measured and starts the background task that
+    /// keeps it current.
+    async fn signed_query(
+        &self,
+        params: &[(&str, &str)],
+        credentials: &Credentials,
+    ) -> Result<String> {
+        if self.time_sync.is_enabled() {
+            self.start_time_sync();
+            if !self.time_sync.is_synced() {
+                self.sync_time_if_due().await;
+            }
+        }
+
+        build_signed_query_string_at(
+            params.iter().copied(),
+            credentials,
+            self.config.recv_window,
+            self.time_sync.timestamp()?,
+        )
+    }
+
+    // Start the background time sync task, once per client and its clones.
+    fn start_time_sync(&self) {
+        if !self.time_sync.start_background() {
+            return;
+        }
+        let client = self.clone();
+        let policy = RestartPolicy::default();
+        self.shutdown.spawn_supervised_on(
+            &self.config.runtime_hints,
+            "time-sync",
+            policy,
+            move || client.clone().time_sync_loop(),
+        );
+    }
+
+    async fn time_sync_loop(self) {
+        let period = TIME_SYNC_RETRY_DELAY.min(self.config.time_sync_interval);
+        loop {
+            // The supervisor and this loop hold the only clones left once
+            // every client was dropped
+            if Arc::strong_count(&self.time_sync) <= 2 {
+                break;
+            }
+            self.sync_time_if_due().await;
+            tokio::select! {
+                _ = self.shutdown.cancelled() => break,
+                _ = tokio::time::sleep(period) => {}
+            }
+        }
+    }
+
+    // Synchronize the server time unless another caller just did, or a
+    // synchronization failed less than the retry delay ago.
+    async fn sync_time_if_due(&self) {
+        let _guard = self.time_sync_lock.lock().await;
+        let now = get_timestamp().unwrap_or_default();
+        if !self.time_sync.needs_sync(now) {
+            return;
+        }
+        if let Err(e) = self.sync_time().await {
+            self.time_sync.record_failure(now);
+            tracing::warn!("Server time synchronization failed: {}", e);
+        }
+    }
+
+    /// Run a request, recording its latency in the metrics and, when the
+    /// `tracing` feature is enabled, in a span.
+    async fn traced<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        request: impl Future<Output = Result<T>>,
+    ) -> Result<T> {
+        let started = std::time::Instant::now();
+        let record_latency = |latency| {
+            if self.config.metrics.is_enabled() {
+                let recorder = self.config.metrics.recorder();
+                recorder.record_latency(method, endpoint, latency);
+            }
         };
 
-        let response = self.http.get(&url).send().await?;
-        self.handle_response(response).await
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "binance.request",
+                method,
+                endpoint,
+                status = tracing::field::Empty,
+                used_weight = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                error = tracing::field::Empty,
+            );
+            let result = request.instrument(span.clone()).await;
+            let latency = started.elapsed();
+            record_latency(latency);
+            span.record("latency_ms", latency.as_millis() as u64);
+            if let Err(e) = &result {
+                span.record("error", tracing::field::display(e));
+            }
+            tracing::debug!(parent: &span, "Request completed");
+            result
+        }
+        #[cfg(not(feature = "tracing"))]
+        {
+            let result = request.await;
+            record_latency(started.elapsed());
+            result
+        }
+    }
+
+    // Build a request with the timeout configured for the endpoint.
+    fn request(&self, method: Method, endpoint: &str, url: &str) -> RequestBuilder {
+        let timeout = self.config.timeout_for(method.as_str(), endpoint);
+        let request = self.http.request(method, url);
+        match timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+
+    /// Make an unsigned GET request (for public endpoints).
+    pub async fn get<T: DeserializeOwned>(&self, endpoint: &str, query: Option<&str>) -> Result<T> {
+        let result: Result<T> = self
+            .traced("GET", endpoint, async {
+                let url = match query {
+                    Some(q) => format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, q),
+                    None => format!("{}{}", self.config.rest_api_endpoint, endpoint),
+                };
+
+                let response = self.request(Method::GET, endpoint, &url).send().await?;
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::from_query("GET", endpoint, query)))
     }
 
     /// Make an unsigned GET request with query parameters as key-value pairs.
@@ -103,24 +371,30 @@ impl Client {
         endpoint: &str,
         query: Option<&str>,
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let url = match query {
-            Some(q) => format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, q),
-            None => format!("{}{}", self.config.rest_api_endpoint, endpoint),
-        };
-
-        let response = self
-            .http
-            .get(&url)
-            .headers(self.build_auth_headers(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("GET", endpoint, async {
+                let api_key = self
+                    .credentials
+                    .as_ref()
+                    .map(Credentials::api_key)
+                    .or(self.api_key.as_deref())
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let url = match query {
+                    Some(q) => format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, q),
+                    None => format!("{}{}", self.config.rest_api_endpoint, endpoint),
+                };
+
+                let response = self
+                    .request(Method::GET, endpoint, &url)
+                    .headers(api_key_headers(api_key)?)
+                    .send()
+                    .await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::from_query("GET", endpoint, query)))
     }
 
     /// Make a signed GET request (requires credentials).
@@ -129,27 +403,27 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let query = build_signed_query_string(
-            params.iter().copied(),
-            credentials,
-            self.config.recv_window,
-        )?;
-
-        let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
-
-        let response = self
-            .http
-            .get(&url)
-            .headers(self.build_auth_headers(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("GET", endpoint, async {
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let query = self.signed_query(params, credentials).await?;
+
+                let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
+
+                let response = self
+                    .request(Method::GET, endpoint, &url)
+                    .headers(self.build_auth_headers(credentials)?)
+                    .send()
+                    .await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("GET", endpoint, params)))
     }
 
     /// Make a signed POST request (requires credentials).
@@ -158,27 +432,29 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let query = build_signed_query_string(
-            params.iter().copied(),
-            credentials,
-            self.config.recv_window,
-        )?;
-
-        let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
-
-        let response = self
-            .http
-            .post(&url)
-            .headers(self.build_auth_headers_with_content_type(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("POST", endpoint, async {
+                let _in_flight = self.begin_order("POST", endpoint)?;
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let query = self.signed_query(params, credentials).await?;
+
+                let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
+
+                let response = self
+                    .request(Method::POST, endpoint, &url)
+                    .headers(self.build_auth_headers_with_content_type(credentials)?)
+                    .send()
+                    .await;
+                let response = self.audit("POST", endpoint, &query, response).await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("POST", endpoint, params)))
     }
 
     /// Make a signed POST request and return the raw response.
@@ -187,27 +463,30 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<reqwest::Response> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let query = build_signed_query_string(
-            params.iter().copied(),
-            credentials,
-            self.config.recv_window,
-        )?;
-
-        let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
-
-        let response = self
-            .http
-            .post(&url)
-            .headers(self.build_auth_headers_with_content_type(credentials)?)
-            .send()
-            .await?;
-
-        Ok(response)
+        let result: Result<reqwest::Response> = self
+            .traced("POST", endpoint, async {
+                let _in_flight = self.begin_order("POST", endpoint)?;
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let query = self.signed_query(params, credentials).await?;
+
+                let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
+
+                let response = self
+                    .request(Method::POST, endpoint, &url)
+                    .headers(self.build_auth_headers_with_content_type(credentials)?)
+                    .send()
+                    .await;
+
+                let response = self.audit("POST", endpoint, &query, response).await?;
+                self.record_response(endpoint, response.status(), response.headers());
+                Ok(response)
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("POST", endpoint, params)))
     }
 
     /// Make a signed DELETE request (requires credentials).
@@ -216,27 +495,29 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let query = build_signed_query_string(
-            params.iter().copied(),
-            credentials,
-            self.config.recv_window,
-        )?;
-
-        let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
-
-        let response = self
-            .http
-            .delete(&url)
-            .headers(self.build_auth_headers_with_content_type(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("DELETE", endpoint, async {
+                let _in_flight = self.begin_order("DELETE", endpoint)?;
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let query = self.signed_query(params, credentials).await?;
+
+                let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
+
+                let response = self
+                    .request(Method::DELETE, endpoint, &url)
+                    .headers(self.build_auth_headers_with_content_type(credentials)?)
+                    .send()
+                    .await;
+                let response = self.audit("DELETE", endpoint, &query, response).await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("DELETE", endpoint, params)))
     }
 
     /// Make a signed PUT request (requires credentials).
@@ -245,27 +526,29 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let query = build_signed_query_string(
-            params.iter().copied(),
-            credentials,
-            self.config.recv_window,
-        )?;
-
-        let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
-
-        let response = self
-            .http
-            .put(&url)
-            .headers(self.build_auth_headers_with_content_type(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("PUT", endpoint, async {
+                let _in_flight = self.begin_order("PUT", endpoint)?;
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let query = self.signed_query(params, credentials).await?;
+
+                let url = format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query);
+
+                let response = self
+                    .request(Method::PUT, endpoint, &url)
+                    .headers(self.build_auth_headers_with_content_type(credentials)?)
+                    .send()
+                    .await;
+                let response = self.audit("PUT", endpoint, &query, response).await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("PUT", endpoint, params)))
     }
 
     /// Make a POST request with API key but no signature (for user stream endpoints).
@@ -274,30 +557,34 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let url = if params.is_empty() {
-            format!("{}{}", self.config.rest_api_endpoint, endpoint)
-        } else {
-            let query = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
-        };
-
-        let response = self
-            .http
-            .post(&url)
-            .headers(self.build_auth_headers(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("POST", endpoint, async {
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let url = if params.is_empty() {
+                    format!("{}{}", self.config.rest_api_endpoint, endpoint)
+                } else {
+                    let query = params
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join("&");
+                    format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
+                };
+
+                let response = self
+                    .request(Method::POST, endpoint, &url)
+                    .headers(self.build_auth_headers(credentials)?)
+                    .send()
+                    .await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("POST", endpoint, params)))
     }
 
     /// Make a PUT request with API key but no signature (for user stream keepalive).
@@ -306,30 +593,34 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
-
-        let url = if params.is_empty() {
-            format!("{}{}", self.config.rest_api_endpoint, endpoint)
-        } else {
-            let query = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
-        };
-
-        let response = self
-            .http
-            .put(&url)
-            .headers(self.build_auth_headers(credentials)?)
-            .send()
-            .await?;
-
-        self.handle_response(response).await
+        let result: Result<T> = self
+            .traced("PUT", endpoint, async {
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let url = if params.is_empty() {
+                    format!("{}{}", self.config.rest_api_endpoint, endpoint)
+                } else {
+                    let query = params
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join("&");
+                    format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
+                };
+
+                let response = self
+                    .request(Method::PUT, endpoint, &url)
+                    .headers(self.build_auth_headers(credentials)?)
+                    .send()
+                    .await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("PUT", endpoint, params)))
     }
 
     /// Make a DELETE request with API key but no signature (for user stream close).
@@ -338,40 +629,114 @@ impl Client {
         endpoint: &str,
         params: &[(&str, &str)],
     ) -> Result<T> {
-        let credentials = self
-            .credentials
-            .as_ref()
-            .ok_or(Error::AuthenticationRequired)?;
+        let result: Result<T> = self
+            .traced("DELETE", endpoint, async {
+                let credentials = self
+                    .credentials
+                    .as_ref()
+                    .ok_or(Error::AuthenticationRequired)?;
+
+                let url = if params.is_empty() {
+                    format!("{}{}", self.config.rest_api_endpoint, endpoint)
+                } else {
+                    let query = params
+                        .iter()
+                        .map(|(k, v)| format!("{}={}", k, v))
+                        .collect::<Vec<_>>()
+                        .join("&");
+                    format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
+                };
+
+                let response = self
+                    .request(Method::DELETE, endpoint, &url)
+                    .headers(self.build_auth_headers(credentials)?)
+                    .send()
+                    .await?;
+
+                self.handle_response(endpoint, response).await
+            })
+            .await;
+        result.map_err(|e| e.with_context(RequestContext::new("DELETE", endpoint, params)))
+    }
 
-        let url = if params.is_empty() {
-            format!("{}{}", self.config.rest_api_endpoint, endpoint)
+    /// Register an in-flight order request so shutdown can wait for it.
+    fn begin_order(&self, method: &str, endpoint: &str) -> Result<Option<InFlightOrder>> {
+        if is_order_action(method, endpoint) {
+            self.shutdown.begin_order().map(Some)
         } else {
-            let query = params
-                .iter()
-                .map(|(k, v)| format!("{}={}", k, v))
-                .collect::<Vec<_>>()
-                .join("&");
-            format!("{}{}?{}", self.config.rest_api_endpoint, endpoint, query)
+            Ok(None)
+        }
+    }
+
+    /// Record a signed request in the audit log if it is an order action.
+    ///
+    /// The response body is buffered so it can be recorded, and a response
+    /// with the same status and body is returned to the caller.
+    async fn audit(
+        &self,
+        method: &str,
+        endpoint: &str,
+        query: &str,
+        response: reqwest_middleware::Result<reqwest::Response>,
+    ) -> Result<reqwest::Response> {
+        let audit_log = match &self.audit_log {
+            Some(log) if AuditLog::is_audited(method, endpoint) => log,
+            _ => return Ok(response?),
+        };
+
+        let response = match response {
+            Ok(response) => response,
+            Err(e) => {
+                audit_log.record_error(method, endpoint, query, e.to_string());
+                return Err(e.into());
+            }
         };
 
-        let response = self
-            .http
-            .delete(&url)
-            .headers(self.build_auth_headers(credentials)?)
-            .send()
-            .await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await?;
+        audit_log.record_response(method, endpoint, query, status.as_u16(), &body);
+
+        let mut buffered = http::Response::new(body);
+        *buffered.status_mut() = status;
+        *buffered.headers_mut() = headers;
+        Ok(reqwest::Response::from(buffered))
+    }
+
+    // Record the status and rate limit headers in the metrics and, with the
+    // `tracing` feature, on the request span.
+    fn record_response(&self, endpoint: &str, status: StatusCode, headers: &HeaderMap) {
+        self.rate_limits.record(headers);
+
+        let metrics = &self.config.metrics;
+        if metrics.is_enabled() {
+            let recorder = metrics.recorder();
+            recorder.record_request(endpoint, status.as_u16());
+            let usage = self.rate_limits.status();
+            for (interval, weight) in &usage.used_weight {
+                recorder.record_used_weight(interval, *weight);
+            }
+            for (interval, count) in &usage.order_count {
+                recorder.record_order_count(interval, *count);
+            }
+        }
 
-        self.handle_response(response).await
+        #[cfg(feature = "tracing")]
+        {
+            let span = tracing::Span::current();
+            span.record("status", status.as_u16());
+            let weight = headers
+                .get("x-mbx-used-weight-1m")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u32>().ok());
+            if let Some(weight) = weight {
+                span.record("used_weight", weight);
+            }
+        }
     }
 
     fn build_auth_headers(&self, credentials: &Credentials) -> Result<HeaderMap> {
-        let mut headers = HeaderMap::new();
-        headers.insert(USER_AGENT, HeaderValue::from_static("binance-api-client-rs"));
-        headers.insert(
-            HeaderName::from_static("x-mbx-apikey"),
-            HeaderValue::from_str(credentials.api_key())?,
-        );
-        Ok(headers)
+        api_key_headers(credentials.api_key())
     }
 
     fn build_auth_headers_with_content_type(&self, credentials: &Credentials) -> Result<HeaderMap> {
@@ -383,9 +748,20 @@ impl Client {
         Ok(headers)
     }
 
-    async fn handle_response<T: DeserializeOwned>(&self, response: reqwest::Response) -> Result<T> {
+    async fn handle_response<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        response: reqwest::Response,
+    ) -> Result<T> {
+        self.record_response(endpoint, response.status(), response.headers());
         match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
+            StatusCode::OK => match &self.schema_drift {
+                Some(detector) => {
+                    let raw: serde_json::Value = response.json().await?;
+                    Ok(detector.deserialize(endpoint, &raw)?)
+                }
+                None => Ok(response.json().await?),
+            },
             StatusCode::INTERNAL_SERVER_ERROR => Err(Error::Api {
                 code: 500,
                 message: "Internal server error".to_string(),
@@ -410,15 +786,34 @@ impl Client {
     }
 }
 
+/// Check if a request creates, cancels or amends an order.
+///
+/// Order queries (`GET`) are not order actions.
+pub(crate) fn is_order_action(method: &str, endpoint: &str) -> bool {
+    method != "GET" && endpoint.to_ascii_lowercase().contains("order")
+}
+
 impl std::fmt::Debug for Client {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Client")
             .field("config", &self.config)
             .field("has_credentials", &self.credentials.is_some())
+            .field("time_offset_ms", &self.time_sync.offset_ms())
+            .field("has_withdraw_policy", &self.withdraw_policy.is_some())
+            .field("has_audit_log", &self.audit_log.is_some())
             .finish()
     }
 }
 
+fn api_key_headers(api_key: &str) -> Result<HeaderMap> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-mbx-apikey"),
+        HeaderValue::from_str(api_key)?,
+    );
+    Ok(headers)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,239 @@
+//! Asset conversion through a graph of ticker prices.
+//!
+//! [`ConversionTable`] treats every trading pair as an edge between its base
+//! and quote assets, so amounts can be converted between any two connected
+//! assets, e.g. ETH to EUR via ETHUSDT and EURUSDT. Conversions use the
+//! path with the fewest hops.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::models::{ExchangeInfo, TickerPrice};
+use crate::types::SymbolStatus;
+use crate::{Binance, Result};
+
+#[derive(Debug, Clone)]
+struct Edge {
+    from: String,
+    to: String,
+    symbol: String,
+    // true if the edge goes from the quote asset to the base asset
+    inverse: bool,
+}
+
+/// Price conversion table built from exchange info and ticker prices.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::ConversionTable;
+///
+/// let mut table = ConversionTable::new();
+/// table.refresh(&client).await?;
+///
+/// if let Some(eur) = table.convert_amount(1.5, "ETH", "EUR") {
+///     println!("1.5 ETH = {:.2} EUR", eur);
+/// }
+///
+/// // Keep prices current from a ticker stream
+/// if let WebSocketEvent::Ticker(ticker) = event {
+///     table.update_price(&ticker.symbol, ticker.close_price);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConversionTable {
+    edges: BTreeMap<String, Vec<Edge>>,
+    symbols: HashSet<String>,
+    prices: HashMap<String, f64>,
+}
+
+impl ConversionTable {
+    /// Create an empty conversion table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a conversion table from exchange info and ticker prices.
+    pub fn from_prices(info: &ExchangeInfo, prices: &[TickerPrice]) -> Self {
+        let mut table = Self::new();
+        table.add_pairs(info);
+        table.update_prices(prices);
+        table
+    }
+
+    /// Register the trading pairs in `info`.
+    ///
+    /// Symbols that are not trading are ignored.
+    pub fn add_pairs(&mut self, info: &ExchangeInfo) {
+        for symbol in &info.symbols {
+            if symbol.status == SymbolStatus::Trading {
+                self.add_pair(&symbol.symbol, &symbol.base_asset, &symbol.quote_asset);
+            }
+        }
+    }
+
+    /// Register a single trading pair.
+    pub fn add_pair(&mut self, symbol: &str, base: &str, quote: &str) {
+        if !self.symbols.insert(symbol.to_string()) {
+            return;
+        }
+        self.edges.entry(base.to_string()).or_default().push(Edge {
+            from: base.to_string(),
+            to: quote.to_string(),
+            symbol: symbol.to_string(),
+            inverse: false,
+        });
+        self.edges.entry(quote.to_string()).or_default().push(Edge {
+            from: quote.to_string(),
+            to: base.to_string(),
+            symbol: symbol.to_string(),
+            inverse: true,
+        });
+    }
+
+    /// Update the price of a symbol.
+    pub fn update_price(&mut self, symbol: &str, price: f64) {
+        if price > 0.0 {
+            self.prices.insert(symbol.to_string(), price);
+        } else {
+            self.prices.remove(symbol);
+        }
+    }
+
+    /// Update the prices of several symbols.
+    pub fn update_prices(&mut self, prices: &[TickerPrice]) {
+        for ticker in prices {
+            self.update_price(&ticker.symbol, ticker.price);
+        }
+    }
+
+    /// Refresh all prices from the REST API.
+    ///
+    /// Exchange info is fetched on the first call to learn the trading pairs.
+    pub async fn refresh(&mut self, client: &Binance) -> Result<()> {
+        let market = client.market();
+        if self.symbols.is_empty() {
+            let info = market.exchange_info().await?;
+            self.add_pairs(&info);
+        }
+        let prices = market.prices().await?;
+        self.update_prices(&prices);
+        Ok(())
+    }
+
+    /// Get the price of one unit of `from` in `to`.
+    pub fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        self.convert_amount(1.0, from, to)
+    }
+
+    /// Convert `amount` of `from` into `to`.
+    ///
+    /// Returns `None` if no path of priced pairs connects the two assets.
+    pub fn convert_amount(&self, amount: f64, from: &str, to: &str) -> Option<f64> {
+        let path = self.path(from, to)?;
+        let mut value = amount;
+        for edge in path {
+            let price = self.prices.get(&edge.symbol)?;
+            if edge.inverse {
+                value /= price;
+            } else {
+                value *= price;
+            }
+        }
+        Some(value)
+    }
+
+    /// Get the symbols used to convert `from` into `to`.
+    pub fn conversion_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        self.path(from, to)
+            .map(|edges| edges.into_iter().map(|e| e.symbol.clone()).collect())
+    }
+
+    // Breadth-first search over priced pairs.
+    fn path(&self, from: &str, to: &str) -> Option<Vec<&Edge>> {
+        if from == to {
+            return Some(Vec::new());
+        }
+
+        let mut previous: HashMap<&str, &Edge> = HashMap::new();
+        let mut visited: HashSet<&str> = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(asset) = queue.pop_front() {
+            for edge in self.edges.get(asset).into_iter().flatten() {
+                if !self.prices.contains_key(&edge.symbol) || !visited.insert(&edge.to) {
+                    continue;
+                }
+                previous.insert(&edge.to, edge);
+                if edge.to == to {
+                    let mut path = Vec::new();
+                    let mut current = to;
+                    while current != from {
+                        let edge = previous[current];
+                        path.push(edge);
+                        current = &edge.from;
+                    }
+                    path.reverse();
+                    return Some(path);
+                }
+                queue.push_back(&edge.to);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> ConversionTable {
+        let mut table = ConversionTable::new();
+        table.add_pair("ETHUSDT", "ETH", "USDT");
+        table.add_pair("EURUSDT", "EUR", "USDT");
+        table.add_pair("BTCUSDT", "BTC", "USDT");
+        table.add_pair("ETHBTC", "ETH", "BTC");
+        table.update_price("ETHUSDT", 3000.0);
+        table.update_price("EURUSDT", 1.2);
+        table.update_price("BTCUSDT", 60000.0);
+        table.update_price("ETHBTC", 0.05);
+        table
+    }
+
+    #[test]
+    fn test_direct_and_inverse_conversion() {
+        let table = table();
+        assert_eq!(table.convert_amount(2.0, "ETH", "USDT"), Some(6000.0));
+        assert_eq!(table.convert_amount(6000.0, "USDT", "ETH"), Some(2.0));
+        assert_eq!(table.convert_amount(5.0, "ETH", "ETH"), Some(5.0));
+        assert_eq!(table.rate("ETH", "BTC"), Some(0.05));
+    }
+
+    #[test]
+    fn test_multi_hop_conversion() {
+        let table = table();
+        let eur = table.convert_amount(1.5, "ETH", "EUR").unwrap();
+        assert!((eur - 3750.0).abs() < 1e-9);
+        assert_eq!(
+            table.conversion_path("ETH", "EUR"),
+            Some(vec!["ETHUSDT".to_string(), "EURUSDT".to_string()])
+        );
+        assert_eq!(table.convert_amount(1.0, "ETH", "JPY"), None);
+    }
+
+    #[test]
+    fn test_unpriced_pairs_are_skipped() {
+        let mut table = table();
+        table.update_price("ETHUSDT", 0.0);
+        let eur = table.convert_amount(1.0, "ETH", "EUR").unwrap();
+        assert!((eur - 2500.0).abs() < 1e-9);
+        assert_eq!(
+            table.conversion_path("ETH", "EUR"),
+            Some(vec![
+                "ETHBTC".to_string(),
+                "BTCUSDT".to_string(),
+                "EURUSDT".to_string()
+            ])
+        );
+    }
+}
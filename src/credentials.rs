@@ -297,8 +297,23 @@ where
     K: AsRef<str>,
     V: AsRef<str>,
 {
-    let timestamp = get_timestamp()?;
+    build_signed_query_string_at(params, credentials, recv_window, get_timestamp()?)
+}
 
+/// Build a signed query string using an explicit timestamp.
+///
+/// This is used when the timestamp is adjusted by the server time offset.
+pub fn build_signed_query_string_at<I, K, V>(
+    params: I,
+    credentials: &Credentials,
+    recv_window: u64,
+    timestamp: u64,
+) -> Result<String>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: AsRef<str>,
+    V: AsRef<str>,
+{
     // Build the base query string
     let mut query_parts: Vec<String> = Vec::new();
 
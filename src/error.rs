@@ -2,6 +2,7 @@ use serde::Deserialize;
 use std::collections::HashMap;
 use thiserror::Error;
 
+use crate::audit::REDACTED;
 use crate::models::account::{CancelReplaceErrorData, CancelReplaceErrorResponse};
 
 /// Binance API error response structure.
@@ -72,6 +73,14 @@ pub enum Error {
     #[error("Invalid header value: {0}")]
     InvalidHeader(#[from] reqwest::header::InvalidHeaderValue),
 
+    /// The client is shutting down and no longer accepts order requests.
+    #[error("Client is shutting down")]
+    ShuttingDown,
+
+    /// I/O error (e.g. writing the audit log).
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
     /// Environment variable error.
     #[error("Environment variable error: {0}")]
     EnvVar(#[from] std::env::VarError),
@@ -79,6 +88,152 @@ pub enum Error {
     /// Invalid credentials (RSA/Ed25519 key parsing error).
     #[error("Invalid credentials: {0}")]
     InvalidCredentials(String),
+
+    /// The symbol does not permit the requested order (checked client-side).
+    #[error("Order not permitted for {symbol}: {reason}")]
+    OrderNotPermitted { symbol: String, reason: String },
+
+    /// The order breaks one of the symbol's exchange filters (checked client-side).
+    #[error("Order violates filters for {symbol}: {violation}")]
+    FilterViolation {
+        symbol: String,
+        violation: FilterViolation,
+    },
+
+    /// A withdrawal was refused by the client-side withdrawal policy.
+    #[error("Withdrawal refused by policy: {0}")]
+    WithdrawRefused(String),
+
+    /// A task run on the configured runtime panicked or was cancelled, e.g.
+    /// because the runtime shut down.
+    #[error("Task failed: {0}")]
+    TaskFailed(String),
+
+    /// An end-to-end check of the `scenarios` feature failed.
+    #[error("Scenario failed: {0}")]
+    ScenarioFailed(String),
+
+    /// An error annotated with the REST request that produced it.
+    ///
+    /// Use [`Error::inner`] to match on the underlying error.
+    #[error("{source} ({context})")]
+    WithContext {
+        context: Box<RequestContext>,
+        source: Box<Error>,
+    },
+}
+
+/// Parameters whose values are never included in error context or the
+/// audit log.
+pub(crate) const REDACTED_PARAMS: &[&str] = &["signature", "listenKey", "apiKey", "secretKey"];
+
+/// An exchange filter an order breaks.
+///
+/// Detected before submission by
+/// [`Symbol::check_filters`](crate::models::Symbol::check_filters); the
+/// exchange would reject the order with error -1013.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum FilterViolation {
+    /// PRICE_FILTER: the price is outside the allowed range.
+    #[error("PRICE_FILTER: price {price} is outside [{min}, {max}]")]
+    PriceOutOfRange { price: f64, min: f64, max: f64 },
+
+    /// PRICE_FILTER: the price is not a multiple of the tick size.
+    #[error("PRICE_FILTER: price {price} is not a multiple of tick size {tick_size}")]
+    PriceTickSize { price: f64, tick_size: f64 },
+
+    /// PERCENT_PRICE: the price is too far from the average price.
+    #[error("PERCENT_PRICE: price {price} is outside [{min}, {max}]")]
+    PercentPrice { price: f64, min: f64, max: f64 },
+
+    /// LOT_SIZE or MARKET_LOT_SIZE: the quantity is outside the allowed range.
+    #[error("{filter}: quantity {quantity} is outside [{min}, {max}]")]
+    QuantityOutOfRange {
+        filter: &'static str,
+        quantity: f64,
+        min: f64,
+        max: f64,
+    },
+
+    /// LOT_SIZE or MARKET_LOT_SIZE: the quantity is not a multiple of the step size.
+    #[error("{filter}: quantity {quantity} is not a multiple of step size {step_size}")]
+    QuantityStepSize {
+        filter: &'static str,
+        quantity: f64,
+        step_size: f64,
+    },
+
+    /// MIN_NOTIONAL or NOTIONAL: the order value is below the minimum.
+    #[error("{filter}: notional {notional} is below {min_notional}")]
+    NotionalTooSmall {
+        filter: &'static str,
+        notional: f64,
+        min_notional: f64,
+    },
+
+    /// NOTIONAL: the order value is above the maximum.
+    #[error("NOTIONAL: notional {notional} is above {max_notional}")]
+    NotionalTooLarge { notional: f64, max_notional: f64 },
+
+    /// A price or quantity could not be parsed as a number.
+    #[error("{field} {value:?} is not a number")]
+    InvalidNumber { field: &'static str, value: String },
+}
+
+/// The REST request an error came from.
+///
+/// Sensitive parameter values (signature, listen key, keys) are redacted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// HTTP method.
+    pub method: String,
+    /// Endpoint path (e.g., "/api/v3/order").
+    pub path: String,
+    /// Request parameters, with sensitive values redacted.
+    pub params: Vec<(String, String)>,
+}
+
+impl RequestContext {
+    /// Create a request context, redacting sensitive parameters.
+    pub fn new(method: &str, path: &str, params: &[(&str, &str)]) -> Self {
+        Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            params: params
+                .iter()
+                .map(|(k, v)| {
+                    let value = if REDACTED_PARAMS.contains(k) {
+                        REDACTED
+                    } else {
+                        v
+                    };
+                    (k.to_string(), value.to_string())
+                })
+                .collect(),
+        }
+    }
+
+    /// Create a request context from a query string.
+    pub fn from_query(method: &str, path: &str, query: Option<&str>) -> Self {
+        let params: Vec<(&str, &str)> = query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| pair.split_once('=').unwrap_or((pair, "")))
+            .collect();
+        Self::new(method, path, &params)
+    }
+}
+
+impl std::fmt::Display for RequestContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        for (i, (k, v)) in self.params.iter().enumerate() {
+            let sep = if i == 0 { '?' } else { '&' };
+            write!(f, "{}{}={}", sep, k, v)?;
+        }
+        Ok(())
+    }
 }
 
 impl Error {
@@ -99,25 +254,93 @@ impl Error {
         }
     }
 
+    /// Attach the request that produced this error.
+    ///
+    /// Errors that already carry a context are returned unchanged.
+    pub fn with_context(self, context: RequestContext) -> Self {
+        match self {
+            Error::WithContext { .. } => self,
+            error => Error::WithContext {
+                context: Box::new(context),
+                source: Box::new(error),
+            },
+        }
+    }
+
+    /// Get the request that produced this error, if known.
+    pub fn context(&self) -> Option<&RequestContext> {
+        match self {
+            Error::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Get the underlying error, without the request context.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// match client.account().get_order("BTCUSDT", Some(1), None).await {
+    ///     Err(e) => match e.inner() {
+    ///         Error::Api { code: -2013, .. } => println!("order does not exist"),
+    ///         _ => println!("{}", e),
+    ///     },
+    ///     Ok(order) => println!("{:?}", order),
+    /// }
+    /// ```
+    pub fn inner(&self) -> &Error {
+        match self {
+            Error::WithContext { source, .. } => source.inner(),
+            error => error,
+        }
+    }
+
+    /// Take the underlying error, dropping the request context.
+    pub fn into_inner(self) -> Error {
+        match self {
+            Error::WithContext { source, .. } => source.into_inner(),
+            error => error,
+        }
+    }
+
     /// Check if this is a rate limit error (code -1003).
     pub fn is_rate_limit(&self) -> bool {
-        matches!(self, Error::Api { code: -1003, .. })
+        matches!(self.inner(), Error::Api { code: -1003, .. })
     }
 
     /// Check if this is an invalid signature error (code -1022).
     pub fn is_invalid_signature(&self) -> bool {
-        matches!(self, Error::Api { code: -1022, .. })
+        matches!(self.inner(), Error::Api { code: -1022, .. })
     }
 
     /// Check if this is a timestamp out of recv_window error (code -1021).
     pub fn is_timestamp_error(&self) -> bool {
-        matches!(self, Error::Api { code: -1021, .. })
+        matches!(self.inner(), Error::Api { code: -1021, .. })
+    }
+
+    /// Check if this is an invalid symbol error (code -1121).
+    pub fn is_invalid_symbol(&self) -> bool {
+        matches!(self.inner(), Error::Api { code: -1121, .. })
+    }
+
+    /// Check if this is an unknown order error (code -2011 or -2013).
+    ///
+    /// Returned when cancelling (-2011) or querying (-2013) an order that
+    /// does not exist or is no longer open.
+    pub fn is_unknown_order(&self) -> bool {
+        matches!(
+            self.inner(),
+            Error::Api {
+                code: -2011 | -2013,
+                ..
+            }
+        )
     }
 
     /// Check if this is an unauthorized error (code -1002 or -2015).
     pub fn is_unauthorized(&self) -> bool {
         matches!(
-            self,
+            self.inner(),
             Error::Api {
                 code: -1002 | -2015,
                 ..
@@ -129,6 +352,58 @@ impl Error {
 /// Result type alias for this library.
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// Extension methods for results of Binance requests.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::ResultExt;
+///
+/// // Cancelling an order that already filled is not an error here
+/// let cancelled = client
+///     .account()
+///     .cancel_order("BTCUSDT", Some(order_id), None)
+///     .await
+///     .ignore_unknown_order()?;
+///
+/// // Fall back to the last known balances if the request fails
+/// let account = client.account().get_account().await.or_stale(last_account.clone())?;
+/// ```
+pub trait ResultExt<T> {
+    /// Treat "unknown order" errors (-2011, -2013) as `Ok(None)`.
+    ///
+    /// Useful when cancelling or querying an order that may already have
+    /// been filled, cancelled or expired.
+    fn ignore_unknown_order(self) -> Result<Option<T>>;
+
+    /// Fall back to a previously fetched value if the request failed.
+    ///
+    /// Returns the error only if there is no stale value to use. The
+    /// fallback is logged as a warning.
+    fn or_stale(self, stale: Option<T>) -> Result<T>;
+}
+
+impl<T> ResultExt<T> for Result<T> {
+    fn ignore_unknown_order(self) -> Result<Option<T>> {
+        match self {
+            Ok(value) => Ok(Some(value)),
+            Err(e) if e.is_unknown_order() => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn or_stale(self, stale: Option<T>) -> Result<T> {
+        match (self, stale) {
+            (Ok(value), _) => Ok(value),
+            (Err(e), Some(stale)) => {
+                tracing::warn!("Request failed, using stale value: {}", e);
+                Ok(stale)
+            }
+            (Err(e), None) => Err(e),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -145,6 +420,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_error_context() {
+        let context = RequestContext::new(
+            "GET",
+            "/api/v3/order",
+            &[("symbol", "BTCUSDT"), ("signature", "abc123")],
+        );
+        let err = Error::Api {
+            code: -1003,
+            message: "Too many requests".to_string(),
+        }
+        .with_context(context.clone());
+
+        assert_eq!(
+            format!("{}", err),
+            "Binance API error -1003: Too many requests \
+             (GET /api/v3/order?symbol=BTCUSDT&signature=<redacted>)"
+        );
+        assert_eq!(err.context(), Some(&context));
+        assert!(err.is_rate_limit());
+
+        // Context is only attached once
+        let err = err.with_context(RequestContext::from_query("GET", "/other", None));
+        assert_eq!(err.context(), Some(&context));
+        assert!(matches!(err.into_inner(), Error::Api { code: -1003, .. }));
+    }
+
+    #[test]
+    fn test_result_ext() {
+        let unknown: Result<u64> = Err(Error::Api {
+            code: -2011,
+            message: "Unknown order sent.".to_string(),
+        }
+        .with_context(RequestContext::new("DELETE", "/api/v3/order", &[])));
+        assert!(matches!(unknown.ignore_unknown_order(), Ok(None)));
+
+        let ok: Result<u64> = Ok(1);
+        assert!(matches!(ok.ignore_unknown_order(), Ok(Some(1))));
+
+        let failed = || -> Result<u64> { Err(Error::AuthenticationRequired) };
+        assert!(failed().ignore_unknown_order().is_err());
+        assert_eq!(failed().or_stale(Some(7)).unwrap(), 7);
+        assert!(failed().or_stale(None).is_err());
+        assert_eq!(Ok(1).or_stale(Some(7)).unwrap(), 1);
+    }
+
     #[test]
     fn test_is_rate_limit() {
         let rate_limit_err = Error::Api {
@@ -178,6 +499,16 @@ mod tests {
         assert!(ts_err.is_timestamp_error());
     }
 
+    #[test]
+    fn test_is_invalid_symbol() {
+        let symbol_err = Error::Api {
+            code: -1121,
+            message: "Invalid symbol.".to_string(),
+        };
+        assert!(symbol_err.is_invalid_symbol());
+        assert!(!symbol_err.is_unknown_order());
+    }
+
     #[test]
     fn test_is_unauthorized() {
         let unauth_err = Error::Api {
@@ -0,0 +1,276 @@
+//! Cached exchange information.
+//!
+//! [`ExchangeInfoCache`] keeps the symbol definitions returned by
+//! `/api/v3/exchangeInfo` so orders can be checked against the symbol's
+//! filters before they are submitted.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::{Mutex, RwLock, watch};
+
+use crate::models::Symbol;
+use crate::rest::{ExchangeInfoParams, NewOrder};
+use crate::supervisor::RestartPolicy;
+use crate::{Binance, Error, Result};
+
+/// Time after a failed TTL reload during which lookups use the stale symbols
+/// instead of reloading again.
+const RELOAD_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Symbol definitions from exchange info, fetched on demand.
+///
+/// Symbols are fetched individually the first time they are needed, or all
+/// at once with [`load`](Self::load). With a [`ttl`](Self::ttl), lookups
+/// reload the whole cache once it is older than the TTL, and
+/// [`auto_refresh`](Self::auto_refresh) reloads it in the background on an
+/// interval. Concurrent lookups share one reload, and keep using the stale
+/// symbols if it fails. Clones share the same cache, so one cache can serve many tasks.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::ExchangeInfoCache;
+///
+/// let cache = ExchangeInfoCache::new(client.clone());
+///
+/// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+///     .quantity("0.001")
+///     .price("50000.00")
+///     .time_in_force(TimeInForce::GTC)
+///     .build();
+///
+/// if let Err(e) = cache.validate(&order).await {
+///     println!("Not sending order: {}", e);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ExchangeInfoCache {
+    client: Binance,
+    params: ExchangeInfoParams,
+    ttl: Option<Duration>,
+    symbols: Arc<RwLock<HashMap<String, Symbol>>>,
+    loaded_at: Arc<RwLock<Option<Instant>>>,
+    // Held while reloading after the TTL, with the time of the last failure
+    reload: Arc<Mutex<Option<Instant>>>,
+}
+
+impl ExchangeInfoCache {
+    /// Create an empty cache.
+    pub fn new(client: Binance) -> Self {
+        Self {
+            client,
+            params: ExchangeInfoParams::default(),
+            ttl: None,
+            symbols: Arc::new(RwLock::new(HashMap::new())),
+            loaded_at: Arc::new(RwLock::new(None)),
+            reload: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Set the query [`load`](Self::load) fetches with, e.g. to only cache
+    /// trading symbols with a given permission.
+    pub fn params(mut self, params: ExchangeInfoParams) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Reload the cache on lookup once it is older than `ttl`.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// Fetch exchange info for every symbol matching the
+    /// [`params`](Self::params), replacing the cached symbols.
+    pub async fn load(&self) -> Result<()> {
+        let info = self
+            .client
+            .market()
+            .exchange_info_with(&self.params)
+            .await?;
+        let symbols = info
+            .symbols
+            .into_iter()
+            .map(|s| (s.symbol.clone(), s))
+            .collect();
+        *self.symbols.write().await = symbols;
+        *self.loaded_at.write().await = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Get a symbol, fetching its exchange info if it is not cached.
+    ///
+    /// Fails with an invalid symbol error (code -1121, see
+    /// [`Error::is_invalid_symbol`]) if the exchange does not list it.
+    pub async fn symbol(&self, symbol: &str) -> Result<Symbol> {
+        let symbol = symbol.to_uppercase();
+        if self.is_stale().await {
+            self.reload_stale().await?;
+        }
+        if let Some(cached) = self.get(&symbol).await {
+            return Ok(cached);
+        }
+
+        let info = self
+            .client
+            .market()
+            .exchange_info_for_symbols(&[&symbol])
+            .await?;
+        let fetched = info
+            .symbols
+            .into_iter()
+            .find(|s| s.symbol == symbol)
+            .ok_or_else(|| Error::Api {
+                code: -1121,
+                message: format!("Invalid symbol {}.", symbol),
+            })?;
+
+        self.symbols.write().await.insert(symbol, fetched.clone());
+        Ok(fetched)
+    }
+
+    /// Get a cached symbol without fetching.
+    pub async fn get(&self, symbol: &str) -> Option<Symbol> {
+        self.symbols
+            .read()
+            .await
+            .get(&symbol.to_uppercase())
+            .cloned()
+    }
+
+    /// Get a copy of every cached symbol, by name.
+    pub async fn symbols(&self) -> HashMap<String, Symbol> {
+        self.symbols.read().await.clone()
+    }
+
+    /// Get the time of the last full [`load`](Self::load).
+    pub async fn loaded_at(&self) -> Option<Instant> {
+        *self.loaded_at.read().await
+    }
+
+    /// Reload the cache every `interval` in a background task.
+    ///
+    /// The first load happens immediately. Failed loads are logged and keep
+    /// the previous symbols. The task is restarted if it panics, and stops
+    /// when the refresher is stopped or dropped or the client shuts down.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let cache = ExchangeInfoCache::new(client.clone());
+    /// let refresher = cache.auto_refresh(Duration::from_secs(3600));
+    ///
+    /// let symbol = cache.symbol("BTCUSDT").await?;
+    /// println!("{}", symbol.round_price(50000.123));
+    /// ```
+    pub fn auto_refresh(&self, interval: Duration) -> ExchangeInfoRefresher {
+        let (stop, stop_rx) = watch::channel(false);
+
+        let cache = self.clone();
+        let shutdown = self.client.shutdown_handle().clone();
+        let runtime = self.client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "exchange-info-refresh", policy, move || {
+            cache.clone().refresh_loop(interval, stop_rx.clone())
+        });
+
+        ExchangeInfoRefresher { stop }
+    }
+
+    async fn refresh_loop(self, interval: Duration, mut stop: watch::Receiver<bool>) {
+        let shutdown = self.client.shutdown_handle().clone();
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                // Stopped, or the refresher was dropped
+                _ = stop.wait_for(|stopped| *stopped) => break,
+                _ = ticker.tick() => {}
+            }
+            if let Err(e) = self.load().await {
+                tracing::warn!("Exchange info refresh failed: {}", e);
+            }
+        }
+    }
+
+    /// Get the number of cached symbols.
+    pub async fn len(&self) -> usize {
+        self.symbols.read().await.len()
+    }
+
+    /// Check whether no symbols are cached.
+    pub async fn is_empty(&self) -> bool {
+        self.symbols.read().await.is_empty()
+    }
+
+    // Whether a TTL is set and the cache was not loaded within it.
+    async fn is_stale(&self) -> bool {
+        let Some(ttl) = self.ttl else {
+            return false;
+        };
+        match *self.loaded_at.read().await {
+            Some(loaded_at) => loaded_at.elapsed() >= ttl,
+            None => true,
+        }
+    }
+
+    // Reload a stale cache once for all waiting callers. A failed reload is
+    // not retried for a while and the stale symbols are kept, unless there
+    // are none.
+    async fn reload_stale(&self) -> Result<()> {
+        let mut failed_at = self.reload.lock().await;
+        if !self.is_stale().await {
+            return Ok(());
+        }
+        let has_symbols = !self.is_empty().await;
+        if has_symbols && failed_at.is_some_and(|t| t.elapsed() < RELOAD_RETRY_DELAY) {
+            return Ok(());
+        }
+
+        match self.load().await {
+            Ok(()) => {
+                *failed_at = None;
+                Ok(())
+            }
+            Err(e) => {
+                *failed_at = Some(Instant::now());
+                if !has_symbols {
+                    return Err(e);
+                }
+                tracing::warn!("Exchange info reload failed, using cached symbols: {}", e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Validate an order against its symbol's exchange filters.
+    ///
+    /// The average price is fetched when a filter needs it (PERCENT_PRICE,
+    /// or the notional of a market order given in base quantity).
+    pub async fn validate(&self, order: &NewOrder) -> Result<()> {
+        let symbol = self.symbol(order.symbol()).await?;
+        let avg_price = if order.needs_avg_price(&symbol) {
+            Some(self.client.market().avg_price(&symbol.symbol).await?.price)
+        } else {
+            None
+        };
+        order.validate(&symbol, avg_price)
+    }
+}
+
+/// Handle to a background task that reloads an [`ExchangeInfoCache`].
+///
+/// The task is stopped when the refresher is dropped.
+#[derive(Debug)]
+pub struct ExchangeInfoRefresher {
+    stop: watch::Sender<bool>,
+}
+
+impl ExchangeInfoRefresher {
+    /// Stop the background task.
+    pub fn stop(&self) {
+        let _ = self.stop.send(true);
+    }
+}
@@ -0,0 +1,394 @@
+//! Post-trade best-execution analysis.
+//!
+//! [`BookRecorder`] keeps a history of top-of-book snapshots, and
+//! [`ExecutionQualityReport`] joins fills (from the user data stream or
+//! `myTrades`) with that history to measure slippage of each order against
+//! the mid price when the order arrived.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::models::account::UserTrade;
+use crate::models::websocket::{BookTickerEvent, ExecutionReportEvent};
+use crate::types::{ExecutionType, OrderSide};
+use crate::ws::DepthCache;
+
+/// Default number of snapshots kept per symbol by [`BookRecorder`].
+const DEFAULT_SNAPSHOT_CAPACITY: usize = 100_000;
+
+/// Top-of-book snapshot.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookSnapshot {
+    /// Time of the snapshot (milliseconds).
+    pub time: u64,
+    /// Best bid price.
+    pub bid: f64,
+    /// Best ask price.
+    pub ask: f64,
+}
+
+impl BookSnapshot {
+    /// Get the mid price.
+    pub fn mid(&self) -> f64 {
+        (self.bid + self.ask) / 2.0
+    }
+}
+
+/// Records top-of-book snapshots per symbol.
+///
+/// Only the most recent `capacity` snapshots of each symbol are kept.
+#[derive(Debug, Clone)]
+pub struct BookRecorder {
+    capacity: usize,
+    snapshots: HashMap<String, VecDeque<BookSnapshot>>,
+}
+
+impl Default for BookRecorder {
+    fn default() -> Self {
+        Self::new(DEFAULT_SNAPSHOT_CAPACITY)
+    }
+}
+
+impl BookRecorder {
+    /// Create a recorder keeping up to `capacity` snapshots per symbol.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            snapshots: HashMap::new(),
+        }
+    }
+
+    /// Record a snapshot.
+    ///
+    /// Snapshots older than the latest recorded one for the symbol are ignored.
+    pub fn record(&mut self, symbol: &str, time: u64, bid: f64, ask: f64) {
+        let snapshots = self.snapshots.entry(symbol.to_string()).or_default();
+        if snapshots.back().is_some_and(|s| s.time > time) {
+            return;
+        }
+        if snapshots.len() == self.capacity {
+            snapshots.pop_front();
+        }
+        snapshots.push_back(BookSnapshot { time, bid, ask });
+    }
+
+    /// Record a book ticker update received at `time`.
+    pub fn record_book_ticker(&mut self, event: &BookTickerEvent, time: u64) {
+        self.record(&event.symbol, time, event.bid_price, event.ask_price);
+    }
+
+    /// Record the top of a depth cache at its last update time.
+    pub fn record_depth(&mut self, cache: &DepthCache) {
+        if let (Some((bid, _)), Some((ask, _)), Some(time)) =
+            (cache.best_bid(), cache.best_ask(), cache.update_time)
+        {
+            self.record(&cache.symbol, time, bid, ask);
+        }
+    }
+
+    /// Get the latest snapshot at or before `time`.
+    pub fn snapshot_at(&self, symbol: &str, time: u64) -> Option<BookSnapshot> {
+        let snapshots = self.snapshots.get(symbol)?;
+        let index = snapshots.partition_point(|s| s.time <= time);
+        index.checked_sub(1).map(|i| snapshots[i])
+    }
+
+    /// Get the mid price at or before `time`.
+    pub fn mid_at(&self, symbol: &str, time: u64) -> Option<f64> {
+        self.snapshot_at(symbol, time).map(|s| s.mid())
+    }
+}
+
+/// A single fill of an order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionFill {
+    /// Symbol.
+    pub symbol: String,
+    /// Order ID.
+    pub order_id: u64,
+    /// Side.
+    pub side: OrderSide,
+    /// Fill price.
+    pub price: f64,
+    /// Fill quantity.
+    pub quantity: f64,
+    /// Commission amount.
+    pub commission: f64,
+    /// Commission asset.
+    pub commission_asset: String,
+    /// Whether the fill was on the maker side.
+    pub is_maker: bool,
+    /// Fill time (milliseconds).
+    pub time: u64,
+    /// Order creation time, if known (milliseconds).
+    pub order_time: Option<u64>,
+}
+
+impl ExecutionFill {
+    /// Build a fill from a `myTrades` record.
+    ///
+    /// The order creation time is unknown, so the first fill of the order is
+    /// used as its arrival time.
+    pub fn from_trade(trade: &UserTrade) -> Self {
+        Self {
+            symbol: trade.symbol.clone(),
+            order_id: trade.order_id,
+            side: if trade.is_buyer {
+                OrderSide::Buy
+            } else {
+                OrderSide::Sell
+            },
+            price: trade.price,
+            quantity: trade.quantity,
+            commission: trade.commission,
+            commission_asset: trade.commission_asset.clone(),
+            is_maker: trade.is_maker,
+            time: trade.time,
+            order_time: None,
+        }
+    }
+
+    /// Build a fill from an execution report, if the report is a trade.
+    pub fn from_report(report: &ExecutionReportEvent) -> Option<Self> {
+        if report.execution_type != ExecutionType::Trade || report.last_executed_quantity <= 0.0 {
+            return None;
+        }
+        Some(Self {
+            symbol: report.symbol.clone(),
+            order_id: report.order_id,
+            side: report.side,
+            price: report.last_executed_price,
+            quantity: report.last_executed_quantity,
+            commission: report.commission,
+            commission_asset: report.commission_asset.clone().unwrap_or_default(),
+            is_maker: report.is_maker,
+            time: report.transaction_time,
+            order_time: Some(report.order_creation_time),
+        })
+    }
+}
+
+/// Execution quality of a single order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderExecutionStats {
+    /// Symbol.
+    pub symbol: String,
+    /// Order ID.
+    pub order_id: u64,
+    /// Side.
+    pub side: OrderSide,
+    /// Arrival time (order creation, or first fill if unknown).
+    pub arrival_time: u64,
+    /// Mid price at arrival, if a snapshot was recorded before it.
+    pub arrival_mid: Option<f64>,
+    /// Volume weighted average fill price.
+    pub avg_price: f64,
+    /// Total filled quantity.
+    pub filled_quantity: f64,
+    /// Total filled quote quantity.
+    pub notional: f64,
+    /// Slippage versus the arrival mid in basis points; positive is worse.
+    pub slippage_bps: Option<f64>,
+    /// Slippage cost in quote asset; positive is worse.
+    pub slippage_cost: Option<f64>,
+    /// Number of fills.
+    pub fills: usize,
+    /// Share of the filled quantity executed as maker (0.0 to 1.0).
+    pub maker_ratio: f64,
+    /// Time from arrival to the last fill (milliseconds).
+    pub duration_ms: u64,
+}
+
+/// Per-order execution quality report.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{BookRecorder, ExecutionFill, ExecutionQualityReport};
+///
+/// let mut book = BookRecorder::default();
+/// let mut fills = Vec::new();
+///
+/// // While trading, record the book and collect fills
+/// match event {
+///     WebSocketEvent::BookTicker(ticker) => book.record_book_ticker(&ticker, now),
+///     WebSocketEvent::ExecutionReport(report) => {
+///         fills.extend(ExecutionFill::from_report(&report));
+///     }
+///     _ => {}
+/// }
+///
+/// // Afterwards
+/// let report = ExecutionQualityReport::build(&fills, &book);
+/// for order in &report.orders {
+///     println!("{} {:?} bps", order.order_id, order.slippage_bps);
+/// }
+/// println!("Average slippage {:?} bps", report.avg_slippage_bps());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExecutionQualityReport {
+    /// Stats of each order, in arrival order.
+    pub orders: Vec<OrderExecutionStats>,
+}
+
+impl ExecutionQualityReport {
+    /// Group fills by order and compare them to the recorded book.
+    pub fn build(fills: &[ExecutionFill], book: &BookRecorder) -> Self {
+        let mut grouped: BTreeMap<(&str, u64), Vec<&ExecutionFill>> = BTreeMap::new();
+        for fill in fills {
+            grouped
+                .entry((fill.symbol.as_str(), fill.order_id))
+                .or_default()
+                .push(fill);
+        }
+
+        let mut orders: Vec<OrderExecutionStats> = grouped
+            .into_values()
+            .filter_map(|fills| order_stats(&fills, book))
+            .collect();
+        orders.sort_by_key(|o| (o.arrival_time, o.order_id));
+
+        Self { orders }
+    }
+
+    /// Get the notional weighted average slippage in basis points.
+    ///
+    /// Orders without an arrival mid are excluded.
+    pub fn avg_slippage_bps(&self) -> Option<f64> {
+        let (weighted, notional) = self
+            .orders
+            .iter()
+            .filter_map(|o| o.slippage_bps.map(|s| (s * o.notional, o.notional)))
+            .fold((0.0, 0.0), |(w, n), (sw, sn)| (w + sw, n + sn));
+        (notional > 0.0).then(|| weighted / notional)
+    }
+
+    /// Get the total slippage cost in quote asset.
+    pub fn total_slippage_cost(&self) -> f64 {
+        self.orders.iter().filter_map(|o| o.slippage_cost).sum()
+    }
+
+    /// Get the total filled notional.
+    pub fn total_notional(&self) -> f64 {
+        self.orders.iter().map(|o| o.notional).sum()
+    }
+}
+
+fn order_stats(fills: &[&ExecutionFill], book: &BookRecorder) -> Option<OrderExecutionStats> {
+    let first = fills.first()?;
+    let filled_quantity: f64 = fills.iter().map(|f| f.quantity).sum();
+    if filled_quantity <= 0.0 {
+        return None;
+    }
+
+    let notional: f64 = fills.iter().map(|f| f.price * f.quantity).sum();
+    let maker_quantity: f64 = fills
+        .iter()
+        .filter(|f| f.is_maker)
+        .map(|f| f.quantity)
+        .sum();
+    let avg_price = notional / filled_quantity;
+    let first_fill = fills.iter().map(|f| f.time).min().unwrap_or_default();
+    let last_fill = fills.iter().map(|f| f.time).max().unwrap_or_default();
+    let arrival_time = fills
+        .iter()
+        .filter_map(|f| f.order_time)
+        .min()
+        .unwrap_or(first_fill);
+
+    let arrival_mid = book.mid_at(&first.symbol, arrival_time);
+    let direction = match first.side {
+        OrderSide::Buy => 1.0,
+        OrderSide::Sell => -1.0,
+    };
+    let slippage_bps = arrival_mid.map(|mid| direction * (avg_price - mid) / mid * 10_000.0);
+    let slippage_cost = arrival_mid.map(|mid| direction * (avg_price - mid) * filled_quantity);
+
+    Some(OrderExecutionStats {
+        symbol: first.symbol.clone(),
+        order_id: first.order_id,
+        side: first.side,
+        arrival_time,
+        arrival_mid,
+        avg_price,
+        filled_quantity,
+        notional,
+        slippage_bps,
+        slippage_cost,
+        fills: fills.len(),
+        maker_ratio: maker_quantity / filled_quantity,
+        duration_ms: last_fill.saturating_sub(arrival_time),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(order_id: u64, side: OrderSide, price: f64, quantity: f64, time: u64) -> ExecutionFill {
+        ExecutionFill {
+            symbol: "BTCUSDT".to_string(),
+            order_id,
+            side,
+            price,
+            quantity,
+            commission: 0.0,
+            commission_asset: "BNB".to_string(),
+            is_maker: false,
+            time,
+            order_time: None,
+        }
+    }
+
+    #[test]
+    fn test_book_recorder_snapshot_at() {
+        let mut book = BookRecorder::new(2);
+        book.record("BTCUSDT", 100, 99.0, 101.0);
+        book.record("BTCUSDT", 200, 101.0, 103.0);
+        book.record("BTCUSDT", 150, 0.0, 0.0);
+        assert_eq!(book.mid_at("BTCUSDT", 50), None);
+        assert_eq!(book.mid_at("BTCUSDT", 100), Some(100.0));
+        assert_eq!(book.mid_at("BTCUSDT", 199), Some(100.0));
+        assert_eq!(book.mid_at("BTCUSDT", 500), Some(102.0));
+
+        book.record("BTCUSDT", 300, 103.0, 105.0);
+        assert_eq!(book.mid_at("BTCUSDT", 100), None);
+    }
+
+    #[test]
+    fn test_report_slippage() {
+        let mut book = BookRecorder::default();
+        book.record("BTCUSDT", 1_000, 99.0, 101.0);
+        book.record("BTCUSDT", 2_000, 109.0, 111.0);
+
+        let mut maker = fill(2, OrderSide::Sell, 99.0, 1.0, 1_500);
+        maker.is_maker = true;
+        let fills = vec![
+            fill(1, OrderSide::Buy, 101.0, 1.0, 1_100),
+            fill(1, OrderSide::Buy, 103.0, 1.0, 1_200),
+            maker,
+            fill(3, OrderSide::Buy, 100.0, 1.0, 500),
+        ];
+
+        let report = ExecutionQualityReport::build(&fills, &book);
+        assert_eq!(report.orders.len(), 3);
+
+        let buy = &report.orders[1];
+        assert_eq!(buy.order_id, 1);
+        assert_eq!(buy.avg_price, 102.0);
+        assert_eq!(buy.filled_quantity, 2.0);
+        assert_eq!(buy.slippage_bps, Some(200.0));
+        assert_eq!(buy.slippage_cost, Some(4.0));
+        assert_eq!(buy.duration_ms, 100);
+
+        let sell = &report.orders[2];
+        assert_eq!(sell.slippage_bps, Some(100.0));
+        assert_eq!(sell.maker_ratio, 1.0);
+
+        // No snapshot before the first fill of order 3
+        assert_eq!(report.orders[0].arrival_mid, None);
+
+        let avg = report.avg_slippage_bps().unwrap();
+        assert!((avg - (200.0 * 204.0 + 100.0 * 99.0) / 303.0).abs() < 1e-9);
+        assert_eq!(report.total_slippage_cost(), 5.0);
+    }
+}
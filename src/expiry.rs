@@ -0,0 +1,590 @@
+//! Expiry of stale GTC orders.
+//!
+//! [`OrderExpiryWatchdog`] tracks the age of resting good-till-cancelled
+//! orders, seeded from the open orders endpoint and kept current from
+//! user data stream execution reports, and cancels (or warns about) orders
+//! that have rested longer than the configured maximum age for their symbol.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use crate::credentials::get_timestamp;
+use crate::models::account::Order;
+use crate::models::websocket::{ExecutionReportEvent, WebSocketEvent};
+use crate::supervisor::RestartPolicy;
+use crate::types::{OrderSide, OrderStatus, TimeInForce};
+use crate::{Binance, Result};
+
+/// What to do with an order that exceeded its maximum age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ExpiryAction {
+    /// Cancel the order.
+    #[default]
+    Cancel,
+    /// Only report the order, once.
+    Warn,
+}
+
+/// Configuration for the order expiry watchdog.
+#[derive(Debug, Clone)]
+pub struct OrderExpiryConfig {
+    /// Maximum age of a resting order, or `None` to only expire symbols
+    /// listed in `symbol_max_age`.
+    pub max_age: Option<Duration>,
+    /// Per-symbol maximum ages overriding `max_age`.
+    pub symbol_max_age: HashMap<String, Duration>,
+    /// Action taken on expired orders.
+    pub action: ExpiryAction,
+    /// How often the background task checks order ages.
+    pub check_interval: Duration,
+}
+
+impl Default for OrderExpiryConfig {
+    fn default() -> Self {
+        Self {
+            max_age: Some(Duration::from_secs(24 * 60 * 60)),
+            symbol_max_age: HashMap::new(),
+            action: ExpiryAction::Cancel,
+            check_interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl OrderExpiryConfig {
+    /// Set the maximum age for a single symbol.
+    pub fn symbol_max_age(mut self, symbol: &str, max_age: Duration) -> Self {
+        self.symbol_max_age.insert(symbol.to_uppercase(), max_age);
+        self
+    }
+
+    fn max_age_for(&self, symbol: &str) -> Option<Duration> {
+        self.symbol_max_age.get(symbol).copied().or(self.max_age)
+    }
+}
+
+/// A resting GTC order tracked by the watchdog.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestingOrder {
+    /// Symbol.
+    pub symbol: String,
+    /// Order ID.
+    pub order_id: u64,
+    /// Client order ID.
+    pub client_order_id: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Limit price.
+    pub price: f64,
+    /// Time the order started resting on the book (milliseconds).
+    pub working_since: u64,
+}
+
+impl RestingOrder {
+    /// Get the age of the order at `now` (milliseconds).
+    pub fn age(&self, now: u64) -> Duration {
+        Duration::from_millis(now.saturating_sub(self.working_since))
+    }
+}
+
+/// Event emitted by the order expiry watchdog.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExpiryEvent {
+    /// An order exceeded its maximum age (warn only).
+    Expired {
+        /// The order.
+        order: RestingOrder,
+        /// Age of the order.
+        age: Duration,
+    },
+    /// An expired order was cancelled.
+    Cancelled {
+        /// The order.
+        order: RestingOrder,
+        /// Age of the order.
+        age: Duration,
+    },
+    /// Cancelling an expired order failed.
+    ///
+    /// An order that was no longer open (already filled or cancelled) stops
+    /// being tracked, so it is reported only once.
+    CancelFailed {
+        /// The order.
+        order: RestingOrder,
+        /// Error message.
+        error: String,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct TrackedOrder {
+    order: RestingOrder,
+    warned: bool,
+}
+
+/// Tracks the age of resting GTC orders.
+#[derive(Debug, Clone, Default)]
+pub struct OrderAgeTracker {
+    orders: HashMap<(String, u64), TrackedOrder>,
+}
+
+impl OrderAgeTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track an order returned by the REST API, if it is a resting GTC order.
+    pub fn insert_order(&mut self, order: &Order) {
+        if order.time_in_force != TimeInForce::GTC || !is_open(order.status) {
+            return;
+        }
+        self.insert(RestingOrder {
+            symbol: order.symbol.clone(),
+            order_id: order.order_id,
+            client_order_id: order.client_order_id.clone(),
+            side: order.side,
+            price: order.price,
+            working_since: order.working_time.unwrap_or(order.time),
+        });
+    }
+
+    /// Update the tracker from a snapshot of the open orders of `symbol` (or
+    /// all symbols), dropping tracked orders that are no longer open.
+    ///
+    /// Orders already tracked keep their warned state.
+    pub fn sync_orders(&mut self, symbol: Option<&str>, orders: &[Order]) {
+        let symbol = symbol.map(str::to_uppercase);
+        let open: HashSet<(&str, u64)> = orders
+            .iter()
+            .map(|o| (o.symbol.as_str(), o.order_id))
+            .collect();
+        self.orders.retain(|(s, order_id), _| {
+            symbol.as_ref().is_some_and(|symbol| symbol != s)
+                || open.contains(&(s.as_str(), *order_id))
+        });
+        for order in orders {
+            if !self
+                .orders
+                .contains_key(&(order.symbol.clone(), order.order_id))
+            {
+                self.insert_order(order);
+            }
+        }
+    }
+
+    /// Update the tracker from an execution report.
+    pub fn apply_report(&mut self, report: &ExecutionReportEvent) {
+        let key = (report.symbol.clone(), report.order_id);
+        if !is_open(report.order_status) {
+            self.orders.remove(&key);
+            return;
+        }
+        if report.time_in_force != TimeInForce::GTC || self.orders.contains_key(&key) {
+            return;
+        }
+        self.insert(RestingOrder {
+            symbol: report.symbol.clone(),
+            order_id: report.order_id,
+            client_order_id: report.client_order_id.clone(),
+            side: report.side,
+            price: report.price,
+            working_since: report.working_time.unwrap_or(report.order_creation_time),
+        });
+    }
+
+    /// Stop tracking an order.
+    pub fn remove(&mut self, symbol: &str, order_id: u64) -> Option<RestingOrder> {
+        self.orders
+            .remove(&(symbol.to_string(), order_id))
+            .map(|t| t.order)
+    }
+
+    /// Get the tracked orders.
+    pub fn orders(&self) -> impl Iterator<Item = &RestingOrder> {
+        self.orders.values().map(|t| &t.order)
+    }
+
+    /// Get the number of tracked orders.
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    /// Check whether no orders are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Get orders older than their maximum age at `now`, oldest first.
+    ///
+    /// With [`ExpiryAction::Warn`], each order is returned only once.
+    pub fn expired(&mut self, now: u64, config: &OrderExpiryConfig) -> Vec<RestingOrder> {
+        let mut expired: Vec<RestingOrder> = self
+            .orders
+            .values_mut()
+            .filter(|t| config.action == ExpiryAction::Cancel || !t.warned)
+            .filter(|t| {
+                config
+                    .max_age_for(&t.order.symbol)
+                    .is_some_and(|max_age| t.order.age(now) >= max_age)
+            })
+            .map(|t| {
+                t.warned = true;
+                t.order.clone()
+            })
+            .collect();
+        expired.sort_by_key(|o| o.working_since);
+        expired
+    }
+
+    fn insert(&mut self, order: RestingOrder) {
+        self.orders.insert(
+            (order.symbol.clone(), order.order_id),
+            TrackedOrder {
+                order,
+                warned: false,
+            },
+        );
+    }
+}
+
+/// Cancels or warns about GTC orders resting longer than a maximum age.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{OrderExpiryConfig, OrderExpiryWatchdog};
+/// use std::time::Duration;
+///
+/// let config = OrderExpiryConfig::default()
+///     .symbol_max_age("BTCUSDT", Duration::from_secs(15 * 60));
+/// let watchdog = OrderExpiryWatchdog::new(client.clone(), config);
+/// watchdog.sync(None).await?;
+///
+/// let mut handle = watchdog.start();
+///
+/// // Keep the tracker current from the user data stream
+/// while let Some(event) = user_stream.next().await {
+///     handle.track(&event?);
+///     while let Some(expiry) = handle.try_next() {
+///         println!("{:?}", expiry);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct OrderExpiryWatchdog {
+    client: Binance,
+    config: OrderExpiryConfig,
+    tracker: Arc<Mutex<OrderAgeTracker>>,
+}
+
+impl OrderExpiryWatchdog {
+    /// Create a watchdog with no tracked orders.
+    pub fn new(client: Binance, config: OrderExpiryConfig) -> Self {
+        Self {
+            client,
+            config,
+            tracker: Arc::new(Mutex::new(OrderAgeTracker::new())),
+        }
+    }
+
+    /// Get the configuration.
+    pub fn config(&self) -> &OrderExpiryConfig {
+        &self.config
+    }
+
+    fn tracker(&self) -> MutexGuard<'_, OrderAgeTracker> {
+        self.tracker.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Track the open orders of `symbol` (or all symbols), dropping tracked
+    /// orders of those symbols that are no longer open.
+    pub async fn sync(&self, symbol: Option<&str>) -> Result<()> {
+        let orders = self.client.account().open_orders(symbol).await?;
+        self.tracker().sync_orders(symbol, &orders);
+        Ok(())
+    }
+
+    /// Update the tracked orders from a user data stream event.
+    pub fn track(&self, event: &WebSocketEvent) {
+        if let WebSocketEvent::ExecutionReport(report) = event {
+            self.tracker().apply_report(report);
+        }
+    }
+
+    /// Get the tracked orders.
+    pub fn resting_orders(&self) -> Vec<RestingOrder> {
+        self.tracker().orders().cloned().collect()
+    }
+
+    /// Check order ages once and act on expired orders.
+    pub async fn check(&self) -> Result<Vec<ExpiryEvent>> {
+        let now = get_timestamp()?;
+        let expired = self.tracker().expired(now, &self.config);
+
+        let mut events = Vec::with_capacity(expired.len());
+        for order in expired {
+            let age = order.age(now);
+            if self.config.action == ExpiryAction::Warn {
+                events.push(ExpiryEvent::Expired { order, age });
+                continue;
+            }
+
+            let result = self
+                .client
+                .account()
+                .cancel_order(&order.symbol, Some(order.order_id), None)
+                .await;
+            match result {
+                Ok(_) => {
+                    self.tracker().remove(&order.symbol, order.order_id);
+                    events.push(ExpiryEvent::Cancelled { order, age });
+                }
+                Err(e) => {
+                    // The order filled or was cancelled without a report
+                    if e.is_unknown_order() {
+                        self.tracker().remove(&order.symbol, order.order_id);
+                    }
+                    events.push(ExpiryEvent::CancelFailed {
+                        order,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Run [`check`](Self::check) every `check_interval` in the background.
+    ///
+    /// The task is restarted if it panics, and stops when the returned
+    /// handle is stopped or dropped or the client shuts down.
+    pub fn start(self) -> OrderExpiryHandle {
+        let (tx, rx) = mpsc::channel(100);
+        let is_stopped = Arc::new(AtomicBool::new(false));
+
+        let watchdog = self.clone();
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = self.client.shutdown_handle().clone();
+        let runtime = self.client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "order-expiry-watchdog", policy, move || {
+            watchdog
+                .clone()
+                .check_loop(is_stopped_clone.clone(), tx.clone())
+        });
+
+        OrderExpiryHandle {
+            watchdog: self,
+            rx,
+            is_stopped,
+        }
+    }
+
+    async fn check_loop(self, is_stopped: Arc<AtomicBool>, tx: mpsc::Sender<ExpiryEvent>) {
+        let shutdown = self.client.shutdown_handle().clone();
+        let mut ticker = tokio::time::interval(self.config.check_interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                // The handle was dropped
+                _ = tx.closed() => break,
+                _ = ticker.tick() => {}
+            }
+            if is_stopped.load(Ordering::SeqCst) {
+                break;
+            }
+
+            match self.check().await {
+                Ok(events) => {
+                    for event in events {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Order expiry check failed: {}", e),
+            }
+        }
+    }
+}
+
+/// Handle to a background watchdog started with [`OrderExpiryWatchdog::start`].
+pub struct OrderExpiryHandle {
+    watchdog: OrderExpiryWatchdog,
+    rx: mpsc::Receiver<ExpiryEvent>,
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl OrderExpiryHandle {
+    /// Update the tracked orders from a user data stream event.
+    pub fn track(&self, event: &WebSocketEvent) {
+        self.watchdog.track(event);
+    }
+
+    /// Get the tracked orders.
+    pub fn resting_orders(&self) -> Vec<RestingOrder> {
+        self.watchdog.resting_orders()
+    }
+
+    /// Receive the next expiry event.
+    pub async fn next(&mut self) -> Option<ExpiryEvent> {
+        self.rx.recv().await
+    }
+
+    /// Receive an expiry event if one is ready.
+    pub fn try_next(&mut self) -> Option<ExpiryEvent> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Stop the background task before its next check.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Drop for OrderExpiryHandle {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn is_open(status: OrderStatus) -> bool {
+    matches!(status, OrderStatus::New | OrderStatus::PartiallyFilled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(symbol: &str, order_id: u64, time: u64, time_in_force: &str) -> Order {
+        serde_json::from_value(serde_json::json!({
+            "symbol": symbol,
+            "orderId": order_id,
+            "orderListId": -1,
+            "clientOrderId": format!("c{}", order_id),
+            "price": "100.0",
+            "origQty": "1.0",
+            "executedQty": "0.0",
+            "cummulativeQuoteQty": "0.0",
+            "status": "NEW",
+            "timeInForce": time_in_force,
+            "type": "LIMIT",
+            "side": "BUY",
+            "stopPrice": "0.0",
+            "icebergQty": "0.0",
+            "time": time,
+            "updateTime": time,
+            "isWorking": true,
+            "origQuoteOrderQty": "0.0"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_expired_orders_use_symbol_max_age() {
+        let config = OrderExpiryConfig {
+            max_age: Some(Duration::from_secs(60)),
+            ..Default::default()
+        }
+        .symbol_max_age("ethusdt", Duration::from_secs(10));
+
+        let mut tracker = OrderAgeTracker::new();
+        tracker.insert_order(&order("BTCUSDT", 1, 0, "GTC"));
+        tracker.insert_order(&order("ETHUSDT", 2, 5_000, "GTC"));
+        tracker.insert_order(&order("ETHUSDT", 3, 0, "IOC"));
+        assert_eq!(tracker.len(), 2);
+
+        let expired = tracker.expired(30_000, &config);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, 2);
+        assert_eq!(expired[0].age(30_000), Duration::from_secs(25));
+
+        let expired = tracker.expired(60_000, &config);
+        let ids: Vec<u64> = expired.iter().map(|o| o.order_id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_warn_reports_each_order_once() {
+        let config = OrderExpiryConfig {
+            max_age: Some(Duration::from_secs(1)),
+            action: ExpiryAction::Warn,
+            ..Default::default()
+        };
+
+        let mut tracker = OrderAgeTracker::new();
+        tracker.insert_order(&order("BTCUSDT", 1, 0, "GTC"));
+        assert_eq!(tracker.expired(5_000, &config).len(), 1);
+        assert!(tracker.expired(10_000, &config).is_empty());
+        assert_eq!(tracker.len(), 1);
+
+        assert!(tracker.remove("BTCUSDT", 1).is_some());
+        assert!(tracker.is_empty());
+    }
+
+    #[test]
+    fn test_sync_orders_drops_closed_orders() {
+        let config = OrderExpiryConfig {
+            max_age: Some(Duration::from_secs(1)),
+            action: ExpiryAction::Warn,
+            ..Default::default()
+        };
+
+        let mut tracker = OrderAgeTracker::new();
+        tracker.insert_order(&order("BTCUSDT", 1, 0, "GTC"));
+        tracker.insert_order(&order("BTCUSDT", 2, 0, "GTC"));
+        tracker.insert_order(&order("ETHUSDT", 3, 0, "GTC"));
+        assert_eq!(tracker.expired(5_000, &config).len(), 3);
+
+        // Order 1 closed while the stream was down; other symbols are kept
+        let snapshot = [order("BTCUSDT", 2, 0, "GTC"), order("BTCUSDT", 4, 0, "GTC")];
+        tracker.sync_orders(Some("btcusdt"), &snapshot);
+        let mut ids: Vec<u64> = tracker.orders().map(|o| o.order_id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![2, 3, 4]);
+        // Only the newly tracked order is reported
+        let expired = tracker.expired(5_000, &config);
+        assert_eq!(expired.len(), 1);
+        assert_eq!(expired[0].order_id, 4);
+
+        tracker.sync_orders(None, &[]);
+        assert!(tracker.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_check_drops_orders_no_longer_open() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v3/order"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .set_body_string(r#"{"code":-2011,"msg":"Unknown order sent."}"#),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = crate::Config::builder()
+            .rest_api_endpoint(mock_server.uri())
+            .build();
+        let client = Binance::with_config(config, Some(("api_key", "secret_key"))).unwrap();
+        let watchdog = OrderExpiryWatchdog::new(client, OrderExpiryConfig::default());
+        watchdog
+            .tracker()
+            .insert_order(&order("BTCUSDT", 1, 0, "GTC"));
+
+        let events = watchdog.check().await.unwrap();
+        assert!(matches!(events[..], [ExpiryEvent::CancelFailed { .. }]));
+        assert!(watchdog.resting_orders().is_empty());
+        // Not cancelled again on the next check
+        assert!(watchdog.check().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,230 @@
+//! Fill progress of working orders.
+//!
+//! [`FillProgressTracker`] turns user data stream execution reports into
+//! [`FillProgress`] events carrying the filled share, remaining quantity and
+//! average price so far, e.g. to drive execution dashboards or to monitor the
+//! child orders of a TWAP.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::models::websocket::ExecutionReportEvent;
+use crate::types::{ExecutionType, OrderSide, OrderStatus};
+
+/// Progress of an order after a fill.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillProgress {
+    /// Symbol.
+    pub symbol: String,
+    /// Order ID.
+    pub order_id: u64,
+    /// Client order ID.
+    pub client_order_id: String,
+    /// Side.
+    pub side: OrderSide,
+    /// Order status after the fill.
+    pub status: OrderStatus,
+    /// Order quantity.
+    pub quantity: f64,
+    /// Quantity filled so far.
+    pub filled_quantity: f64,
+    /// Quantity left to fill.
+    pub remaining_quantity: f64,
+    /// Filled share of the order quantity in percent (0.0 to 100.0).
+    pub filled_pct: f64,
+    /// Volume weighted average fill price so far.
+    pub avg_price: f64,
+    /// Quote quantity filled so far.
+    pub filled_quote_quantity: f64,
+    /// Price of the latest fill.
+    pub last_price: f64,
+    /// Quantity of the latest fill.
+    pub last_quantity: f64,
+    /// Time of the latest fill (milliseconds).
+    pub time: u64,
+}
+
+impl FillProgress {
+    /// Build the progress of an order from an execution report, if the
+    /// report is a fill.
+    pub fn from_report(report: &ExecutionReportEvent) -> Option<Self> {
+        if report.execution_type != ExecutionType::Trade {
+            return None;
+        }
+        let filled = report.cumulative_filled_quantity;
+        let avg_price = if filled > 0.0 {
+            report.cumulative_quote_quantity / filled
+        } else {
+            0.0
+        };
+        let filled_pct = if report.quantity > 0.0 {
+            (filled / report.quantity * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+        Some(Self {
+            symbol: report.symbol.clone(),
+            order_id: report.order_id,
+            client_order_id: report.client_order_id.clone(),
+            side: report.side,
+            status: report.order_status,
+            quantity: report.quantity,
+            filled_quantity: filled,
+            remaining_quantity: (report.quantity - filled).max(0.0),
+            filled_pct,
+            avg_price,
+            filled_quote_quantity: report.cumulative_quote_quantity,
+            last_price: report.last_executed_price,
+            last_quantity: report.last_executed_quantity,
+            time: report.transaction_time,
+        })
+    }
+
+    /// Check whether the order is completely filled.
+    pub fn is_complete(&self) -> bool {
+        self.status == OrderStatus::Filled
+    }
+}
+
+/// Tracks the fill progress of orders from execution reports.
+///
+/// By default every order is tracked; once [`watch`](Self::watch) has been
+/// called only the watched orders are.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::FillProgressTracker;
+/// use binance_api_client::models::websocket::WebSocketEvent;
+///
+/// let mut tracker = FillProgressTracker::new();
+/// tracker.watch("BTCUSDT", order.order_id);
+///
+/// while let Some(event) = user_stream.next().await {
+///     if let Ok(WebSocketEvent::ExecutionReport(report)) = event {
+///         if let Some(progress) = tracker.apply_report(&report) {
+///             println!(
+///                 "{:.1}% filled at {}, {} left",
+///                 progress.filled_pct, progress.avg_price, progress.remaining_quantity
+///             );
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct FillProgressTracker {
+    watched: HashSet<(String, u64)>,
+    orders: HashMap<(String, u64), FillProgress>,
+}
+
+impl FillProgressTracker {
+    /// Create a tracker for every order.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only track the given order. May be called several times.
+    pub fn watch(&mut self, symbol: &str, order_id: u64) {
+        self.watched.insert((symbol.to_string(), order_id));
+    }
+
+    /// Update the tracker from an execution report.
+    ///
+    /// Returns the order's progress if the report is a fill of a tracked
+    /// order. Reports older than the latest recorded fill are ignored.
+    pub fn apply_report(&mut self, report: &ExecutionReportEvent) -> Option<FillProgress> {
+        let key = (report.symbol.clone(), report.order_id);
+        if !self.watched.is_empty() && !self.watched.contains(&key) {
+            return None;
+        }
+        let progress = FillProgress::from_report(report)?;
+        if let Some(previous) = self.orders.get(&key) {
+            if previous.filled_quantity > progress.filled_quantity {
+                return None;
+            }
+        }
+        self.orders.insert(key, progress.clone());
+        Some(progress)
+    }
+
+    /// Get the latest progress of an order.
+    pub fn get(&self, symbol: &str, order_id: u64) -> Option<&FillProgress> {
+        self.orders.get(&(symbol.to_string(), order_id))
+    }
+
+    /// Stop tracking an order.
+    pub fn remove(&mut self, symbol: &str, order_id: u64) -> Option<FillProgress> {
+        let key = (symbol.to_string(), order_id);
+        self.watched.remove(&key);
+        self.orders.remove(&key)
+    }
+
+    /// Get the latest progress of every order with at least one fill.
+    pub fn orders(&self) -> impl Iterator<Item = &FillProgress> {
+        self.orders.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(
+        order_id: u64,
+        last_qty: f64,
+        last_price: f64,
+        filled: f64,
+        quote: f64,
+    ) -> ExecutionReportEvent {
+        let status = if filled >= 2.0 {
+            "FILLED"
+        } else {
+            "PARTIALLY_FILLED"
+        };
+        serde_json::from_value(serde_json::json!({
+            "E": 1, "s": "BTCUSDT", "c": "twap-1", "S": "BUY", "o": "LIMIT", "f": "GTC",
+            "q": "2.0", "p": "101.0", "P": "0", "F": "0", "g": -1, "C": "",
+            "x": "TRADE", "X": status, "r": "NONE", "i": order_id,
+            "l": last_qty.to_string(), "z": filled.to_string(), "L": last_price.to_string(),
+            "n": "0", "N": null, "T": 2, "t": 1, "I": 0, "w": false, "m": false, "M": false,
+            "O": 1, "Z": quote.to_string(), "Y": "0", "Q": "0"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fill_progress() {
+        let mut tracker = FillProgressTracker::new();
+        tracker.watch("BTCUSDT", 1);
+
+        let progress = tracker
+            .apply_report(&report(1, 0.5, 100.0, 0.5, 50.0))
+            .unwrap();
+        assert_eq!(progress.filled_pct, 25.0);
+        assert_eq!(progress.remaining_quantity, 1.5);
+        assert_eq!(progress.avg_price, 100.0);
+        assert!(!progress.is_complete());
+
+        // Unwatched orders are ignored
+        assert!(
+            tracker
+                .apply_report(&report(2, 0.5, 100.0, 0.5, 50.0))
+                .is_none()
+        );
+
+        let progress = tracker
+            .apply_report(&report(1, 1.5, 102.0, 2.0, 203.0))
+            .unwrap();
+        assert_eq!(progress.filled_pct, 100.0);
+        assert_eq!(progress.remaining_quantity, 0.0);
+        assert_eq!(progress.avg_price, 101.5);
+        assert!(progress.is_complete());
+
+        // Out-of-order reports are ignored
+        assert!(
+            tracker
+                .apply_report(&report(1, 0.5, 100.0, 0.5, 50.0))
+                .is_none()
+        );
+        assert_eq!(tracker.get("BTCUSDT", 1).unwrap().filled_quantity, 2.0);
+    }
+}
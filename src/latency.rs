@@ -0,0 +1,364 @@
+//! Multi-region latency probing.
+//!
+//! Binance serves the Spot API from several hosts (`api.binance.com`,
+//! `api-gcp.binance.com`, `api1`..`api4`) and WebSocket streams on more than
+//! one port. Depending on where a bot runs, one of them is often noticeably
+//! faster. [`LatencyProbe`] measures the round-trip latency to each host and
+//! recommends the fastest one; [`LatencyMonitor`] re-probes periodically so
+//! long-running services can switch hosts when conditions change.
+
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use futures_util::{SinkExt, StreamExt};
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::Result;
+use crate::config::{ALTERNATE_REST_API_ENDPOINTS, ALTERNATE_WS_ENDPOINTS, Config};
+
+/// Default number of samples taken per host.
+pub const DEFAULT_PROBE_SAMPLES: usize = 3;
+
+/// Default timeout for a single probe sample.
+pub const DEFAULT_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// API endpoints.
+const API_V3_PING: &str = "/api/v3/ping";
+
+/// Latency measured for a single host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostLatency {
+    /// Base URL of the host.
+    pub endpoint: String,
+    /// Median round-trip time of the successful samples.
+    pub median: Option<Duration>,
+    /// Fastest round-trip time of the successful samples.
+    pub min: Option<Duration>,
+    /// Number of samples that failed or timed out.
+    pub failures: usize,
+}
+
+impl HostLatency {
+    fn from_samples(endpoint: &str, mut samples: Vec<Duration>, failures: usize) -> Self {
+        samples.sort();
+        Self {
+            endpoint: endpoint.to_string(),
+            median: samples.get(samples.len() / 2).copied(),
+            min: samples.first().copied(),
+            failures,
+        }
+    }
+
+    /// Check if at least one sample succeeded.
+    pub fn is_reachable(&self) -> bool {
+        self.median.is_some()
+    }
+}
+
+/// Result of probing all hosts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyReport {
+    /// REST API hosts.
+    pub rest: Vec<HostLatency>,
+    /// WebSocket hosts.
+    pub ws: Vec<HostLatency>,
+}
+
+impl LatencyReport {
+    /// Get the reachable REST host with the lowest median latency.
+    pub fn fastest_rest(&self) -> Option<&HostLatency> {
+        fastest(&self.rest)
+    }
+
+    /// Get the reachable WebSocket host with the lowest median latency.
+    pub fn fastest_ws(&self) -> Option<&HostLatency> {
+        fastest(&self.ws)
+    }
+
+    /// Point a configuration at the fastest reachable hosts.
+    ///
+    /// Endpoints are left unchanged when no host of that kind was reachable.
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(host) = self.fastest_rest() {
+            config.rest_api_endpoint = host.endpoint.clone();
+        }
+        if let Some(host) = self.fastest_ws() {
+            config.ws_endpoint = host.endpoint.clone();
+        }
+    }
+}
+
+fn fastest(hosts: &[HostLatency]) -> Option<&HostLatency> {
+    hosts
+        .iter()
+        .filter(|h| h.is_reachable())
+        .min_by_key(|h| (h.median, h.failures))
+}
+
+/// Measures REST and WebSocket round-trip latency to alternate Binance hosts.
+///
+/// REST latency is the time taken by `GET /api/v3/ping`. WebSocket latency
+/// is the ping/pong round-trip on an established connection.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{Binance, Config, LatencyProbe};
+///
+/// let report = LatencyProbe::new().probe().await?;
+/// if let Some(host) = report.fastest_rest() {
+///     println!("Fastest REST host: {} ({:?})", host.endpoint, host.median);
+/// }
+///
+/// let mut config = Config::default();
+/// report.apply(&mut config);
+/// let client = Binance::with_config(config, None::<(&str, &str)>)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct LatencyProbe {
+    rest_endpoints: Vec<String>,
+    ws_endpoints: Vec<String>,
+    samples: usize,
+    timeout: Duration,
+}
+
+impl Default for LatencyProbe {
+    fn default() -> Self {
+        Self {
+            rest_endpoints: ALTERNATE_REST_API_ENDPOINTS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            ws_endpoints: ALTERNATE_WS_ENDPOINTS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            samples: DEFAULT_PROBE_SAMPLES,
+            timeout: DEFAULT_PROBE_TIMEOUT,
+        }
+    }
+}
+
+impl LatencyProbe {
+    /// Create a probe for the production Spot hosts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the REST API hosts to probe.
+    pub fn rest_endpoints<I, S>(mut self, endpoints: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.rest_endpoints = endpoints.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the WebSocket hosts to probe.
+    pub fn ws_endpoints<I, S>(mut self, endpoints: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.ws_endpoints = endpoints.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set the number of samples taken per host (at least 1).
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples.max(1);
+        self
+    }
+
+    /// Set the timeout for a single sample.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Probe all REST and WebSocket hosts concurrently.
+    pub async fn probe(&self) -> Result<LatencyReport> {
+        let (rest, ws) = tokio::join!(self.probe_rest(), self.probe_ws());
+        Ok(LatencyReport { rest: rest?, ws })
+    }
+
+    /// Probe the REST API hosts concurrently.
+    pub async fn probe_rest(&self) -> Result<Vec<HostLatency>> {
+        let http = reqwest::Client::builder().timeout(self.timeout).build()?;
+        let probes = self
+            .rest_endpoints
+            .iter()
+            .map(|endpoint| self.probe_rest_host(&http, endpoint));
+        Ok(join_all(probes).await)
+    }
+
+    /// Probe the WebSocket hosts concurrently.
+    pub async fn probe_ws(&self) -> Vec<HostLatency> {
+        let probes = self
+            .ws_endpoints
+            .iter()
+            .map(|endpoint| self.probe_ws_host(endpoint));
+        join_all(probes).await
+    }
+
+    /// Periodically re-probe all hosts in a background task.
+    pub fn monitor(self, interval: Duration) -> LatencyMonitor {
+        let (tx, rx) = watch::channel(None);
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                match self.probe().await {
+                    Ok(report) => {
+                        if tx.send(Some(report)).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => tracing::warn!("Latency probe failed: {}", e),
+                }
+            }
+        });
+        LatencyMonitor { rx, handle }
+    }
+
+    async fn probe_rest_host(&self, http: &reqwest::Client, endpoint: &str) -> HostLatency {
+        let url = format!("{}{}", endpoint, API_V3_PING);
+        let mut samples = Vec::with_capacity(self.samples);
+        let mut failures = 0;
+
+        for _ in 0..self.samples {
+            let start = Instant::now();
+            match http.get(&url).send().await {
+                Ok(response) if response.status().is_success() => samples.push(start.elapsed()),
+                _ => failures += 1,
+            }
+        }
+
+        HostLatency::from_samples(endpoint, samples, failures)
+    }
+
+    async fn probe_ws_host(&self, endpoint: &str) -> HostLatency {
+        let url = format!("{}/ws", endpoint);
+        let connected = tokio::time::timeout(self.timeout, connect_async(url.as_str())).await;
+        let mut ws = match connected {
+            Ok(Ok((ws, _))) => ws,
+            _ => return HostLatency::from_samples(endpoint, Vec::new(), self.samples),
+        };
+
+        let mut samples = Vec::with_capacity(self.samples);
+        let mut failures = 0;
+
+        for i in 0..self.samples {
+            let payload = (i as u64).to_be_bytes().to_vec();
+            let start = Instant::now();
+            let round_trip = async {
+                ws.send(Message::Ping(payload.clone().into())).await.ok()?;
+                while let Some(Ok(msg)) = ws.next().await {
+                    if let Message::Pong(data) = msg {
+                        if data.as_ref() == payload.as_slice() {
+                            return Some(());
+                        }
+                    }
+                }
+                None
+            };
+            match tokio::time::timeout(self.timeout, round_trip).await {
+                Ok(Some(())) => samples.push(start.elapsed()),
+                _ => failures += 1,
+            }
+        }
+
+        let _ = ws.close(None).await;
+        HostLatency::from_samples(endpoint, samples, failures)
+    }
+}
+
+/// Handle to a background task that periodically re-probes hosts.
+///
+/// The task is stopped when the monitor is dropped.
+#[derive(Debug)]
+pub struct LatencyMonitor {
+    rx: watch::Receiver<Option<LatencyReport>>,
+    handle: JoinHandle<()>,
+}
+
+impl LatencyMonitor {
+    /// Get the most recent report, if a probe has completed.
+    pub fn latest(&self) -> Option<LatencyReport> {
+        self.rx.borrow().clone()
+    }
+
+    /// Subscribe to new reports.
+    pub fn subscribe(&self) -> watch::Receiver<Option<LatencyReport>> {
+        self.rx.clone()
+    }
+
+    /// Get a copy of `base` pointed at the fastest hosts from the latest report.
+    pub fn recommended_config(&self, base: &Config) -> Config {
+        let mut config = base.clone();
+        if let Some(report) = self.rx.borrow().as_ref() {
+            report.apply(&mut config);
+        }
+        config
+    }
+
+    /// Stop the background task.
+    pub fn stop(&self) {
+        self.handle.abort();
+    }
+}
+
+impl Drop for LatencyMonitor {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn host(endpoint: &str, millis: &[u64], failures: usize) -> HostLatency {
+        let samples = millis.iter().map(|m| Duration::from_millis(*m)).collect();
+        HostLatency::from_samples(endpoint, samples, failures)
+    }
+
+    #[test]
+    fn test_host_latency_from_samples() {
+        let h = host("https://api1.binance.com", &[30, 10, 20], 0);
+        assert_eq!(h.median, Some(Duration::from_millis(20)));
+        assert_eq!(h.min, Some(Duration::from_millis(10)));
+        assert!(h.is_reachable());
+
+        let down = host("https://api2.binance.com", &[], 3);
+        assert!(!down.is_reachable());
+        assert_eq!(down.failures, 3);
+    }
+
+    #[test]
+    fn test_report_selects_fastest() {
+        let report = LatencyReport {
+            rest: vec![
+                host("https://api.binance.com", &[50, 60, 55], 0),
+                host("https://api-gcp.binance.com", &[], 3),
+                host("https://api3.binance.com", &[20, 25, 22], 0),
+            ],
+            ws: vec![],
+        };
+
+        assert_eq!(
+            report.fastest_rest().unwrap().endpoint,
+            "https://api3.binance.com"
+        );
+        assert!(report.fastest_ws().is_none());
+
+        let mut config = Config::default();
+        report.apply(&mut config);
+        assert_eq!(config.rest_api_endpoint, "https://api3.binance.com");
+        assert_eq!(config.ws_endpoint, Config::default().ws_endpoint);
+    }
+}
@@ -12,6 +12,14 @@
 //! - Production and testnet environment support
 //! - Binance.US support
 //!
+//! # Optional Features
+//!
+//! - `broker` - Binance Link broker endpoints ([`Binance::broker`])
+//! - `institutional-loan` - Institutional (VIP) loan endpoints
+//!   ([`Binance::institutional_loan`])
+//! - `tracing` - Spans around REST requests and background tasks, and
+//!   WebSocket reconnect events
+//!
 //! # Quick Start
 //!
 //! ## Public API (No Authentication Required)
@@ -53,6 +61,15 @@
 //! }
 //! ```
 //!
+//! ## Prelude
+//!
+//! The [`prelude`] re-exports the client, order builders, common enums and
+//! key models for a single glob import:
+//!
+//! ```rust,ignore
+//! use binance_api_client::prelude::*;
+//! ```
+//!
 //! ## Using Testnet
 //!
 //! ```rust,ignore
@@ -79,37 +96,92 @@
 )]
 
 pub mod rest;
+pub mod audit;
 pub mod client;
 pub mod config;
+pub mod conversion;
 pub mod credentials;
 pub mod error;
+pub mod exchange_info;
+pub mod execution_quality;
+pub mod expiry;
+pub mod fill_progress;
+pub mod latency;
+pub mod metrics;
 pub mod models;
+pub mod network_status;
+pub mod prelude;
+pub mod rate_limit;
+pub mod runtime;
+#[cfg(feature = "scenarios")]
+pub mod scenarios;
+pub mod schema_drift;
+pub mod shutdown;
+pub mod statement;
+pub mod supervisor;
+pub mod sweep;
+pub mod time_sync;
 pub mod types;
+pub mod withdraw_policy;
 pub mod ws;
 
 // Re-export main types at crate root
+pub use audit::{AuditLog, AuditRecord, AuditSink, JsonlAuditSink};
 pub use client::Client;
 pub use config::{Config, ConfigBuilder};
+pub use conversion::ConversionTable;
 pub use credentials::{Credentials, SignatureType};
-pub use error::{Error, Result};
+pub use error::{Error, FilterViolation, RequestContext, Result, ResultExt};
+pub use exchange_info::{ExchangeInfoCache, ExchangeInfoRefresher};
+pub use execution_quality::{
+    BookRecorder, BookSnapshot, ExecutionFill, ExecutionQualityReport, OrderExecutionStats,
+};
+pub use expiry::{
+    ExpiryAction, ExpiryEvent, OrderAgeTracker, OrderExpiryConfig, OrderExpiryHandle,
+    OrderExpiryWatchdog, RestingOrder,
+};
+pub use fill_progress::{FillProgress, FillProgressTracker};
+pub use latency::{HostLatency, LatencyMonitor, LatencyProbe, LatencyReport};
+pub use metrics::{Metrics, MetricsRecorder};
+pub use network_status::{
+    NetworkStatus, NetworkStatusEvent, NetworkStatusHandle, NetworkStatusTracker,
+    NetworkStatusWatcher,
+};
+pub use rate_limit::{RateLimitStatus, RateLimitTracker};
+pub use runtime::{RuntimeHints, RuntimeKind};
+pub use schema_drift::{SchemaDriftDetector, SchemaDriftReport};
+pub use shutdown::{ShutdownHandle, ShutdownReport};
+pub use statement::{StatementEntry, StatementEntryKind, StatementExporter};
+pub use supervisor::{RestartPolicy, TaskEvent};
+pub use sweep::{EarnSweeper, SweepEvent, SweepRule, SweepScheduler};
+pub use time_sync::{ClockSkew, TimeSync};
+pub use withdraw_policy::{AllowedAddress, WithdrawPolicy};
 pub use ws::{
-    ConnectionHealthMonitor, ConnectionState, DepthCache, DepthCacheConfig, DepthCacheManager,
-    DepthCacheState, ReconnectConfig, ReconnectingWebSocket, UserDataStreamManager,
-    WebSocketClient, WebSocketConnection, WebSocketEventStream,
+    BestBidOffer, BookTickerCache, ConnectionHealthMonitor, ConnectionState, DepthCache,
+    DepthCacheConfig, DepthCacheEvent, DepthCacheManager, DepthCacheState, DepthCacheStats,
+    DepthCacheWatch, KlineCache, KlineCacheConfig, KlineCacheManager, MultiDepthCacheManager,
+    OverflowPolicy, ReconnectConfig, ReconnectingWebSocket, RetryMode, SpreadAlert, SpreadMonitor,
+    SpreadMonitorConfig, SpreadStats, StreamEvent, StreamMultiplexer, Subscription,
+    SubscriptionManager, UserDataReconciliation, UserDataStreamConfig, UserDataStreamEvent,
+    UserDataStreamManager, UserDataStreamStatus, WebSocketClient, WebSocketConnection,
+    WebSocketEventStream,
 };
 
 // Re-export commonly used types
 pub use types::{
-    AccountType, CancelReplaceMode, CancelReplaceResult, CancelRestrictions, ContingencyType,
-    ExecutionType, KlineInterval, OcoOrderStatus, OcoStatus, OrderRateLimitExceededMode,
-    OrderResponseType, OrderSide, OrderStatus, OrderType, RateLimitInterval, RateLimitType,
-    SymbolPermission, SymbolStatus, TickerType, TimeInForce,
+    AccountKind, AccountType, CancelReplaceMode, CancelReplaceResult, CancelRestrictions,
+    ContingencyType, DepthLimit, ExecutionType, KlineInterval, OcoOrderStatus, OcoStatus,
+    OrderRateLimitExceededMode, OrderResponseType, OrderSide, OrderStatus, OrderType,
+    PegOffsetType, PegPriceType, QtyParam, RateLimitInterval, RateLimitType,
+    SelfTradePreventionMode, SymbolPermission, SymbolStatus, TickerType, TimeInForce, TimeUnit,
+    WorkingFloor,
 };
 
 // Re-export commonly used models
 pub use models::{
     // Account models
     AccountCommission,
+    AccountDiff,
     AccountInfo,
     // Wallet models
     AccountSnapshot,
@@ -123,48 +195,81 @@ pub use models::{
     AmendedOrderInfo,
     ApiKeyPermissions,
     ApiTradingStatus,
+    ApiTradingStatusData,
     AssetDetail,
+    AssetDividendRecord,
+    AssetDividendRecords,
     AveragePrice,
     Balance,
+    BalanceChange,
     // Margin models
     BnbBurnStatus,
     BookTicker,
+    BorrowRepayRecord,
+    BorrowRepayType,
     CancelOrderResponse,
     CancelReplaceErrorData,
     CancelReplaceErrorInfo,
     CancelReplaceErrorResponse,
     CancelReplaceResponse,
     CancelReplaceSideResponse,
+    CanceledOpenOrder,
     CoinInfo,
     CoinNetwork,
+    ConvertAccountType,
+    ConvertTransferHistory,
+    ConvertTransferRecord,
+    ConvertibleCoins,
+    CrossMarginFeeData,
     DepositAddress,
+    DepositAddressEntry,
+    DepositCreditResult,
     DepositRecord,
     DepositStatus,
+    DustAsset,
+    DustAssets,
+    DustLog,
+    DustLogDetail,
+    DustLogEntry,
+    DustTransferItem,
+    DustTransferResult,
     ExchangeInfo,
     Fill,
+    ForceLiquidationRecord,
     FundingAsset,
     InterestHistoryRecord,
     InterestRateRecord,
     IsolatedAccountLimit,
+    IsolatedAccountToggle,
     IsolatedAssetDetails,
     IsolatedMarginAccountAsset,
     IsolatedMarginAccountDetails,
+    IsolatedMarginFeeCoin,
+    IsolatedMarginFeeData,
+    IsolatedMarginPair,
     IsolatedMarginTransferType,
     Kline,
     ListenKey,
     LoanRecord,
+    ManualLiquidationResult,
     MarginAccountDetails,
     MarginAsset,
     MarginAssetInfo,
+    MarginAvailableInventory,
+    MarginMaxLeverage,
     MarginOrderCancellation,
     MarginOrderResult,
     MarginOrderState,
     MarginPairDetails,
     MarginPriceIndex,
     MarginTrade,
+    MarginTradeCoeff,
     MarginTransferType,
+    MarketSnapshot,
     MaxBorrowableAmount,
+    MaxLeverageResult,
     MaxTransferableAmount,
+    NextHourlyInterestRate,
     OcoOrder,
     OcoOrderDetail,
     OcoOrderReport,
@@ -176,6 +281,7 @@ pub use models::{
     OrderFull,
     OrderResponse,
     OrderResult,
+    PlacedOrder,
     PreventedMatch,
     RateLimit,
     RecordsQueryResult,
@@ -184,17 +290,22 @@ pub use models::{
     RollingWindowTickerMini,
     ServerTime,
     SideEffectType,
+    SmallLiabilityAsset,
+    SmallLiabilityExchangeRecord,
     SorOrderCommissionRates,
     SorOrderTestResponse,
     Symbol,
     SymbolFilter,
     SystemStatus,
     Ticker24h,
+    Ticker24hMini,
     TickerPrice,
     Trade,
     TradeFee,
     TradingDayTicker,
     TradingDayTickerMini,
+    TradingIndicator,
+    TradingTriggerCondition,
     TransactionId,
     TransferHistory,
     TransferRecord,
@@ -203,22 +314,63 @@ pub use models::{
     UniversalTransferType,
     UserTrade,
     WalletBalance,
+    WithdrawAddress,
     WithdrawRecord,
     WithdrawResponse,
     WithdrawStatus,
+    WithdrawWalletType,
+    // Algo trading models
+    algo::{
+        AlgoCancelResponse, AlgoOrder, AlgoOrderResponse, AlgoOrders, AlgoStatus, AlgoSubOrder,
+        AlgoSubOrders,
+    },
+    // Simple Earn models
+    earn::{EarnAccount, EarnRedeemResult, EarnSubscribeResult, FlexiblePosition, FlexibleProduct},
+    // Fiat and C2C models
+    fiat::{C2cTrade, FiatHistory, FiatOrder, FiatPayment, FiatPaymentType, FiatTransactionType},
+    // Crypto loan models
+    loan::{
+        FlexibleCollateralAsset, FlexibleLoanBorrowRecord, FlexibleLoanBorrowResult,
+        FlexibleLoanLtvAdjustRecord, FlexibleLoanLtvAdjustResult, FlexibleLoanOngoingOrder,
+        FlexibleLoanRepayRecord, FlexibleLoanRepayResult, FlexibleLoanableAsset,
+        LtvAdjustDirection, LtvStatus,
+    },
+    // Staking models
+    staking::{
+        EthRedeemResult, EthRedemptionRecord, EthRewardRecord, EthStakeResult, EthStakingAccount,
+        EthStakingHoldings, EthStakingProfit, EthStakingQuota, EthStakingRecord, SolRedeemResult,
+        SolRedemptionRecord, SolRewardRecord, SolStakeResult, SolStakingAccount, SolStakingQuota,
+        SolStakingRecord, WbethWrapRecord, WbethWrapResult,
+    },
     // WebSocket models
     websocket::{
-        AccountBalance, AccountPositionEvent, AggTradeEvent, BalanceUpdateEvent, BookTickerEvent,
-        DepthEvent, DepthLevel, ExecutionReportEvent, KlineData, KlineEvent, ListStatusEvent,
-        ListStatusOrder, MiniTickerEvent, TickerEvent, TradeEvent, WebSocketEvent,
+        AccountBalance, AccountPositionEvent, AggTradeEvent, AvgPriceEvent, BalanceUpdateEvent,
+        BookTickerEvent, DepthEvent, DepthLevel, EventStreamTerminatedEvent, ExecutionReportEvent,
+        ExternalLockUpdateEvent, KlineData, KlineEvent, ListStatusEvent, ListStatusOrder,
+        ListenKeyExpiredEvent, MiniTickerEvent, RollingWindowTickerEvent, TickerEvent, TradeEvent,
+        WebSocketEvent,
     },
 };
 
+// Broker models
+#[cfg(feature = "broker")]
+pub use models::broker::{
+    BrokerCommission, BrokerInfo, BrokerIpRestriction, BrokerRebate, BrokerSubAccount,
+};
+
+// Institutional loan models
+#[cfg(feature = "institutional-loan")]
+pub use models::institutional_loan::{
+    InstitutionalCollateralAccount, InstitutionalLoanOrder, InstitutionalLoanRepayRecord,
+    InstitutionalLoanRepayResult, InstitutionalLoanableAsset,
+};
+
 // Re-export order builders for convenience
 pub use rest::{
-    CancelReplaceOrder, CancelReplaceOrderBuilder, NewOcoOrder, NewOpoOrder, NewOpocoOrder,
-    NewOrder, NewOtoOrder, NewOtocoOrder, OcoOrderBuilder, OpoOrderBuilder, OpocoOrderBuilder,
-    OrderBuilder, OtoOrderBuilder, OtocoOrderBuilder,
+    AlgoOrderBuilder, CancelOutcome, CancelReplaceOrder, CancelReplaceOrderBuilder,
+    ExchangeInfoParams, NewAlgoOrder, NewOcoOrder, NewOpoOrder, NewOpocoOrder, NewOrder,
+    NewOtoOrder, NewOtocoOrder, NewWithdraw, OcoOrderBuilder, OpoOrderBuilder, OpocoOrderBuilder,
+    OrderBuilder, OtoOrderBuilder, OtocoOrderBuilder, QuoteRefresh, QuoteUpdate, WithdrawBuilder,
 };
 
 /// Main entry point for the Binance API client.
@@ -276,6 +428,27 @@ impl Binance {
         Ok(Self { client })
     }
 
+    /// Create a Binance client with an API key but no secret.
+    ///
+    /// Besides public endpoints, the client can call endpoints that need the
+    /// API key header but no signature, such as
+    /// [`historical_trades`](rest::Market::historical_trades).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use binance_api_client::{Binance, Config};
+    ///
+    /// # fn run() -> binance_api_client::Result<()> {
+    /// let client = Binance::with_api_key(Config::default(), "api_key")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_key(config: Config, api_key: impl Into<String>) -> Result<Self> {
+        let client = Client::new_with_api_key(config, api_key)?;
+        Ok(Self { client })
+    }
+
     /// Create a new Binance client with custom configuration.
     ///
     /// # Arguments
@@ -313,6 +486,24 @@ impl Binance {
         Ok(Self { client })
     }
 
+    /// Create a new Binance client with prebuilt credentials.
+    ///
+    /// Use this for RSA or Ed25519 keys.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{Binance, Config, Credentials};
+    ///
+    /// let pem = std::fs::read_to_string("ed25519-private.pem")?;
+    /// let credentials = Credentials::with_ed25519_pem("api_key", &pem)?;
+    /// let client = Binance::with_credentials(Config::default(), credentials)?;
+    /// ```
+    pub fn with_credentials(config: Config, credentials: Credentials) -> Result<Self> {
+        let client = Client::new(config, credentials)?;
+        Ok(Self { client })
+    }
+
     /// Create a new Binance client from environment variables.
     ///
     /// Expects `BINANCE_API_KEY` and `BINANCE_SECRET_KEY` environment variables.
@@ -403,11 +594,164 @@ impl Binance {
         self.client.config()
     }
 
+    /// Gracefully shut down the client.
+    ///
+    /// Stops accepting new order requests, waits for in-flight orders to
+    /// complete, then stops all background tasks started from this client
+    /// (reconnecting sockets, depth cache managers and user data stream
+    /// keep-alive loops, which close their listen keys). Tasks that are still
+    /// running after [`DEFAULT_SHUTDOWN_TIMEOUT`](shutdown::DEFAULT_SHUTDOWN_TIMEOUT)
+    /// are aborted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let report = client.shutdown().await;
+    /// if !report.is_clean() {
+    ///     eprintln!("Forced shutdown: {:?}", report);
+    /// }
+    /// ```
+    pub async fn shutdown(&self) -> ShutdownReport {
+        self.shutdown_with_timeout(shutdown::DEFAULT_SHUTDOWN_TIMEOUT)
+            .await
+    }
+
+    /// Gracefully shut down the client with a custom timeout.
+    pub async fn shutdown_with_timeout(&self, timeout: std::time::Duration) -> ShutdownReport {
+        let report = self.client.shutdown_handle().shutdown(timeout).await;
+        self.client.log_schema_drift();
+        report
+    }
+
+    /// Get the response fields and enum values the models did not cover.
+    ///
+    /// Returns `None` unless enabled with
+    /// [`ConfigBuilder::detect_schema_drift`].
+    pub fn schema_drift(&self) -> Option<SchemaDriftReport> {
+        self.client.schema_drift()
+    }
+
+    /// Get the shutdown handle, e.g. to register custom background tasks.
+    pub fn shutdown_handle(&self) -> &ShutdownHandle {
+        self.client.shutdown_handle()
+    }
+
+    /// Subscribe to panic and restart events of background tasks.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut events = client.task_events();
+    /// tokio::spawn(async move {
+    ///     while let Ok(event) = events.recv().await {
+    ///         eprintln!("Background task event: {:?}", event);
+    ///     }
+    /// });
+    /// ```
+    pub fn task_events(&self) -> tokio::sync::broadcast::Receiver<TaskEvent> {
+        self.client.shutdown_handle().task_events()
+    }
+
+    /// Attach a client-side withdrawal policy.
+    ///
+    /// Withdrawals to addresses outside the policy, or above its daily
+    /// limits, are refused before they are signed and sent.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{Binance, WithdrawPolicy};
+    ///
+    /// let policy = WithdrawPolicy::new()
+    ///     .allow_address("USDT", "0x1234...", Some("ETH"))
+    ///     .daily_limit("USDT", 1000.0);
+    /// let client = Binance::from_env()?.with_withdraw_policy(policy);
+    /// ```
+    pub fn with_withdraw_policy(mut self, policy: WithdrawPolicy) -> Self {
+        self.client = self.client.with_withdraw_policy(policy);
+        self
+    }
+
+    /// Attach an audit log for order actions.
+    ///
+    /// Every order create, cancel and amend request is recorded together
+    /// with its response, with the request signature redacted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{AuditLog, Binance};
+    ///
+    /// let client = Binance::from_env()?.with_audit_log(AuditLog::jsonl("orders.jsonl")?);
+    /// ```
+    pub fn with_audit_log(mut self, audit_log: AuditLog) -> Self {
+        self.client = self.client.with_audit_log(audit_log);
+        self
+    }
+
     /// Check if this client has credentials for authenticated endpoints.
     pub fn has_credentials(&self) -> bool {
         self.client.has_credentials()
     }
 
+    /// Synchronize with the Binance server time.
+    ///
+    /// The measured offset is applied to the `timestamp` of all signed
+    /// requests made through this client and its clones. Enable
+    /// [`ConfigBuilder::auto_time_sync`] to keep the offset up to date
+    /// automatically.
+    ///
+    /// Returns the offset (server time minus local time) in milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    /// let offset = client.sync_time().await?;
+    /// println!("Local clock is off by {}ms", offset);
+    /// ```
+    pub async fn sync_time(&self) -> Result<i64> {
+        self.client.sync_time().await
+    }
+
+    /// Get the measured clock skew between this host and the Binance servers.
+    ///
+    /// Includes the current offset and its drift rate, which can be used to
+    /// detect NTP issues before signed requests start failing with `-1021`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// client.sync_time().await?;
+    /// let skew = client.clock_skew();
+    /// if let Some(drift) = skew.drift_ms_per_hour {
+    ///     println!("Clock drifting {:.1}ms/hour", drift);
+    /// }
+    /// ```
+    pub fn clock_skew(&self) -> ClockSkew {
+        self.client.time_sync().skew()
+    }
+
+    /// Get the request weight and order count usage reported by the most
+    /// recent REST responses.
+    ///
+    /// Updated from the `X-MBX-USED-WEIGHT-*`, `X-MBX-ORDER-COUNT-*` and
+    /// `X-SAPI-USED-*-WEIGHT-*` headers after every call made through this
+    /// client and its clones.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// client.market().depth("BTCUSDT", Some(5000)).await?;
+    /// let status = client.rate_limit_status();
+    /// if status.used_weight_1m().unwrap_or(0) > 5000 {
+    ///     println!("Backing off");
+    /// }
+    /// ```
+    pub fn rate_limit_status(&self) -> RateLimitStatus {
+        self.client.rate_limit_status()
+    }
+
     /// Access market data API endpoints.
     ///
     /// Market data endpoints are public and don't require authentication.
@@ -428,6 +772,19 @@ impl Binance {
         rest::Market::new(self.client.clone())
     }
 
+    /// Create an empty [`ExchangeInfoCache`] using this client.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let cache = client.exchange_info_cache();
+    /// cache.load().await?;
+    /// let symbol = cache.symbol("BTCUSDT").await?;
+    /// ```
+    pub fn exchange_info_cache(&self) -> ExchangeInfoCache {
+        ExchangeInfoCache::new(self.clone())
+    }
+
     /// Access user data stream API endpoints.
     ///
     /// User data streams provide real-time updates for account balance changes,
@@ -545,12 +902,181 @@ impl Binance {
     ///     .await?;
     ///
     /// // Borrow
-    /// let loan = client.margin().loan("USDT", "50.0", false, None).await?;
+    /// use binance_api_client::BorrowRepayType;
+    /// let loan = client.margin()
+    ///     .borrow_repay("USDT", "50.0", BorrowRepayType::Borrow, false, None)
+    ///     .await?;
     /// ```
     pub fn margin(&self) -> rest::Margin {
         rest::Margin::new(self.client.clone())
     }
 
+    /// Access spot algo trading API endpoints.
+    ///
+    /// Algo orders (TWAP) are executed by the exchange over a fixed duration
+    /// as a series of smaller sub-orders.
+    ///
+    /// **Requires authentication.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{AlgoOrderBuilder, OrderSide};
+    ///
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// // Sell 1 BTC over two hours
+    /// let order = AlgoOrderBuilder::new("BTCUSDT", OrderSide::Sell, "1", 7200).build();
+    /// let response = client.algo().new_twap_order(&order).await?;
+    ///
+    /// // Cancel it
+    /// client.algo().cancel_order(response.algo_id).await?;
+    /// ```
+    pub fn algo(&self) -> rest::Algo {
+        rest::Algo::new(self.client.clone())
+    }
+
+    /// Access broker (Binance Link) API endpoints.
+    ///
+    /// Broker endpoints let platform operators create sub-accounts, set
+    /// their commissions, query rebates and manage API key IP restrictions.
+    ///
+    /// **Requires authentication and the `broker` feature.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// let info = client.broker().info().await?;
+    /// println!("{}/{} sub-accounts", info.sub_account_qty, info.max_sub_account_qty);
+    ///
+    /// let rebates = client.broker().rebates(None, None, Some(100)).await?;
+    /// ```
+    #[cfg(feature = "broker")]
+    pub fn broker(&self) -> rest::Broker {
+        rest::Broker::new(self.client.clone())
+    }
+
+    /// Access Simple Earn API endpoints.
+    ///
+    /// Earn endpoints cover Simple Earn flexible products. See
+    /// [`EarnSweeper`] for moving idle balances into Earn automatically.
+    ///
+    /// **Requires authentication.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// // Current flexible positions
+    /// let positions = client.earn().flexible_positions(None, None, None, None).await?;
+    /// for position in positions.rows {
+    ///     println!("{}: {}", position.asset, position.total_amount);
+    /// }
+    /// ```
+    pub fn earn(&self) -> rest::Earn {
+        rest::Earn::new(self.client.clone())
+    }
+
+    /// Access fiat and C2C API endpoints.
+    ///
+    /// Fiat endpoints cover fiat deposit/withdrawal history, crypto bought or
+    /// sold with fiat, and C2C (P2P) trade history.
+    ///
+    /// **Requires authentication.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{FiatPaymentType, OrderSide};
+    ///
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// // Card purchases
+    /// let fiat = client.fiat();
+    /// let payments = fiat.payments(FiatPaymentType::Buy, None, None, None, None).await?;
+    ///
+    /// // P2P sales
+    /// let trades = fiat.c2c_trade_history(OrderSide::Sell, None, None, None, None).await?;
+    /// ```
+    pub fn fiat(&self) -> rest::Fiat {
+        rest::Fiat::new(self.client.clone())
+    }
+
+    /// Access crypto loan API endpoints.
+    ///
+    /// Loan endpoints cover flexible rate crypto loans: borrowing, repaying,
+    /// adjusting LTV, and loanable and collateral asset data.
+    ///
+    /// **Requires authentication.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// // Borrow USDT against BNB
+    /// let result = client.loan().borrow("USDT", Some("100"), "BNB", None).await?;
+    ///
+    /// // Check LTV of ongoing loans
+    /// let orders = client.loan().ongoing_orders(None, None, None, None).await?;
+    /// for order in orders.rows {
+    ///     println!("{}/{} LTV {}", order.loan_coin, order.collateral_coin, order.current_ltv);
+    /// }
+    /// ```
+    pub fn loan(&self) -> rest::Loan {
+        rest::Loan::new(self.client.clone())
+    }
+
+    /// Access institutional (VIP) loan API endpoints.
+    ///
+    /// Institutional loan endpoints cover ongoing orders and their LTV,
+    /// repayments, collateral group accounts and loanable asset rates.
+    ///
+    /// **Requires authentication and the `institutional-loan` feature.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// let accounts = client.institutional_loan().collateral_accounts(None, None).await?;
+    /// for account in accounts.rows {
+    ///     println!("{}: {}", account.collateral_account_id, account.collateral_coin);
+    /// }
+    /// ```
+    #[cfg(feature = "institutional-loan")]
+    pub fn institutional_loan(&self) -> rest::InstitutionalLoan {
+        rest::InstitutionalLoan::new(self.client.clone())
+    }
+
+    /// Access staking API endpoints.
+    ///
+    /// Staking endpoints cover ETH staking (including WBETH) and SOL staking.
+    ///
+    /// **Requires authentication.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new("api_key", "secret_key")?;
+    ///
+    /// // Stake ETH for WBETH
+    /// let result = client.staking().stake_eth("0.5").await?;
+    ///
+    /// // Check remaining quota
+    /// let quota = client.staking().eth_quota().await?;
+    /// println!("Can still stake {} ETH", quota.left_staking_personal_quota);
+    ///
+    /// // Review SOL rewards
+    /// let rewards = client.staking().sol_rewards_history(None, None, None, Some(10)).await?;
+    /// ```
+    pub fn staking(&self) -> rest::Staking {
+        rest::Staking::new(self.client.clone())
+    }
+
     /// Access WebSocket streaming API.
     ///
     /// The WebSocket client provides real-time market data streams including
@@ -575,7 +1101,10 @@ impl Binance {
     /// }
     /// ```
     pub fn websocket(&self) -> ws::WebSocketClient {
-        ws::WebSocketClient::new(self.client.config().clone())
+        ws::WebSocketClient::new(
+            self.client.config().clone(),
+            self.client.shutdown_handle().clone(),
+        )
     }
 }
 
@@ -0,0 +1,168 @@
+//! Hooks for exporting client metrics.
+//!
+//! Implement [`MetricsRecorder`] to forward request counts, latencies, rate
+//! limit usage and WebSocket reconnects to a metrics system such as
+//! Prometheus, and install it with [`ConfigBuilder::metrics`].
+//!
+//! [`ConfigBuilder::metrics`]: crate::ConfigBuilder::metrics
+
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Receives metrics from the REST client and reconnecting WebSockets.
+///
+/// Every method has a no-op default, so implementations only override the
+/// metrics they export. Methods are called inline on the request path and
+/// should not block.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{Config, MetricsRecorder};
+/// use std::time::Duration;
+///
+/// struct Prometheus {
+///     requests: prometheus::IntCounterVec,
+///     latency: prometheus::HistogramVec,
+///     used_weight: prometheus::IntGaugeVec,
+/// }
+///
+/// impl MetricsRecorder for Prometheus {
+///     fn record_request(&self, endpoint: &str, status: u16) {
+///         self.requests.with_label_values(&[endpoint, &status.to_string()]).inc();
+///     }
+///
+///     fn record_latency(&self, method: &str, endpoint: &str, latency: Duration) {
+///         self.latency
+///             .with_label_values(&[method, endpoint])
+///             .observe(latency.as_secs_f64());
+///     }
+///
+///     fn record_used_weight(&self, interval: &str, weight: u32) {
+///         self.used_weight.with_label_values(&[interval]).set(weight as i64);
+///     }
+/// }
+///
+/// let config = Config::builder().metrics(Prometheus::new()).build();
+/// ```
+pub trait MetricsRecorder: Send + Sync {
+    /// A REST response was received with the given HTTP status.
+    fn record_request(&self, endpoint: &str, status: u16) {
+        let _ = (endpoint, status);
+    }
+
+    /// A REST request completed (successfully or not) after `latency`.
+    fn record_latency(&self, method: &str, endpoint: &str, latency: Duration) {
+        let _ = (method, endpoint, latency);
+    }
+
+    /// The exchange reported the request weight used in an interval
+    /// (e.g. `"1M"`).
+    fn record_used_weight(&self, interval: &str, weight: u32) {
+        let _ = (interval, weight);
+    }
+
+    /// The exchange reported the number of orders placed in an interval
+    /// (e.g. `"10S"` or `"1D"`).
+    fn record_order_count(&self, interval: &str, count: u32) {
+        let _ = (interval, count);
+    }
+
+    /// A reconnecting WebSocket attempted to reconnect.
+    fn record_ws_reconnect(&self, url: &str, attempt: u64, success: bool) {
+        let _ = (url, attempt, success);
+    }
+}
+
+struct NoopRecorder;
+
+impl MetricsRecorder for NoopRecorder {}
+
+/// Shared handle to the installed [`MetricsRecorder`].
+///
+/// The default handle discards all metrics.
+#[derive(Clone)]
+pub struct Metrics {
+    recorder: Arc<dyn MetricsRecorder>,
+    enabled: bool,
+}
+
+impl Metrics {
+    /// Wrap a recorder.
+    pub fn new(recorder: impl MetricsRecorder + 'static) -> Self {
+        Self {
+            recorder: Arc::new(recorder),
+            enabled: true,
+        }
+    }
+
+    /// Check whether a recorder is installed.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Get the installed recorder.
+    pub fn recorder(&self) -> &dyn MetricsRecorder {
+        self.recorder.as_ref()
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self {
+            recorder: Arc::new(NoopRecorder),
+            enabled: false,
+        }
+    }
+}
+
+impl std::fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Metrics")
+            .field("enabled", &self.enabled)
+            .finish()
+    }
+}
+
+impl PartialEq for Metrics {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.recorder, &other.recorder)
+    }
+}
+
+impl Eq for Metrics {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct Recorded(Mutex<Vec<(String, u16)>>);
+
+    impl MetricsRecorder for Arc<Recorded> {
+        fn record_request(&self, endpoint: &str, status: u16) {
+            self.0.lock().unwrap().push((endpoint.to_string(), status));
+        }
+    }
+
+    #[test]
+    fn test_metrics_recorder() {
+        let noop = Metrics::default();
+        assert!(!noop.is_enabled());
+        noop.recorder().record_request("/api/v3/ping", 200);
+
+        let recorded = Arc::new(Recorded::default());
+        let metrics = Metrics::new(recorded.clone());
+        assert!(metrics.is_enabled());
+        metrics.recorder().record_request("/api/v3/ping", 200);
+        metrics.recorder().record_used_weight("1M", 10);
+        assert_eq!(
+            *recorded.0.lock().unwrap(),
+            vec![("/api/v3/ping".to_string(), 200)]
+        );
+
+        assert_eq!(metrics.clone(), metrics);
+        assert_ne!(metrics, noop);
+    }
+}
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::{
     AccountType, CancelReplaceResult, ContingencyType, OcoOrderStatus, OcoStatus, OrderSide,
-    OrderStatus, OrderType, TimeInForce,
+    OrderStatus, OrderType, TimeInForce, WorkingFloor,
 };
 
+use super::margin::MarginOrderResult;
 use super::market::string_or_float;
 
 /// Account information response.
@@ -52,6 +53,135 @@ pub struct AccountInfo {
     pub uid: Option<u64>,
 }
 
+impl AccountInfo {
+    /// Get the balance of an asset, if listed.
+    pub fn balance(&self, asset: &str) -> Option<&Balance> {
+        self.balances.iter().find(|b| b.asset == asset)
+    }
+
+    /// Compare this snapshot with a later one.
+    ///
+    /// Balances are matched by asset; an asset missing from one of the
+    /// snapshots (e.g. because zero balances were omitted) counts as added
+    /// or removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut previous = client.account().get_account().await?;
+    /// loop {
+    ///     tokio::time::sleep(Duration::from_secs(60)).await;
+    ///     let current = client.account().get_account().await?;
+    ///     let diff = previous.diff(&current);
+    ///     if !diff.permissions_removed.is_empty() || diff.can_withdraw.is_some() {
+    ///         alert(&diff);
+    ///     }
+    ///     previous = current;
+    /// }
+    /// ```
+    pub fn diff(&self, other: &AccountInfo) -> AccountDiff {
+        let mut diff = AccountDiff::default();
+
+        for new in &other.balances {
+            match self.balance(&new.asset) {
+                None => diff.balances_added.push(new.clone()),
+                Some(old) if old.free != new.free || old.locked != new.locked => {
+                    diff.balances_changed.push(BalanceChange {
+                        asset: new.asset.clone(),
+                        old: old.clone(),
+                        new: new.clone(),
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for old in &self.balances {
+            if other.balance(&old.asset).is_none() {
+                diff.balances_removed.push(old.clone());
+            }
+        }
+
+        diff.permissions_added = other
+            .permissions
+            .iter()
+            .filter(|p| !self.permissions.contains(p))
+            .copied()
+            .collect();
+        diff.permissions_removed = self
+            .permissions
+            .iter()
+            .filter(|p| !other.permissions.contains(p))
+            .copied()
+            .collect();
+
+        let changed = |old: bool, new: bool| (old != new).then_some(new);
+        diff.can_trade = changed(self.can_trade, other.can_trade);
+        diff.can_withdraw = changed(self.can_withdraw, other.can_withdraw);
+        diff.can_deposit = changed(self.can_deposit, other.can_deposit);
+        diff
+    }
+}
+
+/// Changes between two account snapshots, from [`AccountInfo::diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AccountDiff {
+    /// Balances of assets only listed in the later snapshot.
+    pub balances_added: Vec<Balance>,
+    /// Balances of assets only listed in the earlier snapshot.
+    pub balances_removed: Vec<Balance>,
+    /// Balances whose free or locked amount changed.
+    pub balances_changed: Vec<BalanceChange>,
+    /// Permissions only granted in the later snapshot.
+    pub permissions_added: Vec<AccountType>,
+    /// Permissions only granted in the earlier snapshot.
+    pub permissions_removed: Vec<AccountType>,
+    /// New `can_trade` value, if it changed.
+    pub can_trade: Option<bool>,
+    /// New `can_withdraw` value, if it changed.
+    pub can_withdraw: Option<bool>,
+    /// New `can_deposit` value, if it changed.
+    pub can_deposit: Option<bool>,
+}
+
+impl AccountDiff {
+    /// Check whether nothing changed.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Check whether any permission or trade/withdraw/deposit flag changed.
+    pub fn permissions_changed(&self) -> bool {
+        !self.permissions_added.is_empty()
+            || !self.permissions_removed.is_empty()
+            || self.can_trade.is_some()
+            || self.can_withdraw.is_some()
+            || self.can_deposit.is_some()
+    }
+}
+
+/// Change of a single asset balance.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceChange {
+    /// Asset symbol.
+    pub asset: String,
+    /// Balance in the earlier snapshot.
+    pub old: Balance,
+    /// Balance in the later snapshot.
+    pub new: Balance,
+}
+
+impl BalanceChange {
+    /// Change of the free balance.
+    pub fn free_delta(&self) -> f64 {
+        self.new.free - self.old.free
+    }
+
+    /// Change of the locked balance.
+    pub fn locked_delta(&self) -> f64 {
+        self.new.locked - self.old.locked
+    }
+}
+
 /// Commission rates.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -220,7 +350,7 @@ pub struct Allocation {
 }
 
 /// Account balance for a single asset.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
     /// Asset symbol (e.g., "BTC").
@@ -246,7 +376,7 @@ impl Balance {
 }
 
 /// Order information.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
     /// Symbol.
@@ -425,11 +555,69 @@ pub struct OrderFull {
     /// Self-trade prevention mode.
     #[serde(default)]
     pub self_trade_prevention_mode: Option<String>,
+    /// Where the order was placed (SOR orders only).
+    #[serde(default)]
+    pub working_floor: Option<WorkingFloor>,
+    /// Whether the order was placed through SOR.
+    #[serde(default)]
+    pub used_sor: bool,
     /// Fills (trades that filled this order).
     #[serde(default)]
     pub fills: Vec<Fill>,
 }
 
+/// Response from placing an order through
+/// [`Account::place_order`](crate::rest::Account::place_order).
+#[derive(Debug, Clone)]
+pub enum PlacedOrder {
+    /// Order placed on the spot account.
+    Spot(OrderFull),
+    /// Order placed on a cross or isolated margin account.
+    Margin(MarginOrderResult),
+}
+
+impl PlacedOrder {
+    /// Get the symbol.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Spot(o) => &o.symbol,
+            Self::Margin(o) => &o.symbol,
+        }
+    }
+
+    /// Get the order ID.
+    pub fn order_id(&self) -> u64 {
+        match self {
+            Self::Spot(o) => o.order_id,
+            Self::Margin(o) => o.order_id,
+        }
+    }
+
+    /// Get the client order ID.
+    pub fn client_order_id(&self) -> &str {
+        match self {
+            Self::Spot(o) => &o.client_order_id,
+            Self::Margin(o) => &o.client_order_id,
+        }
+    }
+
+    /// Get the order status.
+    pub fn status(&self) -> OrderStatus {
+        match self {
+            Self::Spot(o) => o.status,
+            Self::Margin(o) => o.status,
+        }
+    }
+
+    /// Get the executed quantity.
+    pub fn executed_qty(&self) -> f64 {
+        match self {
+            Self::Spot(o) => o.executed_qty,
+            Self::Margin(o) => o.executed_qty,
+        }
+    }
+}
+
 /// Order fill information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -445,9 +633,15 @@ pub struct Fill {
     pub commission: f64,
     /// Commission asset.
     pub commission_asset: String,
-    /// Trade ID.
+    /// Trade ID (-1 for SOR allocations).
+    #[serde(default)]
+    pub trade_id: Option<i64>,
+    /// Match type (e.g., "ONE_PARTY_TRADE_REPORT" for SOR allocations).
+    #[serde(default)]
+    pub match_type: Option<String>,
+    /// Allocation ID (SOR fills only).
     #[serde(default)]
-    pub trade_id: Option<u64>,
+    pub alloc_id: Option<u64>,
 }
 
 /// Cancel order response.
@@ -565,8 +759,21 @@ pub enum OrderResponse {
     Full(OrderFull),
 }
 
-/// User trade (my trades).
+/// Entry of a cancel all open orders response.
+///
+/// Orders belonging to an order list (e.g. OCO) are reported once, as part
+/// of their order list.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CanceledOpenOrder {
+    /// Canceled order list.
+    OrderList(OcoOrder),
+    /// Canceled standalone order.
+    Order(CancelOrderResponse),
+}
+
+/// User trade (my trades).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserTrade {
     /// Symbol.
@@ -910,6 +1117,49 @@ mod tests {
         assert_eq!(order.fills[0].commission, 0.001);
     }
 
+    #[test]
+    fn test_sor_order_full_deserialize() {
+        let json = r#"{
+            "symbol": "BTCUSDT",
+            "orderId": 2,
+            "orderListId": -1,
+            "clientOrderId": "sBI1KM6nNtOfj5tccZSKly",
+            "transactTime": 1689149087774,
+            "price": "31000.00000000",
+            "origQty": "0.50000000",
+            "executedQty": "0.50000000",
+            "cummulativeQuoteQty": "14000.00000000",
+            "status": "FILLED",
+            "timeInForce": "GTC",
+            "type": "LIMIT",
+            "side": "BUY",
+            "workingTime": 1689149087774,
+            "fills": [
+                {
+                    "matchType": "ONE_PARTY_TRADE_REPORT",
+                    "price": "28000.00000000",
+                    "qty": "0.50000000",
+                    "commission": "0.00000000",
+                    "commissionAsset": "BTC",
+                    "tradeId": -1,
+                    "allocId": 0
+                }
+            ],
+            "workingFloor": "SOR",
+            "selfTradePreventionMode": "NONE",
+            "usedSor": true
+        }"#;
+        let order: OrderFull = serde_json::from_str(json).unwrap();
+        assert!(order.used_sor);
+        assert_eq!(order.working_floor, Some(WorkingFloor::Sor));
+        assert_eq!(order.fills[0].trade_id, Some(-1));
+        assert_eq!(order.fills[0].alloc_id, Some(0));
+        assert_eq!(
+            order.fills[0].match_type.as_deref(),
+            Some("ONE_PARTY_TRADE_REPORT")
+        );
+    }
+
     #[test]
     fn test_user_trade_deserialize() {
         let json = r#"{
@@ -965,4 +1215,118 @@ mod tests {
         assert_eq!(account.balances.len(), 1);
         assert_eq!(account.balances[0].asset, "BTC");
     }
+
+    #[test]
+    fn test_account_info_diff() {
+        let account = |balances: serde_json::Value, permissions: serde_json::Value| {
+            serde_json::from_value::<AccountInfo>(serde_json::json!({
+                "makerCommission": 10, "takerCommission": 10, "buyerCommission": 0,
+                "sellerCommission": 0, "canTrade": true, "canWithdraw": true,
+                "canDeposit": true, "updateTime": 1, "accountType": "SPOT",
+                "balances": balances, "permissions": permissions
+            }))
+            .unwrap()
+        };
+        let before = account(
+            serde_json::json!([
+                {"asset": "BTC", "free": "1.0", "locked": "0.0"},
+                {"asset": "ETH", "free": "5.0", "locked": "0.0"},
+                {"asset": "BNB", "free": "2.0", "locked": "0.0"}
+            ]),
+            serde_json::json!(["SPOT", "LEVERAGED"]),
+        );
+        let mut after = account(
+            serde_json::json!([
+                {"asset": "BTC", "free": "0.5", "locked": "0.25"},
+                {"asset": "BNB", "free": "2.0", "locked": "0.0"},
+                {"asset": "USDT", "free": "100.0", "locked": "0.0"}
+            ]),
+            serde_json::json!(["SPOT"]),
+        );
+        after.can_withdraw = false;
+
+        assert!(before.diff(&before).is_empty());
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.balances_added[0].asset, "USDT");
+        assert_eq!(diff.balances_removed[0].asset, "ETH");
+        assert_eq!(diff.balances_changed.len(), 1);
+        assert_eq!(diff.balances_changed[0].free_delta(), -0.5);
+        assert_eq!(diff.balances_changed[0].locked_delta(), 0.25);
+        assert!(diff.permissions_added.is_empty());
+        assert_eq!(diff.permissions_removed, vec![AccountType::Leveraged]);
+        assert_eq!(diff.can_withdraw, Some(false));
+        assert_eq!(diff.can_trade, None);
+        assert!(diff.permissions_changed());
+    }
+
+    #[test]
+    fn test_canceled_open_orders_deserialize() {
+        let json = r#"[
+            {
+                "symbol": "BTCUSDT",
+                "origClientOrderId": "E6APeyTJvkMvLMYMqu1KQ4",
+                "orderId": 11,
+                "orderListId": -1,
+                "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                "transactTime": 1684804350068,
+                "price": "0.089853",
+                "origQty": "0.178622",
+                "executedQty": "0.000000",
+                "cummulativeQuoteQty": "0.000000",
+                "status": "CANCELED",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "BUY",
+                "selfTradePreventionMode": "NONE"
+            },
+            {
+                "orderListId": 1929,
+                "contingencyType": "OCO",
+                "listStatusType": "ALL_DONE",
+                "listOrderStatus": "ALL_DONE",
+                "listClientOrderId": "2inzWQdDvZLHbbAmAozX2N",
+                "transactionTime": 1585230948299,
+                "symbol": "BTCUSDT",
+                "orders": [
+                    {"symbol": "BTCUSDT", "orderId": 20, "clientOrderId": "CwOOIPHSmYywx6jZX77TdL"},
+                    {"symbol": "BTCUSDT", "orderId": 21, "clientOrderId": "461cPg51vQjV3zIMOXNz39"}
+                ],
+                "orderReports": [
+                    {
+                        "symbol": "BTCUSDT",
+                        "origClientOrderId": "CwOOIPHSmYywx6jZX77TdL",
+                        "orderId": 20,
+                        "orderListId": 1929,
+                        "clientOrderId": "pXLV6Hz6mprAcVYpVMTGgx",
+                        "transactTime": 1688005070874,
+                        "price": "0.668611",
+                        "origQty": "0.690354",
+                        "executedQty": "0.000000",
+                        "cummulativeQuoteQty": "0.000000",
+                        "status": "CANCELED",
+                        "timeInForce": "GTC",
+                        "type": "STOP_LOSS_LIMIT",
+                        "side": "BUY",
+                        "stopPrice": "0.378131",
+                        "selfTradePreventionMode": "NONE"
+                    }
+                ]
+            }
+        ]"#;
+        let canceled: Vec<CanceledOpenOrder> = serde_json::from_str(json).unwrap();
+        assert_eq!(canceled.len(), 2);
+        match &canceled[0] {
+            CanceledOpenOrder::Order(order) => assert_eq!(order.order_id, 11),
+            other => panic!("Expected order, got {:?}", other),
+        }
+        match &canceled[1] {
+            CanceledOpenOrder::OrderList(list) => {
+                assert_eq!(list.order_list_id, 1929);
+                assert_eq!(list.orders.len(), 2);
+                assert_eq!(list.order_reports[0].stop_price, Some(0.378131));
+            }
+            other => panic!("Expected order list, got {:?}", other),
+        }
+    }
 }
@@ -0,0 +1,213 @@
+//! Algo trading API response models.
+//!
+//! Models for the Binance spot algo order (TWAP) SAPI endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use super::string_or_float;
+use crate::types::{OrderSide, OrderStatus};
+
+/// Status of an algo order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlgoStatus {
+    /// The algo order is executing.
+    Working,
+    /// The algo order has finished.
+    Finished,
+    /// The algo order was cancelled.
+    Cancelled,
+    /// Unknown status
+    #[serde(other)]
+    Other,
+}
+
+/// Response from placing an algo order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrderResponse {
+    /// Client algo ID.
+    pub client_algo_id: String,
+    /// Whether the order was accepted.
+    pub success: bool,
+    /// Response code.
+    pub code: i32,
+    /// Response message.
+    pub msg: String,
+    /// Algo order ID.
+    pub algo_id: u64,
+}
+
+/// Response from cancelling an algo order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoCancelResponse {
+    /// Algo order ID.
+    pub algo_id: u64,
+    /// Whether the order was cancelled.
+    pub success: bool,
+    /// Response code.
+    pub code: i32,
+    /// Response message.
+    pub msg: String,
+}
+
+/// An algo order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrder {
+    /// Algo order ID.
+    pub algo_id: u64,
+    /// Trading symbol.
+    pub symbol: String,
+    /// Order side.
+    pub side: OrderSide,
+    /// Executed quantity.
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    /// Executed quote amount.
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    /// Average execution price.
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    /// Client algo ID.
+    pub client_algo_id: String,
+    /// Time the order was placed.
+    pub book_time: u64,
+    /// Time the order ended (0 while working).
+    pub end_time: u64,
+    /// Algo order status.
+    pub algo_status: AlgoStatus,
+    /// Algo type (e.g., "TWAP").
+    pub algo_type: String,
+    /// Execution urgency.
+    #[serde(default)]
+    pub urgency: Option<String>,
+}
+
+/// A page of algo orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgoOrders {
+    /// Total number of orders.
+    pub total: u64,
+    /// Orders in this page.
+    #[serde(default)]
+    pub orders: Vec<AlgoOrder>,
+}
+
+/// A sub-order executed on behalf of an algo order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrder {
+    /// Parent algo order ID.
+    pub algo_id: u64,
+    /// Spot order ID.
+    pub order_id: u64,
+    /// Order status.
+    pub order_status: OrderStatus,
+    /// Executed quantity.
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    /// Executed quote amount.
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    /// Fee amount.
+    #[serde(with = "string_or_float")]
+    pub fee_amt: f64,
+    /// Fee asset.
+    pub fee_asset: String,
+    /// Time the sub-order was placed.
+    pub book_time: u64,
+    /// Average execution price.
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    /// Order side.
+    pub side: OrderSide,
+    /// Trading symbol.
+    pub symbol: String,
+    /// Sub-order sequence number.
+    pub sub_id: u64,
+    /// Time in force (e.g., "IMMEDIATE_OR_CANCEL").
+    pub time_in_force: String,
+    /// Original quantity.
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+}
+
+/// A page of algo sub-orders.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrders {
+    /// Total number of sub-orders.
+    pub total: u64,
+    /// Total executed quantity.
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    /// Total executed quote amount.
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    /// Sub-orders in this page.
+    #[serde(default)]
+    pub sub_orders: Vec<AlgoSubOrder>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algo_orders_deserialize() {
+        let json = r#"{
+            "total": 1,
+            "orders": [{
+                "algoId": 14518,
+                "symbol": "BNBUSDT",
+                "side": "BUY",
+                "executedQty": "0.00000000",
+                "executedAmt": "0.00000000",
+                "avgPrice": "0.00",
+                "clientAlgoId": "acacab56b3c44bef9f6a8f8ebd2a8408",
+                "bookTime": 1649756817004,
+                "endTime": 0,
+                "algoStatus": "WORKING",
+                "algoType": "TWAP",
+                "urgency": "LOW"
+            }]
+        }"#;
+        let orders: AlgoOrders = serde_json::from_str(json).unwrap();
+        assert_eq!(orders.total, 1);
+        assert_eq!(orders.orders[0].algo_id, 14518);
+        assert_eq!(orders.orders[0].side, OrderSide::Buy);
+        assert_eq!(orders.orders[0].algo_status, AlgoStatus::Working);
+    }
+
+    #[test]
+    fn test_algo_sub_orders_deserialize() {
+        let json = r#"{
+            "total": 1,
+            "executedQty": "1.000",
+            "executedAmt": "3229.44000000",
+            "subOrders": [{
+                "algoId": 13723,
+                "orderId": 8389765519993908929,
+                "orderStatus": "FILLED",
+                "executedQty": "1.000",
+                "executedAmt": "3229.44000000",
+                "feeAmt": "-1.61471999",
+                "feeAsset": "USDT",
+                "bookTime": 1649319001964,
+                "avgPrice": "3229.44",
+                "side": "SELL",
+                "symbol": "ETHUSDT",
+                "subId": 1,
+                "timeInForce": "IMMEDIATE_OR_CANCEL",
+                "origQty": "1.000"
+            }]
+        }"#;
+        let page: AlgoSubOrders = serde_json::from_str(json).unwrap();
+        assert_eq!(page.executed_qty, 1.0);
+        assert_eq!(page.sub_orders[0].order_status, OrderStatus::Filled);
+        assert_eq!(page.sub_orders[0].fee_amt, -1.61471999);
+    }
+}
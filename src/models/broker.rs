@@ -0,0 +1,150 @@
+//! Broker (Binance Link) API response models.
+//!
+//! Models for the Binance Link broker SAPI endpoints. Only available with
+//! the `broker` feature.
+
+use serde::{Deserialize, Serialize};
+
+use super::string_or_float;
+
+/// Broker account information.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerInfo {
+    /// Maximum maker commission that can be set on a sub-account.
+    #[serde(with = "string_or_float")]
+    pub max_maker_commission: f64,
+    /// Minimum maker commission that can be set on a sub-account.
+    #[serde(with = "string_or_float")]
+    pub min_maker_commission: f64,
+    /// Maximum taker commission that can be set on a sub-account.
+    #[serde(with = "string_or_float")]
+    pub max_taker_commission: f64,
+    /// Minimum taker commission that can be set on a sub-account.
+    #[serde(with = "string_or_float")]
+    pub min_taker_commission: f64,
+    /// Number of sub-accounts created.
+    pub sub_account_qty: u32,
+    /// Maximum number of sub-accounts.
+    pub max_sub_account_qty: u32,
+}
+
+/// Broker sub-account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerSubAccount {
+    /// Sub-account ID.
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    /// Sub-account email.
+    pub email: String,
+    /// Tag.
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Maker commission (returned when querying sub-accounts).
+    #[serde(default)]
+    pub maker_commission: Option<String>,
+    /// Taker commission (returned when querying sub-accounts).
+    #[serde(default)]
+    pub taker_commission: Option<String>,
+    /// Creation time.
+    #[serde(default)]
+    pub create_time: Option<u64>,
+}
+
+/// Commission settings of a broker sub-account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerCommission {
+    /// Sub-account ID.
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    /// Spot maker commission.
+    #[serde(with = "string_or_float")]
+    pub maker_commission: f64,
+    /// Spot taker commission.
+    #[serde(with = "string_or_float")]
+    pub taker_commission: f64,
+    /// Margin maker commission (-1 if not set).
+    #[serde(with = "string_or_float")]
+    pub margin_maker_commission: f64,
+    /// Margin taker commission (-1 if not set).
+    #[serde(with = "string_or_float")]
+    pub margin_taker_commission: f64,
+}
+
+/// Broker rebate record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerRebate {
+    /// Sub-account ID.
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    /// Rebate amount.
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    /// Rebate asset.
+    pub asset: String,
+    /// Symbol traded.
+    pub symbol: String,
+    /// Trade ID.
+    pub trade_id: u64,
+    /// Rebate time.
+    pub time: u64,
+    /// Rebate status (0 pending, 1 success, 2 failed).
+    pub status: i32,
+}
+
+/// IP restriction of a broker sub-account API key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerIpRestriction {
+    /// Sub-account ID.
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    /// API key.
+    #[serde(rename = "apikey")]
+    pub api_key: String,
+    /// Restriction status ("1" unrestricted, "2" restricted to the IP list).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Allowed IP addresses.
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    /// Last update time.
+    pub update_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broker_commission_deserialize() {
+        let json = r#"{
+            "subaccountId": "1",
+            "makerCommission": 0.0002,
+            "takerCommission": 0.0004,
+            "marginMakerCommission": -1,
+            "marginTakerCommission": -1
+        }"#;
+        let commission: BrokerCommission = serde_json::from_str(json).unwrap();
+        assert_eq!(commission.sub_account_id, "1");
+        assert_eq!(commission.taker_commission, 0.0004);
+        assert_eq!(commission.margin_maker_commission, -1.0);
+    }
+
+    #[test]
+    fn test_broker_ip_restriction_deserialize() {
+        let json = r#"{
+            "subaccountId": "1",
+            "apikey": "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+            "status": "2",
+            "ipList": ["69.210.67.14", "8.34.21.10"],
+            "updateTime": 1636371437000
+        }"#;
+        let restriction: BrokerIpRestriction = serde_json::from_str(json).unwrap();
+        assert_eq!(restriction.status.as_deref(), Some("2"));
+        assert_eq!(restriction.ip_list.len(), 2);
+    }
+}
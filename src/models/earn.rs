@@ -0,0 +1,142 @@
+//! Simple Earn API response models.
+//!
+//! Models for the Binance Simple Earn flexible product SAPI endpoints.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::{string_or_float, string_or_float_opt};
+
+/// Wallet used to fund a subscription or receive a redemption.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EarnAccount {
+    /// Spot wallet
+    #[default]
+    Spot,
+    /// Funding wallet
+    Fund,
+    /// Spot and funding wallets (subscriptions only)
+    All,
+}
+
+impl std::fmt::Display for EarnAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Spot => "SPOT",
+            Self::Fund => "FUND",
+            Self::All => "ALL",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Simple Earn flexible product.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    /// Asset.
+    pub asset: String,
+    /// Latest annual percentage rate.
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// Tiered annual percentage rates, keyed by amount range (e.g., "0-5BTC").
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    /// Airdrop percentage rate.
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<f64>,
+    /// Whether the product can be subscribed to.
+    pub can_purchase: bool,
+    /// Whether the product can be redeemed.
+    pub can_redeem: bool,
+    /// Whether the product is sold out.
+    pub is_sold_out: bool,
+    /// Whether the product is featured.
+    #[serde(default)]
+    pub hot: bool,
+    /// Minimum subscription amount.
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    /// Product ID.
+    pub product_id: String,
+    /// Subscription start time.
+    #[serde(default)]
+    pub subscription_start_time: Option<u64>,
+    /// Product status (e.g., "PURCHASING").
+    pub status: String,
+}
+
+/// Simple Earn flexible position.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    /// Asset.
+    pub asset: String,
+    /// Product ID.
+    pub product_id: String,
+    /// Total amount held.
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    /// Latest annual percentage rate.
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// Whether the position can be redeemed.
+    pub can_redeem: bool,
+    /// Amount used as loan collateral.
+    #[serde(default, with = "string_or_float_opt")]
+    pub collateral_amount: Option<f64>,
+    /// Cumulative total rewards.
+    #[serde(default, with = "string_or_float_opt")]
+    pub cumulative_total_rewards: Option<f64>,
+    /// Whether auto-subscribe is enabled.
+    #[serde(default)]
+    pub auto_subscribe: bool,
+}
+
+/// Response from subscribing to a flexible product.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnSubscribeResult {
+    /// Purchase ID.
+    pub purchase_id: u64,
+    /// Whether the subscription succeeded.
+    pub success: bool,
+}
+
+/// Response from redeeming a flexible product.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedeemResult {
+    /// Redemption ID.
+    pub redeem_id: u64,
+    /// Whether the redemption succeeded.
+    pub success: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flexible_product_deserialize() {
+        let json = r#"{
+            "asset": "BTC",
+            "latestAnnualPercentageRate": "0.05000000",
+            "tierAnnualPercentageRate": {"0-5BTC": 0.05, "5-10BTC": 0.03},
+            "airDropPercentageRate": "0.05000000",
+            "canPurchase": true,
+            "canRedeem": true,
+            "isSoldOut": true,
+            "hot": true,
+            "minPurchaseAmount": "0.01000000",
+            "productId": "BTC001",
+            "subscriptionStartTime": 1646182276000,
+            "status": "PURCHASING"
+        }"#;
+        let product: FlexibleProduct = serde_json::from_str(json).unwrap();
+        assert_eq!(product.product_id, "BTC001");
+        assert_eq!(product.latest_annual_percentage_rate, 0.05);
+        assert_eq!(product.tier_annual_percentage_rate["5-10BTC"], 0.03);
+    }
+}
@@ -0,0 +1,227 @@
+//! Fiat and C2C API response models.
+//!
+//! Models for the Binance fiat deposit/withdrawal, fiat payment and C2C
+//! trade history SAPI endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use super::string_or_float;
+use crate::types::OrderSide;
+
+/// Fiat deposit or withdrawal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiatTransactionType {
+    /// Fiat deposit.
+    Deposit,
+    /// Fiat withdrawal.
+    Withdraw,
+}
+
+impl std::fmt::Display for FiatTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Deposit => "0",
+            Self::Withdraw => "1",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Crypto bought or sold with fiat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiatPaymentType {
+    /// Crypto bought with fiat.
+    Buy,
+    /// Crypto sold for fiat.
+    Sell,
+}
+
+impl std::fmt::Display for FiatPaymentType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Buy => "0",
+            Self::Sell => "1",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Paged history response from the fiat and C2C endpoints.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FiatHistory<T> {
+    /// Response code.
+    pub code: String,
+    /// Response message.
+    pub message: String,
+    /// Records in this page.
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+    /// Total number of records.
+    #[serde(default)]
+    pub total: u64,
+    /// Whether the request succeeded.
+    pub success: bool,
+}
+
+/// Fiat deposit or withdrawal order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatOrder {
+    /// Order number.
+    pub order_no: String,
+    /// Fiat currency.
+    pub fiat_currency: String,
+    /// Requested amount.
+    #[serde(with = "string_or_float")]
+    pub indicated_amount: f64,
+    /// Actual amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Total fee.
+    #[serde(with = "string_or_float")]
+    pub total_fee: f64,
+    /// Payment method (e.g., "BankAccount").
+    pub method: String,
+    /// Status (e.g., "Processing", "Failed", "Successful").
+    pub status: String,
+    /// Creation time.
+    pub create_time: u64,
+    /// Last update time.
+    pub update_time: u64,
+}
+
+/// Crypto purchase or sale paid with fiat.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FiatPayment {
+    /// Order number.
+    pub order_no: String,
+    /// Amount paid.
+    #[serde(with = "string_or_float")]
+    pub source_amount: f64,
+    /// Fiat currency.
+    pub fiat_currency: String,
+    /// Amount received.
+    #[serde(with = "string_or_float")]
+    pub obtain_amount: f64,
+    /// Crypto currency.
+    pub crypto_currency: String,
+    /// Total fee.
+    #[serde(with = "string_or_float")]
+    pub total_fee: f64,
+    /// Price.
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// Status (e.g., "Processing", "Completed", "Failed", "Refunded").
+    pub status: String,
+    /// Payment method (e.g., "Cash Balance", "Credit Card").
+    #[serde(default)]
+    pub payment_method: Option<String>,
+    /// Creation time.
+    pub create_time: u64,
+    /// Last update time.
+    pub update_time: u64,
+}
+
+/// C2C (P2P) trade.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct C2cTrade {
+    /// Order number.
+    pub order_number: String,
+    /// Advertisement number.
+    pub adv_no: String,
+    /// Trade side.
+    pub trade_type: OrderSide,
+    /// Crypto asset.
+    pub asset: String,
+    /// Fiat currency.
+    pub fiat: String,
+    /// Fiat currency symbol.
+    pub fiat_symbol: String,
+    /// Crypto amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Total fiat price.
+    #[serde(with = "string_or_float")]
+    pub total_price: f64,
+    /// Unit price.
+    #[serde(with = "string_or_float")]
+    pub unit_price: f64,
+    /// Order status (e.g., "COMPLETED", "CANCELLED").
+    pub order_status: String,
+    /// Creation time.
+    pub create_time: u64,
+    /// Commission.
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    /// Counterparty nickname.
+    pub counter_part_nick_name: String,
+    /// Whether the user was the "MAKER" or "TAKER" of the advertisement.
+    pub advertisement_role: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiat_payments_deserialize() {
+        let json = r#"{
+            "code": "000000",
+            "message": "success",
+            "data": [{
+                "orderNo": "353fca443f06466db0c4dc89f94f027a",
+                "sourceAmount": "20.0",
+                "fiatCurrency": "EUR",
+                "obtainAmount": "4.462",
+                "cryptoCurrency": "LUNA",
+                "totalFee": "0.2",
+                "price": "4.437472",
+                "status": "Failed",
+                "paymentMethod": "Credit Card",
+                "createTime": 1624529919000,
+                "updateTime": 1624529919000
+            }],
+            "total": 1,
+            "success": true
+        }"#;
+        let history: FiatHistory<FiatPayment> = serde_json::from_str(json).unwrap();
+        assert!(history.success);
+        assert_eq!(history.total, 1);
+        assert_eq!(history.data[0].obtain_amount, 4.462);
+        assert_eq!(
+            history.data[0].payment_method.as_deref(),
+            Some("Credit Card")
+        );
+    }
+
+    #[test]
+    fn test_c2c_trades_deserialize() {
+        let json = r#"{
+            "code": "000000",
+            "message": "success",
+            "data": [{
+                "orderNumber": "20219644646554779648",
+                "advNo": "11218246497340923904",
+                "tradeType": "SELL",
+                "asset": "BUSD",
+                "fiat": "CNY",
+                "fiatSymbol": "¥",
+                "amount": "5000.00000000",
+                "totalPrice": "33400.00000000",
+                "unitPrice": "6.68",
+                "orderStatus": "COMPLETED",
+                "createTime": 1619361369000,
+                "commission": "0",
+                "counterPartNickName": "ab***",
+                "advertisementRole": "TAKER"
+            }],
+            "total": 1,
+            "success": true
+        }"#;
+        let history: FiatHistory<C2cTrade> = serde_json::from_str(json).unwrap();
+        assert_eq!(history.data[0].trade_type, OrderSide::Sell);
+        assert_eq!(history.data[0].total_price, 33400.0);
+    }
+}
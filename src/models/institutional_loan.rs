@@ -0,0 +1,227 @@
+//! Institutional (VIP) loan API response models.
+//!
+//! Models for the Binance institutional loan SAPI endpoints. Only available
+//! with the `institutional-loan` feature.
+
+use serde::{Deserialize, Serialize};
+
+use super::{string_or_float, string_or_float_opt};
+
+/// Institutional loan ongoing order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionalLoanOrder {
+    /// Order ID.
+    pub order_id: u64,
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Total debt (principal plus interest).
+    #[serde(with = "string_or_float")]
+    pub total_debt: f64,
+    /// Outstanding interest.
+    #[serde(with = "string_or_float")]
+    pub residual_interest: f64,
+    /// Collateral account IDs, comma separated.
+    pub collateral_account_id: String,
+    /// Collateral coins, comma separated.
+    pub collateral_coin: String,
+    /// Total collateral value after haircut.
+    #[serde(with = "string_or_float")]
+    pub total_collateral_value_after_haircut: f64,
+    /// Collateral value locked for this order.
+    #[serde(with = "string_or_float")]
+    pub locked_collateral_value: f64,
+    /// Current loan-to-value ratio (e.g., 0.65 for 65%).
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Expiration time (0 for open-term loans).
+    #[serde(default)]
+    pub expiration_time: u64,
+    /// Loan date.
+    #[serde(default)]
+    pub loan_date: Option<String>,
+    /// Loan term in days (empty for open-term loans).
+    #[serde(default)]
+    pub loan_term: Option<String>,
+}
+
+impl InstitutionalLoanOrder {
+    /// Get the collateral account IDs backing this order.
+    pub fn collateral_account_ids(&self) -> Vec<&str> {
+        split_list(&self.collateral_account_id)
+    }
+
+    /// Get the collateral coins backing this order.
+    pub fn collateral_coins(&self) -> Vec<&str> {
+        split_list(&self.collateral_coin)
+    }
+}
+
+/// Institutional loan repay response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionalLoanRepayResult {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Repaid amount.
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    /// Remaining principal.
+    #[serde(with = "string_or_float")]
+    pub remaining_principal: f64,
+    /// Remaining interest.
+    #[serde(with = "string_or_float")]
+    pub remaining_interest: f64,
+    /// Collateral coins, comma separated.
+    pub collateral_coin: String,
+    /// Loan-to-value ratio after the repayment.
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Repay status (e.g., "Repaid", "Repaying", "Failed").
+    pub repay_status: String,
+}
+
+/// Institutional loan repayment history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionalLoanRepayRecord {
+    /// Order ID.
+    pub order_id: u64,
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Repaid amount.
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    /// Collateral coins, comma separated.
+    pub collateral_coin: String,
+    /// Repay status.
+    pub repay_status: String,
+    /// Loan date.
+    #[serde(default)]
+    pub loan_date: Option<String>,
+    /// Repay time.
+    pub repay_time: u64,
+}
+
+/// Collateral account of an institutional loan group.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionalCollateralAccount {
+    /// Collateral account ID.
+    pub collateral_account_id: String,
+    /// Collateral coins held in the account, comma separated.
+    pub collateral_coin: String,
+}
+
+/// Institutional loanable asset with flexible and fixed rates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstitutionalLoanableAsset {
+    /// Loanable coin.
+    pub loan_coin: String,
+    /// Flexible rate daily interest.
+    #[serde(
+        rename = "_flexibleDailyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub flexible_daily_interest_rate: Option<f64>,
+    /// Flexible rate yearly interest.
+    #[serde(
+        rename = "_flexibleYearlyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub flexible_yearly_interest_rate: Option<f64>,
+    /// 30 day fixed rate daily interest.
+    #[serde(
+        rename = "_30dDailyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub daily_interest_rate_30d: Option<f64>,
+    /// 30 day fixed rate yearly interest.
+    #[serde(
+        rename = "_30dYearlyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub yearly_interest_rate_30d: Option<f64>,
+    /// 60 day fixed rate daily interest.
+    #[serde(
+        rename = "_60dDailyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub daily_interest_rate_60d: Option<f64>,
+    /// 60 day fixed rate yearly interest.
+    #[serde(
+        rename = "_60dYearlyInterestRate",
+        default,
+        with = "string_or_float_opt"
+    )]
+    pub yearly_interest_rate_60d: Option<f64>,
+    /// Minimum borrow amount.
+    #[serde(with = "string_or_float")]
+    pub min_limit: f64,
+    /// Maximum borrow amount.
+    #[serde(with = "string_or_float")]
+    pub max_limit: f64,
+    /// VIP level the rates apply to.
+    pub vip_level: u32,
+}
+
+fn split_list(value: &str) -> Vec<&str> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_institutional_loan_order_deserialize() {
+        let json = r#"{
+            "orderId": 100000001,
+            "loanCoin": "BUSD",
+            "totalDebt": "10000",
+            "residualInterest": "10.27687923",
+            "collateralAccountId": "12345678,23456789",
+            "collateralCoin": "BNB,BTC,ETH",
+            "totalCollateralValueAfterHaircut": "5000000",
+            "lockedCollateralValue": "1500000",
+            "currentLTV": "0.1",
+            "expirationTime": 1676851200000,
+            "loanDate": "1676851200000",
+            "loanTerm": "30days"
+        }"#;
+        let order: InstitutionalLoanOrder = serde_json::from_str(json).unwrap();
+        assert_eq!(order.current_ltv, 0.1);
+        assert_eq!(order.collateral_account_ids(), vec!["12345678", "23456789"]);
+        assert_eq!(order.collateral_coins(), vec!["BNB", "BTC", "ETH"]);
+    }
+
+    #[test]
+    fn test_institutional_loanable_asset_deserialize() {
+        let json = r#"{
+            "loanCoin": "BUSD",
+            "_flexibleDailyInterestRate": "0.001503",
+            "_flexibleYearlyInterestRate": "0.548595",
+            "_30dDailyInterestRate": "0.000136",
+            "_30dYearlyInterestRate": "0.03450",
+            "_60dDailyInterestRate": "0.000145",
+            "_60dYearlyInterestRate": "0.04103",
+            "minLimit": "100",
+            "maxLimit": "1000000",
+            "vipLevel": 1
+        }"#;
+        let asset: InstitutionalLoanableAsset = serde_json::from_str(json).unwrap();
+        assert_eq!(asset.flexible_yearly_interest_rate, Some(0.548595));
+        assert_eq!(asset.yearly_interest_rate_60d, Some(0.04103));
+        assert_eq!(asset.max_limit, 1000000.0);
+    }
+}
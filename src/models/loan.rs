@@ -0,0 +1,269 @@
+//! Crypto loan API response models.
+//!
+//! Models for the Binance flexible rate crypto loan SAPI endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use super::string_or_float;
+
+/// Direction of a flexible loan LTV adjustment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LtvAdjustDirection {
+    /// Add collateral (lowers LTV).
+    Additional,
+    /// Remove collateral (raises LTV).
+    Reduced,
+}
+
+impl std::fmt::Display for LtvAdjustDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Additional => "ADDITIONAL",
+            Self::Reduced => "REDUCED",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Health of a loan position relative to its collateral thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LtvStatus {
+    /// LTV is below the margin call threshold.
+    Healthy,
+    /// LTV has reached the margin call threshold.
+    MarginCall,
+    /// LTV has reached the liquidation threshold.
+    Liquidation,
+}
+
+/// Flexible loan borrow response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanBorrowResult {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Borrowed amount.
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Collateral amount.
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    /// Status (e.g., "Succeeds", "Failed", "Processing").
+    pub status: String,
+}
+
+/// Flexible loan ongoing order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanOngoingOrder {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Total debt (principal plus interest).
+    #[serde(with = "string_or_float")]
+    pub total_debt: f64,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Collateral amount.
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    /// Current loan-to-value ratio (e.g., 0.65 for 65%).
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+}
+
+/// Flexible loan borrow history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanBorrowRecord {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Initial loan amount.
+    #[serde(with = "string_or_float")]
+    pub initial_loan_amount: f64,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Initial collateral amount.
+    #[serde(with = "string_or_float")]
+    pub initial_collateral_amount: f64,
+    /// Borrow time.
+    pub borrow_time: u64,
+    /// Status.
+    pub status: String,
+}
+
+/// Flexible loan repay response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanRepayResult {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Remaining debt.
+    #[serde(with = "string_or_float")]
+    pub remaining_debt: f64,
+    /// Remaining collateral.
+    #[serde(with = "string_or_float")]
+    pub remaining_collateral: f64,
+    /// Whether the loan was fully repaid.
+    pub full_repayment: bool,
+    /// Loan-to-value ratio after the repayment.
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Repay status (e.g., "Repaid", "Repaying", "Failed").
+    pub repay_status: String,
+}
+
+/// Flexible loan repayment history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanRepayRecord {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Repaid amount.
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Collateral returned.
+    #[serde(with = "string_or_float")]
+    pub collateral_return: f64,
+    /// Repay status.
+    pub repay_status: String,
+    /// Repay time.
+    pub repay_time: u64,
+}
+
+/// Flexible loan LTV adjustment response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanLtvAdjustResult {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Adjustment direction.
+    pub direction: LtvAdjustDirection,
+    /// Collateral amount added or removed.
+    #[serde(with = "string_or_float")]
+    pub adjustment_amount: f64,
+    /// Loan-to-value ratio after the adjustment.
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Status.
+    pub status: String,
+}
+
+/// Flexible loan LTV adjustment history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanLtvAdjustRecord {
+    /// Borrowed coin.
+    pub loan_coin: String,
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Adjustment direction.
+    pub direction: LtvAdjustDirection,
+    /// Collateral amount added or removed.
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    /// LTV before the adjustment.
+    #[serde(rename = "preLTV", with = "string_or_float")]
+    pub pre_ltv: f64,
+    /// LTV after the adjustment.
+    #[serde(rename = "afterLTV", with = "string_or_float")]
+    pub after_ltv: f64,
+    /// Adjustment time.
+    pub adjust_time: u64,
+}
+
+/// Loanable asset data for flexible loans.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanableAsset {
+    /// Loanable coin.
+    pub loan_coin: String,
+    /// Flexible interest rate (annualized).
+    #[serde(with = "string_or_float")]
+    pub flexible_interest_rate: f64,
+    /// Minimum borrow amount.
+    #[serde(with = "string_or_float")]
+    pub flexible_min_limit: f64,
+    /// Maximum borrow amount.
+    #[serde(with = "string_or_float")]
+    pub flexible_max_limit: f64,
+}
+
+/// Collateral asset data for flexible loans.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleCollateralAsset {
+    /// Collateral coin.
+    pub collateral_coin: String,
+    /// Initial LTV.
+    #[serde(rename = "initialLTV", with = "string_or_float")]
+    pub initial_ltv: f64,
+    /// LTV at which a margin call is issued.
+    #[serde(rename = "marginCallLTV", with = "string_or_float")]
+    pub margin_call_ltv: f64,
+    /// LTV at which the position is liquidated.
+    #[serde(rename = "liquidationLTV", with = "string_or_float")]
+    pub liquidation_ltv: f64,
+    /// Maximum collateral amount.
+    #[serde(with = "string_or_float")]
+    pub max_limit: f64,
+}
+
+impl FlexibleCollateralAsset {
+    /// Classify an LTV against this collateral's thresholds.
+    pub fn ltv_status(&self, ltv: f64) -> LtvStatus {
+        if ltv >= self.liquidation_ltv {
+            LtvStatus::Liquidation
+        } else if ltv >= self.margin_call_ltv {
+            LtvStatus::MarginCall
+        } else {
+            LtvStatus::Healthy
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collateral_ltv_status() {
+        let json = r#"{
+            "collateralCoin": "BNB",
+            "initialLTV": "0.65",
+            "marginCallLTV": "0.75",
+            "liquidationLTV": "0.83",
+            "maxLimit": "1000000"
+        }"#;
+        let asset: FlexibleCollateralAsset = serde_json::from_str(json).unwrap();
+        assert_eq!(asset.margin_call_ltv, 0.75);
+        assert_eq!(asset.ltv_status(0.5), LtvStatus::Healthy);
+        assert_eq!(asset.ltv_status(0.75), LtvStatus::MarginCall);
+        assert_eq!(asset.ltv_status(0.9), LtvStatus::Liquidation);
+    }
+
+    #[test]
+    fn test_ltv_adjust_record_deserialize() {
+        let json = r#"{
+            "loanCoin": "BUSD",
+            "collateralCoin": "BNB",
+            "direction": "ADDITIONAL",
+            "collateralAmount": "5.235",
+            "preLTV": "0.78",
+            "afterLTV": "0.56",
+            "adjustTime": 1575018510000
+        }"#;
+        let record: FlexibleLoanLtvAdjustRecord = serde_json::from_str(json).unwrap();
+        assert_eq!(record.direction, LtvAdjustDirection::Additional);
+        assert_eq!(record.pre_ltv, 0.78);
+        assert_eq!(record.after_ltv, 0.56);
+    }
+}
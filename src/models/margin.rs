@@ -2,9 +2,11 @@
 //!
 //! Models for the Binance Margin SAPI endpoints.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
-use super::string_or_float;
+use super::{string_or_float, string_or_float_map};
 use crate::types::{OrderSide, OrderStatus, OrderType, TimeInForce};
 
 /// Margin transfer type.
@@ -28,6 +30,26 @@ pub enum IsolatedMarginTransferType {
     IsolatedMargin,
 }
 
+/// Direction of a margin borrow-repay operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BorrowRepayType {
+    /// Borrow an asset
+    Borrow,
+    /// Repay a loan
+    Repay,
+}
+
+impl std::fmt::Display for BorrowRepayType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Borrow => "BORROW",
+            Self::Repay => "REPAY",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Side effect type for margin orders.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -41,6 +63,53 @@ pub enum SideEffectType {
     AutoRepay,
 }
 
+impl std::fmt::Display for SideEffectType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::NoSideEffect => "NO_SIDE_EFFECT",
+            Self::MarginBuy => "MARGIN_BUY",
+            Self::AutoRepay => "AUTO_REPAY",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Maximum leverage of the cross margin account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MarginMaxLeverage {
+    /// 3x (Cross Margin Classic)
+    X3,
+    /// 5x
+    X5,
+    /// 10x (Cross Margin Pro)
+    X10,
+}
+
+impl MarginMaxLeverage {
+    /// Get the leverage multiple.
+    pub fn value(&self) -> u8 {
+        match self {
+            Self::X3 => 3,
+            Self::X5 => 5,
+            Self::X10 => 10,
+        }
+    }
+}
+
+impl std::fmt::Display for MarginMaxLeverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// Response from adjusting the cross margin max leverage.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaxLeverageResult {
+    /// Whether the leverage was changed.
+    pub success: bool,
+}
+
 /// Transaction ID response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -444,6 +513,99 @@ pub struct RepayRecord {
     pub tx_id: u64,
 }
 
+impl From<BorrowRepayRecord> for LoanRecord {
+    fn from(record: BorrowRepayRecord) -> Self {
+        Self {
+            asset: record.asset,
+            principal: record.principal,
+            timestamp: record.timestamp,
+            status: record.status,
+            isolated_symbol: record.isolated_symbol,
+            tx_id: record.tx_id,
+        }
+    }
+}
+
+impl From<BorrowRepayRecord> for RepayRecord {
+    fn from(record: BorrowRepayRecord) -> Self {
+        Self {
+            asset: record.asset,
+            amount: record.amount,
+            interest: record.interest,
+            principal: record.principal,
+            timestamp: record.timestamp,
+            status: record.status,
+            isolated_symbol: record.isolated_symbol,
+            tx_id: record.tx_id,
+        }
+    }
+}
+
+/// Borrow or repay record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BorrowRepayRecord {
+    /// Asset.
+    pub asset: String,
+    /// Total amount borrowed or repaid.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Interest repaid.
+    #[serde(default, with = "string_or_float")]
+    pub interest: f64,
+    /// Principal borrowed or repaid.
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    /// Timestamp.
+    pub timestamp: u64,
+    /// Status (PENDING, CONFIRMED, FAILED).
+    pub status: String,
+    /// Isolated symbol (for isolated margin).
+    #[serde(default)]
+    pub isolated_symbol: Option<String>,
+    /// Transaction ID.
+    pub tx_id: u64,
+}
+
+/// Small liability that can be exchanged for BNB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallLiabilityAsset {
+    /// Asset.
+    pub asset: String,
+    /// Interest owed.
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    /// Principal owed.
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    /// Asset the liability is valued in.
+    pub liability_asset: String,
+    /// Liability value in `liability_asset`.
+    #[serde(with = "string_or_float")]
+    pub liability_qty: f64,
+}
+
+/// Small liability exchange record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SmallLiabilityExchangeRecord {
+    /// Asset exchanged.
+    pub asset: String,
+    /// Amount exchanged.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Asset received.
+    pub target_asset: String,
+    /// Amount received.
+    #[serde(with = "string_or_float")]
+    pub target_amount: f64,
+    /// Business type.
+    pub biz_type: String,
+    /// Timestamp.
+    pub timestamp: u64,
+}
+
 /// Records query result (paginated).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecordsQueryResult<T> {
@@ -473,6 +635,34 @@ pub struct MarginPairDetails {
     pub is_sell_allowed: bool,
 }
 
+/// Isolated margin pair details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginPair {
+    /// Symbol.
+    pub symbol: String,
+    /// Base asset.
+    pub base: String,
+    /// Quote asset.
+    pub quote: String,
+    /// Whether margin trading is enabled.
+    pub is_margin_trade: bool,
+    /// Whether buy is allowed.
+    pub is_buy_allowed: bool,
+    /// Whether sell is allowed.
+    pub is_sell_allowed: bool,
+}
+
+/// Response from enabling or disabling an isolated margin account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedAccountToggle {
+    /// Whether the account was enabled or disabled.
+    pub success: bool,
+    /// Symbol of the isolated margin account.
+    pub symbol: String,
+}
+
 /// Margin asset info.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -506,6 +696,147 @@ pub struct MarginPriceIndex {
     pub symbol: String,
 }
 
+/// Cross margin fee and borrowing data of a coin for a VIP level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CrossMarginFeeData {
+    /// VIP level.
+    pub vip_level: u32,
+    /// Coin.
+    pub coin: String,
+    /// Whether the coin can be transferred into the margin account.
+    pub transfer_in: bool,
+    /// Whether the coin can be borrowed.
+    pub borrowable: bool,
+    /// Daily interest rate.
+    #[serde(with = "string_or_float")]
+    pub daily_interest: f64,
+    /// Yearly interest rate.
+    #[serde(with = "string_or_float")]
+    pub yearly_interest: f64,
+    /// Borrow limit.
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+    /// Margin pairs the coin belongs to.
+    #[serde(default)]
+    pub marginable_pairs: Vec<String>,
+}
+
+/// Isolated margin fee data of a symbol for a VIP level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginFeeData {
+    /// VIP level.
+    pub vip_level: u32,
+    /// Symbol.
+    pub symbol: String,
+    /// Maximum leverage.
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    /// Interest and borrow limit of the base and quote assets.
+    pub data: Vec<IsolatedMarginFeeCoin>,
+}
+
+/// Interest and borrow limit of an asset in an isolated margin pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IsolatedMarginFeeCoin {
+    /// Coin.
+    pub coin: String,
+    /// Daily interest rate.
+    #[serde(with = "string_or_float")]
+    pub daily_interest: f64,
+    /// Borrow limit.
+    #[serde(with = "string_or_float")]
+    pub borrow_limit: f64,
+}
+
+/// Order executed to liquidate a margin account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceLiquidationRecord {
+    /// Average fill price.
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    /// Executed quantity.
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    /// Order ID.
+    pub order_id: u64,
+    /// Order price.
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// Order quantity.
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    /// Order side.
+    pub side: OrderSide,
+    /// Symbol.
+    pub symbol: String,
+    /// Time in force.
+    pub time_in_force: TimeInForce,
+    /// Whether the isolated margin account was liquidated.
+    pub is_isolated: bool,
+    /// Liquidation time.
+    pub updated_time: u64,
+}
+
+/// Liability repaid by a manual liquidation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualLiquidationResult {
+    /// Asset.
+    pub asset: String,
+    /// Interest repaid.
+    #[serde(with = "string_or_float")]
+    pub interest: f64,
+    /// Principal repaid.
+    #[serde(with = "string_or_float")]
+    pub principal: f64,
+    /// Liability asset.
+    pub liability_asset: String,
+    /// Liability quantity.
+    #[serde(with = "string_or_float")]
+    pub liability_qty: f64,
+}
+
+/// Amount of each asset available to borrow on the margin platform.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAvailableInventory {
+    /// Available amount by asset.
+    #[serde(with = "string_or_float_map")]
+    pub assets: HashMap<String, f64>,
+    /// Update time.
+    pub update_time: u64,
+}
+
+/// Interest rate of an asset for the next hour.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NextHourlyInterestRate {
+    /// Asset.
+    pub asset: String,
+    /// Hourly interest rate.
+    #[serde(with = "string_or_float")]
+    pub next_hourly_interest_rate: f64,
+}
+
+/// Margin level thresholds of the cross margin account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginTradeCoeff {
+    /// Margin level above which the account is in good standing.
+    #[serde(with = "string_or_float")]
+    pub normal_bar: f64,
+    /// Margin level at which a margin call is issued.
+    #[serde(with = "string_or_float")]
+    pub margin_call_bar: f64,
+    /// Margin level at which the account is liquidated.
+    #[serde(with = "string_or_float")]
+    pub force_liquidation_bar: f64,
+}
+
 /// Isolated margin account limit.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -2,9 +2,16 @@
 //!
 //! These models represent responses from public market data endpoints.
 
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::FilterViolation;
 use crate::types::{OrderType, RateLimitInterval, RateLimitType, SymbolPermission, SymbolStatus};
+use crate::{Error, Result};
+
+/// Relative tolerance when checking that a value is a multiple of a step.
+const STEP_TOLERANCE: f64 = 1e-9;
 
 /// Server time response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +96,12 @@ pub struct Symbol {
     /// Symbol permissions.
     #[serde(default)]
     pub permissions: Vec<SymbolPermission>,
+    /// Symbol permission sets.
+    ///
+    /// An account may trade the symbol if it holds every permission in at
+    /// least one of the sets.
+    #[serde(default)]
+    pub permission_sets: Vec<Vec<SymbolPermission>>,
 }
 
 fn default_true() -> bool {
@@ -116,6 +129,330 @@ impl Symbol {
             .iter()
             .find(|f| matches!(f, SymbolFilter::MinNotional { .. }))
     }
+
+    /// Get the NOTIONAL filter for this symbol.
+    pub fn notional(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::Notional { .. }))
+    }
+
+    /// Get the MARKET_LOT_SIZE filter for this symbol.
+    pub fn market_lot_size(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::MarketLotSize { .. }))
+    }
+
+    /// Get the PERCENT_PRICE filter for this symbol.
+    pub fn percent_price(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::PercentPrice { .. }))
+    }
+
+    /// Get the PERCENT_PRICE_BY_SIDE filter for this symbol.
+    pub fn percent_price_by_side(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::PercentPriceBySide { .. }))
+    }
+
+    /// Get the ICEBERG_PARTS filter for this symbol.
+    pub fn iceberg_parts(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::IcebergParts { .. }))
+    }
+
+    /// Get the MAX_NUM_ORDERS filter for this symbol.
+    pub fn max_num_orders(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::MaxNumOrders { .. }))
+    }
+
+    /// Get the TRAILING_DELTA filter for this symbol.
+    pub fn trailing_delta(&self) -> Option<&SymbolFilter> {
+        self.filters
+            .iter()
+            .find(|f| matches!(f, SymbolFilter::TrailingDelta { .. }))
+    }
+
+    /// Check whether the symbol carries the given permission.
+    ///
+    /// Both the legacy `permissions` list and the newer `permissionSets`
+    /// are consulted, along with the `isSpotTradingAllowed` and
+    /// `isMarginTradingAllowed` flags.
+    pub fn has_permission(&self, permission: SymbolPermission) -> bool {
+        let allowed_by_flag = match permission {
+            SymbolPermission::Spot => self.is_spot_trading_allowed,
+            SymbolPermission::Margin => self.is_margin_trading_allowed,
+            SymbolPermission::Other => false,
+        };
+        if !allowed_by_flag {
+            return false;
+        }
+
+        // Older exchange info responses may omit both lists entirely.
+        if self.permissions.is_empty() && self.permission_sets.is_empty() {
+            return true;
+        }
+
+        self.permissions.contains(&permission)
+            || self
+                .permission_sets
+                .iter()
+                .any(|set| set.contains(&permission))
+    }
+
+    /// Check whether the symbol accepts the given order type.
+    pub fn allows_order_type(&self, order_type: OrderType) -> bool {
+        self.order_types.contains(&order_type)
+    }
+
+    /// Validate that an order of `order_type` may be placed on this symbol
+    /// from an account with the given permission.
+    ///
+    /// Returns [`Error::OrderNotPermitted`] describing the first violation,
+    /// so callers can reject an order before it is sent to the exchange.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{OrderType, SymbolPermission};
+    ///
+    /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT"]).await?;
+    /// let symbol = &info.symbols[0];
+    /// symbol.check_order(SymbolPermission::Margin, OrderType::Limit)?;
+    /// ```
+    pub fn check_order(&self, permission: SymbolPermission, order_type: OrderType) -> Result<()> {
+        if !self.has_permission(permission) {
+            return Err(Error::OrderNotPermitted {
+                symbol: self.symbol.clone(),
+                reason: format!("symbol is not enabled for {} trading", permission),
+            });
+        }
+        if !self.allows_order_type(order_type) {
+            return Err(Error::OrderNotPermitted {
+                symbol: self.symbol.clone(),
+                reason: format!("order type {} is not supported", order_type),
+            });
+        }
+        Ok(())
+    }
+
+    /// Check an order's price and quantity against the symbol's filters.
+    ///
+    /// Checks PRICE_FILTER, PERCENT_PRICE, LOT_SIZE, MARKET_LOT_SIZE,
+    /// MIN_NOTIONAL and NOTIONAL. Returns [`Error::FilterViolation`]
+    /// describing the first violation, so callers can reject an order before
+    /// the exchange does with error -1013.
+    ///
+    /// # Arguments
+    ///
+    /// * `price` - Limit price (`None` for market orders)
+    /// * `quantity` - Base asset quantity
+    /// * `quote_quantity` - Quote asset quantity (market orders)
+    /// * `is_market` - Whether the order executes at market
+    /// * `avg_price` - Current average price, used by PERCENT_PRICE and to
+    ///   estimate the notional of market orders; those checks are skipped
+    ///   when it is `None`
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT"]).await?;
+    /// info.symbols[0].check_filters(Some(50000.0), Some(0.001), None, false, None)?;
+    /// ```
+    pub fn check_filters(
+        &self,
+        price: Option<f64>,
+        quantity: Option<f64>,
+        quote_quantity: Option<f64>,
+        is_market: bool,
+        avg_price: Option<f64>,
+    ) -> Result<()> {
+        let price = price.filter(|_| !is_market);
+        let notional = quote_quantity.or_else(|| Some(quantity? * price.or(avg_price)?));
+
+        for filter in &self.filters {
+            let violation =
+                match *filter {
+                    SymbolFilter::PriceFilter {
+                        min_price,
+                        max_price,
+                        tick_size,
+                    } => price.and_then(|p| check_price(p, min_price, max_price, tick_size)),
+                    SymbolFilter::PercentPrice {
+                        multiplier_up,
+                        multiplier_down,
+                        ..
+                    } => {
+                        match (price, avg_price) {
+                            (Some(price), Some(avg)) => {
+                                let (min, max) = (avg * multiplier_down, avg * multiplier_up);
+                                (price < min || price > max)
+                                    .then_some(FilterViolation::PercentPrice { price, min, max })
+                            }
+                            _ => None,
+                        }
+                    }
+                    SymbolFilter::LotSize {
+                        min_qty,
+                        max_qty,
+                        step_size,
+                    } => quantity
+                        .and_then(|q| check_quantity("LOT_SIZE", q, min_qty, max_qty, step_size)),
+                    SymbolFilter::MarketLotSize {
+                        min_qty,
+                        max_qty,
+                        step_size,
+                    } if is_market => quantity.and_then(|q| {
+                        check_quantity("MARKET_LOT_SIZE", q, min_qty, max_qty, step_size)
+                    }),
+                    SymbolFilter::MinNotional {
+                        min_notional,
+                        apply_to_market,
+                        ..
+                    } if !is_market || apply_to_market => notional
+                        .filter(|n| *n < min_notional)
+                        .map(|notional| FilterViolation::NotionalTooSmall {
+                            filter: "MIN_NOTIONAL",
+                            notional,
+                            min_notional,
+                        }),
+                    SymbolFilter::Notional {
+                        min_notional,
+                        apply_min_to_market,
+                        max_notional,
+                        apply_max_to_market,
+                        ..
+                    } => notional.and_then(|notional| {
+                        if (!is_market || apply_min_to_market) && notional < min_notional {
+                            Some(FilterViolation::NotionalTooSmall {
+                                filter: "NOTIONAL",
+                                notional,
+                                min_notional,
+                            })
+                        } else if (!is_market || apply_max_to_market)
+                            && max_notional > 0.0
+                            && notional > max_notional
+                        {
+                            Some(FilterViolation::NotionalTooLarge {
+                                notional,
+                                max_notional,
+                            })
+                        } else {
+                            None
+                        }
+                    }),
+                    _ => None,
+                };
+
+            if let Some(violation) = violation {
+                return Err(Error::FilterViolation {
+                    symbol: self.symbol.clone(),
+                    violation,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Round a price to the nearest multiple of the PRICE_FILTER tick size.
+    ///
+    /// Returns the price formatted with the tick size's decimal places,
+    /// ready to pass to an order builder. Without a tick size the price is
+    /// formatted as is.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT"]).await?;
+    /// let symbol = &info.symbols[0];
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+    ///     .price(&symbol.round_price(mid * 0.999))
+    ///     .quantity(&symbol.round_qty(1000.0 / mid))
+    ///     .time_in_force(TimeInForce::GTC)
+    ///     .build();
+    /// ```
+    pub fn round_price(&self, price: f64) -> String {
+        match self.price_filter() {
+            Some(SymbolFilter::PriceFilter { tick_size, .. }) if *tick_size > 0.0 => {
+                snap(price, *tick_size, f64::round)
+            }
+            _ => price.to_string(),
+        }
+    }
+
+    /// Round a quantity down to a multiple of the LOT_SIZE step size.
+    ///
+    /// Rounding down keeps the order within the intended amount. Returns the
+    /// quantity formatted with the step size's decimal places.
+    pub fn round_qty(&self, quantity: f64) -> String {
+        match self.lot_size() {
+            Some(SymbolFilter::LotSize { step_size, .. }) if *step_size > 0.0 => {
+                snap(quantity, *step_size, |steps| {
+                    (steps + STEP_TOLERANCE).floor()
+                })
+            }
+            _ => quantity.to_string(),
+        }
+    }
+}
+
+// Snap `value` to a multiple of `step` and format it with the step's decimals.
+fn snap(value: f64, step: f64, round: impl Fn(f64) -> f64) -> String {
+    let decimals = step_decimals(step);
+    format!("{:.*}", decimals, round(value / step) * step)
+}
+
+// Number of decimal places in a step size such as 0.00100000.
+fn step_decimals(step: f64) -> usize {
+    let formatted = step.to_string();
+    match formatted.split_once('.') {
+        Some((_, fraction)) => fraction.trim_end_matches('0').len(),
+        None => 0,
+    }
+}
+
+fn check_price(price: f64, min: f64, max: f64, tick_size: f64) -> Option<FilterViolation> {
+    if (min > 0.0 && price < min) || (max > 0.0 && price > max) {
+        return Some(FilterViolation::PriceOutOfRange { price, min, max });
+    }
+    (tick_size > 0.0 && !is_multiple(price - min, tick_size))
+        .then_some(FilterViolation::PriceTickSize { price, tick_size })
+}
+
+fn check_quantity(
+    filter: &'static str,
+    quantity: f64,
+    min: f64,
+    max: f64,
+    step_size: f64,
+) -> Option<FilterViolation> {
+    if quantity < min || (max > 0.0 && quantity > max) {
+        return Some(FilterViolation::QuantityOutOfRange {
+            filter,
+            quantity,
+            min,
+            max,
+        });
+    }
+    (step_size > 0.0 && !is_multiple(quantity - min, step_size)).then_some(
+        FilterViolation::QuantityStepSize {
+            filter,
+            quantity,
+            step_size,
+        },
+    )
+}
+
+fn is_multiple(value: f64, step: f64) -> bool {
+    let steps = value / step;
+    (steps - steps.round()).abs() <= STEP_TOLERANCE * steps.abs().max(1.0)
 }
 
 /// Symbol filter types.
@@ -149,6 +486,25 @@ pub enum SymbolFilter {
         /// Average price minutes.
         avg_price_mins: u64,
     },
+    /// Percent price by side filter - price relative to average, per side.
+    #[serde(rename = "PERCENT_PRICE_BY_SIDE")]
+    #[serde(rename_all = "camelCase")]
+    PercentPriceBySide {
+        /// Upper multiplier for bids.
+        #[serde(with = "string_or_float")]
+        bid_multiplier_up: f64,
+        /// Lower multiplier for bids.
+        #[serde(with = "string_or_float")]
+        bid_multiplier_down: f64,
+        /// Upper multiplier for asks.
+        #[serde(with = "string_or_float")]
+        ask_multiplier_up: f64,
+        /// Lower multiplier for asks.
+        #[serde(with = "string_or_float")]
+        ask_multiplier_down: f64,
+        /// Average price minutes.
+        avg_price_mins: u64,
+    },
     /// Lot size filter - valid quantity range and step size.
     #[serde(rename = "LOT_SIZE")]
     #[serde(rename_all = "camelCase")]
@@ -220,6 +576,20 @@ pub enum SymbolFilter {
         /// Maximum number of orders.
         max_num_orders: u16,
     },
+    /// Max order lists filter.
+    #[serde(rename = "MAX_NUM_ORDER_LISTS")]
+    #[serde(rename_all = "camelCase")]
+    MaxNumOrderLists {
+        /// Maximum number of open order lists.
+        max_num_order_lists: u16,
+    },
+    /// Max order amends filter.
+    #[serde(rename = "MAX_NUM_ORDER_AMENDS")]
+    #[serde(rename_all = "camelCase")]
+    MaxNumOrderAmends {
+        /// Maximum number of amendments to a single order.
+        max_num_order_amends: u16,
+    },
     /// Max algo orders filter.
     #[serde(rename = "MAX_NUM_ALGO_ORDERS")]
     #[serde(rename_all = "camelCase")]
@@ -256,6 +626,20 @@ pub enum SymbolFilter {
         /// Maximum number of algo orders.
         max_num_algo_orders: u16,
     },
+    /// Exchange max iceberg orders filter.
+    #[serde(rename = "EXCHANGE_MAX_NUM_ICEBERG_ORDERS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumIcebergOrders {
+        /// Maximum number of iceberg orders.
+        max_num_iceberg_orders: u16,
+    },
+    /// Exchange max order lists filter.
+    #[serde(rename = "EXCHANGE_MAX_NUM_ORDER_LISTS")]
+    #[serde(rename_all = "camelCase")]
+    ExchangeMaxNumOrderLists {
+        /// Maximum number of open order lists.
+        max_num_order_lists: u16,
+    },
     /// Trailing delta filter.
     #[serde(rename = "TRAILING_DELTA")]
     #[serde(rename_all = "camelCase")]
@@ -350,7 +734,20 @@ pub struct AggTrade {
 }
 
 /// Kline/candlestick data.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Unset fields default to zero, which keeps test fixtures short:
+///
+/// ```rust
+/// use binance_api_client::Kline;
+///
+/// let kline = Kline {
+///     open_time: 1_700_000_000_000,
+///     close: 42_000.0,
+///     ..Default::default()
+/// };
+/// assert_eq!(kline.volume, 0.0);
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Kline {
     /// Kline open time.
     pub open_time: i64,
@@ -439,6 +836,42 @@ pub struct Ticker24h {
     pub count: u64,
 }
 
+/// 24hr ticker price change statistics (MINI).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker24hMini {
+    /// Symbol.
+    pub symbol: String,
+    /// Open price.
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    /// High price.
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    /// Low price.
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    /// Last price.
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    /// Total volume.
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    /// Quote volume.
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    /// Open time.
+    pub open_time: u64,
+    /// Close time.
+    pub close_time: u64,
+    /// First trade ID.
+    pub first_id: i64,
+    /// Last trade ID.
+    pub last_id: i64,
+    /// Trade count.
+    pub count: u64,
+}
+
 /// Trading day ticker statistics (FULL).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -633,12 +1066,62 @@ pub struct BookTicker {
 
 /// Average price response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AveragePrice {
     /// Number of minutes the average is calculated over.
     pub mins: u64,
     /// Average price.
     #[serde(with = "string_or_float")]
     pub price: f64,
+    /// Time of the last trade in the window (not sent by older API
+    /// versions).
+    #[serde(default)]
+    pub close_time: Option<u64>,
+}
+
+impl AveragePrice {
+    /// Get the length of the window the average is calculated over.
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(self.mins * 60)
+    }
+
+    /// Get the start of the window in milliseconds, if the close time is
+    /// known.
+    pub fn start_time(&self) -> Option<u64> {
+        let window_ms = self.window().as_millis() as u64;
+        self.close_time.map(|t| t.saturating_sub(window_ms))
+    }
+}
+
+/// Combined market data for one symbol.
+///
+/// Returned by [`Market::snapshot`](crate::rest::Market::snapshot).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSnapshot {
+    /// Symbol.
+    pub symbol: String,
+    /// Last price.
+    pub price: f64,
+    /// 24hr ticker statistics.
+    pub ticker_24h: Ticker24h,
+    /// Best bid and ask.
+    pub book_ticker: BookTicker,
+    /// Most recent trades, oldest first.
+    pub recent_trades: Vec<Trade>,
+    /// Top levels of the order book.
+    pub depth: OrderBook,
+}
+
+impl MarketSnapshot {
+    /// Get the mid price of the best bid and ask.
+    pub fn mid_price(&self) -> f64 {
+        (self.book_ticker.bid_price + self.book_ticker.ask_price) / 2.0
+    }
+
+    /// Get the spread between the best ask and bid.
+    pub fn spread(&self) -> f64 {
+        self.book_ticker.ask_price - self.book_ticker.bid_price
+    }
 }
 
 /// Helper module for deserializing string or float values.
@@ -711,6 +1194,31 @@ pub mod string_or_float_opt {
     }
 }
 
+/// Helper for maps of f64 values that may be strings.
+pub mod string_or_float_map {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &HashMap<String, f64>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(value.iter().map(|(k, v)| (k, v.to_string())))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Amount(#[serde(with = "super::string_or_float")] f64);
+
+        let map: HashMap<String, Amount> = HashMap::deserialize(deserializer)?;
+        Ok(map.into_iter().map(|(k, Amount(v))| (k, v)).collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -785,6 +1293,36 @@ mod tests {
         let avg: AveragePrice = serde_json::from_str(json).unwrap();
         assert_eq!(avg.mins, 5);
         assert_eq!(avg.price, 50000.0);
+        assert_eq!(avg.close_time, None);
+
+        let json = r#"{"mins": 5, "price": "50000.00", "closeTime": 1704067500000}"#;
+        let avg: AveragePrice = serde_json::from_str(json).unwrap();
+        assert_eq!(avg.window(), Duration::from_secs(300));
+        assert_eq!(avg.start_time(), Some(1704067200000));
+    }
+
+    #[test]
+    fn test_ticker_24h_mini_deserialize() {
+        let json = r#"{
+            "symbol": "BNBBTC",
+            "openPrice": "99.00000000",
+            "highPrice": "100.00000000",
+            "lowPrice": "0.10000000",
+            "lastPrice": "4.00000200",
+            "volume": "8913.30000000",
+            "quoteVolume": "15.30000000",
+            "openTime": 1499783499040,
+            "closeTime": 1499869899040,
+            "firstId": 28385,
+            "lastId": 28460,
+            "count": 76
+        }"#;
+        let ticker: Ticker24hMini = serde_json::from_str(json).unwrap();
+        assert_eq!(ticker.last_price, 4.000002);
+        assert_eq!(ticker.count, 76);
+
+        // The MINI response cannot be mistaken for the full one
+        assert!(serde_json::from_str::<Ticker24h>(json).is_err());
     }
 
     #[test]
@@ -833,10 +1371,183 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_symbol_filter_percent_price_by_side() {
+        let json = r#"{
+            "filterType": "PERCENT_PRICE_BY_SIDE",
+            "bidMultiplierUp": "1.2",
+            "bidMultiplierDown": "0.2",
+            "askMultiplierUp": "5",
+            "askMultiplierDown": "0.8",
+            "avgPriceMins": 1
+        }"#;
+        let filter: SymbolFilter = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            filter,
+            SymbolFilter::PercentPriceBySide {
+                bid_multiplier_up: 1.2,
+                bid_multiplier_down: 0.2,
+                ask_multiplier_up: 5.0,
+                ask_multiplier_down: 0.8,
+                avg_price_mins: 1,
+            }
+        );
+
+        let json = r#"{"filterType": "MAX_NUM_ORDER_AMENDS", "maxNumOrderAmends": 10}"#;
+        let filter: SymbolFilter = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            filter,
+            SymbolFilter::MaxNumOrderAmends {
+                max_num_order_amends: 10
+            }
+        );
+    }
+
     #[test]
     fn test_unknown_filter_type() {
         let json = r#"{"filterType": "UNKNOWN_FILTER_TYPE"}"#;
         let filter: SymbolFilter = serde_json::from_str(json).unwrap();
         assert_eq!(filter, SymbolFilter::Other);
     }
+
+    fn sample_symbol(margin_allowed: bool) -> Symbol {
+        let json = format!(
+            r#"{{
+                "symbol": "BTCUSDT",
+                "status": "TRADING",
+                "baseAsset": "BTC",
+                "baseAssetPrecision": 8,
+                "quoteAsset": "USDT",
+                "quotePrecision": 8,
+                "quoteAssetPrecision": 8,
+                "orderTypes": ["LIMIT", "MARKET"],
+                "icebergAllowed": true,
+                "ocoAllowed": true,
+                "isSpotTradingAllowed": true,
+                "isMarginTradingAllowed": {margin_allowed},
+                "filters": [],
+                "permissions": [],
+                "permissionSets": [["SPOT", "TRD_GRP_004"]]
+            }}"#
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_symbol_permission_sets() {
+        let symbol = sample_symbol(false);
+        assert_eq!(
+            symbol.permission_sets,
+            vec![vec![SymbolPermission::Spot, SymbolPermission::Other]]
+        );
+        assert!(symbol.has_permission(SymbolPermission::Spot));
+        assert!(!symbol.has_permission(SymbolPermission::Margin));
+    }
+
+    #[test]
+    fn test_symbol_check_order() {
+        let symbol = sample_symbol(false);
+        assert!(
+            symbol
+                .check_order(SymbolPermission::Spot, OrderType::Limit)
+                .is_ok()
+        );
+
+        let err = symbol
+            .check_order(SymbolPermission::Margin, OrderType::Limit)
+            .unwrap_err();
+        assert!(matches!(err, Error::OrderNotPermitted { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Order not permitted for BTCUSDT: symbol is not enabled for MARGIN trading"
+        );
+
+        let err = symbol
+            .check_order(SymbolPermission::Spot, OrderType::StopLossLimit)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Order not permitted for BTCUSDT: order type STOP_LOSS_LIMIT is not supported"
+        );
+    }
+
+    #[test]
+    fn test_symbol_check_filters() {
+        let mut symbol = sample_symbol(false);
+        symbol.filters = serde_json::from_str(
+            r#"[
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000",
+                 "tickSize": "0.01"},
+                {"filterType": "PERCENT_PRICE", "multiplierUp": "5", "multiplierDown": "0.2",
+                 "avgPriceMins": 5},
+                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000",
+                 "stepSize": "0.00001"},
+                {"filterType": "NOTIONAL", "minNotional": "5", "applyMinToMarket": true,
+                 "maxNotional": "9000000", "applyMaxToMarket": false, "avgPriceMins": 5}
+            ]"#,
+        )
+        .unwrap();
+
+        assert!(
+            symbol
+                .check_filters(Some(50000.01), Some(0.001), None, false, None)
+                .is_ok()
+        );
+
+        let violation = |result: Result<()>| match result.unwrap_err() {
+            Error::FilterViolation { violation, .. } => violation,
+            e => panic!("unexpected error: {}", e),
+        };
+
+        assert!(matches!(
+            violation(symbol.check_filters(Some(50000.005), Some(0.001), None, false, None)),
+            FilterViolation::PriceTickSize { .. }
+        ));
+        assert!(matches!(
+            violation(symbol.check_filters(Some(50000.0), Some(0.000015), None, false, None)),
+            FilterViolation::QuantityStepSize { .. }
+        ));
+        assert!(matches!(
+            violation(symbol.check_filters(Some(50000.0), Some(0.00005), None, false, None)),
+            FilterViolation::NotionalTooSmall { .. }
+        ));
+        assert!(matches!(
+            violation(symbol.check_filters(Some(500.0), Some(1.0), None, false, Some(50000.0))),
+            FilterViolation::PercentPrice { .. }
+        ));
+
+        // Market orders use the quote quantity or the average price for notional
+        assert!(
+            symbol
+                .check_filters(None, None, Some(10.0), true, None)
+                .is_ok()
+        );
+        assert!(matches!(
+            violation(symbol.check_filters(None, Some(0.00001), None, true, Some(50000.0))),
+            FilterViolation::NotionalTooSmall { .. }
+        ));
+    }
+
+    #[test]
+    fn test_symbol_rounding() {
+        let mut symbol = sample_symbol(false);
+        assert_eq!(symbol.round_price(1.5), "1.5");
+
+        symbol.filters = serde_json::from_str(
+            r#"[
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000",
+                 "tickSize": "0.01000000"},
+                {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "9000",
+                 "stepSize": "0.00100000"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(symbol.round_price(50000.123456), "50000.12");
+        assert_eq!(symbol.round_price(50000.126), "50000.13");
+        assert_eq!(symbol.round_price(0.1 + 0.2), "0.30");
+        assert_eq!(symbol.round_qty(0.0019999), "0.001");
+        assert_eq!(symbol.round_qty(0.3), "0.300");
+        assert_eq!(symbol.round_qty(12.0), "12.000");
+    }
 }
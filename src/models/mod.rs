@@ -4,8 +4,17 @@
 //! and request payloads.
 
 pub mod account;
+pub mod algo;
+#[cfg(feature = "broker")]
+pub mod broker;
+pub mod earn;
+pub mod fiat;
+#[cfg(feature = "institutional-loan")]
+pub mod institutional_loan;
+pub mod loan;
 pub mod margin;
 pub mod market;
+pub mod staking;
 pub mod wallet;
 pub mod websocket;
 
@@ -0,0 +1,386 @@
+//! Staking API response models.
+//!
+//! Models for the Binance ETH staking (WBETH) and SOL staking (BNSOL) SAPI endpoints.
+
+use serde::{Deserialize, Serialize};
+
+use super::string_or_float;
+
+// ETH staking.
+
+/// Response from staking ETH.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakeResult {
+    /// Whether the request succeeded.
+    pub success: bool,
+    /// WBETH amount received.
+    #[serde(with = "string_or_float")]
+    pub wbeth_amount: f64,
+    /// ETH to WBETH conversion ratio.
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+/// Response from redeeming ETH (from BETH or WBETH).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRedeemResult {
+    /// Whether the request succeeded.
+    pub success: bool,
+    /// ETH amount to be received.
+    #[serde(with = "string_or_float")]
+    pub eth_amount: f64,
+    /// Conversion ratio applied.
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+    /// Estimated arrival time.
+    pub arrival_time: u64,
+}
+
+/// Response from wrapping BETH into WBETH.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WbethWrapResult {
+    /// Whether the request succeeded.
+    pub success: bool,
+    /// WBETH amount received.
+    #[serde(with = "string_or_float")]
+    pub wbeth_amount: f64,
+    /// BETH to WBETH exchange rate.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+}
+
+/// ETH staking account holdings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthStakingHoldings {
+    /// WBETH amount.
+    #[serde(rename = "wbethAmount", with = "string_or_float")]
+    pub wbeth_amount: f64,
+    /// BETH amount.
+    #[serde(rename = "bethAmount", with = "string_or_float")]
+    pub beth_amount: f64,
+}
+
+/// ETH staking profit over the last 30 days.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthStakingProfit {
+    /// Profit from WBETH, in ETH.
+    #[serde(rename = "amountFromWBETH", with = "string_or_float")]
+    pub amount_from_wbeth: f64,
+    /// Profit from BETH, in ETH.
+    #[serde(rename = "amountFromBETH", with = "string_or_float")]
+    pub amount_from_beth: f64,
+}
+
+/// ETH staking account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EthStakingAccount {
+    /// Total holdings, in ETH.
+    #[serde(rename = "holdingInETH", with = "string_or_float")]
+    pub holding_in_eth: f64,
+    /// Holdings by asset.
+    pub holdings: EthStakingHoldings,
+    /// Profit over the last 30 days, in ETH.
+    #[serde(rename = "thirtyDaysProfitInETH", with = "string_or_float")]
+    pub thirty_days_profit_in_eth: f64,
+    /// Profit breakdown by asset.
+    pub profit: EthStakingProfit,
+}
+
+/// Remaining personal staking and redemption quota.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingQuota {
+    /// Remaining personal staking quota.
+    #[serde(with = "string_or_float")]
+    pub left_staking_personal_quota: f64,
+    /// Remaining personal redemption quota.
+    #[serde(with = "string_or_float")]
+    pub left_redemption_personal_quota: f64,
+}
+
+/// ETH staking history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingRecord {
+    /// Record time.
+    pub time: u64,
+    /// Staked asset.
+    pub asset: String,
+    /// Staked amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Status (e.g., "PENDING", "SUCCESS", "FAILED").
+    pub status: String,
+    /// Amount of WBETH/BETH received.
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    /// Conversion ratio applied.
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+/// ETH redemption history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRedemptionRecord {
+    /// Record time.
+    pub time: u64,
+    /// Estimated arrival time.
+    pub arrival_time: u64,
+    /// Redeemed asset (BETH or WBETH).
+    pub asset: String,
+    /// Redeemed amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Status (e.g., "PENDING", "SUCCESS", "FAILED").
+    pub status: String,
+    /// Asset received.
+    pub distribute_asset: String,
+    /// Amount received.
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    /// Conversion ratio applied.
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+/// BETH rewards distribution record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRewardRecord {
+    /// Distribution time.
+    pub time: u64,
+    /// Reward asset.
+    pub asset: String,
+    /// Holding at distribution time.
+    #[serde(with = "string_or_float")]
+    pub holding: f64,
+    /// Reward amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Annual percentage rate (e.g., 0.0503 for 5.03%).
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+    /// Status.
+    pub status: String,
+}
+
+/// WBETH wrap or unwrap history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WbethWrapRecord {
+    /// Record time.
+    pub time: u64,
+    /// Source asset.
+    pub from_asset: String,
+    /// Source amount.
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    /// Destination asset.
+    pub to_asset: String,
+    /// Destination amount.
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    /// Exchange rate applied.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    /// Status.
+    pub status: String,
+}
+
+// SOL staking.
+
+/// Response from staking SOL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakeResult {
+    /// Whether the request succeeded.
+    pub success: bool,
+    /// BNSOL amount received.
+    #[serde(with = "string_or_float")]
+    pub bnsol_amount: f64,
+    /// SOL to BNSOL exchange rate.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+}
+
+/// Response from redeeming BNSOL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolRedeemResult {
+    /// Whether the request succeeded.
+    pub success: bool,
+    /// SOL amount to be received.
+    #[serde(with = "string_or_float")]
+    pub sol_amount: f64,
+    /// BNSOL to SOL exchange rate.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    /// Estimated arrival time.
+    pub arrival_time: u64,
+}
+
+/// SOL staking account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolStakingAccount {
+    /// BNSOL amount held.
+    #[serde(rename = "bnsolAmount", with = "string_or_float")]
+    pub bnsol_amount: f64,
+    /// Total holdings, in SOL.
+    #[serde(rename = "holdingInSOL", with = "string_or_float")]
+    pub holding_in_sol: f64,
+    /// Profit over the last 30 days, in SOL.
+    #[serde(rename = "thirtyDaysProfitInSOL", with = "string_or_float")]
+    pub thirty_days_profit_in_sol: f64,
+}
+
+/// SOL staking quota and product details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakingQuota {
+    /// Remaining personal staking quota.
+    #[serde(with = "string_or_float")]
+    pub left_staking_personal_quota: f64,
+    /// Remaining personal redemption quota.
+    #[serde(with = "string_or_float")]
+    pub left_redemption_personal_quota: f64,
+    /// Minimum stake amount.
+    #[serde(with = "string_or_float")]
+    pub min_stake_amount: f64,
+    /// Minimum redeem amount.
+    #[serde(with = "string_or_float")]
+    pub min_redeem_amount: f64,
+    /// Redemption period in days.
+    #[serde(default)]
+    pub redeem_period: Option<u32>,
+    /// Whether staking is currently possible.
+    #[serde(default)]
+    pub stakeable: bool,
+    /// Whether redemption is currently possible.
+    #[serde(default)]
+    pub redeemable: bool,
+    /// Whether the staking quota is sold out.
+    #[serde(default)]
+    pub sold_out: bool,
+    /// Commission fee rate.
+    #[serde(default, with = "super::string_or_float_opt")]
+    pub commission_fee: Option<f64>,
+    /// Start time of the next epoch.
+    #[serde(default)]
+    pub next_epoch_time: Option<u64>,
+    /// Whether rewards are currently being calculated.
+    #[serde(default)]
+    pub calculating: bool,
+}
+
+/// SOL staking history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakingRecord {
+    /// Record time.
+    pub time: u64,
+    /// Staked asset.
+    pub asset: String,
+    /// Staked amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Asset received.
+    pub distribute_asset: String,
+    /// Amount received.
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    /// Exchange rate applied.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    /// Status.
+    pub status: String,
+}
+
+/// SOL redemption history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolRedemptionRecord {
+    /// Record time.
+    pub time: u64,
+    /// Estimated arrival time.
+    pub arrival_time: u64,
+    /// Redeemed asset.
+    pub asset: String,
+    /// Redeemed amount.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Asset received.
+    pub distribute_asset: String,
+    /// Amount received.
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    /// Exchange rate applied.
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    /// Status.
+    pub status: String,
+}
+
+/// BNSOL rewards history record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolRewardRecord {
+    /// Distribution time.
+    pub time: u64,
+    /// Reward amount, in SOL.
+    #[serde(rename = "amountInSOL", with = "string_or_float")]
+    pub amount_in_sol: f64,
+    /// BNSOL holding at distribution time.
+    #[serde(with = "string_or_float")]
+    pub holding: f64,
+    /// Holding at distribution time, in SOL.
+    #[serde(rename = "holdingInSOL", with = "string_or_float")]
+    pub holding_in_sol: f64,
+    /// Annual percentage rate.
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_eth_staking_account_deserialize() {
+        let json = r#"{
+            "holdingInETH": "1.22330928",
+            "holdings": {"wbethAmount": "1.10928781", "bethAmount": "1.90002112"},
+            "thirtyDaysProfitInETH": "0.22330928",
+            "profit": {"amountFromWBETH": "0.12330928", "amountFromBETH": "0.1"}
+        }"#;
+        let account: EthStakingAccount = serde_json::from_str(json).unwrap();
+        assert_eq!(account.holding_in_eth, 1.22330928);
+        assert_eq!(account.holdings.wbeth_amount, 1.10928781);
+        assert_eq!(account.profit.amount_from_beth, 0.1);
+    }
+
+    #[test]
+    fn test_sol_staking_quota_deserialize() {
+        let json = r#"{
+            "leftStakingPersonalQuota": "1000",
+            "leftRedemptionPersonalQuota": "1000",
+            "minStakeAmount": "0.01000000",
+            "minRedeemAmount": "0.00000001",
+            "redeemPeriod": 4,
+            "stakeable": true,
+            "redeemable": true,
+            "soldOut": false,
+            "commissionFee": "0.25000000",
+            "nextEpochTime": 1725506160000,
+            "calculating": false
+        }"#;
+        let quota: SolStakingQuota = serde_json::from_str(json).unwrap();
+        assert_eq!(quota.min_stake_amount, 0.01);
+        assert_eq!(quota.redeem_period, Some(4));
+        assert_eq!(quota.commission_fee, Some(0.25));
+        assert!(quota.stakeable);
+    }
+}
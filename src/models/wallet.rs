@@ -5,7 +5,9 @@
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 
-use super::string_or_float;
+use std::collections::HashMap;
+
+use super::{string_or_float, string_or_float_map};
 
 /// System status response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -138,6 +140,39 @@ pub struct DepositAddress {
     pub url: Option<String>,
 }
 
+/// Deposit address on one network, from the address list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressEntry {
+    /// Coin symbol.
+    pub coin: String,
+    /// Deposit address.
+    pub address: String,
+    /// Tag/memo (if applicable).
+    #[serde(default)]
+    pub tag: String,
+    /// 1 for the coin's default network, 0 otherwise.
+    #[serde(default)]
+    pub is_default: u8,
+}
+
+/// Result of a one-click deposit credit request.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositCreditResult {
+    /// Response code.
+    #[serde(default)]
+    pub code: String,
+    /// Response message.
+    #[serde(default)]
+    pub message: String,
+    /// Whether the deposit was credited.
+    #[serde(default)]
+    pub data: bool,
+    /// Whether the request succeeded.
+    pub success: bool,
+}
+
 /// Deposit record from history.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -285,6 +320,55 @@ pub struct TradeFee {
     pub taker_commission: f64,
 }
 
+/// Wallet a withdrawal is paid from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum WithdrawWalletType {
+    /// Spot wallet
+    Spot = 0,
+    /// Funding wallet
+    Funding = 1,
+}
+
+/// Address saved in the withdrawal address book.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawAddress {
+    /// Address.
+    pub address: String,
+    /// Tag/memo (if applicable).
+    #[serde(default)]
+    pub address_tag: String,
+    /// Coin symbol.
+    pub coin: String,
+    /// Label of the address.
+    pub name: String,
+    /// Network.
+    pub network: String,
+    /// Where the address was added from.
+    #[serde(default)]
+    pub origin: String,
+    /// Origin type.
+    #[serde(default)]
+    pub origin_type: String,
+    /// Whether the address is on the withdrawal whitelist.
+    #[serde(default)]
+    pub white_status: bool,
+}
+
+/// Stablecoins converted to BUSD on deposit and withdrawal.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertibleCoins {
+    /// Whether auto-conversion is enabled for the account.
+    pub convert_enabled: bool,
+    /// Coins that are converted.
+    pub coins: Vec<String>,
+    /// Conversion rate by coin.
+    #[serde(with = "string_or_float_map")]
+    pub exchange_rates: HashMap<String, f64>,
+}
+
 /// Universal transfer type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -309,11 +393,22 @@ pub enum UniversalTransferType {
     MarginUmfuture,
     /// Margin (cross) to COINM Futures
     MarginCmfuture,
-    /// Spot to Isolated Margin
+    /// Not a transfer type the API accepts; transfers to and from isolated
+    /// margin go through cross margin.
+    #[deprecated(note = "use `MarginIsolatedMargin`")]
     MainIsolatedMargin,
-    /// Isolated Margin to Spot
+    /// Not a transfer type the API accepts; transfers to and from isolated
+    /// margin go through cross margin.
+    #[deprecated(note = "use `IsolatedMarginMargin`")]
     IsolatedMarginMain,
+    /// Margin (cross) to Isolated Margin
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginIsolatedMargin,
+    /// Isolated Margin to Margin (cross)
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginMargin,
     /// Isolated Margin to Isolated Margin
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
     IsolatedMarginIsolatedMargin,
     /// Spot to Funding
     MainFunding,
@@ -331,10 +426,66 @@ pub enum UniversalTransferType {
     FundingCmfuture,
     /// COINM Futures to Funding
     CmfutureFunding,
+    /// Spot to Options
+    MainOption,
+    /// Options to Spot
+    OptionMain,
+    /// USDM Futures to Options
+    UmfutureOption,
+    /// Options to USDM Futures
+    OptionUmfuture,
+    /// Margin (cross) to Options
+    MarginOption,
+    /// Options to Margin (cross)
+    OptionMargin,
+    /// Funding to Options
+    FundingOption,
+    /// Options to Funding
+    OptionFunding,
+    /// Spot to Portfolio Margin
+    MainPortfolioMargin,
+    /// Portfolio Margin to Spot
+    PortfolioMarginMain,
 }
 
 impl UniversalTransferType {
+    /// Every transfer type the API accepts.
+    pub const ALL: &'static [Self] = &[
+        Self::MainUmfuture,
+        Self::MainCmfuture,
+        Self::MainMargin,
+        Self::UmfutureMain,
+        Self::UmfutureMargin,
+        Self::CmfutureMain,
+        Self::CmfutureMargin,
+        Self::MarginMain,
+        Self::MarginUmfuture,
+        Self::MarginCmfuture,
+        Self::MarginIsolatedMargin,
+        Self::IsolatedMarginMargin,
+        Self::IsolatedMarginIsolatedMargin,
+        Self::MainFunding,
+        Self::FundingMain,
+        Self::FundingUmfuture,
+        Self::UmfutureFunding,
+        Self::MarginFunding,
+        Self::FundingMargin,
+        Self::FundingCmfuture,
+        Self::CmfutureFunding,
+        Self::MainOption,
+        Self::OptionMain,
+        Self::UmfutureOption,
+        Self::OptionUmfuture,
+        Self::MarginOption,
+        Self::OptionMargin,
+        Self::FundingOption,
+        Self::OptionFunding,
+        Self::MainPortfolioMargin,
+        Self::PortfolioMarginMain,
+    ];
+
     /// Return the API wire value for this transfer type.
+    #[allow(deprecated)]
     pub fn as_str(self) -> &'static str {
         match self {
             Self::MainUmfuture => "MAIN_UMFUTURE",
@@ -349,7 +500,9 @@ impl UniversalTransferType {
             Self::MarginCmfuture => "MARGIN_CMFUTURE",
             Self::MainIsolatedMargin => "MAIN_ISOLATED_MARGIN",
             Self::IsolatedMarginMain => "ISOLATED_MARGIN_MAIN",
-            Self::IsolatedMarginIsolatedMargin => "ISOLATED_MARGIN_ISOLATED_MARGIN",
+            Self::MarginIsolatedMargin => "MARGIN_ISOLATEDMARGIN",
+            Self::IsolatedMarginMargin => "ISOLATEDMARGIN_MARGIN",
+            Self::IsolatedMarginIsolatedMargin => "ISOLATEDMARGIN_ISOLATEDMARGIN",
             Self::MainFunding => "MAIN_FUNDING",
             Self::FundingMain => "FUNDING_MAIN",
             Self::FundingUmfuture => "FUNDING_UMFUTURE",
@@ -358,10 +511,26 @@ impl UniversalTransferType {
             Self::FundingMargin => "FUNDING_MARGIN",
             Self::FundingCmfuture => "FUNDING_CMFUTURE",
             Self::CmfutureFunding => "CMFUTURE_FUNDING",
+            Self::MainOption => "MAIN_OPTION",
+            Self::OptionMain => "OPTION_MAIN",
+            Self::UmfutureOption => "UMFUTURE_OPTION",
+            Self::OptionUmfuture => "OPTION_UMFUTURE",
+            Self::MarginOption => "MARGIN_OPTION",
+            Self::OptionMargin => "OPTION_MARGIN",
+            Self::FundingOption => "FUNDING_OPTION",
+            Self::OptionFunding => "OPTION_FUNDING",
+            Self::MainPortfolioMargin => "MAIN_PORTFOLIO_MARGIN",
+            Self::PortfolioMarginMain => "PORTFOLIO_MARGIN_MAIN",
         }
     }
 }
 
+impl std::fmt::Display for UniversalTransferType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Universal transfer response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -396,7 +565,8 @@ pub struct TransferRecord {
 pub struct TransferHistory {
     /// Total count.
     pub total: u64,
-    /// Transfer records.
+    /// Transfer records (omitted by the API when there are none).
+    #[serde(default)]
     pub rows: Vec<TransferRecord>,
 }
 
@@ -436,6 +606,221 @@ pub struct FundingAsset {
     pub btc_valuation: Option<f64>,
 }
 
+/// Result of converting small balances to BNB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferResult {
+    /// Total service charge in BNB.
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    /// Total BNB received.
+    #[serde(with = "string_or_float")]
+    pub total_transfered: f64,
+    /// Per-asset results.
+    pub transfer_result: Vec<DustTransferItem>,
+}
+
+/// Conversion result for a single dust asset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferItem {
+    /// Amount of the asset converted.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Converted asset.
+    pub from_asset: String,
+    /// Operation time.
+    pub operate_time: u64,
+    /// Service charge in BNB.
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    /// Transaction ID.
+    pub tran_id: u64,
+    /// BNB received.
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+}
+
+/// Dust conversion history.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLog {
+    /// Total number of conversions.
+    pub total: u64,
+    /// Conversions.
+    #[serde(default)]
+    pub user_asset_dribblets: Vec<DustLogEntry>,
+}
+
+/// A single dust conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogEntry {
+    /// Operation time.
+    pub operate_time: u64,
+    /// Total BNB received.
+    #[serde(with = "string_or_float")]
+    pub total_transfered_amount: f64,
+    /// Total service charge in BNB.
+    #[serde(with = "string_or_float")]
+    pub total_service_charge_amount: f64,
+    /// Transaction ID.
+    pub trans_id: u64,
+    /// Per-asset details.
+    pub user_asset_dribblet_details: Vec<DustLogDetail>,
+}
+
+/// Per-asset detail of a dust conversion.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustLogDetail {
+    /// Transaction ID.
+    pub trans_id: u64,
+    /// Service charge in BNB.
+    #[serde(with = "string_or_float")]
+    pub service_charge_amount: f64,
+    /// Amount of the asset converted.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Operation time.
+    pub operate_time: u64,
+    /// BNB received.
+    #[serde(with = "string_or_float")]
+    pub transfered_amount: f64,
+    /// Converted asset.
+    pub from_asset: String,
+}
+
+/// Assets that can be converted to BNB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAssets {
+    /// Convertible assets.
+    pub details: Vec<DustAsset>,
+    /// Total value in BTC.
+    #[serde(with = "string_or_float")]
+    pub total_transfer_btc: f64,
+    /// Total value in BNB.
+    #[serde(rename = "totalTransferBNB", with = "string_or_float")]
+    pub total_transfer_bnb: f64,
+    /// Commission fee rate.
+    #[serde(with = "string_or_float")]
+    pub dribblet_percentage: f64,
+}
+
+/// An asset that can be converted to BNB.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustAsset {
+    /// Asset.
+    pub asset: String,
+    /// Asset full name.
+    pub asset_full_name: String,
+    /// Free balance.
+    #[serde(with = "string_or_float")]
+    pub amount_free: f64,
+    /// Value in BTC.
+    #[serde(rename = "toBTC", with = "string_or_float")]
+    pub to_btc: f64,
+    /// Value in BNB, after the commission fee.
+    #[serde(rename = "toBNB", with = "string_or_float")]
+    pub to_bnb: f64,
+    /// Value in BNB, before the commission fee.
+    #[serde(rename = "toBNBOffExchange", with = "string_or_float")]
+    pub to_bnb_off_exchange: f64,
+    /// Commission fee in BNB.
+    #[serde(with = "string_or_float")]
+    pub exchange: f64,
+}
+
+/// Asset dividend (distribution) record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividendRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Amount distributed.
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Asset.
+    pub asset: String,
+    /// Distribution time.
+    pub div_time: u64,
+    /// Distribution description (e.g., "BNB Vault").
+    pub en_info: String,
+    /// Transaction ID.
+    pub tran_id: u64,
+}
+
+/// Asset dividend records.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetDividendRecords {
+    /// Records.
+    #[serde(default)]
+    pub rows: Vec<AssetDividendRecord>,
+    /// Total number of records.
+    pub total: u64,
+}
+
+/// Account a BUSD conversion is made in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertAccountType {
+    /// Spot wallet
+    Main,
+    /// Binance Card wallet
+    Card,
+}
+
+impl ConvertAccountType {
+    /// Return the API wire value for this account type.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Main => "MAIN",
+            Self::Card => "CARD",
+        }
+    }
+}
+
+/// BUSD/stablecoin conversion record.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferRecord {
+    /// Transaction ID.
+    pub tran_id: u64,
+    /// Conversion type (e.g., "AUTO_CONVERTING").
+    #[serde(rename = "type")]
+    pub convert_type: String,
+    /// Conversion time.
+    pub time: u64,
+    /// Asset converted from.
+    pub deducted_asset: String,
+    /// Amount converted from.
+    #[serde(with = "string_or_float")]
+    pub deducted_amount: f64,
+    /// Asset converted to.
+    pub target_asset: String,
+    /// Amount converted to.
+    #[serde(with = "string_or_float")]
+    pub target_amount: f64,
+    /// Status (e.g., "S" for success).
+    pub status: String,
+    /// Account the conversion was made in.
+    pub account_type: ConvertAccountType,
+}
+
+/// BUSD/stablecoin conversion history (paginated).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferHistory {
+    /// Total count.
+    pub total: u64,
+    /// Conversion records.
+    #[serde(default)]
+    pub rows: Vec<ConvertTransferRecord>,
+}
+
 /// Account snapshot type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -497,6 +882,15 @@ pub struct ApiKeyPermissions {
     pub enable_futures: bool,
     /// Whether margin loan/borrow/repay is enabled.
     pub enable_margin: bool,
+    /// Whether portfolio margin trading is enabled.
+    #[serde(default)]
+    pub enable_portfolio_margin_trading: bool,
+    /// Whether FIX API trading is enabled.
+    #[serde(default)]
+    pub enable_fix_api_trade: bool,
+    /// Whether FIX API read-only access is enabled.
+    #[serde(default)]
+    pub enable_fix_read_only: bool,
     /// Trading authority expiration time.
     #[serde(default)]
     pub trading_authority_expiration_time: Option<u64>,
@@ -527,12 +921,74 @@ pub struct ApiTradingStatusData {
     /// Planned recovery time (if locked).
     #[serde(default)]
     pub planned_recover_time: Option<u64>,
-    /// Trigger condition.
-    pub trigger_condition: serde_json::Value,
+    /// Thresholds that lock trading when an indicator reaches them.
+    pub trigger_condition: TradingTriggerCondition,
+    /// Current indicator values by symbol.
+    #[serde(default)]
+    pub indicators: HashMap<String, Vec<TradingIndicator>>,
     /// Update time.
     pub update_time: u64,
 }
 
+impl ApiTradingStatusData {
+    /// Get the indicators that reached `fraction` of their trigger value
+    /// (e.g. `0.8` for 80%), with their symbol.
+    pub fn indicators_near_trigger(&self, fraction: f64) -> Vec<(&str, &TradingIndicator)> {
+        self.indicators
+            .iter()
+            .flat_map(|(symbol, indicators)| {
+                indicators
+                    .iter()
+                    .filter(move |i| i.trigger_ratio() >= fraction)
+                    .map(move |i| (symbol.as_str(), i))
+            })
+            .collect()
+    }
+}
+
+/// Thresholds of the trading rule indicators.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TradingTriggerCondition {
+    /// Number of GTC orders placed.
+    #[serde(rename = "GCR", default)]
+    pub gcr: u64,
+    /// Number of FOK/IOC orders placed.
+    #[serde(rename = "IFER", default)]
+    pub ifer: u64,
+    /// Number of orders placed.
+    #[serde(rename = "UFR", default)]
+    pub ufr: u64,
+}
+
+/// Trading rule indicator of a symbol.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradingIndicator {
+    /// Indicator name: `UFR` (unfilled ratio), `IFER` (IOC/FOK expiration
+    /// ratio) or `GCR` (GTC cancellation ratio).
+    #[serde(rename = "i")]
+    pub indicator: String,
+    /// Number of orders counted.
+    #[serde(rename = "c")]
+    pub count: u64,
+    /// Current value.
+    #[serde(rename = "v")]
+    pub value: f64,
+    /// Value at which trading is locked.
+    #[serde(rename = "t")]
+    pub trigger_value: f64,
+}
+
+impl TradingIndicator {
+    /// Get the current value as a share of the trigger value.
+    pub fn trigger_ratio(&self) -> f64 {
+        if self.trigger_value > 0.0 {
+            self.value / self.trigger_value
+        } else {
+            0.0
+        }
+    }
+}
+
 /// Helper for optional f64 fields that may be strings.
 mod string_or_float_option {
     use serde::{self, Deserialize, Deserializer, Serializer};
@@ -568,3 +1024,59 @@ mod string_or_float_option {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_trading_status_deserialize() {
+        let json = r#"{
+            "data": {
+                "isLocked": false,
+                "plannedRecoverTime": 0,
+                "triggerCondition": {"GCR": 150, "IFER": 150, "UFR": 300},
+                "indicators": {
+                    "BTCUSDT": [
+                        {"i": "UFR", "c": 20, "v": 0.05, "t": 0.995},
+                        {"i": "IFER", "c": 20, "v": 0.9, "t": 0.99}
+                    ]
+                },
+                "updateTime": 1547630471725
+            }
+        }"#;
+        let status: ApiTradingStatus = serde_json::from_str(json).unwrap();
+        assert_eq!(status.data.trigger_condition.ufr, 300);
+        assert_eq!(status.data.indicators["BTCUSDT"].len(), 2);
+
+        let near = status.data.indicators_near_trigger(0.8);
+        assert_eq!(near.len(), 1);
+        assert_eq!(near[0].0, "BTCUSDT");
+        assert_eq!(near[0].1.indicator, "IFER");
+    }
+
+    #[test]
+    fn test_convert_transfer_history_deserialize() {
+        let json = r#"{
+            "total": 1,
+            "rows": [{
+                "tranId": 118263407119,
+                "type": "AUTO_CONVERTING",
+                "time": 1664442078000,
+                "deductedAsset": "BUSD",
+                "deductedAmount": "1",
+                "targetAsset": "USDC",
+                "targetAmount": "1",
+                "status": "S",
+                "accountType": "MAIN"
+            }]
+        }"#;
+        let history: ConvertTransferHistory = serde_json::from_str(json).unwrap();
+        assert_eq!(history.rows[0].deducted_asset, "BUSD");
+        assert_eq!(history.rows[0].target_amount, 1.0);
+        assert_eq!(history.rows[0].account_type, ConvertAccountType::Main);
+
+        let empty: ConvertTransferHistory = serde_json::from_str(r#"{"total": 0}"#).unwrap();
+        assert!(empty.rows.is_empty());
+    }
+}
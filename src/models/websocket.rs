@@ -2,17 +2,23 @@
 //!
 //! These models represent events received from Binance WebSocket streams.
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::types::{ExecutionType, KlineInterval, OrderSide, OrderStatus, OrderType, TimeInForce};
+use crate::types::{
+    ContingencyType, ExecutionType, KlineInterval, OcoOrderStatus, OcoStatus, OrderSide,
+    OrderStatus, OrderType, TimeInForce, WorkingFloor,
+};
 
-use super::market::string_or_float;
+use super::market::{Kline, string_or_float, string_or_float_opt};
 
 /// WebSocket event wrapper.
 ///
 /// All WebSocket events have an "e" field indicating the event type.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Messages with an event type this crate does not know, or without one,
+/// are returned as [`Unknown`](Self::Unknown) instead of failing to parse.
+#[derive(Debug, Clone, Serialize)]
 #[serde(tag = "e")]
+#[allow(clippy::large_enum_variant)]
 pub enum WebSocketEvent {
     /// Aggregate trade event.
     #[serde(rename = "aggTrade")]
@@ -32,6 +38,9 @@ pub enum WebSocketEvent {
     /// Book ticker event.
     #[serde(rename = "bookTicker")]
     BookTicker(BookTickerEvent),
+    /// Average price event.
+    #[serde(rename = "avgPrice")]
+    AvgPrice(AvgPriceEvent),
     /// Depth update event.
     #[serde(rename = "depthUpdate")]
     Depth(DepthEvent),
@@ -44,9 +53,95 @@ pub enum WebSocketEvent {
     /// Order update (user data stream).
     #[serde(rename = "executionReport")]
     ExecutionReport(ExecutionReportEvent),
-    /// OCO order update (user data stream).
+    /// Order list update (user data stream).
     #[serde(rename = "listStatus")]
     ListStatus(ListStatusEvent),
+    /// Listen key expired (user data stream).
+    ///
+    /// No more events are sent on the stream, which has to be reconnected
+    /// with a new listen key.
+    #[serde(rename = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+    /// Balance locked or unlocked by an external system (user data stream).
+    #[serde(rename = "externalLockUpdate")]
+    ExternalLockUpdate(ExternalLockUpdateEvent),
+    /// User data stream terminated (WebSocket API).
+    ///
+    /// Sent after logging out or when the session's API key is revoked.
+    #[serde(rename = "eventStreamTerminated")]
+    EventStreamTerminated(EventStreamTerminatedEvent),
+    /// Rolling window ticker event (`1hTicker`, `4hTicker` or `1dTicker`).
+    ///
+    /// The event type depends on the window, so it is kept in the event.
+    #[serde(untagged)]
+    RollingWindowTicker(RollingWindowTickerEvent),
+    /// Any other message, such as an event type added by Binance after this
+    /// version of the crate, kept as raw JSON.
+    #[serde(untagged)]
+    Unknown(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for WebSocketEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Known event types are parsed strictly, anything else is kept as JSON
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let event = match value.get("e").and_then(|e| e.as_str()) {
+            Some("aggTrade") => serde_json::from_value(value).map(Self::AggTrade),
+            Some("trade") => serde_json::from_value(value).map(Self::Trade),
+            Some("kline") => serde_json::from_value(value).map(Self::Kline),
+            Some("24hrMiniTicker") => serde_json::from_value(value).map(Self::MiniTicker),
+            Some("24hrTicker") => serde_json::from_value(value).map(Self::Ticker),
+            Some("bookTicker") => serde_json::from_value(value).map(Self::BookTicker),
+            Some("avgPrice") => serde_json::from_value(value).map(Self::AvgPrice),
+            Some("depthUpdate") => serde_json::from_value(value).map(Self::Depth),
+            Some("outboundAccountPosition") => {
+                serde_json::from_value(value).map(Self::AccountPosition)
+            }
+            Some("balanceUpdate") => serde_json::from_value(value).map(Self::BalanceUpdate),
+            Some("executionReport") => serde_json::from_value(value).map(Self::ExecutionReport),
+            Some("listStatus") => serde_json::from_value(value).map(Self::ListStatus),
+            Some("listenKeyExpired") => serde_json::from_value(value).map(Self::ListenKeyExpired),
+            Some("externalLockUpdate") => {
+                serde_json::from_value(value).map(Self::ExternalLockUpdate)
+            }
+            Some("eventStreamTerminated") => {
+                serde_json::from_value(value).map(Self::EventStreamTerminated)
+            }
+            Some(e) if is_rolling_window_ticker(e) => {
+                serde_json::from_value(value).map(Self::RollingWindowTicker)
+            }
+            _ => return Ok(Self::Unknown(value)),
+        };
+        event.map_err(serde::de::Error::custom)
+    }
+}
+
+impl WebSocketEvent {
+    /// Get the event type (the `e` field), if the message has one.
+    pub fn event_type(&self) -> Option<&str> {
+        match self {
+            Self::AggTrade(_) => Some("aggTrade"),
+            Self::Trade(_) => Some("trade"),
+            Self::Kline(_) => Some("kline"),
+            Self::MiniTicker(_) => Some("24hrMiniTicker"),
+            Self::Ticker(_) => Some("24hrTicker"),
+            Self::BookTicker(_) => Some("bookTicker"),
+            Self::AvgPrice(_) => Some("avgPrice"),
+            Self::Depth(_) => Some("depthUpdate"),
+            Self::AccountPosition(_) => Some("outboundAccountPosition"),
+            Self::BalanceUpdate(_) => Some("balanceUpdate"),
+            Self::ExecutionReport(_) => Some("executionReport"),
+            Self::ListStatus(_) => Some("listStatus"),
+            Self::ListenKeyExpired(_) => Some("listenKeyExpired"),
+            Self::ExternalLockUpdate(_) => Some("externalLockUpdate"),
+            Self::EventStreamTerminated(_) => Some("eventStreamTerminated"),
+            Self::RollingWindowTicker(ticker) => Some(&ticker.event_type),
+            Self::Unknown(value) => value.get("e").and_then(|e| e.as_str()),
+        }
+    }
 }
 
 /// Aggregate trade event.
@@ -186,6 +281,36 @@ pub struct KlineData {
     pub taker_buy_quote_volume: f64,
 }
 
+impl KlineEvent {
+    /// Check whether this update is the final one for its candle.
+    pub fn is_closed(&self) -> bool {
+        self.kline.is_closed
+    }
+
+    /// Convert the candle into the REST [`Kline`] type.
+    pub fn into_kline(self) -> Kline {
+        Kline::from(&self.kline)
+    }
+}
+
+impl From<&KlineData> for Kline {
+    fn from(data: &KlineData) -> Self {
+        Self {
+            open_time: data.start_time,
+            open: data.open,
+            high: data.high,
+            low: data.low,
+            close: data.close,
+            volume: data.volume,
+            close_time: data.close_time,
+            quote_asset_volume: data.quote_asset_volume,
+            number_of_trades: data.number_of_trades,
+            taker_buy_base_asset_volume: data.taker_buy_base_volume,
+            taker_buy_quote_asset_volume: data.taker_buy_quote_volume,
+        }
+    }
+}
+
 /// 24hr mini ticker event.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MiniTickerEvent {
@@ -286,6 +411,111 @@ pub struct TickerEvent {
     pub number_of_trades: u64,
 }
 
+/// Rolling window ticker event.
+///
+/// Received on `<symbol>@ticker_<window>` and `!ticker_<window>@arr`
+/// streams.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollingWindowTickerEvent {
+    /// Event type (e.g., "1hTicker").
+    #[serde(rename = "e")]
+    pub event_type: String,
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Price change.
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: f64,
+    /// Price change percent.
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: f64,
+    /// Open price.
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open_price: f64,
+    /// High price.
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high_price: f64,
+    /// Low price.
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low_price: f64,
+    /// Last price.
+    #[serde(rename = "c", with = "string_or_float")]
+    pub last_price: f64,
+    /// Weighted average price.
+    #[serde(rename = "w", with = "string_or_float")]
+    pub weighted_avg_price: f64,
+    /// Total traded base asset volume.
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: f64,
+    /// Total traded quote asset volume.
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: f64,
+    /// Statistics open time.
+    #[serde(rename = "O")]
+    pub open_time: u64,
+    /// Statistics close time.
+    #[serde(rename = "C")]
+    pub close_time: u64,
+    /// First trade ID.
+    #[serde(rename = "F")]
+    pub first_trade_id: i64,
+    /// Last trade ID.
+    #[serde(rename = "L")]
+    pub last_trade_id: i64,
+    /// Total number of trades.
+    #[serde(rename = "n")]
+    pub number_of_trades: u64,
+}
+
+impl RollingWindowTickerEvent {
+    /// Get the window size (e.g., "1h") from the event type.
+    pub fn window_size(&self) -> &str {
+        self.event_type
+            .strip_suffix("Ticker")
+            .unwrap_or(&self.event_type)
+    }
+}
+
+// Check whether an event type is a rolling window ticker (`<window>Ticker`).
+fn is_rolling_window_ticker(event_type: &str) -> bool {
+    match event_type.strip_suffix("Ticker") {
+        Some(window) => {
+            window.len() >= 2
+                && window != "24hr"
+                && window.ends_with(['m', 'h', 'd'])
+                && window[..window.len() - 1]
+                    .bytes()
+                    .all(|b| b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Average price event.
+///
+/// Received on `<symbol>@avgPrice` streams.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvgPriceEvent {
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Average price interval (e.g., "5m").
+    #[serde(rename = "i")]
+    pub interval: String,
+    /// Average price.
+    #[serde(rename = "w", with = "string_or_float")]
+    pub price: f64,
+    /// Last trade time.
+    #[serde(rename = "T")]
+    pub last_trade_time: u64,
+}
+
 /// Book ticker event (best bid/ask).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookTickerEvent {
@@ -388,6 +618,55 @@ pub struct BalanceUpdateEvent {
     pub clear_time: u64,
 }
 
+impl ExecutionReportEvent {
+    /// Get the execution ID, unique per order.
+    ///
+    /// Together with the order ID it identifies a report, so reports
+    /// repeated after a reconnect can be recognized.
+    pub fn execution_id(&self) -> u64 {
+        self.ignore_a
+    }
+}
+
+/// Listen key expired event (user data stream).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListenKeyExpiredEvent {
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// The listen key that expired.
+    #[serde(rename = "listenKey", default)]
+    pub listen_key: String,
+}
+
+/// External lock update event (user data stream).
+///
+/// Sent when part of a spot wallet balance is locked or unlocked by an
+/// external system, such as when used as margin collateral.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExternalLockUpdateEvent {
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Asset.
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// Locked amount delta.
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: f64,
+    /// Transaction time.
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+}
+
+/// Event stream terminated event (WebSocket API user data stream).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventStreamTerminatedEvent {
+    /// Event time.
+    #[serde(rename = "E")]
+    pub event_time: u64,
+}
+
 /// Order execution report event (user data stream).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionReportEvent {
@@ -460,7 +739,7 @@ pub struct ExecutionReportEvent {
     /// Trade ID.
     #[serde(rename = "t")]
     pub trade_id: i64,
-    /// Ignore.
+    /// Execution ID (see [`execution_id`](Self::execution_id)).
     #[serde(rename = "I")]
     pub ignore_a: u64,
     /// Is the order on the book.
@@ -484,9 +763,59 @@ pub struct ExecutionReportEvent {
     /// Quote order quantity.
     #[serde(rename = "Q", with = "string_or_float")]
     pub quote_order_quantity: f64,
+    /// Working time (when the order started working on the book).
+    #[serde(rename = "W", default)]
+    pub working_time: Option<u64>,
+    /// Self-trade prevention mode.
+    #[serde(rename = "V", default)]
+    pub self_trade_prevention_mode: Option<String>,
+    /// Prevented match ID (self-trade prevention expiries only).
+    #[serde(rename = "v", default)]
+    pub prevented_match_id: Option<u64>,
+    /// Cumulative prevented quantity.
+    #[serde(rename = "A", default, with = "string_or_float_opt")]
+    pub prevented_quantity: Option<f64>,
+    /// Last prevented quantity.
+    #[serde(rename = "B", default, with = "string_or_float_opt")]
+    pub last_prevented_quantity: Option<f64>,
+    /// Trade group ID (self-trade prevention expiries only).
+    #[serde(rename = "u", default)]
+    pub trade_group_id: Option<i64>,
+    /// Counter order ID (self-trade prevention expiries only).
+    #[serde(rename = "U", default)]
+    pub counter_order_id: Option<i64>,
+    /// Counter symbol (self-trade prevention expiries only).
+    #[serde(rename = "Cs", default)]
+    pub counter_symbol: Option<String>,
+    /// Peg price type (pegged orders only).
+    #[serde(rename = "gP", default)]
+    pub peg_price_type: Option<String>,
+    /// Peg offset type (pegged orders only).
+    #[serde(rename = "gOT", default)]
+    pub peg_offset_type: Option<String>,
+    /// Peg offset value (pegged orders only).
+    #[serde(rename = "gOV", default)]
+    pub peg_offset_value: Option<i64>,
+    /// Current pegged price (pegged orders only).
+    #[serde(rename = "gp", default, with = "string_or_float_opt")]
+    pub pegged_price: Option<f64>,
+    /// Where the order was placed (SOR orders only).
+    #[serde(rename = "k", default)]
+    pub working_floor: Option<WorkingFloor>,
+    /// Whether the order was placed through SOR.
+    #[serde(rename = "uS", default)]
+    pub used_sor: bool,
+    /// Match type of the trade (e.g., "ONE_PARTY_TRADE_REPORT" for SOR allocations).
+    #[serde(rename = "b", default)]
+    pub match_type: Option<String>,
+    /// Allocation ID of the trade (SOR allocations only).
+    #[serde(rename = "a", default)]
+    pub allocation_id: Option<u64>,
 }
 
-/// OCO list status event (user data stream).
+/// Order list status event (user data stream).
+///
+/// Sent for every order list type (OCO, OTO, OTOCO, OPO and OPOCO).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListStatusEvent {
     /// Event time.
@@ -500,13 +829,13 @@ pub struct ListStatusEvent {
     pub order_list_id: u64,
     /// Contingency type.
     #[serde(rename = "c")]
-    pub contingency_type: String,
+    pub contingency_type: ContingencyType,
     /// List status type.
     #[serde(rename = "l")]
-    pub list_status_type: String,
+    pub list_status_type: OcoStatus,
     /// List order status.
     #[serde(rename = "L")]
-    pub list_order_status: String,
+    pub list_order_status: OcoOrderStatus,
     /// List reject reason.
     #[serde(rename = "r")]
     pub list_reject_reason: String,
@@ -590,6 +919,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_kline_event_into_kline() {
+        let json = r#"{
+            "e": "kline",
+            "E": 1672515782136,
+            "s": "BNBBTC",
+            "k": {
+                "t": 1672515780000, "T": 1672515839999, "s": "BNBBTC", "i": "1m",
+                "f": 100, "L": 200, "o": "0.0010", "c": "0.0020", "h": "0.0025",
+                "l": "0.0015", "v": "1000", "n": 100, "x": true, "q": "1.0000",
+                "V": "500", "Q": "0.500", "B": "123456"
+            }
+        }"#;
+
+        let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+        let WebSocketEvent::Kline(event) = event else {
+            panic!("Expected Kline event");
+        };
+        assert!(event.is_closed());
+
+        let kline = event.into_kline();
+        assert_eq!(kline.open_time, 1672515780000);
+        assert_eq!(kline.close_time, 1672515839999);
+        assert_eq!(kline.high, 0.0025);
+        assert_eq!(kline.number_of_trades, 100);
+        assert_eq!(kline.taker_buy_quote_asset_volume, 0.5);
+    }
+
     #[test]
     fn test_mini_ticker_event_deserialize() {
         let json = r#"{
@@ -614,6 +971,163 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sor_execution_report_deserialize() {
+        let json = r#"{
+            "e": "executionReport",
+            "E": 1689149087774,
+            "s": "BTCUSDT",
+            "c": "sBI1KM6nNtOfj5tccZSKly",
+            "S": "BUY",
+            "o": "LIMIT",
+            "f": "GTC",
+            "q": "0.50000000",
+            "p": "31000.00000000",
+            "P": "0.00000000",
+            "F": "0.00000000",
+            "g": -1,
+            "C": "",
+            "x": "TRADE",
+            "X": "FILLED",
+            "r": "NONE",
+            "i": 2,
+            "l": "0.50000000",
+            "z": "0.50000000",
+            "L": "28000.00000000",
+            "n": "0.00000000",
+            "N": "BTC",
+            "T": 1689149087774,
+            "t": -1,
+            "I": 7,
+            "w": false,
+            "m": false,
+            "M": true,
+            "O": 1689149087774,
+            "Z": "14000.00000000",
+            "Y": "14000.00000000",
+            "Q": "0.00000000",
+            "W": 1689149087774,
+            "V": "NONE",
+            "b": "ONE_PARTY_TRADE_REPORT",
+            "a": 0,
+            "k": "SOR",
+            "uS": true
+        }"#;
+
+        let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+        match event {
+            WebSocketEvent::ExecutionReport(e) => {
+                assert!(e.used_sor);
+                assert_eq!(e.working_floor, Some(WorkingFloor::Sor));
+                assert_eq!(e.allocation_id, Some(0));
+                assert_eq!(e.match_type.as_deref(), Some("ONE_PARTY_TRADE_REPORT"));
+            }
+            _ => panic!("Expected ExecutionReport event"),
+        }
+    }
+
+    #[test]
+    fn test_prevented_execution_report_deserialize() {
+        let json = r#"{
+            "e": "executionReport",
+            "E": 1499405658658,
+            "s": "ETHBTC",
+            "c": "mUvoqJxFIILMdfAW5iGSOW",
+            "S": "BUY",
+            "o": "LIMIT",
+            "f": "GTC",
+            "q": "1.00000000",
+            "p": "0.10264410",
+            "P": "0.00000000",
+            "F": "0.00000000",
+            "g": -1,
+            "C": "",
+            "x": "TRADE_PREVENTION",
+            "X": "EXPIRED",
+            "r": "NONE",
+            "i": 4293153,
+            "l": "0.00000000",
+            "z": "0.00000000",
+            "L": "0.00000000",
+            "n": "0",
+            "N": null,
+            "T": 1499405658657,
+            "t": -1,
+            "v": 3,
+            "I": 8641984,
+            "w": false,
+            "m": false,
+            "M": false,
+            "O": 1499405658657,
+            "Z": "0.00000000",
+            "Y": "0.00000000",
+            "Q": "0.00000000",
+            "W": 1499405658657,
+            "V": "EXPIRE_MAKER",
+            "A": "1.00000000",
+            "B": "1.00000000",
+            "u": 1,
+            "U": 37,
+            "Cs": "ETHBTC",
+            "gP": "PRIMARY_PEG",
+            "gOT": "PRICE_LEVEL",
+            "gOV": 1,
+            "gp": "0.10264410"
+        }"#;
+
+        let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+        match event {
+            WebSocketEvent::ExecutionReport(e) => {
+                assert_eq!(e.execution_type, ExecutionType::TradePrevention);
+                assert_eq!(e.prevented_match_id, Some(3));
+                assert_eq!(e.prevented_quantity, Some(1.0));
+                assert_eq!(e.last_prevented_quantity, Some(1.0));
+                assert_eq!(e.working_time, Some(1499405658657));
+                assert_eq!(
+                    e.self_trade_prevention_mode.as_deref(),
+                    Some("EXPIRE_MAKER")
+                );
+                assert_eq!(e.peg_price_type.as_deref(), Some("PRIMARY_PEG"));
+                assert_eq!(e.peg_offset_value, Some(1));
+                assert_eq!(e.pegged_price, Some(0.1026441));
+            }
+            _ => panic!("Expected ExecutionReport event"),
+        }
+    }
+
+    #[test]
+    fn test_opoco_list_status_deserialize() {
+        let json = r#"{
+            "e": "listStatus",
+            "E": 1564035303637,
+            "s": "ETHBTC",
+            "g": 2,
+            "c": "OPOCO",
+            "l": "EXEC_STARTED",
+            "L": "EXECUTING",
+            "r": "NONE",
+            "C": "F4QN4G8DlFATFlIUQ0cjdD",
+            "T": 1564035303625,
+            "O": [
+                {"s": "ETHBTC", "i": 17, "c": "AJYsMjErWJesZvqlJCTUgL"},
+                {"s": "ETHBTC", "i": 18, "c": "bfYPSQdLoqAJeNrOr9adzq"},
+                {"s": "ETHBTC", "i": 19, "c": "nRzSWfTbCJwY0gRTxKmf2D"}
+            ]
+        }"#;
+
+        let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+        match event {
+            WebSocketEvent::ListStatus(e) => {
+                assert_eq!(e.contingency_type, ContingencyType::Opoco);
+                assert_eq!(e.list_status_type, OcoStatus::ExecStarted);
+                assert_eq!(e.list_order_status, OcoOrderStatus::Executing);
+                assert_eq!(e.orders.len(), 3);
+                assert_eq!(e.orders[2].order_id, 19);
+            }
+            _ => panic!("Expected ListStatus event"),
+        }
+    }
+
     #[test]
     fn test_depth_level_deserialize() {
         let json = r#"["50000.00", "1.5"]"#;
@@ -0,0 +1,375 @@
+//! Deposit and withdrawal network status changes.
+//!
+//! [`NetworkStatusWatcher`] polls the coin configuration
+//! ([`Wallet::all_coins`](crate::rest::Wallet::all_coins)) and reports when
+//! deposits or withdrawals are suspended or resumed on a network, so
+//! operational tooling knows when to hold back withdrawals of an asset.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{mpsc, watch};
+
+use crate::models::wallet::CoinInfo;
+use crate::supervisor::RestartPolicy;
+use crate::{Binance, Result};
+
+/// Deposit and withdrawal status of one coin on one network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkStatus {
+    /// Coin symbol.
+    pub coin: String,
+    /// Network identifier.
+    pub network: String,
+    /// Whether deposits are enabled.
+    pub deposit_enable: bool,
+    /// Whether withdrawals are enabled.
+    pub withdraw_enable: bool,
+}
+
+/// Change of a network's deposit or withdrawal status.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NetworkStatusEvent {
+    /// Deposits were suspended.
+    DepositSuspended {
+        /// Coin symbol.
+        coin: String,
+        /// Network identifier.
+        network: String,
+    },
+    /// Deposits were resumed.
+    DepositResumed {
+        /// Coin symbol.
+        coin: String,
+        /// Network identifier.
+        network: String,
+    },
+    /// Withdrawals were suspended.
+    WithdrawSuspended {
+        /// Coin symbol.
+        coin: String,
+        /// Network identifier.
+        network: String,
+    },
+    /// Withdrawals were resumed.
+    WithdrawResumed {
+        /// Coin symbol.
+        coin: String,
+        /// Network identifier.
+        network: String,
+    },
+}
+
+/// Tracks network statuses between polls.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkStatusTracker {
+    statuses: HashMap<(String, String), NetworkStatus>,
+}
+
+impl NetworkStatusTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update the tracker from a coin configuration snapshot.
+    ///
+    /// Returns the status changes since the previous snapshot. Networks seen
+    /// for the first time are recorded without an event.
+    pub fn update(&mut self, coins: &[CoinInfo]) -> Vec<NetworkStatusEvent> {
+        let mut events = Vec::new();
+        for network in coins.iter().flat_map(|c| &c.network_list) {
+            let status = NetworkStatus {
+                coin: network.coin.clone(),
+                network: network.network.clone(),
+                deposit_enable: network.deposit_enable,
+                withdraw_enable: network.withdraw_enable,
+            };
+            let key = (status.coin.clone(), status.network.clone());
+            if let Some(previous) = self.statuses.insert(key, status.clone()) {
+                events.extend(changes(&previous, &status));
+            }
+        }
+        events
+    }
+
+    /// Get the status of a coin on a network.
+    pub fn get(&self, coin: &str, network: &str) -> Option<&NetworkStatus> {
+        self.statuses.get(&(coin.to_string(), network.to_string()))
+    }
+
+    /// Get the tracked statuses.
+    pub fn statuses(&self) -> impl Iterator<Item = &NetworkStatus> {
+        self.statuses.values()
+    }
+
+    /// Get the number of tracked networks.
+    pub fn len(&self) -> usize {
+        self.statuses.len()
+    }
+
+    /// Check whether no networks are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.statuses.is_empty()
+    }
+}
+
+/// Reports deposit and withdrawal suspensions and resumptions.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{NetworkStatusEvent, NetworkStatusWatcher};
+/// use std::time::Duration;
+///
+/// let watcher = NetworkStatusWatcher::new(client.clone()).coin("USDT").coin("BTC");
+/// watcher.check().await?;
+///
+/// if watcher.is_withdraw_enabled("USDT", "TRX") == Some(false) {
+///     println!("USDT withdrawals on TRX are suspended");
+/// }
+///
+/// let mut handle = watcher.start(Duration::from_secs(60));
+/// while let Some(event) = handle.next().await {
+///     if let NetworkStatusEvent::WithdrawSuspended { coin, network } = event {
+///         println!("Pausing {} withdrawals on {}", coin, network);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct NetworkStatusWatcher {
+    client: Binance,
+    coins: HashSet<String>,
+    tracker: Arc<Mutex<NetworkStatusTracker>>,
+}
+
+impl NetworkStatusWatcher {
+    /// Create a watcher for all coins.
+    pub fn new(client: Binance) -> Self {
+        Self {
+            client,
+            coins: HashSet::new(),
+            tracker: Arc::new(Mutex::new(NetworkStatusTracker::new())),
+        }
+    }
+
+    /// Only watch the given coin. May be called several times.
+    pub fn coin(mut self, coin: &str) -> Self {
+        self.coins.insert(coin.to_uppercase());
+        self
+    }
+
+    /// Fetch the coin configuration once and return the status changes.
+    ///
+    /// The first check records the current statuses without events.
+    ///
+    /// **Requires authentication.**
+    pub async fn check(&self) -> Result<Vec<NetworkStatusEvent>> {
+        let mut coins = self.client.wallet().all_coins().await?;
+        if !self.coins.is_empty() {
+            coins.retain(|c| self.coins.contains(&c.coin));
+        }
+        Ok(self.tracker.lock().unwrap().update(&coins))
+    }
+
+    /// Get the last known status of a coin on a network.
+    pub fn status(&self, coin: &str, network: &str) -> Option<NetworkStatus> {
+        self.tracker.lock().unwrap().get(coin, network).cloned()
+    }
+
+    /// Get the last known statuses.
+    pub fn statuses(&self) -> Vec<NetworkStatus> {
+        self.tracker.lock().unwrap().statuses().cloned().collect()
+    }
+
+    /// Check whether withdrawals were enabled on the last check, or `None` if
+    /// the network is unknown.
+    pub fn is_withdraw_enabled(&self, coin: &str, network: &str) -> Option<bool> {
+        self.status(coin, network).map(|s| s.withdraw_enable)
+    }
+
+    /// Check whether deposits were enabled on the last check, or `None` if
+    /// the network is unknown.
+    pub fn is_deposit_enabled(&self, coin: &str, network: &str) -> Option<bool> {
+        self.status(coin, network).map(|s| s.deposit_enable)
+    }
+
+    /// Run [`check`](Self::check) every `interval` in the background.
+    ///
+    /// The task stops when the returned handle is dropped or the client
+    /// shuts down.
+    pub fn start(self, interval: Duration) -> NetworkStatusHandle {
+        let (tx, rx) = mpsc::channel(100);
+        let (stop, stop_rx) = watch::channel(false);
+
+        let watcher = self.clone();
+        let shutdown = self.client.shutdown_handle().clone();
+        let runtime = self.client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "network-status", policy, move || {
+            watcher
+                .clone()
+                .watch_loop(interval, tx.clone(), stop_rx.clone())
+        });
+
+        NetworkStatusHandle {
+            watcher: self,
+            rx,
+            stop,
+        }
+    }
+
+    async fn watch_loop(
+        self,
+        interval: Duration,
+        tx: mpsc::Sender<NetworkStatusEvent>,
+        mut stop: watch::Receiver<bool>,
+    ) {
+        let shutdown = self.client.shutdown_handle().clone();
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                // Stopped, or the handle was dropped
+                _ = stop.wait_for(|stopped| *stopped) => break,
+                _ = ticker.tick() => {}
+            }
+
+            match self.check().await {
+                Ok(events) => {
+                    for event in events {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Network status check failed: {}", e),
+            }
+        }
+    }
+}
+
+/// Handle to a background watcher started with [`NetworkStatusWatcher::start`].
+pub struct NetworkStatusHandle {
+    watcher: NetworkStatusWatcher,
+    rx: mpsc::Receiver<NetworkStatusEvent>,
+    stop: watch::Sender<bool>,
+}
+
+impl NetworkStatusHandle {
+    /// Get the last known status of a coin on a network.
+    pub fn status(&self, coin: &str, network: &str) -> Option<NetworkStatus> {
+        self.watcher.status(coin, network)
+    }
+
+    /// Check whether withdrawals were enabled on the last check.
+    pub fn is_withdraw_enabled(&self, coin: &str, network: &str) -> Option<bool> {
+        self.watcher.is_withdraw_enabled(coin, network)
+    }
+
+    /// Receive the next status change.
+    pub async fn next(&mut self) -> Option<NetworkStatusEvent> {
+        self.rx.recv().await
+    }
+
+    /// Receive a status change if one is ready.
+    pub fn try_next(&mut self) -> Option<NetworkStatusEvent> {
+        self.rx.try_recv().ok()
+    }
+
+    /// Stop the background task.
+    pub fn stop(&self) {
+        let _ = self.stop.send(true);
+    }
+}
+
+fn changes(previous: &NetworkStatus, current: &NetworkStatus) -> Vec<NetworkStatusEvent> {
+    let coin = current.coin.clone();
+    let network = current.network.clone();
+    let mut events = Vec::new();
+
+    if previous.deposit_enable != current.deposit_enable {
+        events.push(if current.deposit_enable {
+            NetworkStatusEvent::DepositResumed {
+                coin: coin.clone(),
+                network: network.clone(),
+            }
+        } else {
+            NetworkStatusEvent::DepositSuspended {
+                coin: coin.clone(),
+                network: network.clone(),
+            }
+        });
+    }
+    if previous.withdraw_enable != current.withdraw_enable {
+        events.push(if current.withdraw_enable {
+            NetworkStatusEvent::WithdrawResumed { coin, network }
+        } else {
+            NetworkStatusEvent::WithdrawSuspended { coin, network }
+        });
+    }
+    events
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(deposit_enable: bool, withdraw_enable: bool) -> CoinInfo {
+        serde_json::from_value(serde_json::json!({
+            "coin": "USDT",
+            "depositAllEnable": deposit_enable,
+            "free": "0", "freeze": "0", "ipoable": "0", "ipoing": "0",
+            "isLegalMoney": false,
+            "locked": "0",
+            "name": "TetherUS",
+            "networkList": [{
+                "coin": "USDT",
+                "depositEnable": deposit_enable,
+                "isDefault": true,
+                "minConfirm": 1,
+                "name": "Tron",
+                "network": "TRX",
+                "withdrawEnable": withdraw_enable,
+                "withdrawFee": "1",
+                "withdrawMax": "1000000",
+                "withdrawMin": "10"
+            }],
+            "storage": "0",
+            "trading": true,
+            "withdrawAllEnable": withdraw_enable,
+            "withdrawing": "0"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_status_changes() {
+        let mut tracker = NetworkStatusTracker::new();
+        assert!(tracker.update(&[coin(true, true)]).is_empty());
+        assert_eq!(tracker.len(), 1);
+        assert!(tracker.update(&[coin(true, true)]).is_empty());
+
+        let events = tracker.update(&[coin(true, false)]);
+        assert_eq!(
+            events,
+            vec![NetworkStatusEvent::WithdrawSuspended {
+                coin: "USDT".to_string(),
+                network: "TRX".to_string(),
+            }]
+        );
+        assert!(!tracker.get("USDT", "TRX").unwrap().withdraw_enable);
+
+        let events = tracker.update(&[coin(false, true)]);
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            events[0],
+            NetworkStatusEvent::DepositSuspended { .. }
+        ));
+        assert!(matches!(
+            events[1],
+            NetworkStatusEvent::WithdrawResumed { .. }
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+//! The most commonly used types, for glob importing.
+//!
+//! ```rust,ignore
+//! use binance_api_client::prelude::*;
+//!
+//! let client = Binance::new("api_key", "secret_key")?;
+//! let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+//!     .quantity("0.001")
+//!     .price("50000.00")
+//!     .time_in_force(TimeInForce::GTC)
+//!     .build();
+//! let response = client.account().create_order(&order).await?;
+//! ```
+
+pub use crate::models::{
+    AccountInfo, Balance, BookTicker, ExchangeInfo, Kline, Order, OrderBook, OrderResponse, Symbol,
+    SymbolFilter, Ticker24h, TickerPrice, Trade, UserTrade, WebSocketEvent,
+};
+pub use crate::types::{
+    ExecutionType, KlineInterval, OrderResponseType, OrderSide, OrderStatus, OrderType, TimeInForce,
+};
+pub use crate::ws::{
+    DepthCache, DepthCacheManager, UserDataStreamManager, WebSocketClient, WebSocketConnection,
+};
+pub use crate::{
+    Binance, CancelReplaceOrderBuilder, Config, ConfigBuilder, Error, OcoOrderBuilder,
+    OrderBuilder, Result,
+};
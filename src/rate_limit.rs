@@ -0,0 +1,151 @@
+//! Request weight and order count usage reported by the API.
+//!
+//! Every REST response carries headers such as `X-MBX-USED-WEIGHT-1M` and
+//! `X-MBX-ORDER-COUNT-10S` with the usage counted against each rate limit.
+//! [`RateLimitTracker`] keeps the latest values so they can be monitored
+//! before the exchange starts rejecting requests.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use reqwest::header::HeaderMap;
+
+use crate::credentials::get_timestamp;
+
+const USED_WEIGHT_PREFIX: &str = "x-mbx-used-weight-";
+const ORDER_COUNT_PREFIX: &str = "x-mbx-order-count-";
+const SAPI_IP_WEIGHT_PREFIX: &str = "x-sapi-used-ip-weight-";
+const SAPI_UID_WEIGHT_PREFIX: &str = "x-sapi-used-uid-weight-";
+
+/// Rate limit usage reported by the most recent responses.
+///
+/// Each map is keyed by the interval suffix of the header in upper case
+/// (e.g. `"1M"` for `X-MBX-USED-WEIGHT-1M`). A value is kept until a later
+/// response reports the same header again.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Request weight used per interval (`X-MBX-USED-WEIGHT-*`).
+    pub used_weight: HashMap<String, u32>,
+    /// Orders placed per interval (`X-MBX-ORDER-COUNT-*`).
+    pub order_count: HashMap<String, u32>,
+    /// SAPI weight used by this IP per interval (`X-SAPI-USED-IP-WEIGHT-*`).
+    pub sapi_ip_weight: HashMap<String, u32>,
+    /// SAPI weight used by this account per interval
+    /// (`X-SAPI-USED-UID-WEIGHT-*`).
+    pub sapi_uid_weight: HashMap<String, u32>,
+    /// Local time the last rate limit header was received (milliseconds).
+    pub updated_at: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Get the request weight used in the current minute.
+    pub fn used_weight_1m(&self) -> Option<u32> {
+        self.used_weight.get("1M").copied()
+    }
+
+    /// Get the number of orders placed in the current 10 seconds.
+    pub fn order_count_10s(&self) -> Option<u32> {
+        self.order_count.get("10S").copied()
+    }
+
+    /// Get the number of orders placed in the current day.
+    pub fn order_count_1d(&self) -> Option<u32> {
+        self.order_count.get("1D").copied()
+    }
+}
+
+/// Records rate limit headers from REST responses.
+///
+/// A `RateLimitTracker` is shared by all clones of a
+/// [`Client`](crate::Client) and updated after every response.
+#[derive(Debug, Default)]
+pub struct RateLimitTracker {
+    status: Mutex<RateLimitStatus>,
+}
+
+impl RateLimitTracker {
+    /// Create an empty tracker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the rate limit headers of a response.
+    ///
+    /// Headers that are missing or not numeric are ignored.
+    pub fn record(&self, headers: &HeaderMap) {
+        let mut updates = Vec::new();
+        for (name, value) in headers {
+            let Some(value) = value.to_str().ok().and_then(|v| v.trim().parse().ok()) else {
+                continue;
+            };
+            let name = name.as_str();
+            for prefix in [
+                USED_WEIGHT_PREFIX,
+                ORDER_COUNT_PREFIX,
+                SAPI_IP_WEIGHT_PREFIX,
+                SAPI_UID_WEIGHT_PREFIX,
+            ] {
+                if let Some(interval) = name.strip_prefix(prefix) {
+                    updates.push((prefix, interval.to_ascii_uppercase(), value));
+                }
+            }
+        }
+        if updates.is_empty() {
+            return;
+        }
+
+        let mut status = self.status.lock().unwrap();
+        for (prefix, interval, value) in updates {
+            let usage = match prefix {
+                USED_WEIGHT_PREFIX => &mut status.used_weight,
+                ORDER_COUNT_PREFIX => &mut status.order_count,
+                SAPI_IP_WEIGHT_PREFIX => &mut status.sapi_ip_weight,
+                _ => &mut status.sapi_uid_weight,
+            };
+            usage.insert(interval, value);
+        }
+        status.updated_at = get_timestamp().ok();
+    }
+
+    /// Get a snapshot of the recorded usage.
+    pub fn status(&self) -> RateLimitStatus {
+        self.status.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_record_headers() {
+        let tracker = RateLimitTracker::new();
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight", HeaderValue::from_static("12"));
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("12"));
+        headers.insert("x-mbx-order-count-10s", HeaderValue::from_static("3"));
+        headers.insert("x-mbx-order-count-1d", HeaderValue::from_static("40"));
+        headers.insert("x-sapi-used-ip-weight-1m", HeaderValue::from_static("bad"));
+        tracker.record(&headers);
+
+        let status = tracker.status();
+        assert_eq!(status.used_weight_1m(), Some(12));
+        assert_eq!(status.used_weight.len(), 1);
+        assert_eq!(status.order_count_10s(), Some(3));
+        assert_eq!(status.order_count_1d(), Some(40));
+        assert!(status.sapi_ip_weight.is_empty());
+        assert!(status.updated_at.is_some());
+
+        // Responses without rate limit headers keep the previous values
+        tracker.record(&HeaderMap::new());
+        assert_eq!(tracker.status().used_weight_1m(), Some(12));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("20"));
+        tracker.record(&headers);
+        let status = tracker.status();
+        assert_eq!(status.used_weight_1m(), Some(20));
+        assert_eq!(status.order_count_10s(), Some(3));
+    }
+}
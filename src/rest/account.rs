@@ -3,21 +3,27 @@
 //! This module provides authenticated endpoints for account information,
 //! order management, and trading.
 
+use futures::{Stream, StreamExt};
 use serde::Serialize;
 
 use crate::client::Client;
 use reqwest::StatusCode;
 
 use crate::Result;
-use crate::error::{BinanceApiError, Error};
+use crate::error::{BinanceApiError, Error, FilterViolation, RequestContext};
+use crate::exchange_info::ExchangeInfoCache;
 use crate::models::{
     AccountCommission, AccountInfo, Allocation, AmendOrderResponse, CancelOrderResponse,
-    CancelReplaceErrorResponse, CancelReplaceResponse, OcoOrder, Order, OrderAmendment, OrderFull,
-    PreventedMatch, SorOrderTestResponse, UnfilledOrderCount, UserTrade,
+    CancelReplaceErrorResponse, CancelReplaceResponse, CanceledOpenOrder, OcoOrder, Order,
+    OrderAmendment, OrderFull, PlacedOrder, PreventedMatch, SideEffectType, SorOrderTestResponse,
+    Symbol, UnfilledOrderCount, UserTrade,
 };
+use crate::rest::Margin;
+use crate::rest::pagination;
 use crate::types::{
-    CancelReplaceMode, CancelRestrictions, OrderRateLimitExceededMode, OrderResponseType,
-    OrderSide, OrderType, TimeInForce,
+    AccountKind, CancelReplaceMode, CancelRestrictions, OrderRateLimitExceededMode,
+    OrderResponseType, OrderSide, OrderStatus, OrderType, PegOffsetType, PegPriceType, QtyParam,
+    SelfTradePreventionMode, SymbolPermission, TimeInForce,
 };
 
 // API endpoints.
@@ -37,6 +43,9 @@ const API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
 const API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
 const API_V3_MY_PREVENTED_MATCHES: &str = "/api/v3/myPreventedMatches";
 const API_V3_MY_ALLOCATIONS: &str = "/api/v3/myAllocations";
+
+/// Largest page returned by the order and trade history endpoints.
+const MAX_HISTORY_LIMIT: u32 = 1000;
 const API_V3_ACCOUNT_COMMISSION: &str = "/api/v3/account/commission";
 const API_V3_ORDER_CANCEL_REPLACE: &str = "/api/v3/order/cancelReplace";
 const API_V3_SOR_ORDER: &str = "/api/v3/sor/order";
@@ -45,6 +54,12 @@ const API_V3_RATE_LIMIT_ORDER: &str = "/api/v3/rateLimit/order";
 const API_V3_ORDER_AMEND: &str = "/api/v3/order/amend/keepPriority";
 const API_V3_ORDER_AMENDMENTS: &str = "/api/v3/order/amendments";
 
+/// Maximum number of cancels [`Account::cancel_orders`] has in flight.
+pub const MAX_CONCURRENT_CANCELS: usize = 5;
+
+// Decimals of quantities computed by the client, the most Binance accepts.
+const QUANTITY_DECIMALS: usize = 8;
+
 /// Account and trading API client.
 ///
 /// Provides authenticated endpoints for account information and trading.
@@ -123,6 +138,48 @@ impl Account {
         self.client.get_signed(API_V3_MY_TRADES, &params_ref).await
     }
 
+    /// Get the full trade history for a symbol, paginating automatically.
+    ///
+    /// Trades are fetched in pages of 1000 by advancing `fromId` past the
+    /// last trade received, oldest first. The stream ends after the most
+    /// recent trade, or at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `from_id` - Trade ID to start from (defaults to the first trade)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let client = Binance::new("api_key", "secret_key")?;
+    /// let trades: Vec<_> = client
+    ///     .account()
+    ///     .my_trades_paginated("BTCUSDT", None)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn my_trades_paginated(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+    ) -> impl Stream<Item = Result<UserTrade>> + Send + 'static {
+        let account = self.clone();
+        let symbol = symbol.to_string();
+        let fetch = move |from_id| {
+            let account = account.clone();
+            let symbol = symbol.clone();
+            async move {
+                account
+                    .my_trades(&symbol, Some(from_id), None, None, Some(MAX_HISTORY_LIMIT))
+                    .await
+            }
+        };
+        pagination::by_id(from_id.unwrap_or(0), MAX_HISTORY_LIMIT, fetch, |t| t.id)
+    }
+
     /// Get orders that were expired due to self-trade prevention.
     ///
     /// # Arguments
@@ -312,6 +369,128 @@ impl Account {
         self.client.post_signed(API_V3_ORDER, &params_ref).await
     }
 
+    /// Create a new order after validating it against the symbol's exchange info.
+    ///
+    /// The order is rejected client-side with [`Error::OrderNotPermitted`] if the
+    /// symbol is not enabled for spot trading or does not accept the order type.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT"]).await?;
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+    ///     .quantity("0.001")
+    ///     .price("50000.00")
+    ///     .time_in_force(TimeInForce::GTC)
+    ///     .build();
+    ///
+    /// let response = client.account().create_order_checked(&order, &info.symbols[0]).await?;
+    /// ```
+    pub async fn create_order_checked(
+        &self,
+        order: &NewOrder,
+        symbol: &Symbol,
+    ) -> Result<OrderFull> {
+        order.check_permissions(symbol, SymbolPermission::Spot)?;
+        self.create_order(order).await
+    }
+
+    /// Create a new order after validating it against the symbol's exchange filters.
+    ///
+    /// The symbol's exchange info comes from `cache`. The order is rejected
+    /// client-side with [`Error::FilterViolation`] if it breaks PRICE_FILTER,
+    /// PERCENT_PRICE, LOT_SIZE, MIN_NOTIONAL or NOTIONAL, instead of costing
+    /// a signed request that fails with -1013.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::ExchangeInfoCache;
+    ///
+    /// let cache = ExchangeInfoCache::new(client.clone());
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+    ///     .quantity("0.001")
+    ///     .price("50000.00")
+    ///     .time_in_force(TimeInForce::GTC)
+    ///     .build();
+    ///
+    /// let response = client.account().create_order_validated(&order, &cache).await?;
+    /// ```
+    pub async fn create_order_validated(
+        &self,
+        order: &NewOrder,
+        cache: &ExchangeInfoCache,
+    ) -> Result<OrderFull> {
+        cache.validate(order).await?;
+        self.create_order(order).await
+    }
+
+    /// Place an order on the spot, cross margin or isolated margin account.
+    ///
+    /// This lets the same strategy code target different wallets by changing
+    /// only the [`AccountKind`]. Spot orders go to `/api/v3/order`, margin
+    /// orders to `/sapi/v1/margin/order`. A margin side effect set with
+    /// [`OrderBuilder::side_effect_type`] is rejected for spot orders.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{AccountKind, OrderBuilder, OrderSide, OrderType};
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+    ///     .quantity("0.001")
+    ///     .build();
+    ///
+    /// let kind = AccountKind::IsolatedMargin("BTCUSDT".to_string());
+    /// let placed = client.account().place_order(&kind, &order).await?;
+    /// println!("Order {} is {:?}", placed.order_id(), placed.status());
+    /// ```
+    pub async fn place_order(&self, kind: &AccountKind, order: &NewOrder) -> Result<PlacedOrder> {
+        match kind {
+            AccountKind::Spot => {
+                if order.side_effect_type.is_some() {
+                    return Err(Error::OrderNotPermitted {
+                        symbol: order.symbol.clone(),
+                        reason: "spot orders do not support sideEffectType".to_string(),
+                    });
+                }
+                self.create_order(order).await.map(PlacedOrder::Spot)
+            }
+            AccountKind::CrossMargin => Margin::new(self.client.clone())
+                .place_order(order, false)
+                .await
+                .map(PlacedOrder::Margin),
+            AccountKind::IsolatedMargin(symbol) => {
+                if *symbol != order.symbol {
+                    return Err(Error::OrderNotPermitted {
+                        symbol: order.symbol.clone(),
+                        reason: format!("isolated margin account is for {}", symbol),
+                    });
+                }
+                Margin::new(self.client.clone())
+                    .place_order(order, true)
+                    .await
+                    .map(PlacedOrder::Margin)
+            }
+        }
+    }
+
+    /// Place an order after validating it against the symbol's exchange info.
+    ///
+    /// Like [`place_order`](Self::place_order), but the symbol must permit
+    /// trading from the given account kind and accept the order type.
+    pub async fn place_order_checked(
+        &self,
+        kind: &AccountKind,
+        order: &NewOrder,
+        symbol: &Symbol,
+    ) -> Result<PlacedOrder> {
+        order.check_permissions(symbol, kind.permission())?;
+        self.place_order(kind, order).await
+    }
+
     /// Test a new order without executing it.
     ///
     /// Validates order parameters but doesn't place the order.
@@ -430,32 +609,131 @@ impl Account {
             .post_signed_raw(API_V3_ORDER_CANCEL_REPLACE, &params_ref)
             .await?;
 
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            StatusCode::BAD_REQUEST | StatusCode::CONFLICT => {
-                let error: CancelReplaceErrorResponse = response.json().await?;
-                Err(Error::from_cancel_replace_error(error))
+        let result: Result<CancelReplaceResponse> = async {
+            match response.status() {
+                StatusCode::OK => Ok(response.json().await?),
+                StatusCode::BAD_REQUEST | StatusCode::CONFLICT => {
+                    let error: CancelReplaceErrorResponse = response.json().await?;
+                    Err(Error::from_cancel_replace_error(error))
+                }
+                StatusCode::UNAUTHORIZED => Err(Error::Api {
+                    code: 401,
+                    message: "Unauthorized".to_string(),
+                }),
+                StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS => {
+                    let error: BinanceApiError = response.json().await?;
+                    Err(Error::from_binance_error(error))
+                }
+                StatusCode::INTERNAL_SERVER_ERROR => Err(Error::Api {
+                    code: 500,
+                    message: "Internal server error".to_string(),
+                }),
+                StatusCode::SERVICE_UNAVAILABLE => Err(Error::Api {
+                    code: 503,
+                    message: "Service unavailable".to_string(),
+                }),
+                status => Err(Error::Api {
+                    code: status.as_u16() as i32,
+                    message: format!("Unexpected status code: {}", status),
+                }),
             }
-            StatusCode::UNAUTHORIZED => Err(Error::Api {
-                code: 401,
-                message: "Unauthorized".to_string(),
-            }),
-            StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS => {
-                let error: BinanceApiError = response.json().await?;
-                Err(Error::from_binance_error(error))
+        }
+        .await;
+        result.map_err(|e| {
+            e.with_context(RequestContext::new(
+                "POST",
+                API_V3_ORDER_CANCEL_REPLACE,
+                &params_ref,
+            ))
+        })
+    }
+
+    /// Move a set of resting orders to new price and size targets.
+    ///
+    /// The open orders for `symbol` are fetched once, then each order is
+    /// refreshed with at most one request: left alone if its price and
+    /// quantity already match, amended with
+    /// [`amend_order_keep_priority`](Self::amend_order_keep_priority) if only
+    /// its quantity decreases, and cancel-replaced otherwise (keeping its
+    /// side, type and time in force). The replacement order is placed for the
+    /// target quantity less the quantity already executed.
+    ///
+    /// The outer result fails only if the open orders cannot be fetched.
+    /// Each update has its own result, in the order given; an order that is
+    /// no longer open is reported as [`QuoteRefresh::NotOpen`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{QuoteRefresh, QuoteUpdate};
+    ///
+    /// let results = client
+    ///     .account()
+    ///     .refresh_quotes(
+    ///         "BTCUSDT",
+    ///         vec![
+    ///             QuoteUpdate::new(bid_id, "49990.00", "0.010"),
+    ///             QuoteUpdate::new(ask_id, "50010.00", "0.005"),
+    ///         ],
+    ///     )
+    ///     .await?;
+    ///
+    /// for result in results {
+    ///     match result? {
+    ///         QuoteRefresh::Replaced(response) => println!("{:?}", response.new_order_result),
+    ///         other => println!("{:?}", other),
+    ///     }
+    /// }
+    /// ```
+    pub async fn refresh_quotes(
+        &self,
+        symbol: &str,
+        updates: Vec<QuoteUpdate>,
+    ) -> Result<Vec<Result<QuoteRefresh>>> {
+        let open = self.open_orders(Some(symbol)).await?;
+
+        let mut results = Vec::with_capacity(updates.len());
+        for update in updates {
+            let result = match open.iter().find(|o| o.order_id == update.order_id) {
+                Some(order) => self.refresh_quote(order, &update).await,
+                None => Ok(QuoteRefresh::NotOpen),
+            };
+            results.push(result);
+        }
+        Ok(results)
+    }
+
+    async fn refresh_quote(&self, order: &Order, update: &QuoteUpdate) -> Result<QuoteRefresh> {
+        match update.action(order)? {
+            QuoteAction::Keep => Ok(QuoteRefresh::Unchanged),
+            QuoteAction::Amend => self
+                .amend_order_keep_priority(
+                    &order.symbol,
+                    Some(order.order_id),
+                    None,
+                    &update.quantity,
+                    None,
+                )
+                .await
+                .map(QuoteRefresh::Amended),
+            QuoteAction::Replace => {
+                let quantity = update.remaining_quantity(order)?;
+                let mut request = CancelReplaceOrderBuilder::new(
+                    &order.symbol,
+                    order.side,
+                    order.order_type,
+                    CancelReplaceMode::StopOnFailure,
+                )
+                .cancel_order_id(order.order_id)
+                .price(&update.price)
+                .quantity(quantity);
+                if order.order_type != OrderType::LimitMaker {
+                    request = request.time_in_force(order.time_in_force);
+                }
+                self.cancel_replace_order(&request.build())
+                    .await
+                    .map(QuoteRefresh::Replaced)
             }
-            StatusCode::INTERNAL_SERVER_ERROR => Err(Error::Api {
-                code: 500,
-                message: "Internal server error".to_string(),
-            }),
-            StatusCode::SERVICE_UNAVAILABLE => Err(Error::Api {
-                code: 503,
-                message: "Service unavailable".to_string(),
-            }),
-            status => Err(Error::Api {
-                code: status.as_u16() as i32,
-                message: format!("Unexpected status code: {}", status),
-            }),
         }
     }
 
@@ -559,6 +837,49 @@ impl Account {
         self.client.delete_signed(API_V3_ORDER, &params_ref).await
     }
 
+    /// Cancel several orders on a symbol concurrently.
+    ///
+    /// At most [`MAX_CONCURRENT_CANCELS`] cancels are in flight at once.
+    /// Returns one result per order ID, in the order given. When the
+    /// exchange reports an unknown order, the order is queried to tell an
+    /// order that already filled or closed from one that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::CancelOutcome;
+    ///
+    /// let results = client.account().cancel_orders("BTCUSDT", &[101, 102, 103]).await;
+    /// for (order_id, result) in results {
+    ///     match result? {
+    ///         CancelOutcome::Canceled(_) => println!("{} canceled", order_id),
+    ///         CancelOutcome::AlreadyFilled(order) => println!("{} filled", order.order_id),
+    ///         CancelOutcome::AlreadyClosed(order) => println!("{} {:?}", order_id, order.status),
+    ///         CancelOutcome::UnknownOrder => println!("{} not found", order_id),
+    ///     }
+    /// }
+    /// ```
+    pub async fn cancel_orders(
+        &self,
+        symbol: &str,
+        order_ids: &[u64],
+    ) -> Vec<(u64, Result<CancelOutcome>)> {
+        futures::stream::iter(order_ids.iter().copied())
+            .map(|order_id| async move {
+                let result = match self.cancel_order(symbol, Some(order_id), None).await {
+                    Ok(response) => Ok(CancelOutcome::Canceled(response)),
+                    Err(e) if e.is_unknown_order() => CancelOutcome::from_lookup(
+                        self.get_order(symbol, Some(order_id), None).await,
+                    ),
+                    Err(e) => Err(e),
+                };
+                (order_id, result)
+            })
+            .buffered(MAX_CONCURRENT_CANCELS)
+            .collect()
+            .await
+    }
+
     /// Get all open orders for a symbol, or all symbols if none specified.
     ///
     /// # Arguments
@@ -601,7 +922,7 @@ impl Account {
     /// let canceled = client.account().cancel_all_orders("BTCUSDT").await?;
     /// println!("Canceled {} orders", canceled.len());
     /// ```
-    pub async fn cancel_all_orders(&self, symbol: &str) -> Result<Vec<CancelOrderResponse>> {
+    pub async fn cancel_all_orders(&self, symbol: &str) -> Result<Vec<CanceledOpenOrder>> {
         let params = [("symbol", symbol)];
         self.client.delete_signed(API_V3_OPEN_ORDERS, &params).await
     }
@@ -649,6 +970,49 @@ impl Account {
         self.client.get_signed(API_V3_ALL_ORDERS, &params_ref).await
     }
 
+    /// Get every order for a symbol, paginating automatically.
+    ///
+    /// Orders are fetched in pages of 1000 by advancing `orderId` past the
+    /// last order received, oldest first. The stream ends after the most
+    /// recent order, or at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `order_id` - Order ID to start from (defaults to the first order)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let client = Binance::new("api_key", "secret_key")?;
+    /// let mut orders = client.account().all_orders_paginated("BTCUSDT", None);
+    /// while let Some(order) = orders.try_next().await? {
+    ///     println!("{} {:?}", order.order_id, order.status);
+    /// }
+    /// ```
+    pub fn all_orders_paginated(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+    ) -> impl Stream<Item = Result<Order>> + Send + 'static {
+        let account = self.clone();
+        let symbol = symbol.to_string();
+        let fetch = move |order_id| {
+            let account = account.clone();
+            let symbol = symbol.clone();
+            async move {
+                account
+                    .all_orders(&symbol, Some(order_id), None, None, Some(MAX_HISTORY_LIMIT))
+                    .await
+            }
+        };
+        pagination::by_id(order_id.unwrap_or(0), MAX_HISTORY_LIMIT, fetch, |o| {
+            o.order_id
+        })
+    }
+
     // OCO Order Endpoints.
 
     /// Create a new OCO (One-Cancels-Other) order.
@@ -1007,6 +1371,14 @@ pub struct OrderBuilder {
     client_order_id: Option<String>,
     iceberg_qty: Option<String>,
     response_type: Option<OrderResponseType>,
+    trailing_delta: Option<u64>,
+    strategy_id: Option<u64>,
+    strategy_type: Option<i32>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    peg_price_type: Option<PegPriceType>,
+    peg_offset_value: Option<i32>,
+    peg_offset_type: Option<PegOffsetType>,
+    side_effect_type: Option<SideEffectType>,
 }
 
 /// Builder for cancel-replace orders.
@@ -1030,12 +1402,12 @@ pub struct CancelReplaceOrderBuilder {
     trailing_delta: Option<u64>,
     iceberg_qty: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     cancel_restrictions: Option<CancelRestrictions>,
     order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
-    peg_price_type: Option<String>,
+    peg_price_type: Option<PegPriceType>,
     peg_offset_value: Option<i32>,
-    peg_offset_type: Option<String>,
+    peg_offset_type: Option<PegOffsetType>,
 }
 
 impl CancelReplaceOrderBuilder {
@@ -1075,26 +1447,26 @@ impl CancelReplaceOrderBuilder {
     }
 
     /// Set the order quantity.
-    pub fn quantity(mut self, quantity: &str) -> Self {
-        self.quantity = Some(quantity.to_string());
+    pub fn quantity(mut self, quantity: impl Into<QtyParam>) -> Self {
+        self.quantity = Some(quantity.into().into());
         self
     }
 
     /// Set the quote order quantity.
-    pub fn quote_quantity(mut self, quantity: &str) -> Self {
-        self.quote_quantity = Some(quantity.to_string());
+    pub fn quote_quantity(mut self, quantity: impl Into<QtyParam>) -> Self {
+        self.quote_quantity = Some(quantity.into().into());
         self
     }
 
     /// Set the order price.
-    pub fn price(mut self, price: &str) -> Self {
-        self.price = Some(price.to_string());
+    pub fn price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.price = Some(price.into().into());
         self
     }
 
     /// Set the stop price.
-    pub fn stop_price(mut self, price: &str) -> Self {
-        self.stop_price = Some(price.to_string());
+    pub fn stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.stop_price = Some(price.into().into());
         self
     }
 
@@ -1147,8 +1519,8 @@ impl CancelReplaceOrderBuilder {
     }
 
     /// Set the iceberg quantity.
-    pub fn iceberg_qty(mut self, qty: &str) -> Self {
-        self.iceberg_qty = Some(qty.to_string());
+    pub fn iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -1159,8 +1531,8 @@ impl CancelReplaceOrderBuilder {
     }
 
     /// Set self-trade prevention mode.
-    pub fn self_trade_prevention_mode(mut self, mode: &str) -> Self {
-        self.self_trade_prevention_mode = Some(mode.to_string());
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
         self
     }
 
@@ -1177,8 +1549,8 @@ impl CancelReplaceOrderBuilder {
     }
 
     /// Set pegged price type.
-    pub fn peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.peg_price_type = Some(peg_price_type.to_string());
+    pub fn peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.peg_price_type = Some(peg_price_type);
         self
     }
 
@@ -1189,8 +1561,8 @@ impl CancelReplaceOrderBuilder {
     }
 
     /// Set pegged offset type.
-    pub fn peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -1246,26 +1618,23 @@ pub struct CancelReplaceOrder {
     trailing_delta: Option<u64>,
     iceberg_qty: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     cancel_restrictions: Option<CancelRestrictions>,
     order_rate_limit_exceeded_mode: Option<OrderRateLimitExceededMode>,
-    peg_price_type: Option<String>,
+    peg_price_type: Option<PegPriceType>,
     peg_offset_value: Option<i32>,
-    peg_offset_type: Option<String>,
+    peg_offset_type: Option<PegOffsetType>,
 }
 
 impl CancelReplaceOrder {
-    fn to_params(&self) -> Vec<(String, String)> {
+    pub(crate) fn to_params(&self) -> Vec<(String, String)> {
         let mut params = vec![
             ("symbol".to_string(), self.symbol.clone()),
             (
                 "side".to_string(),
                 format!("{:?}", self.side).to_uppercase(),
             ),
-            (
-                "type".to_string(),
-                format!("{:?}", self.order_type).to_uppercase(),
-            ),
+            ("type".to_string(), self.order_type.to_string()),
             (
                 "cancelReplaceMode".to_string(),
                 self.cancel_replace_mode.to_string(),
@@ -1317,8 +1686,8 @@ impl CancelReplaceOrder {
                 format!("{:?}", resp).to_uppercase(),
             ));
         }
-        if let Some(ref mode) = self.self_trade_prevention_mode {
-            params.push(("selfTradePreventionMode".to_string(), mode.clone()));
+        if let Some(mode) = self.self_trade_prevention_mode {
+            params.push(("selfTradePreventionMode".to_string(), mode.to_string()));
         }
         if let Some(restrictions) = self.cancel_restrictions {
             params.push(("cancelRestrictions".to_string(), restrictions.to_string()));
@@ -1326,20 +1695,123 @@ impl CancelReplaceOrder {
         if let Some(mode) = self.order_rate_limit_exceeded_mode {
             params.push(("orderRateLimitExceededMode".to_string(), mode.to_string()));
         }
-        if let Some(ref peg) = self.peg_price_type {
-            params.push(("pegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.peg_price_type {
+            params.push(("pegPriceType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.peg_offset_value {
             params.push(("pegOffsetValue".to_string(), value.to_string()));
         }
-        if let Some(ref peg) = self.peg_offset_type {
-            params.push(("pegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.peg_offset_type {
+            params.push(("pegOffsetType".to_string(), peg.to_string()));
         }
 
         params
     }
 }
 
+/// New price and size target for a resting order.
+///
+/// See [`Account::refresh_quotes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuoteUpdate {
+    /// ID of the resting order.
+    pub order_id: u64,
+    /// Target price.
+    pub price: String,
+    /// Target order quantity.
+    pub quantity: String,
+}
+
+impl QuoteUpdate {
+    /// Create a quote update for an order.
+    pub fn new(order_id: u64, price: &str, quantity: &str) -> Self {
+        Self {
+            order_id,
+            price: price.to_string(),
+            quantity: quantity.to_string(),
+        }
+    }
+
+    fn action(&self, order: &Order) -> Result<QuoteAction> {
+        let price = parse_decimal("price", &self.price)?;
+        let quantity = parse_decimal("quantity", &self.quantity)?;
+        if price != order.price {
+            return Ok(QuoteAction::Replace);
+        }
+        if quantity == order.orig_qty {
+            Ok(QuoteAction::Keep)
+        } else if quantity < order.orig_qty {
+            Ok(QuoteAction::Amend)
+        } else {
+            Ok(QuoteAction::Replace)
+        }
+    }
+
+    // Quantity of the replacement order: the target less what already filled.
+    fn remaining_quantity(&self, order: &Order) -> Result<QtyParam> {
+        let quantity = parse_decimal("quantity", &self.quantity)?;
+        let remaining = quantity - order.executed_qty;
+        if remaining <= 0.0 {
+            return Err(Error::InvalidConfig(format!(
+                "quantity {} is not above the executed quantity {} of order {}",
+                self.quantity, order.executed_qty, order.order_id
+            )));
+        }
+        Ok(QtyParam::from_f64(remaining, QUANTITY_DECIMALS))
+    }
+}
+
+fn parse_decimal(name: &str, value: &str) -> Result<f64> {
+    value
+        .parse()
+        .map_err(|_| Error::InvalidConfig(format!("invalid {}: {}", name, value)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuoteAction {
+    Keep,
+    Amend,
+    Replace,
+}
+
+/// Outcome of refreshing one order with [`Account::refresh_quotes`].
+#[derive(Debug, Clone)]
+pub enum QuoteRefresh {
+    /// The order already had the target price and quantity.
+    Unchanged,
+    /// The order quantity was reduced, keeping its queue priority.
+    Amended(AmendOrderResponse),
+    /// The order was canceled and replaced at the target price and quantity.
+    Replaced(CancelReplaceResponse),
+    /// No open order with this ID exists on the symbol.
+    NotOpen,
+}
+
+/// Outcome of canceling one order with [`Account::cancel_orders`].
+#[derive(Debug, Clone)]
+pub enum CancelOutcome {
+    /// The order was canceled.
+    Canceled(CancelOrderResponse),
+    /// The order had already filled.
+    AlreadyFilled(Order),
+    /// The order had already been canceled, rejected or expired.
+    AlreadyClosed(Order),
+    /// No order with this ID exists on the symbol.
+    UnknownOrder,
+}
+
+impl CancelOutcome {
+    // Classify an order that could not be canceled from its lookup.
+    fn from_lookup(lookup: Result<Order>) -> Result<Self> {
+        match lookup {
+            Ok(order) if order.status == OrderStatus::Filled => Ok(Self::AlreadyFilled(order)),
+            Ok(order) => Ok(Self::AlreadyClosed(order)),
+            Err(e) if e.is_unknown_order() => Ok(Self::UnknownOrder),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 impl OrderBuilder {
     /// Create a new order builder.
     pub fn new(symbol: &str, side: OrderSide, order_type: OrderType) -> Self {
@@ -1355,30 +1827,38 @@ impl OrderBuilder {
             client_order_id: None,
             iceberg_qty: None,
             response_type: None,
+            trailing_delta: None,
+            strategy_id: None,
+            strategy_type: None,
+            self_trade_prevention_mode: None,
+            peg_price_type: None,
+            peg_offset_value: None,
+            peg_offset_type: None,
+            side_effect_type: None,
         }
     }
 
     /// Set the order quantity.
-    pub fn quantity(mut self, quantity: &str) -> Self {
-        self.quantity = Some(quantity.to_string());
+    pub fn quantity(mut self, quantity: impl Into<QtyParam>) -> Self {
+        self.quantity = Some(quantity.into().into());
         self
     }
 
     /// Set the quote order quantity (for market orders).
-    pub fn quote_quantity(mut self, quantity: &str) -> Self {
-        self.quote_quantity = Some(quantity.to_string());
+    pub fn quote_quantity(mut self, quantity: impl Into<QtyParam>) -> Self {
+        self.quote_quantity = Some(quantity.into().into());
         self
     }
 
     /// Set the order price (required for limit orders).
-    pub fn price(mut self, price: &str) -> Self {
-        self.price = Some(price.to_string());
+    pub fn price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.price = Some(price.into().into());
         self
     }
 
     /// Set the stop price (for stop orders).
-    pub fn stop_price(mut self, price: &str) -> Self {
-        self.stop_price = Some(price.to_string());
+    pub fn stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.stop_price = Some(price.into().into());
         self
     }
 
@@ -1395,8 +1875,8 @@ impl OrderBuilder {
     }
 
     /// Set the iceberg quantity.
-    pub fn iceberg_qty(mut self, qty: &str) -> Self {
-        self.iceberg_qty = Some(qty.to_string());
+    pub fn iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -1406,6 +1886,57 @@ impl OrderBuilder {
         self
     }
 
+    /// Set the trailing delta in basis points (for trailing stop orders).
+    pub fn trailing_delta(mut self, delta: u64) -> Self {
+        self.trailing_delta = Some(delta);
+        self
+    }
+
+    /// Set the strategy ID.
+    pub fn strategy_id(mut self, id: u64) -> Self {
+        self.strategy_id = Some(id);
+        self
+    }
+
+    /// Set the strategy type (must be at least 1000000).
+    pub fn strategy_type(mut self, strategy_type: i32) -> Self {
+        self.strategy_type = Some(strategy_type);
+        self
+    }
+
+    /// Set self-trade prevention mode.
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
+        self
+    }
+
+    /// Peg the order price to the best bid or ask instead of setting
+    /// [`price`](Self::price).
+    pub fn peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.peg_price_type = Some(peg_price_type);
+        self
+    }
+
+    /// Set pegged offset value.
+    pub fn peg_offset_value(mut self, peg_offset_value: i32) -> Self {
+        self.peg_offset_value = Some(peg_offset_value);
+        self
+    }
+
+    /// Set pegged offset type.
+    pub fn peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.peg_offset_type = Some(peg_offset_type);
+        self
+    }
+
+    /// Set the margin side effect, such as borrowing to fund the order.
+    ///
+    /// Only margin orders accept a side effect.
+    pub fn side_effect_type(mut self, side_effect_type: SideEffectType) -> Self {
+        self.side_effect_type = Some(side_effect_type);
+        self
+    }
+
     /// Build the order.
     pub fn build(self) -> NewOrder {
         NewOrder {
@@ -1420,6 +1951,14 @@ impl OrderBuilder {
             client_order_id: self.client_order_id,
             iceberg_qty: self.iceberg_qty,
             response_type: self.response_type,
+            trailing_delta: self.trailing_delta,
+            strategy_id: self.strategy_id,
+            strategy_type: self.strategy_type,
+            self_trade_prevention_mode: self.self_trade_prevention_mode,
+            peg_price_type: self.peg_price_type,
+            peg_offset_value: self.peg_offset_value,
+            peg_offset_type: self.peg_offset_type,
+            side_effect_type: self.side_effect_type,
         }
     }
 }
@@ -1448,20 +1987,195 @@ pub struct NewOrder {
     iceberg_qty: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", rename = "newOrderRespType")]
     response_type: Option<OrderResponseType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trailing_delta: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strategy_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strategy_type: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peg_price_type: Option<PegPriceType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peg_offset_value: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peg_offset_type: Option<PegOffsetType>,
+    #[serde(skip)]
+    side_effect_type: Option<SideEffectType>,
 }
 
 impl NewOrder {
-    fn to_params(&self) -> Vec<(String, String)> {
+    /// Get the order's symbol.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Check whether the order executes at market price.
+    pub fn is_market(&self) -> bool {
+        matches!(
+            self.order_type,
+            OrderType::Market | OrderType::StopLoss | OrderType::TakeProfit
+        )
+    }
+
+    /// Validate this order's price and quantity against a symbol's filters.
+    ///
+    /// See [`Symbol::check_filters`] for the filters checked and how
+    /// `avg_price` is used.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT"]).await?;
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+    ///     .quantity("0.001")
+    ///     .price("50000.005")
+    ///     .time_in_force(TimeInForce::GTC)
+    ///     .build();
+    ///
+    /// // Fails with a PRICE_FILTER tick size violation
+    /// order.validate(&info.symbols[0], None)?;
+    /// ```
+    pub fn validate(&self, symbol: &Symbol, avg_price: Option<f64>) -> Result<()> {
+        if symbol.symbol != self.symbol {
+            return Err(Error::OrderNotPermitted {
+                symbol: self.symbol.clone(),
+                reason: format!("exchange info is for {}", symbol.symbol),
+            });
+        }
+
+        let parse = |field: &'static str, value: &Option<String>| -> Result<Option<f64>> {
+            value
+                .as_deref()
+                .map(|v| {
+                    v.parse().map_err(|_| Error::FilterViolation {
+                        symbol: self.symbol.clone(),
+                        violation: FilterViolation::InvalidNumber {
+                            field,
+                            value: v.to_string(),
+                        },
+                    })
+                })
+                .transpose()
+        };
+
+        symbol.check_filters(
+            parse("price", &self.price)?,
+            parse("quantity", &self.quantity)?,
+            parse("quoteOrderQty", &self.quote_quantity)?,
+            self.is_market(),
+            avg_price,
+        )
+    }
+
+    // Whether `validate` needs the average price to check every filter.
+    pub(crate) fn needs_avg_price(&self, symbol: &Symbol) -> bool {
+        if self.is_market() {
+            return self.quote_quantity.is_none();
+        }
+        symbol.percent_price().is_some()
+    }
+
+    /// Validate this order against a symbol's permissions and order types.
+    pub fn check_permissions(&self, symbol: &Symbol, permission: SymbolPermission) -> Result<()> {
+        if symbol.symbol != self.symbol {
+            return Err(Error::OrderNotPermitted {
+                symbol: self.symbol.clone(),
+                reason: format!("exchange info is for {}", symbol.symbol),
+            });
+        }
+        symbol.check_order(permission, self.order_type)
+    }
+
+    pub(crate) fn to_params(&self) -> Vec<(String, String)> {
         let mut params = vec![
             ("symbol".to_string(), self.symbol.clone()),
             (
                 "side".to_string(),
                 format!("{:?}", self.side).to_uppercase(),
             ),
+            ("type".to_string(), self.order_type.to_string()),
+        ];
+
+        if let Some(ref qty) = self.quantity {
+            params.push(("quantity".to_string(), qty.clone()));
+        }
+        if let Some(ref qty) = self.quote_quantity {
+            params.push(("quoteOrderQty".to_string(), qty.clone()));
+        }
+        if let Some(ref price) = self.price {
+            params.push(("price".to_string(), price.clone()));
+        }
+        if let Some(ref stop) = self.stop_price {
+            params.push(("stopPrice".to_string(), stop.clone()));
+        }
+        if let Some(ref tif) = self.time_in_force {
+            params.push(("timeInForce".to_string(), format!("{:?}", tif)));
+        }
+        if let Some(ref cid) = self.client_order_id {
+            params.push(("newClientOrderId".to_string(), cid.clone()));
+        }
+        if let Some(ref ice) = self.iceberg_qty {
+            params.push(("icebergQty".to_string(), ice.clone()));
+        }
+        if let Some(ref resp) = self.response_type {
+            params.push((
+                "newOrderRespType".to_string(),
+                format!("{:?}", resp).to_uppercase(),
+            ));
+        }
+        if let Some(delta) = self.trailing_delta {
+            params.push(("trailingDelta".to_string(), delta.to_string()));
+        }
+        if let Some(id) = self.strategy_id {
+            params.push(("strategyId".to_string(), id.to_string()));
+        }
+        if let Some(id) = self.strategy_type {
+            params.push(("strategyType".to_string(), id.to_string()));
+        }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            params.push(("selfTradePreventionMode".to_string(), mode.to_string()));
+        }
+        if let Some(peg) = self.peg_price_type {
+            params.push(("pegPriceType".to_string(), peg.to_string()));
+        }
+        if let Some(value) = self.peg_offset_value {
+            params.push(("pegOffsetValue".to_string(), value.to_string()));
+        }
+        if let Some(peg) = self.peg_offset_type {
+            params.push(("pegOffsetType".to_string(), peg.to_string()));
+        }
+
+        params
+    }
+
+    // Parameters for `/sapi/v1/margin/order`, rejecting the spot-only fields
+    // the margin endpoint does not accept.
+    pub(crate) fn to_margin_params(&self) -> Result<Vec<(String, String)>> {
+        let unsupported = [
+            ("trailingDelta", self.trailing_delta.is_some()),
+            ("strategyId", self.strategy_id.is_some()),
+            ("strategyType", self.strategy_type.is_some()),
+            ("pegPriceType", self.peg_price_type.is_some()),
+            ("pegOffsetValue", self.peg_offset_value.is_some()),
+            ("pegOffsetType", self.peg_offset_type.is_some()),
+        ];
+        if let Some((field, _)) = unsupported.iter().find(|(_, set)| *set) {
+            return Err(Error::OrderNotPermitted {
+                symbol: self.symbol.clone(),
+                reason: format!("margin orders do not support {}", field),
+            });
+        }
+
+        let mut params = vec![
+            ("symbol".to_string(), self.symbol.clone()),
             (
-                "type".to_string(),
-                format!("{:?}", self.order_type).to_uppercase(),
+                "side".to_string(),
+                format!("{:?}", self.side).to_uppercase(),
             ),
+            ("type".to_string(), self.order_type.to_string()),
         ];
 
         if let Some(ref qty) = self.quantity {
@@ -1491,8 +2205,14 @@ impl NewOrder {
                 format!("{:?}", resp).to_uppercase(),
             ));
         }
+        if let Some(mode) = self.self_trade_prevention_mode {
+            params.push(("selfTradePreventionMode".to_string(), mode.to_string()));
+        }
+        if let Some(side_effect) = self.side_effect_type {
+            params.push(("sideEffectType".to_string(), side_effect.to_string()));
+        }
 
-        params
+        Ok(params)
     }
 }
 
@@ -1524,16 +2244,16 @@ impl OcoOrderBuilder {
     pub fn new(
         symbol: &str,
         side: OrderSide,
-        quantity: &str,
-        price: &str,
-        stop_price: &str,
+        quantity: impl Into<QtyParam>,
+        price: impl Into<QtyParam>,
+        stop_price: impl Into<QtyParam>,
     ) -> Self {
         Self {
             symbol: symbol.to_string(),
             side,
-            quantity: quantity.to_string(),
-            price: price.to_string(),
-            stop_price: stop_price.to_string(),
+            quantity: quantity.into().into(),
+            price: price.into().into(),
+            stop_price: stop_price.into().into(),
             stop_limit_price: None,
             stop_limit_time_in_force: None,
             list_client_order_id: None,
@@ -1543,8 +2263,8 @@ impl OcoOrderBuilder {
     }
 
     /// Set the stop limit price.
-    pub fn stop_limit_price(mut self, price: &str) -> Self {
-        self.stop_limit_price = Some(price.to_string());
+    pub fn stop_limit_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.stop_limit_price = Some(price.into().into());
         self
     }
 
@@ -1635,6 +2355,54 @@ impl NewOcoOrder {
 
         params
     }
+
+    // Parameters for the order list OCO API (`aboveType`/`belowType`). A
+    // sell OCO has its limit leg above the market and its stop leg below,
+    // a buy OCO the other way round.
+    pub(crate) fn to_order_list_params(&self) -> Vec<(String, String)> {
+        let (limit_leg, stop_leg) = match self.side {
+            OrderSide::Sell => ("above", "below"),
+            OrderSide::Buy => ("below", "above"),
+        };
+        let stop_type = if self.stop_limit_price.is_some() {
+            OrderType::StopLossLimit
+        } else {
+            OrderType::StopLoss
+        };
+
+        let mut params = vec![
+            ("symbol".to_string(), self.symbol.clone()),
+            (
+                "side".to_string(),
+                format!("{:?}", self.side).to_uppercase(),
+            ),
+            ("quantity".to_string(), self.quantity.clone()),
+            (
+                format!("{}Type", limit_leg),
+                OrderType::LimitMaker.to_string(),
+            ),
+            (format!("{}Price", limit_leg), self.price.clone()),
+            (format!("{}Type", stop_leg), stop_type.to_string()),
+            (format!("{}StopPrice", stop_leg), self.stop_price.clone()),
+        ];
+
+        if let Some(ref slp) = self.stop_limit_price {
+            params.push((format!("{}Price", stop_leg), slp.clone()));
+            let tif = self.stop_limit_time_in_force.unwrap_or(TimeInForce::GTC);
+            params.push((format!("{}TimeInForce", stop_leg), format!("{:?}", tif)));
+        }
+        if let Some(ref id) = self.list_client_order_id {
+            params.push(("listClientOrderId".to_string(), id.clone()));
+        }
+        if let Some(ref id) = self.limit_client_order_id {
+            params.push((format!("{}ClientOrderId", limit_leg), id.clone()));
+        }
+        if let Some(ref id) = self.stop_client_order_id {
+            params.push((format!("{}ClientOrderId", stop_leg), id.clone()));
+        }
+
+        params
+    }
 }
 
 /// Builder for creating OTO order lists.
@@ -1650,14 +2418,14 @@ pub struct OtoOrderBuilder {
     pending_quantity: String,
     list_client_order_id: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     working_client_order_id: Option<String>,
     working_iceberg_qty: Option<String>,
     working_time_in_force: Option<TimeInForce>,
     working_strategy_id: Option<u64>,
     working_strategy_type: Option<i32>,
-    working_peg_price_type: Option<String>,
-    working_peg_offset_type: Option<String>,
+    working_peg_price_type: Option<PegPriceType>,
+    working_peg_offset_type: Option<PegOffsetType>,
     working_peg_offset_value: Option<i32>,
     pending_client_order_id: Option<String>,
     pending_price: Option<String>,
@@ -1667,8 +2435,8 @@ pub struct OtoOrderBuilder {
     pending_time_in_force: Option<TimeInForce>,
     pending_strategy_id: Option<u64>,
     pending_strategy_type: Option<i32>,
-    pending_peg_price_type: Option<String>,
-    pending_peg_offset_type: Option<String>,
+    pending_peg_price_type: Option<PegPriceType>,
+    pending_peg_offset_type: Option<PegOffsetType>,
     pending_peg_offset_value: Option<i32>,
 }
 
@@ -1679,21 +2447,21 @@ impl OtoOrderBuilder {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: &str,
-        working_quantity: &str,
+        working_price: impl Into<QtyParam>,
+        working_quantity: impl Into<QtyParam>,
         pending_type: OrderType,
         pending_side: OrderSide,
-        pending_quantity: &str,
+        pending_quantity: impl Into<QtyParam>,
     ) -> Self {
         Self {
             symbol: symbol.to_string(),
             working_type,
             working_side,
-            working_price: working_price.to_string(),
-            working_quantity: working_quantity.to_string(),
+            working_price: working_price.into().into(),
+            working_quantity: working_quantity.into().into(),
             pending_type,
             pending_side,
-            pending_quantity: pending_quantity.to_string(),
+            pending_quantity: pending_quantity.into().into(),
             list_client_order_id: None,
             response_type: None,
             self_trade_prevention_mode: None,
@@ -1729,8 +2497,8 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn self_trade_prevention_mode(mut self, mode: &str) -> Self {
-        self.self_trade_prevention_mode = Some(mode.to_string());
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
         self
     }
 
@@ -1739,8 +2507,8 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn working_iceberg_qty(mut self, qty: &str) -> Self {
-        self.working_iceberg_qty = Some(qty.to_string());
+    pub fn working_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.working_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -1759,13 +2527,13 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn working_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.working_peg_price_type = Some(peg_price_type.to_string());
+    pub fn working_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.working_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn working_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.working_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn working_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.working_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -1779,13 +2547,13 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn pending_price(mut self, price: &str) -> Self {
-        self.pending_price = Some(price.to_string());
+    pub fn pending_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_stop_price(mut self, price: &str) -> Self {
-        self.pending_stop_price = Some(price.to_string());
+    pub fn pending_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_stop_price = Some(price.into().into());
         self
     }
 
@@ -1794,8 +2562,8 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn pending_iceberg_qty(mut self, qty: &str) -> Self {
-        self.pending_iceberg_qty = Some(qty.to_string());
+    pub fn pending_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.pending_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -1814,13 +2582,13 @@ impl OtoOrderBuilder {
         self
     }
 
-    pub fn pending_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.pending_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.pending_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.pending_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.pending_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -1878,14 +2646,14 @@ pub struct NewOtoOrder {
     pending_quantity: Option<String>,
     list_client_order_id: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     working_client_order_id: Option<String>,
     working_iceberg_qty: Option<String>,
     working_time_in_force: Option<TimeInForce>,
     working_strategy_id: Option<u64>,
     working_strategy_type: Option<i32>,
-    working_peg_price_type: Option<String>,
-    working_peg_offset_type: Option<String>,
+    working_peg_price_type: Option<PegPriceType>,
+    working_peg_offset_type: Option<PegOffsetType>,
     working_peg_offset_value: Option<i32>,
     pending_client_order_id: Option<String>,
     pending_price: Option<String>,
@@ -1895,8 +2663,8 @@ pub struct NewOtoOrder {
     pending_time_in_force: Option<TimeInForce>,
     pending_strategy_id: Option<u64>,
     pending_strategy_type: Option<i32>,
-    pending_peg_price_type: Option<String>,
-    pending_peg_offset_type: Option<String>,
+    pending_peg_price_type: Option<PegPriceType>,
+    pending_peg_offset_type: Option<PegOffsetType>,
     pending_peg_offset_value: Option<i32>,
 }
 
@@ -1936,8 +2704,8 @@ impl NewOtoOrder {
                 format!("{:?}", resp).to_uppercase(),
             ));
         }
-        if let Some(ref mode) = self.self_trade_prevention_mode {
-            params.push(("selfTradePreventionMode".to_string(), mode.clone()));
+        if let Some(mode) = self.self_trade_prevention_mode {
+            params.push(("selfTradePreventionMode".to_string(), mode.to_string()));
         }
         if let Some(ref id) = self.working_client_order_id {
             params.push(("workingClientOrderId".to_string(), id.clone()));
@@ -1954,11 +2722,11 @@ impl NewOtoOrder {
         if let Some(id) = self.working_strategy_type {
             params.push(("workingStrategyType".to_string(), id.to_string()));
         }
-        if let Some(ref peg) = self.working_peg_price_type {
-            params.push(("workingPegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.working_peg_price_type {
+            params.push(("workingPegPriceType".to_string(), peg.to_string()));
         }
-        if let Some(ref peg) = self.working_peg_offset_type {
-            params.push(("workingPegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.working_peg_offset_type {
+            params.push(("workingPegOffsetType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.working_peg_offset_value {
             params.push(("workingPegOffsetValue".to_string(), value.to_string()));
@@ -1987,11 +2755,11 @@ impl NewOtoOrder {
         if let Some(id) = self.pending_strategy_type {
             params.push(("pendingStrategyType".to_string(), id.to_string()));
         }
-        if let Some(ref peg) = self.pending_peg_price_type {
-            params.push(("pendingPegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_peg_price_type {
+            params.push(("pendingPegPriceType".to_string(), peg.to_string()));
         }
-        if let Some(ref peg) = self.pending_peg_offset_type {
-            params.push(("pendingPegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_peg_offset_type {
+            params.push(("pendingPegOffsetType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.pending_peg_offset_value {
             params.push(("pendingPegOffsetValue".to_string(), value.to_string()));
@@ -2013,8 +2781,8 @@ impl OpoOrderBuilder {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: &str,
-        working_quantity: &str,
+        working_price: impl Into<QtyParam>,
+        working_quantity: impl Into<QtyParam>,
         pending_type: OrderType,
         pending_side: OrderSide,
     ) -> Self {
@@ -2023,8 +2791,8 @@ impl OpoOrderBuilder {
                 symbol: symbol.to_string(),
                 working_type,
                 working_side,
-                working_price: working_price.to_string(),
-                working_quantity: working_quantity.to_string(),
+                working_price: working_price.into().into(),
+                working_quantity: working_quantity.into().into(),
                 pending_type,
                 pending_side,
                 pending_quantity: None,
@@ -2064,8 +2832,8 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn self_trade_prevention_mode(mut self, mode: &str) -> Self {
-        self.inner.self_trade_prevention_mode = Some(mode.to_string());
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.inner.self_trade_prevention_mode = Some(mode);
         self
     }
 
@@ -2074,8 +2842,8 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn working_iceberg_qty(mut self, qty: &str) -> Self {
-        self.inner.working_iceberg_qty = Some(qty.to_string());
+    pub fn working_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.working_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2094,13 +2862,13 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn working_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.inner.working_peg_price_type = Some(peg_price_type.to_string());
+    pub fn working_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.inner.working_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn working_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.inner.working_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn working_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.inner.working_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2114,18 +2882,18 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn pending_quantity(mut self, qty: &str) -> Self {
-        self.inner.pending_quantity = Some(qty.to_string());
+    pub fn pending_quantity(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.pending_quantity = Some(qty.into().into());
         self
     }
 
-    pub fn pending_price(mut self, price: &str) -> Self {
-        self.inner.pending_price = Some(price.to_string());
+    pub fn pending_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_stop_price(mut self, price: &str) -> Self {
-        self.inner.pending_stop_price = Some(price.to_string());
+    pub fn pending_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_stop_price = Some(price.into().into());
         self
     }
 
@@ -2134,8 +2902,8 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn pending_iceberg_qty(mut self, qty: &str) -> Self {
-        self.inner.pending_iceberg_qty = Some(qty.to_string());
+    pub fn pending_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.pending_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2154,13 +2922,13 @@ impl OpoOrderBuilder {
         self
     }
 
-    pub fn pending_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.inner.pending_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.inner.pending_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.inner.pending_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.inner.pending_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2199,14 +2967,14 @@ pub struct OtocoOrderBuilder {
     pending_above_type: OrderType,
     list_client_order_id: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     working_client_order_id: Option<String>,
     working_iceberg_qty: Option<String>,
     working_time_in_force: Option<TimeInForce>,
     working_strategy_id: Option<u64>,
     working_strategy_type: Option<i32>,
-    working_peg_price_type: Option<String>,
-    working_peg_offset_type: Option<String>,
+    working_peg_price_type: Option<PegPriceType>,
+    working_peg_offset_type: Option<PegOffsetType>,
     working_peg_offset_value: Option<i32>,
     pending_above_client_order_id: Option<String>,
     pending_above_price: Option<String>,
@@ -2216,8 +2984,8 @@ pub struct OtocoOrderBuilder {
     pending_above_time_in_force: Option<TimeInForce>,
     pending_above_strategy_id: Option<u64>,
     pending_above_strategy_type: Option<i32>,
-    pending_above_peg_price_type: Option<String>,
-    pending_above_peg_offset_type: Option<String>,
+    pending_above_peg_price_type: Option<PegPriceType>,
+    pending_above_peg_offset_type: Option<PegOffsetType>,
     pending_above_peg_offset_value: Option<i32>,
     pending_below_type: Option<OrderType>,
     pending_below_client_order_id: Option<String>,
@@ -2228,8 +2996,8 @@ pub struct OtocoOrderBuilder {
     pending_below_time_in_force: Option<TimeInForce>,
     pending_below_strategy_id: Option<u64>,
     pending_below_strategy_type: Option<i32>,
-    pending_below_peg_price_type: Option<String>,
-    pending_below_peg_offset_type: Option<String>,
+    pending_below_peg_price_type: Option<PegPriceType>,
+    pending_below_peg_offset_type: Option<PegOffsetType>,
     pending_below_peg_offset_value: Option<i32>,
 }
 
@@ -2240,20 +3008,20 @@ impl OtocoOrderBuilder {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: &str,
-        working_quantity: &str,
+        working_price: impl Into<QtyParam>,
+        working_quantity: impl Into<QtyParam>,
         pending_side: OrderSide,
-        pending_quantity: &str,
+        pending_quantity: impl Into<QtyParam>,
         pending_above_type: OrderType,
     ) -> Self {
         Self {
             symbol: symbol.to_string(),
             working_type,
             working_side,
-            working_price: working_price.to_string(),
-            working_quantity: working_quantity.to_string(),
+            working_price: working_price.into().into(),
+            working_quantity: working_quantity.into().into(),
             pending_side,
-            pending_quantity: pending_quantity.to_string(),
+            pending_quantity: pending_quantity.into().into(),
             pending_above_type,
             list_client_order_id: None,
             response_type: None,
@@ -2302,8 +3070,8 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn self_trade_prevention_mode(mut self, mode: &str) -> Self {
-        self.self_trade_prevention_mode = Some(mode.to_string());
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.self_trade_prevention_mode = Some(mode);
         self
     }
 
@@ -2312,8 +3080,8 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn working_iceberg_qty(mut self, qty: &str) -> Self {
-        self.working_iceberg_qty = Some(qty.to_string());
+    pub fn working_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.working_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2332,13 +3100,13 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn working_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.working_peg_price_type = Some(peg_price_type.to_string());
+    pub fn working_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.working_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn working_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.working_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn working_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.working_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2352,13 +3120,13 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_price(mut self, price: &str) -> Self {
-        self.pending_above_price = Some(price.to_string());
+    pub fn pending_above_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_above_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_above_stop_price(mut self, price: &str) -> Self {
-        self.pending_above_stop_price = Some(price.to_string());
+    pub fn pending_above_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_above_stop_price = Some(price.into().into());
         self
     }
 
@@ -2367,8 +3135,8 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_iceberg_qty(mut self, qty: &str) -> Self {
-        self.pending_above_iceberg_qty = Some(qty.to_string());
+    pub fn pending_above_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.pending_above_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2387,13 +3155,13 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.pending_above_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_above_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.pending_above_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_above_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.pending_above_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_above_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.pending_above_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2412,13 +3180,13 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_price(mut self, price: &str) -> Self {
-        self.pending_below_price = Some(price.to_string());
+    pub fn pending_below_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_below_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_below_stop_price(mut self, price: &str) -> Self {
-        self.pending_below_stop_price = Some(price.to_string());
+    pub fn pending_below_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.pending_below_stop_price = Some(price.into().into());
         self
     }
 
@@ -2427,8 +3195,8 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_iceberg_qty(mut self, qty: &str) -> Self {
-        self.pending_below_iceberg_qty = Some(qty.to_string());
+    pub fn pending_below_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.pending_below_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2447,13 +3215,13 @@ impl OtocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.pending_below_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_below_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.pending_below_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_below_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.pending_below_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_below_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.pending_below_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2523,14 +3291,14 @@ pub struct NewOtocoOrder {
     pending_above_type: OrderType,
     list_client_order_id: Option<String>,
     response_type: Option<OrderResponseType>,
-    self_trade_prevention_mode: Option<String>,
+    self_trade_prevention_mode: Option<SelfTradePreventionMode>,
     working_client_order_id: Option<String>,
     working_iceberg_qty: Option<String>,
     working_time_in_force: Option<TimeInForce>,
     working_strategy_id: Option<u64>,
     working_strategy_type: Option<i32>,
-    working_peg_price_type: Option<String>,
-    working_peg_offset_type: Option<String>,
+    working_peg_price_type: Option<PegPriceType>,
+    working_peg_offset_type: Option<PegOffsetType>,
     working_peg_offset_value: Option<i32>,
     pending_above_client_order_id: Option<String>,
     pending_above_price: Option<String>,
@@ -2540,8 +3308,8 @@ pub struct NewOtocoOrder {
     pending_above_time_in_force: Option<TimeInForce>,
     pending_above_strategy_id: Option<u64>,
     pending_above_strategy_type: Option<i32>,
-    pending_above_peg_price_type: Option<String>,
-    pending_above_peg_offset_type: Option<String>,
+    pending_above_peg_price_type: Option<PegPriceType>,
+    pending_above_peg_offset_type: Option<PegOffsetType>,
     pending_above_peg_offset_value: Option<i32>,
     pending_below_type: Option<OrderType>,
     pending_below_client_order_id: Option<String>,
@@ -2552,8 +3320,8 @@ pub struct NewOtocoOrder {
     pending_below_time_in_force: Option<TimeInForce>,
     pending_below_strategy_id: Option<u64>,
     pending_below_strategy_type: Option<i32>,
-    pending_below_peg_price_type: Option<String>,
-    pending_below_peg_offset_type: Option<String>,
+    pending_below_peg_price_type: Option<PegPriceType>,
+    pending_below_peg_offset_type: Option<PegOffsetType>,
     pending_below_peg_offset_value: Option<i32>,
 }
 
@@ -2593,8 +3361,8 @@ impl NewOtocoOrder {
                 format!("{:?}", resp).to_uppercase(),
             ));
         }
-        if let Some(ref mode) = self.self_trade_prevention_mode {
-            params.push(("selfTradePreventionMode".to_string(), mode.clone()));
+        if let Some(mode) = self.self_trade_prevention_mode {
+            params.push(("selfTradePreventionMode".to_string(), mode.to_string()));
         }
         if let Some(ref id) = self.working_client_order_id {
             params.push(("workingClientOrderId".to_string(), id.clone()));
@@ -2611,11 +3379,11 @@ impl NewOtocoOrder {
         if let Some(id) = self.working_strategy_type {
             params.push(("workingStrategyType".to_string(), id.to_string()));
         }
-        if let Some(ref peg) = self.working_peg_price_type {
-            params.push(("workingPegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.working_peg_price_type {
+            params.push(("workingPegPriceType".to_string(), peg.to_string()));
         }
-        if let Some(ref peg) = self.working_peg_offset_type {
-            params.push(("workingPegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.working_peg_offset_type {
+            params.push(("workingPegOffsetType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.working_peg_offset_value {
             params.push(("workingPegOffsetValue".to_string(), value.to_string()));
@@ -2644,11 +3412,11 @@ impl NewOtocoOrder {
         if let Some(id) = self.pending_above_strategy_type {
             params.push(("pendingAboveStrategyType".to_string(), id.to_string()));
         }
-        if let Some(ref peg) = self.pending_above_peg_price_type {
-            params.push(("pendingAbovePegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_above_peg_price_type {
+            params.push(("pendingAbovePegPriceType".to_string(), peg.to_string()));
         }
-        if let Some(ref peg) = self.pending_above_peg_offset_type {
-            params.push(("pendingAbovePegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_above_peg_offset_type {
+            params.push(("pendingAbovePegOffsetType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.pending_above_peg_offset_value {
             params.push(("pendingAbovePegOffsetValue".to_string(), value.to_string()));
@@ -2683,11 +3451,11 @@ impl NewOtocoOrder {
         if let Some(id) = self.pending_below_strategy_type {
             params.push(("pendingBelowStrategyType".to_string(), id.to_string()));
         }
-        if let Some(ref peg) = self.pending_below_peg_price_type {
-            params.push(("pendingBelowPegPriceType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_below_peg_price_type {
+            params.push(("pendingBelowPegPriceType".to_string(), peg.to_string()));
         }
-        if let Some(ref peg) = self.pending_below_peg_offset_type {
-            params.push(("pendingBelowPegOffsetType".to_string(), peg.clone()));
+        if let Some(peg) = self.pending_below_peg_offset_type {
+            params.push(("pendingBelowPegOffsetType".to_string(), peg.to_string()));
         }
         if let Some(value) = self.pending_below_peg_offset_value {
             params.push(("pendingBelowPegOffsetValue".to_string(), value.to_string()));
@@ -2709,8 +3477,8 @@ impl OpocoOrderBuilder {
         symbol: &str,
         working_type: OrderType,
         working_side: OrderSide,
-        working_price: &str,
-        working_quantity: &str,
+        working_price: impl Into<QtyParam>,
+        working_quantity: impl Into<QtyParam>,
         pending_side: OrderSide,
         pending_above_type: OrderType,
     ) -> Self {
@@ -2719,8 +3487,8 @@ impl OpocoOrderBuilder {
                 symbol: symbol.to_string(),
                 working_type,
                 working_side,
-                working_price: working_price.to_string(),
-                working_quantity: working_quantity.to_string(),
+                working_price: working_price.into().into(),
+                working_quantity: working_quantity.into().into(),
                 pending_side,
                 pending_quantity: None,
                 pending_above_type,
@@ -2772,8 +3540,8 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn self_trade_prevention_mode(mut self, mode: &str) -> Self {
-        self.inner.self_trade_prevention_mode = Some(mode.to_string());
+    pub fn self_trade_prevention_mode(mut self, mode: SelfTradePreventionMode) -> Self {
+        self.inner.self_trade_prevention_mode = Some(mode);
         self
     }
 
@@ -2782,8 +3550,8 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn working_iceberg_qty(mut self, qty: &str) -> Self {
-        self.inner.working_iceberg_qty = Some(qty.to_string());
+    pub fn working_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.working_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2802,13 +3570,13 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn working_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.inner.working_peg_price_type = Some(peg_price_type.to_string());
+    pub fn working_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.inner.working_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn working_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.inner.working_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn working_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.inner.working_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2817,8 +3585,8 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_quantity(mut self, qty: &str) -> Self {
-        self.inner.pending_quantity = Some(qty.to_string());
+    pub fn pending_quantity(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.pending_quantity = Some(qty.into().into());
         self
     }
 
@@ -2827,13 +3595,13 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_price(mut self, price: &str) -> Self {
-        self.inner.pending_above_price = Some(price.to_string());
+    pub fn pending_above_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_above_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_above_stop_price(mut self, price: &str) -> Self {
-        self.inner.pending_above_stop_price = Some(price.to_string());
+    pub fn pending_above_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_above_stop_price = Some(price.into().into());
         self
     }
 
@@ -2842,8 +3610,8 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_iceberg_qty(mut self, qty: &str) -> Self {
-        self.inner.pending_above_iceberg_qty = Some(qty.to_string());
+    pub fn pending_above_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.pending_above_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2862,13 +3630,13 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_above_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.inner.pending_above_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_above_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.inner.pending_above_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_above_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.inner.pending_above_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_above_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.inner.pending_above_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2887,13 +3655,13 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_price(mut self, price: &str) -> Self {
-        self.inner.pending_below_price = Some(price.to_string());
+    pub fn pending_below_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_below_price = Some(price.into().into());
         self
     }
 
-    pub fn pending_below_stop_price(mut self, price: &str) -> Self {
-        self.inner.pending_below_stop_price = Some(price.to_string());
+    pub fn pending_below_stop_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.inner.pending_below_stop_price = Some(price.into().into());
         self
     }
 
@@ -2902,8 +3670,8 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_iceberg_qty(mut self, qty: &str) -> Self {
-        self.inner.pending_below_iceberg_qty = Some(qty.to_string());
+    pub fn pending_below_iceberg_qty(mut self, qty: impl Into<QtyParam>) -> Self {
+        self.inner.pending_below_iceberg_qty = Some(qty.into().into());
         self
     }
 
@@ -2922,13 +3690,13 @@ impl OpocoOrderBuilder {
         self
     }
 
-    pub fn pending_below_peg_price_type(mut self, peg_price_type: &str) -> Self {
-        self.inner.pending_below_peg_price_type = Some(peg_price_type.to_string());
+    pub fn pending_below_peg_price_type(mut self, peg_price_type: PegPriceType) -> Self {
+        self.inner.pending_below_peg_price_type = Some(peg_price_type);
         self
     }
 
-    pub fn pending_below_peg_offset_type(mut self, peg_offset_type: &str) -> Self {
-        self.inner.pending_below_peg_offset_type = Some(peg_offset_type.to_string());
+    pub fn pending_below_peg_offset_type(mut self, peg_offset_type: PegOffsetType) -> Self {
+        self.inner.pending_below_peg_offset_type = Some(peg_offset_type);
         self
     }
 
@@ -2987,6 +3755,87 @@ mod tests {
         assert!(order.price.is_none());
     }
 
+    #[test]
+    fn test_quote_update_action() {
+        let order: Order = serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT", "orderId": 1, "orderListId": -1, "clientOrderId": "bid",
+            "price": "50000.00", "origQty": "0.010", "executedQty": "0.002",
+            "cummulativeQuoteQty": "100.00", "status": "PARTIALLY_FILLED",
+            "timeInForce": "GTC", "type": "LIMIT", "side": "BUY", "stopPrice": "0",
+            "icebergQty": "0", "time": 1, "updateTime": 2, "isWorking": true,
+            "origQuoteOrderQty": "0"
+        }))
+        .unwrap();
+
+        let action = |price, qty| QuoteUpdate::new(1, price, qty).action(&order).unwrap();
+        assert_eq!(action("50000.0", "0.01"), QuoteAction::Keep);
+        assert_eq!(action("50000.00", "0.005"), QuoteAction::Amend);
+        assert_eq!(action("50000.00", "0.020"), QuoteAction::Replace);
+        assert_eq!(action("49990.00", "0.005"), QuoteAction::Replace);
+        assert!(QuoteUpdate::new(1, "abc", "0.01").action(&order).is_err());
+
+        // Only the unfilled part of the target is re-placed
+        let remaining = |qty| QuoteUpdate::new(1, "49990.00", qty).remaining_quantity(&order);
+        assert_eq!(remaining("0.010").unwrap().as_str(), "0.00800000");
+        assert_eq!(remaining("0.020").unwrap().as_str(), "0.01800000");
+        assert!(remaining("0.002").is_err());
+    }
+
+    #[test]
+    fn test_cancel_outcome_from_lookup() {
+        let order = |status: &str| {
+            serde_json::from_value::<Order>(serde_json::json!({
+                "symbol": "BTCUSDT", "orderId": 1, "orderListId": -1, "clientOrderId": "a",
+                "price": "50000.00", "origQty": "0.01", "executedQty": "0.01",
+                "cummulativeQuoteQty": "500.00", "status": status, "timeInForce": "GTC",
+                "type": "LIMIT", "side": "BUY", "stopPrice": "0", "icebergQty": "0",
+                "time": 1, "updateTime": 2, "isWorking": true, "origQuoteOrderQty": "0"
+            }))
+            .unwrap()
+        };
+
+        assert!(matches!(
+            CancelOutcome::from_lookup(Ok(order("FILLED"))),
+            Ok(CancelOutcome::AlreadyFilled(_))
+        ));
+        assert!(matches!(
+            CancelOutcome::from_lookup(Ok(order("CANCELED"))),
+            Ok(CancelOutcome::AlreadyClosed(_))
+        ));
+        let unknown = Error::Api {
+            code: -2013,
+            message: "Order does not exist.".to_string(),
+        };
+        assert!(matches!(
+            CancelOutcome::from_lookup(Err(unknown)),
+            Ok(CancelOutcome::UnknownOrder)
+        ));
+        let other = Error::Api {
+            code: -1003,
+            message: "Too many requests.".to_string(),
+        };
+        assert!(CancelOutcome::from_lookup(Err(other)).is_err());
+    }
+
+    #[test]
+    fn test_cancel_replace_limit_maker_params() {
+        let request = CancelReplaceOrderBuilder::new(
+            "BTCUSDT",
+            OrderSide::Sell,
+            OrderType::LimitMaker,
+            CancelReplaceMode::StopOnFailure,
+        )
+        .cancel_order_id(1)
+        .build();
+
+        let params = request.to_params();
+        assert!(
+            params
+                .iter()
+                .any(|(k, v)| k == "type" && v == "LIMIT_MAKER")
+        );
+    }
+
     #[test]
     fn test_order_to_params() {
         let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
@@ -3004,6 +3853,117 @@ mod tests {
         assert!(params.iter().any(|(k, v)| k == "price" && v == "50000.00"));
     }
 
+    #[test]
+    fn test_order_builder_numeric() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .quantity((0.00001, 5))
+            .price(String::from("50000.00"))
+            .build();
+
+        assert_eq!(order.quantity, Some("0.00001".to_string()));
+        assert_eq!(order.price, Some("50000.00".to_string()));
+    }
+
+    #[test]
+    fn test_trailing_stop_order_params() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::StopLossLimit)
+            .quantity("0.001")
+            .price("48000.00")
+            .time_in_force(TimeInForce::GTC)
+            .trailing_delta(200)
+            .strategy_id(7)
+            .strategy_type(1000000)
+            .self_trade_prevention_mode(SelfTradePreventionMode::ExpireMaker)
+            .build();
+
+        let params = order.to_params();
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("type"), Some("STOP_LOSS_LIMIT"));
+        assert_eq!(get("trailingDelta"), Some("200"));
+        assert_eq!(get("strategyId"), Some("7"));
+        assert_eq!(get("strategyType"), Some("1000000"));
+        assert_eq!(get("selfTradePreventionMode"), Some("EXPIRE_MAKER"));
+        assert!(get("stopPrice").is_none());
+    }
+
+    #[test]
+    fn test_pegged_order_params() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .quantity("0.001")
+            .time_in_force(TimeInForce::GTC)
+            .peg_price_type(PegPriceType::Primary)
+            .peg_offset_value(2)
+            .peg_offset_type(PegOffsetType::PriceLevel)
+            .build();
+
+        let params = order.to_params();
+        assert!(
+            params
+                .iter()
+                .any(|(k, v)| k == "pegPriceType" && v == "PRIMARY_PEG")
+        );
+        assert!(
+            params
+                .iter()
+                .any(|(k, v)| k == "pegOffsetValue" && v == "2")
+        );
+        assert!(
+            params
+                .iter()
+                .any(|(k, v)| k == "pegOffsetType" && v == "PRICE_LEVEL")
+        );
+        assert!(!params.iter().any(|(k, _)| k == "price"));
+    }
+
+    #[test]
+    fn test_margin_order_params() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .quantity("0.001")
+            .price("50000.00")
+            .time_in_force(TimeInForce::GTC)
+            .self_trade_prevention_mode(SelfTradePreventionMode::ExpireMaker)
+            .side_effect_type(SideEffectType::MarginBuy)
+            .build();
+
+        let params = order.to_margin_params().unwrap();
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("sideEffectType"), Some("MARGIN_BUY"));
+        assert_eq!(get("selfTradePreventionMode"), Some("EXPIRE_MAKER"));
+        assert!(!order.to_params().iter().any(|(k, _)| k == "sideEffectType"));
+    }
+
+    #[test]
+    fn test_margin_order_rejects_spot_fields() {
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::StopLossLimit)
+            .quantity("0.001")
+            .price("48000.00")
+            .trailing_delta(200)
+            .build();
+        assert!(matches!(
+            order.to_margin_params(),
+            Err(Error::OrderNotPermitted { reason, .. }) if reason.contains("trailingDelta")
+        ));
+
+        let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+            .quantity("0.001")
+            .peg_price_type(PegPriceType::Primary)
+            .build();
+        assert!(matches!(
+            order.to_margin_params(),
+            Err(Error::OrderNotPermitted { reason, .. }) if reason.contains("pegPriceType")
+        ));
+    }
+
     #[test]
     fn test_oco_order_builder() {
         let order = OcoOrderBuilder::new("BTCUSDT", OrderSide::Sell, "1.0", "55000.00", "48000.00")
@@ -3018,4 +3978,25 @@ mod tests {
         assert_eq!(order.stop_price, "48000.00");
         assert_eq!(order.stop_limit_price, Some("47900.00".to_string()));
     }
+
+    #[test]
+    fn test_oco_order_list_params() {
+        let order = OcoOrderBuilder::new("BTCUSDT", OrderSide::Buy, "1.0", "45000.00", "52000.00")
+            .stop_limit_price("52100.00")
+            .build();
+
+        let params = order.to_order_list_params();
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("belowType"), Some("LIMIT_MAKER"));
+        assert_eq!(get("belowPrice"), Some("45000.00"));
+        assert_eq!(get("aboveType"), Some("STOP_LOSS_LIMIT"));
+        assert_eq!(get("aboveStopPrice"), Some("52000.00"));
+        assert_eq!(get("abovePrice"), Some("52100.00"));
+        assert_eq!(get("aboveTimeInForce"), Some("GTC"));
+    }
 }
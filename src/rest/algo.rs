@@ -0,0 +1,295 @@
+//! Algo trading API endpoints (SAPI).
+//!
+//! This module provides access to the Binance spot algo order SAPI endpoints for:
+//! - Placing TWAP orders
+//! - Cancelling algo orders
+//! - Querying open and historical algo orders
+//! - Querying the sub-orders executed by an algo order
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::algo::{AlgoCancelResponse, AlgoOrderResponse, AlgoOrders, AlgoSubOrders};
+use crate::types::{OrderSide, QtyParam};
+
+// SAPI endpoints.
+const SAPI_V1_ALGO_SPOT_NEW_ORDER_TWAP: &str = "/sapi/v1/algo/spot/newOrderTwap";
+const SAPI_V1_ALGO_SPOT_ORDER: &str = "/sapi/v1/algo/spot/order";
+const SAPI_V1_ALGO_SPOT_OPEN_ORDERS: &str = "/sapi/v1/algo/spot/openOrders";
+const SAPI_V1_ALGO_SPOT_HISTORICAL_ORDERS: &str = "/sapi/v1/algo/spot/historicalOrders";
+const SAPI_V1_ALGO_SPOT_SUB_ORDERS: &str = "/sapi/v1/algo/spot/subOrders";
+
+/// Algo trading API client.
+///
+/// Provides access to Binance spot algo orders, which are executed by the
+/// exchange over time as a series of smaller sub-orders.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{AlgoOrderBuilder, OrderSide};
+///
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// // Buy 10 BNB over one hour
+/// let order = AlgoOrderBuilder::new("BNBUSDT", OrderSide::Buy, "10", 3600).build();
+/// let response = client.algo().new_twap_order(&order).await?;
+///
+/// let sub_orders = client.algo().sub_orders(response.algo_id, None, None).await?;
+/// println!("Executed {} so far", sub_orders.executed_qty);
+/// ```
+#[derive(Clone)]
+pub struct Algo {
+    pub(crate) client: Client,
+}
+
+impl Algo {
+    /// Create a new Algo API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Place a spot TWAP order.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - The TWAP order to place (use [`AlgoOrderBuilder`] to create)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let order = AlgoOrderBuilder::new("BTCUSDT", OrderSide::Sell, "0.5", 1800)
+    ///     .limit_price("60000")
+    ///     .build();
+    ///
+    /// let response = client.algo().new_twap_order(&order).await?;
+    /// println!("Algo order {} placed", response.algo_id);
+    /// ```
+    pub async fn new_twap_order(&self, order: &NewAlgoOrder) -> Result<AlgoOrderResponse> {
+        let params = order.to_params();
+        let params_ref: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        self.client
+            .post_signed(SAPI_V1_ALGO_SPOT_NEW_ORDER_TWAP, &params_ref)
+            .await
+    }
+
+    /// Cancel an open algo order.
+    ///
+    /// Sub-orders that have already executed are not affected.
+    ///
+    /// # Arguments
+    ///
+    /// * `algo_id` - Algo order ID
+    pub async fn cancel_order(&self, algo_id: u64) -> Result<AlgoCancelResponse> {
+        let algo_id = algo_id.to_string();
+        let params = [("algoId", algo_id.as_str())];
+        self.client
+            .delete_signed(SAPI_V1_ALGO_SPOT_ORDER, &params)
+            .await
+    }
+
+    /// Get open algo orders.
+    pub async fn open_orders(&self) -> Result<AlgoOrders> {
+        self.client
+            .get_signed(SAPI_V1_ALGO_SPOT_OPEN_ORDERS, &[])
+            .await
+    }
+
+    /// Get historical algo orders.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Filter by symbol (optional)
+    /// * `side` - Filter by side (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `page` - Page number, starting at 1 (optional)
+    /// * `page_size` - Page size, max 100 (optional)
+    pub async fn historical_orders(
+        &self,
+        symbol: Option<&str>,
+        side: Option<OrderSide>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> Result<AlgoOrders> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(s) = symbol {
+            params.push(("symbol", s.to_string()));
+        }
+        if let Some(s) = side {
+            params.push(("side", format!("{:?}", s).to_uppercase()));
+        }
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(p) = page {
+            params.push(("page", p.to_string()));
+        }
+        if let Some(p) = page_size {
+            params.push(("pageSize", p.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_ALGO_SPOT_HISTORICAL_ORDERS, &params_ref)
+            .await
+    }
+
+    /// Get the sub-orders executed by an algo order.
+    ///
+    /// # Arguments
+    ///
+    /// * `algo_id` - Algo order ID
+    /// * `page` - Page number, starting at 1 (optional)
+    /// * `page_size` - Page size, max 100 (optional)
+    pub async fn sub_orders(
+        &self,
+        algo_id: u64,
+        page: Option<u32>,
+        page_size: Option<u32>,
+    ) -> Result<AlgoSubOrders> {
+        let mut params: Vec<(&str, String)> = vec![("algoId", algo_id.to_string())];
+
+        if let Some(p) = page {
+            params.push(("page", p.to_string()));
+        }
+        if let Some(p) = page_size {
+            params.push(("pageSize", p.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_ALGO_SPOT_SUB_ORDERS, &params_ref)
+            .await
+    }
+}
+
+/// Builder for spot TWAP algo orders.
+#[derive(Debug, Clone)]
+pub struct AlgoOrderBuilder {
+    symbol: String,
+    side: OrderSide,
+    quantity: String,
+    duration: u64,
+    client_algo_id: Option<String>,
+    limit_price: Option<String>,
+}
+
+impl AlgoOrderBuilder {
+    /// Create a new TWAP order builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair
+    /// * `side` - Order side
+    /// * `quantity` - Total quantity to execute
+    /// * `duration` - Execution duration in seconds (300 to 86400)
+    pub fn new(
+        symbol: &str,
+        side: OrderSide,
+        quantity: impl Into<QtyParam>,
+        duration: u64,
+    ) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            side,
+            quantity: quantity.into().into(),
+            duration,
+            client_algo_id: None,
+            limit_price: None,
+        }
+    }
+
+    /// Set a custom client algo ID.
+    pub fn client_algo_id(mut self, id: &str) -> Self {
+        self.client_algo_id = Some(id.to_string());
+        self
+    }
+
+    /// Set a limit price; sub-orders are not executed beyond this price.
+    pub fn limit_price(mut self, price: impl Into<QtyParam>) -> Self {
+        self.limit_price = Some(price.into().into());
+        self
+    }
+
+    /// Build the algo order.
+    pub fn build(self) -> NewAlgoOrder {
+        NewAlgoOrder {
+            symbol: self.symbol,
+            side: self.side,
+            quantity: self.quantity,
+            duration: self.duration,
+            client_algo_id: self.client_algo_id,
+            limit_price: self.limit_price,
+        }
+    }
+}
+
+/// TWAP algo order parameters.
+#[derive(Debug, Clone)]
+pub struct NewAlgoOrder {
+    symbol: String,
+    side: OrderSide,
+    quantity: String,
+    duration: u64,
+    client_algo_id: Option<String>,
+    limit_price: Option<String>,
+}
+
+impl NewAlgoOrder {
+    fn to_params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("symbol".to_string(), self.symbol.clone()),
+            (
+                "side".to_string(),
+                format!("{:?}", self.side).to_uppercase(),
+            ),
+            ("quantity".to_string(), self.quantity.clone()),
+            ("duration".to_string(), self.duration.to_string()),
+        ];
+
+        if let Some(ref id) = self.client_algo_id {
+            params.push(("clientAlgoId".to_string(), id.clone()));
+        }
+        if let Some(ref price) = self.limit_price {
+            params.push(("limitPrice".to_string(), price.clone()));
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algo_order_builder() {
+        let order = AlgoOrderBuilder::new("BNBUSDT", OrderSide::Sell, "10", 3600)
+            .limit_price("600")
+            .client_algo_id("my-twap")
+            .build();
+
+        let params = order.to_params();
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("symbol"), Some("BNBUSDT"));
+        assert_eq!(get("side"), Some("SELL"));
+        assert_eq!(get("quantity"), Some("10"));
+        assert_eq!(get("duration"), Some("3600"));
+        assert_eq!(get("limitPrice"), Some("600"));
+        assert_eq!(get("clientAlgoId"), Some("my-twap"));
+    }
+}
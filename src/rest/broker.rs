@@ -0,0 +1,242 @@
+//! Broker (Binance Link) API endpoints (SAPI).
+//!
+//! This module provides access to Binance Link broker SAPI endpoints for:
+//! - Broker account information
+//! - Sub-account creation and listing
+//! - Sub-account commission settings
+//! - Rebate records
+//! - Sub-account API key IP restrictions
+//!
+//! Only available with the `broker` feature.
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::broker::{
+    BrokerCommission, BrokerInfo, BrokerIpRestriction, BrokerRebate, BrokerSubAccount,
+};
+
+// SAPI endpoints.
+const SAPI_V1_BROKER_INFO: &str = "/sapi/v1/broker/info";
+const SAPI_V1_BROKER_SUB_ACCOUNT: &str = "/sapi/v1/broker/subAccount";
+const SAPI_V1_BROKER_SUB_ACCOUNT_COMMISSION: &str = "/sapi/v1/broker/subAccountApi/commission";
+const SAPI_V1_BROKER_REBATE_RECENT: &str = "/sapi/v1/broker/rebate/recentRecord";
+const SAPI_V1_BROKER_IP_RESTRICTION: &str = "/sapi/v1/broker/subAccountApi/ipRestriction";
+const SAPI_V2_BROKER_IP_RESTRICTION: &str = "/sapi/v2/broker/subAccountApi/ipRestriction";
+const SAPI_V1_BROKER_IP_RESTRICTION_LIST: &str =
+    "/sapi/v1/broker/subAccountApi/ipRestriction/ipList";
+
+/// Broker (Binance Link) API client.
+///
+/// Provides access to Binance Link endpoints for platform operators managing
+/// sub-accounts on behalf of their users.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// let account = client.broker().create_sub_account(Some("user-42")).await?;
+/// client
+///     .broker()
+///     .set_commission(&account.sub_account_id, 0.0002, 0.0004)
+///     .await?;
+/// ```
+#[derive(Clone)]
+pub struct Broker {
+    pub(crate) client: Client,
+}
+
+impl Broker {
+    /// Create a new Broker API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get broker account information, including commission limits.
+    pub async fn info(&self) -> Result<BrokerInfo> {
+        self.client.get_signed(SAPI_V1_BROKER_INFO, &[]).await
+    }
+
+    /// Create a broker sub-account.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Tag to identify the sub-account (optional)
+    pub async fn create_sub_account(&self, tag: Option<&str>) -> Result<BrokerSubAccount> {
+        let mut params: Vec<(&str, &str)> = vec![];
+
+        if let Some(t) = tag {
+            params.push(("tag", t));
+        }
+
+        self.client
+            .post_signed(SAPI_V1_BROKER_SUB_ACCOUNT, &params)
+            .await
+    }
+
+    /// List broker sub-accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_account_id` - Filter by sub-account ID (optional)
+    /// * `page` - Page number, starting at 1 (optional)
+    /// * `size` - Page size, max 500 (optional)
+    pub async fn sub_accounts(
+        &self,
+        sub_account_id: Option<&str>,
+        page: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<Vec<BrokerSubAccount>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(id) = sub_account_id {
+            params.push(("subAccountId", id.to_string()));
+        }
+        if let Some(p) = page {
+            params.push(("page", p.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_BROKER_SUB_ACCOUNT, &params_ref)
+            .await
+    }
+
+    /// Set the spot commission of a sub-account.
+    ///
+    /// Commissions must be within the limits returned by [`info`](Self::info).
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_account_id` - Sub-account ID
+    /// * `maker_commission` - Maker commission (e.g., 0.0002)
+    /// * `taker_commission` - Taker commission (e.g., 0.0004)
+    pub async fn set_commission(
+        &self,
+        sub_account_id: &str,
+        maker_commission: f64,
+        taker_commission: f64,
+    ) -> Result<BrokerCommission> {
+        let maker = maker_commission.to_string();
+        let taker = taker_commission.to_string();
+        let params = [
+            ("subAccountId", sub_account_id),
+            ("makerCommission", maker.as_str()),
+            ("takerCommission", taker.as_str()),
+        ];
+
+        self.client
+            .post_signed(SAPI_V1_BROKER_SUB_ACCOUNT_COMMISSION, &params)
+            .await
+    }
+
+    /// Get recent rebate records.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional, max 7 days after start)
+    /// * `limit` - Max records, default 500, max 500 (optional)
+    pub async fn rebates(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<BrokerRebate>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(l) = limit {
+            params.push(("limit", l.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_BROKER_REBATE_RECENT, &params_ref)
+            .await
+    }
+
+    /// Get the IP restriction of a sub-account API key.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_account_id` - Sub-account ID
+    /// * `sub_account_api_key` - Sub-account API key
+    pub async fn ip_restriction(
+        &self,
+        sub_account_id: &str,
+        sub_account_api_key: &str,
+    ) -> Result<BrokerIpRestriction> {
+        let params = [
+            ("subAccountId", sub_account_id),
+            ("subAccountApiKey", sub_account_api_key),
+        ];
+
+        self.client
+            .get_signed(SAPI_V1_BROKER_IP_RESTRICTION, &params)
+            .await
+    }
+
+    /// Restrict a sub-account API key to an IP address, or lift the restriction.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_account_id` - Sub-account ID
+    /// * `sub_account_api_key` - Sub-account API key
+    /// * `ip_address` - IP address to allow, or `None` to remove the IP restriction
+    pub async fn set_ip_restriction(
+        &self,
+        sub_account_id: &str,
+        sub_account_api_key: &str,
+        ip_address: Option<&str>,
+    ) -> Result<BrokerIpRestriction> {
+        let mut params: Vec<(&str, &str)> = vec![
+            ("subAccountId", sub_account_id),
+            ("subAccountApiKey", sub_account_api_key),
+        ];
+
+        match ip_address {
+            Some(ip) => {
+                params.push(("status", "2"));
+                params.push(("ipAddress", ip));
+            }
+            None => params.push(("status", "1")),
+        }
+
+        self.client
+            .post_signed(SAPI_V2_BROKER_IP_RESTRICTION, &params)
+            .await
+    }
+
+    /// Remove an IP address from a sub-account API key's IP list.
+    ///
+    /// # Arguments
+    ///
+    /// * `sub_account_id` - Sub-account ID
+    /// * `sub_account_api_key` - Sub-account API key
+    /// * `ip_address` - IP address to remove
+    pub async fn delete_ip_restriction(
+        &self,
+        sub_account_id: &str,
+        sub_account_api_key: &str,
+        ip_address: &str,
+    ) -> Result<BrokerIpRestriction> {
+        let params = [
+            ("subAccountId", sub_account_id),
+            ("subAccountApiKey", sub_account_api_key),
+            ("ipAddress", ip_address),
+        ];
+
+        self.client
+            .delete_signed(SAPI_V1_BROKER_IP_RESTRICTION_LIST, &params)
+            .await
+    }
+}
@@ -0,0 +1,171 @@
+//! Simple Earn API endpoints (SAPI).
+//!
+//! This module provides access to the Binance Simple Earn flexible product
+//! SAPI endpoints for:
+//! - Product listing
+//! - Subscribing and redeeming
+//! - Positions
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::earn::{
+    EarnAccount, EarnRedeemResult, EarnSubscribeResult, FlexiblePosition, FlexibleProduct,
+};
+use crate::models::margin::RecordsQueryResult;
+
+// SAPI endpoints.
+const SAPI_V1_SIMPLE_EARN_FLEXIBLE_LIST: &str = "/sapi/v1/simple-earn/flexible/list";
+const SAPI_V1_SIMPLE_EARN_FLEXIBLE_SUBSCRIBE: &str = "/sapi/v1/simple-earn/flexible/subscribe";
+const SAPI_V1_SIMPLE_EARN_FLEXIBLE_REDEEM: &str = "/sapi/v1/simple-earn/flexible/redeem";
+const SAPI_V1_SIMPLE_EARN_FLEXIBLE_POSITION: &str = "/sapi/v1/simple-earn/flexible/position";
+
+/// Simple Earn API client.
+///
+/// Provides access to Binance Simple Earn flexible products.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// let products = client.earn().flexible_products(Some("USDT"), None, None).await?;
+/// let product = &products.rows[0];
+/// println!("{} APR {}", product.asset, product.latest_annual_percentage_rate);
+///
+/// client.earn().subscribe_flexible(&product.product_id, "100", None).await?;
+/// ```
+#[derive(Clone)]
+pub struct Earn {
+    pub(crate) client: Client,
+}
+
+impl Earn {
+    /// Create a new Earn API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get flexible products.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Filter by asset (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `size` - Page size, max 100 (optional)
+    pub async fn flexible_products(
+        &self,
+        asset: Option<&str>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexibleProduct>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_SIMPLE_EARN_FLEXIBLE_LIST, &params_ref)
+            .await
+    }
+
+    /// Get flexible product positions.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Filter by asset (optional)
+    /// * `product_id` - Filter by product (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `size` - Page size, max 100 (optional)
+    pub async fn flexible_positions(
+        &self,
+        asset: Option<&str>,
+        product_id: Option<&str>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexiblePosition>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
+        if let Some(p) = product_id {
+            params.push(("productId", p.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_SIMPLE_EARN_FLEXIBLE_POSITION, &params_ref)
+            .await
+    }
+
+    /// Subscribe to a flexible product.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_id` - Product ID
+    /// * `amount` - Amount to subscribe
+    /// * `source_account` - Wallet to fund the subscription from (optional, defaults to spot)
+    pub async fn subscribe_flexible(
+        &self,
+        product_id: &str,
+        amount: &str,
+        source_account: Option<EarnAccount>,
+    ) -> Result<EarnSubscribeResult> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("productId", product_id.to_string()),
+            ("amount", amount.to_string()),
+        ];
+
+        if let Some(a) = source_account {
+            params.push(("sourceAccount", a.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_SIMPLE_EARN_FLEXIBLE_SUBSCRIBE, &params_ref)
+            .await
+    }
+
+    /// Redeem a flexible product.
+    ///
+    /// # Arguments
+    ///
+    /// * `product_id` - Product ID
+    /// * `amount` - Amount to redeem (`None` redeems the whole position)
+    /// * `dest_account` - Wallet to redeem to (optional, defaults to spot)
+    pub async fn redeem_flexible(
+        &self,
+        product_id: &str,
+        amount: Option<&str>,
+        dest_account: Option<EarnAccount>,
+    ) -> Result<EarnRedeemResult> {
+        let mut params: Vec<(&str, String)> = vec![("productId", product_id.to_string())];
+
+        match amount {
+            Some(a) => params.push(("amount", a.to_string())),
+            None => params.push(("redeemAll", "true".to_string())),
+        }
+        if let Some(a) = dest_account {
+            params.push(("destAccount", a.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_SIMPLE_EARN_FLEXIBLE_REDEEM, &params_ref)
+            .await
+    }
+}
@@ -0,0 +1,174 @@
+//! Fiat and C2C API endpoints (SAPI).
+//!
+//! This module provides access to Binance SAPI endpoints for:
+//! - Fiat deposit and withdrawal history
+//! - Fiat payment (buy/sell crypto) history
+//! - C2C (P2P) trade history
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::fiat::{
+    C2cTrade, FiatHistory, FiatOrder, FiatPayment, FiatPaymentType, FiatTransactionType,
+};
+use crate::types::OrderSide;
+
+// SAPI endpoints.
+const SAPI_V1_FIAT_ORDERS: &str = "/sapi/v1/fiat/orders";
+const SAPI_V1_FIAT_PAYMENTS: &str = "/sapi/v1/fiat/payments";
+const SAPI_V1_C2C_ORDER_HISTORY: &str = "/sapi/v1/c2c/orderMatch/listUserOrderHistory";
+
+/// Fiat and C2C API client.
+///
+/// Provides access to fiat funding and C2C trade history.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::FiatTransactionType;
+///
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// let deposits = client.fiat()
+///     .orders(FiatTransactionType::Deposit, None, None, None, None)
+///     .await?;
+/// for order in deposits.data {
+///     println!("{} {} {}", order.order_no, order.amount, order.fiat_currency);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Fiat {
+    pub(crate) client: Client,
+}
+
+impl Fiat {
+    /// Create a new Fiat API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get fiat deposit or withdrawal history.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction_type` - Deposit or withdrawal
+    /// * `begin_time` - Start timestamp (optional, defaults to 30 days ago)
+    /// * `end_time` - End timestamp (optional)
+    /// * `page` - Page number, starting at 1 (optional)
+    /// * `rows` - Page size, max 500 (optional)
+    pub async fn orders(
+        &self,
+        transaction_type: FiatTransactionType,
+        begin_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u32>,
+        rows: Option<u32>,
+    ) -> Result<FiatHistory<FiatOrder>> {
+        self.history(
+            SAPI_V1_FIAT_ORDERS,
+            transaction_type.to_string(),
+            begin_time,
+            end_time,
+            page,
+            rows,
+        )
+        .await
+    }
+
+    /// Get history of crypto bought or sold with fiat.
+    ///
+    /// See [`orders`](Self::orders) for the arguments.
+    pub async fn payments(
+        &self,
+        transaction_type: FiatPaymentType,
+        begin_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u32>,
+        rows: Option<u32>,
+    ) -> Result<FiatHistory<FiatPayment>> {
+        self.history(
+            SAPI_V1_FIAT_PAYMENTS,
+            transaction_type.to_string(),
+            begin_time,
+            end_time,
+            page,
+            rows,
+        )
+        .await
+    }
+
+    /// Get C2C (P2P) trade history.
+    ///
+    /// # Arguments
+    ///
+    /// * `trade_type` - Buy or sell
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `page` - Page number, starting at 1 (optional)
+    /// * `rows` - Page size, max 100 (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let trades = client.fiat()
+    ///     .c2c_trade_history(OrderSide::Buy, None, None, None, None)
+    ///     .await?;
+    /// println!("{} C2C buys", trades.total);
+    /// ```
+    pub async fn c2c_trade_history(
+        &self,
+        trade_type: OrderSide,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u32>,
+        rows: Option<u32>,
+    ) -> Result<FiatHistory<C2cTrade>> {
+        let mut params: Vec<(&str, String)> =
+            vec![("tradeType", format!("{:?}", trade_type).to_uppercase())];
+
+        if let Some(s) = start_time {
+            params.push(("startTimestamp", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTimestamp", e.to_string()));
+        }
+        if let Some(p) = page {
+            params.push(("page", p.to_string()));
+        }
+        if let Some(r) = rows {
+            params.push(("rows", r.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_C2C_ORDER_HISTORY, &params_ref)
+            .await
+    }
+
+    async fn history<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        transaction_type: String,
+        begin_time: Option<u64>,
+        end_time: Option<u64>,
+        page: Option<u32>,
+        rows: Option<u32>,
+    ) -> Result<FiatHistory<T>> {
+        let mut params: Vec<(&str, String)> = vec![("transactionType", transaction_type)];
+
+        if let Some(s) = begin_time {
+            params.push(("beginTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(p) = page {
+            params.push(("page", p.to_string()));
+        }
+        if let Some(r) = rows {
+            params.push(("rows", r.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client.get_signed(endpoint, &params_ref).await
+    }
+}
@@ -0,0 +1,216 @@
+//! Institutional (VIP) loan API endpoints (SAPI).
+//!
+//! This module provides access to the Binance institutional loan SAPI
+//! endpoints for:
+//! - Ongoing orders and LTV
+//! - Repaying and repayment history
+//! - Collateral (group) account data
+//! - Loanable assets with flexible and fixed rates
+//!
+//! Only available with the `institutional-loan` feature.
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::institutional_loan::{
+    InstitutionalCollateralAccount, InstitutionalLoanOrder, InstitutionalLoanRepayRecord,
+    InstitutionalLoanRepayResult, InstitutionalLoanableAsset,
+};
+use crate::models::margin::RecordsQueryResult;
+
+// SAPI endpoints.
+const SAPI_V1_LOAN_VIP_ONGOING_ORDERS: &str = "/sapi/v1/loan/vip/ongoing/orders";
+const SAPI_V1_LOAN_VIP_REPAY: &str = "/sapi/v1/loan/vip/repay";
+const SAPI_V1_LOAN_VIP_REPAY_HISTORY: &str = "/sapi/v1/loan/vip/repay/history";
+const SAPI_V1_LOAN_VIP_COLLATERAL_ACCOUNT: &str = "/sapi/v1/loan/vip/collateral/account";
+const SAPI_V1_LOAN_VIP_LOANABLE_DATA: &str = "/sapi/v1/loan/vip/loanable/data";
+
+/// Institutional loan API client.
+///
+/// Provides access to the Binance institutional (VIP) loan SAPI endpoints.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// let orders = client
+///     .institutional_loan()
+///     .ongoing_orders(None, None, None, None, None, None)
+///     .await?;
+/// for order in orders.rows {
+///     println!(
+///         "{} debt {} LTV {:.2} (collateral {})",
+///         order.loan_coin, order.total_debt, order.current_ltv, order.collateral_coin
+///     );
+/// }
+/// ```
+#[derive(Clone)]
+pub struct InstitutionalLoan {
+    pub(crate) client: Client,
+}
+
+impl InstitutionalLoan {
+    /// Create a new Institutional Loan API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// Get ongoing institutional loan orders.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - Filter by order ID (optional)
+    /// * `collateral_account_id` - Filter by collateral account (optional)
+    /// * `loan_coin` - Filter by borrowed coin (optional)
+    /// * `collateral_coin` - Filter by collateral coin (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `limit` - Page size, max 100 (optional)
+    pub async fn ongoing_orders(
+        &self,
+        order_id: Option<u64>,
+        collateral_account_id: Option<&str>,
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<InstitutionalLoanOrder>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(id) = order_id {
+            params.push(("orderId", id.to_string()));
+        }
+        if let Some(id) = collateral_account_id {
+            params.push(("collateralAccountId", id.to_string()));
+        }
+        if let Some(c) = loan_coin {
+            params.push(("loanCoin", c.to_string()));
+        }
+        if let Some(c) = collateral_coin {
+            params.push(("collateralCoin", c.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(l) = limit {
+            params.push(("limit", l.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_LOAN_VIP_ONGOING_ORDERS, &params_ref)
+            .await
+    }
+
+    /// Repay an institutional loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - Order ID
+    /// * `amount` - Amount to repay
+    pub async fn repay(&self, order_id: u64, amount: &str) -> Result<InstitutionalLoanRepayResult> {
+        let order_id = order_id.to_string();
+        let params = [("orderId", order_id.as_str()), ("amount", amount)];
+
+        self.client
+            .post_signed(SAPI_V1_LOAN_VIP_REPAY, &params)
+            .await
+    }
+
+    /// Get institutional loan repayment history.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - Filter by order ID (optional)
+    /// * `loan_coin` - Filter by borrowed coin (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `limit` - Page size, max 100 (optional)
+    pub async fn repay_history(
+        &self,
+        order_id: Option<u64>,
+        loan_coin: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<InstitutionalLoanRepayRecord>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(id) = order_id {
+            params.push(("orderId", id.to_string()));
+        }
+        if let Some(c) = loan_coin {
+            params.push(("loanCoin", c.to_string()));
+        }
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(l) = limit {
+            params.push(("limit", l.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_LOAN_VIP_REPAY_HISTORY, &params_ref)
+            .await
+    }
+
+    /// Get collateral accounts of the loan group.
+    ///
+    /// # Arguments
+    ///
+    /// * `order_id` - Filter by order ID (optional)
+    /// * `collateral_account_id` - Filter by collateral account (optional)
+    pub async fn collateral_accounts(
+        &self,
+        order_id: Option<u64>,
+        collateral_account_id: Option<&str>,
+    ) -> Result<RecordsQueryResult<InstitutionalCollateralAccount>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(id) = order_id {
+            params.push(("orderId", id.to_string()));
+        }
+        if let Some(id) = collateral_account_id {
+            params.push(("collateralAccountId", id.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_LOAN_VIP_COLLATERAL_ACCOUNT, &params_ref)
+            .await
+    }
+
+    /// Get loanable assets with their flexible and fixed rates.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Filter by coin (optional)
+    /// * `vip_level` - VIP level to get rates for (optional, defaults to the user's level)
+    pub async fn loanable_data(
+        &self,
+        loan_coin: Option<&str>,
+        vip_level: Option<u32>,
+    ) -> Result<RecordsQueryResult<InstitutionalLoanableAsset>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(c) = loan_coin {
+            params.push(("loanCoin", c.to_string()));
+        }
+        if let Some(l) = vip_level {
+            params.push(("vipLevel", l.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_LOAN_VIP_LOANABLE_DATA, &params_ref)
+            .await
+    }
+}
@@ -0,0 +1,374 @@
+//! Crypto loan API endpoints (SAPI).
+//!
+//! This module provides access to the Binance flexible rate crypto loan
+//! SAPI endpoints for:
+//! - Borrowing and repaying
+//! - Adjusting LTV
+//! - Loanable and collateral asset data
+//! - Ongoing orders and history
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::loan::{
+    FlexibleCollateralAsset, FlexibleLoanBorrowRecord, FlexibleLoanBorrowResult,
+    FlexibleLoanLtvAdjustRecord, FlexibleLoanLtvAdjustResult, FlexibleLoanOngoingOrder,
+    FlexibleLoanRepayRecord, FlexibleLoanRepayResult, FlexibleLoanableAsset, LtvAdjustDirection,
+};
+use crate::models::margin::RecordsQueryResult;
+
+// SAPI endpoints.
+const SAPI_V2_LOAN_FLEXIBLE_BORROW: &str = "/sapi/v2/loan/flexible/borrow";
+const SAPI_V2_LOAN_FLEXIBLE_ONGOING_ORDERS: &str = "/sapi/v2/loan/flexible/ongoing/orders";
+const SAPI_V2_LOAN_FLEXIBLE_BORROW_HISTORY: &str = "/sapi/v2/loan/flexible/borrow/history";
+const SAPI_V2_LOAN_FLEXIBLE_REPAY: &str = "/sapi/v2/loan/flexible/repay";
+const SAPI_V2_LOAN_FLEXIBLE_REPAY_HISTORY: &str = "/sapi/v2/loan/flexible/repay/history";
+const SAPI_V2_LOAN_FLEXIBLE_ADJUST_LTV: &str = "/sapi/v2/loan/flexible/adjust/ltv";
+const SAPI_V2_LOAN_FLEXIBLE_LTV_HISTORY: &str = "/sapi/v2/loan/flexible/ltv/adjustment/history";
+const SAPI_V2_LOAN_FLEXIBLE_LOANABLE_DATA: &str = "/sapi/v2/loan/flexible/loanable/data";
+const SAPI_V2_LOAN_FLEXIBLE_COLLATERAL_DATA: &str = "/sapi/v2/loan/flexible/collateral/data";
+
+/// Crypto loan API client.
+///
+/// Provides access to the Binance flexible rate crypto loan SAPI endpoints.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::LtvStatus;
+///
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// let collateral = client.loan().collateral_data(Some("BNB")).await?;
+/// let orders = client.loan().ongoing_orders(None, Some("BNB"), None, None).await?;
+/// for order in orders.rows {
+///     if collateral.rows[0].ltv_status(order.current_ltv) != LtvStatus::Healthy {
+///         println!("{} loan at LTV {:.2}", order.loan_coin, order.current_ltv);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Loan {
+    pub(crate) client: Client,
+}
+
+impl Loan {
+    /// Create a new Loan API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    // Borrow.
+
+    /// Borrow against collateral.
+    ///
+    /// Either `loan_amount` or `collateral_amount` must be provided.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Coin to borrow
+    /// * `loan_amount` - Amount to borrow (optional)
+    /// * `collateral_coin` - Coin used as collateral
+    /// * `collateral_amount` - Collateral amount (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.loan()
+    ///     .borrow("USDT", Some("100"), "BNB", None)
+    ///     .await?;
+    /// println!("Locked {} BNB", result.collateral_amount);
+    /// ```
+    pub async fn borrow(
+        &self,
+        loan_coin: &str,
+        loan_amount: Option<&str>,
+        collateral_coin: &str,
+        collateral_amount: Option<&str>,
+    ) -> Result<FlexibleLoanBorrowResult> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("loanCoin", loan_coin.to_string()),
+            ("collateralCoin", collateral_coin.to_string()),
+        ];
+
+        if let Some(a) = loan_amount {
+            params.push(("loanAmount", a.to_string()));
+        }
+        if let Some(a) = collateral_amount {
+            params.push(("collateralAmount", a.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V2_LOAN_FLEXIBLE_BORROW, &params_ref)
+            .await
+    }
+
+    /// Get ongoing loan orders.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Filter by borrowed coin (optional)
+    /// * `collateral_coin` - Filter by collateral coin (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `limit` - Page size, max 100 (optional)
+    pub async fn ongoing_orders(
+        &self,
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexibleLoanOngoingOrder>> {
+        let params = Self::query_params(loan_coin, collateral_coin, None, None, current, limit);
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_ONGOING_ORDERS, &params_ref)
+            .await
+    }
+
+    /// Get borrow history.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Filter by borrowed coin (optional)
+    /// * `collateral_coin` - Filter by collateral coin (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `limit` - Page size, max 100 (optional)
+    pub async fn borrow_history(
+        &self,
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexibleLoanBorrowRecord>> {
+        let params = Self::query_params(
+            loan_coin,
+            collateral_coin,
+            start_time,
+            end_time,
+            current,
+            limit,
+        );
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_BORROW_HISTORY, &params_ref)
+            .await
+    }
+
+    // Repay.
+
+    /// Repay a loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Borrowed coin
+    /// * `collateral_coin` - Collateral coin
+    /// * `repay_amount` - Amount to repay
+    /// * `collateral_return` - Return collateral after a partial repayment (optional, default true)
+    /// * `full_repayment` - Repay the whole loan (optional, default false)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.loan()
+    ///     .repay("USDT", "BNB", "50", None, None)
+    ///     .await?;
+    /// println!("Remaining debt: {}, LTV {}", result.remaining_debt, result.current_ltv);
+    /// ```
+    pub async fn repay(
+        &self,
+        loan_coin: &str,
+        collateral_coin: &str,
+        repay_amount: &str,
+        collateral_return: Option<bool>,
+        full_repayment: Option<bool>,
+    ) -> Result<FlexibleLoanRepayResult> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("loanCoin", loan_coin.to_string()),
+            ("collateralCoin", collateral_coin.to_string()),
+            ("repayAmount", repay_amount.to_string()),
+        ];
+
+        if let Some(r) = collateral_return {
+            params.push(("collateralReturn", r.to_string().to_uppercase()));
+        }
+        if let Some(f) = full_repayment {
+            params.push(("fullRepayment", f.to_string().to_uppercase()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V2_LOAN_FLEXIBLE_REPAY, &params_ref)
+            .await
+    }
+
+    /// Get repayment history.
+    ///
+    /// See [`borrow_history`](Self::borrow_history) for the arguments.
+    pub async fn repay_history(
+        &self,
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexibleLoanRepayRecord>> {
+        let params = Self::query_params(
+            loan_coin,
+            collateral_coin,
+            start_time,
+            end_time,
+            current,
+            limit,
+        );
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_REPAY_HISTORY, &params_ref)
+            .await
+    }
+
+    // LTV.
+
+    /// Add or remove collateral to adjust the LTV of a loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Borrowed coin
+    /// * `collateral_coin` - Collateral coin
+    /// * `adjustment_amount` - Collateral amount to add or remove
+    /// * `direction` - Whether to add or remove collateral
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::LtvAdjustDirection;
+    ///
+    /// let result = client.loan()
+    ///     .adjust_ltv("USDT", "BNB", "1.5", LtvAdjustDirection::Additional)
+    ///     .await?;
+    /// println!("New LTV: {}", result.current_ltv);
+    /// ```
+    pub async fn adjust_ltv(
+        &self,
+        loan_coin: &str,
+        collateral_coin: &str,
+        adjustment_amount: &str,
+        direction: LtvAdjustDirection,
+    ) -> Result<FlexibleLoanLtvAdjustResult> {
+        let params: Vec<(&str, String)> = vec![
+            ("loanCoin", loan_coin.to_string()),
+            ("collateralCoin", collateral_coin.to_string()),
+            ("adjustmentAmount", adjustment_amount.to_string()),
+            ("direction", direction.to_string()),
+        ];
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V2_LOAN_FLEXIBLE_ADJUST_LTV, &params_ref)
+            .await
+    }
+
+    /// Get LTV adjustment history.
+    ///
+    /// See [`borrow_history`](Self::borrow_history) for the arguments.
+    pub async fn ltv_adjustment_history(
+        &self,
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<RecordsQueryResult<FlexibleLoanLtvAdjustRecord>> {
+        let params = Self::query_params(
+            loan_coin,
+            collateral_coin,
+            start_time,
+            end_time,
+            current,
+            limit,
+        );
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_LTV_HISTORY, &params_ref)
+            .await
+    }
+
+    // Asset Data.
+
+    /// Get loanable asset data.
+    ///
+    /// # Arguments
+    ///
+    /// * `loan_coin` - Filter by coin (optional)
+    pub async fn loanable_data(
+        &self,
+        loan_coin: Option<&str>,
+    ) -> Result<RecordsQueryResult<FlexibleLoanableAsset>> {
+        let mut params: Vec<(&str, &str)> = vec![];
+
+        if let Some(c) = loan_coin {
+            params.push(("loanCoin", c));
+        }
+
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_LOANABLE_DATA, &params)
+            .await
+    }
+
+    /// Get collateral asset data, including margin call and liquidation LTVs.
+    ///
+    /// # Arguments
+    ///
+    /// * `collateral_coin` - Filter by coin (optional)
+    pub async fn collateral_data(
+        &self,
+        collateral_coin: Option<&str>,
+    ) -> Result<RecordsQueryResult<FlexibleCollateralAsset>> {
+        let mut params: Vec<(&str, &str)> = vec![];
+
+        if let Some(c) = collateral_coin {
+            params.push(("collateralCoin", c));
+        }
+
+        self.client
+            .get_signed(SAPI_V2_LOAN_FLEXIBLE_COLLATERAL_DATA, &params)
+            .await
+    }
+
+    fn query_params(
+        loan_coin: Option<&str>,
+        collateral_coin: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        limit: Option<u32>,
+    ) -> Vec<(&'static str, String)> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(c) = loan_coin {
+            params.push(("loanCoin", c.to_string()));
+        }
+        if let Some(c) = collateral_coin {
+            params.push(("collateralCoin", c.to_string()));
+        }
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(l) = limit {
+            params.push(("limit", l.to_string()));
+        }
+
+        params
+    }
+}
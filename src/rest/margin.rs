@@ -5,23 +5,33 @@
 //! - Margin transfers, loans, and repayments
 //! - Margin trading (orders)
 //! - Interest and loan history
+//! - Liquidation
+
+use futures::Stream;
 
 use crate::client::Client;
 use crate::error::Result;
 use crate::models::margin::{
-    BnbBurnStatus, InterestHistoryRecord, InterestRateRecord, IsolatedAccountLimit,
-    IsolatedMarginAccountDetails, IsolatedMarginTransferType, LoanRecord, MarginAccountDetails,
-    MarginAssetInfo, MarginOrderCancellation, MarginOrderResult, MarginOrderState,
-    MarginPairDetails, MarginPriceIndex, MarginTrade, MarginTransferType, MaxBorrowableAmount,
-    MaxTransferableAmount, RecordsQueryResult, RepayRecord, SideEffectType, TransactionId,
+    BnbBurnStatus, BorrowRepayRecord, BorrowRepayType, CrossMarginFeeData, ForceLiquidationRecord,
+    InterestHistoryRecord, InterestRateRecord, IsolatedAccountLimit, IsolatedAccountToggle,
+    IsolatedMarginAccountDetails, IsolatedMarginFeeData, IsolatedMarginPair,
+    IsolatedMarginTransferType, LoanRecord, ManualLiquidationResult, MarginAccountDetails,
+    MarginAssetInfo, MarginAvailableInventory, MarginMaxLeverage, MarginOrderCancellation,
+    MarginOrderResult, MarginOrderState, MarginPairDetails, MarginPriceIndex, MarginTrade,
+    MarginTradeCoeff, MarginTransferType, MaxBorrowableAmount, MaxLeverageResult,
+    MaxTransferableAmount, NextHourlyInterestRate, RecordsQueryResult, RepayRecord, SideEffectType,
+    SmallLiabilityAsset, SmallLiabilityExchangeRecord, TransactionId,
 };
+use crate::rest::account::NewOrder;
+use crate::rest::pagination;
 use crate::types::{OrderSide, OrderType, TimeInForce};
 
 // SAPI endpoints.
 const SAPI_V1_MARGIN_TRANSFER: &str = "/sapi/v1/margin/transfer";
 const SAPI_V1_MARGIN_ISOLATED_TRANSFER: &str = "/sapi/v1/margin/isolated/transfer";
-const SAPI_V1_MARGIN_LOAN: &str = "/sapi/v1/margin/loan";
-const SAPI_V1_MARGIN_REPAY: &str = "/sapi/v1/margin/repay";
+const SAPI_V1_MARGIN_BORROW_REPAY: &str = "/sapi/v1/margin/borrow-repay";
+const SAPI_V1_MARGIN_AVAILABLE_INVENTORY: &str = "/sapi/v1/margin/available-inventory";
+const SAPI_V1_MARGIN_NEXT_HOURLY_INTEREST_RATE: &str = "/sapi/v1/margin/next-hourly-interest-rate";
 const SAPI_V1_MARGIN_ACCOUNT: &str = "/sapi/v1/margin/account";
 const SAPI_V1_MARGIN_ISOLATED_ACCOUNT: &str = "/sapi/v1/margin/isolated/account";
 const SAPI_V1_MARGIN_ORDER: &str = "/sapi/v1/margin/order";
@@ -38,7 +48,24 @@ const SAPI_V1_MARGIN_ASSET: &str = "/sapi/v1/margin/asset";
 const SAPI_V1_MARGIN_ALL_ASSETS: &str = "/sapi/v1/margin/allAssets";
 const SAPI_V1_MARGIN_PRICE_INDEX: &str = "/sapi/v1/margin/priceIndex";
 const SAPI_V1_MARGIN_ISOLATED_ACCOUNT_LIMIT: &str = "/sapi/v1/margin/isolated/accountLimit";
+const SAPI_V1_MARGIN_ISOLATED_PAIR: &str = "/sapi/v1/margin/isolated/pair";
+const SAPI_V1_MARGIN_ISOLATED_ALL_PAIRS: &str = "/sapi/v1/margin/isolated/allPairs";
+const SAPI_V1_MARGIN_CROSS_MARGIN_DATA: &str = "/sapi/v1/margin/crossMarginData";
+const SAPI_V1_MARGIN_ISOLATED_MARGIN_DATA: &str = "/sapi/v1/margin/isolatedMarginData";
+const SAPI_V1_MARGIN_TRADE_COEFF: &str = "/sapi/v1/margin/tradeCoeff";
+const SAPI_V1_MARGIN_FORCE_LIQUIDATION_REC: &str = "/sapi/v1/margin/forceLiquidationRec";
+const SAPI_V1_MARGIN_MANUAL_LIQUIDATION: &str = "/sapi/v1/margin/manual-liquidation";
+const SAPI_V1_MARGIN_MAX_LEVERAGE: &str = "/sapi/v1/margin/max-leverage";
 const SAPI_V1_BNB_BURN: &str = "/sapi/v1/bnbBurn";
+const SAPI_V1_MARGIN_SMALL_LIABILITY: &str = "/sapi/v1/margin/exchange-small-liability";
+const SAPI_V1_MARGIN_SMALL_LIABILITY_HISTORY: &str =
+    "/sapi/v1/margin/exchange-small-liability-history";
+
+/// Largest page returned by the margin order history endpoint.
+const MAX_ORDERS_LIMIT: u32 = 500;
+
+/// Largest page returned by the margin trade history endpoint.
+const MAX_TRADES_LIMIT: u32 = 1000;
 
 /// Margin Trading API client.
 ///
@@ -122,6 +149,44 @@ impl Margin {
             .await
     }
 
+    /// Enable an isolated margin account for a symbol.
+    ///
+    /// At most [`isolated_account_limit`](Self::isolated_account_limit)
+    /// isolated margin accounts can be enabled at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.margin().enable_isolated_symbol("BTCUSDT").await?;
+    /// assert!(result.success);
+    /// ```
+    pub async fn enable_isolated_symbol(&self, symbol: &str) -> Result<IsolatedAccountToggle> {
+        let params: Vec<(&str, String)> = vec![("symbol", symbol.to_string())];
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_MARGIN_ISOLATED_ACCOUNT, &params_ref)
+            .await
+    }
+
+    /// Disable the isolated margin account for a symbol.
+    ///
+    /// The account must have no assets or liabilities. An account can only
+    /// be disabled once every 24 hours.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.margin().disable_isolated_symbol("BTCUSDT").await?;
+    /// assert!(result.success);
+    /// ```
+    pub async fn disable_isolated_symbol(&self, symbol: &str) -> Result<IsolatedAccountToggle> {
+        let params: Vec<(&str, String)> = vec![("symbol", symbol.to_string())];
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .delete_signed(SAPI_V1_MARGIN_ISOLATED_ACCOUNT, &params_ref)
+            .await
+    }
+
     /// Get max borrowable amount for an asset.
     ///
     /// # Arguments
@@ -196,6 +261,185 @@ impl Margin {
             .await
     }
 
+    /// Get the margin level thresholds of the cross margin account.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let account = client.margin().account().await?;
+    /// let coeff = client.margin().trade_coeff().await?;
+    /// if account.margin_level < coeff.margin_call_bar {
+    ///     println!("Margin call");
+    /// }
+    /// ```
+    pub async fn trade_coeff(&self) -> Result<MarginTradeCoeff> {
+        self.client
+            .get_signed(SAPI_V1_MARGIN_TRADE_COEFF, &[])
+            .await
+    }
+
+    /// Get cross margin interest rates and borrow limits.
+    ///
+    /// Without a VIP level, the data for the account's own level is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `vip_level` - VIP level (optional)
+    /// * `coin` - Coin to query (optional, all coins if omitted)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let data = client.margin().cross_margin_data(None, Some("USDT")).await?;
+    /// println!("Daily interest: {}", data[0].daily_interest);
+    /// ```
+    pub async fn cross_margin_data(
+        &self,
+        vip_level: Option<u32>,
+        coin: Option<&str>,
+    ) -> Result<Vec<CrossMarginFeeData>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(level) = vip_level {
+            params.push(("vipLevel", level.to_string()));
+        }
+        if let Some(c) = coin {
+            params.push(("coin", c.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_CROSS_MARGIN_DATA, &params_ref)
+            .await
+    }
+
+    /// Get isolated margin interest rates and borrow limits.
+    ///
+    /// Without a VIP level, the data for the account's own level is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `vip_level` - VIP level (optional)
+    /// * `symbol` - Isolated margin symbol (optional, all symbols if omitted)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let data = client.margin().isolated_margin_data(None, Some("BTCUSDT")).await?;
+    /// for coin in &data[0].data {
+    ///     println!("{}: {}", coin.coin, coin.daily_interest);
+    /// }
+    /// ```
+    pub async fn isolated_margin_data(
+        &self,
+        vip_level: Option<u32>,
+        symbol: Option<&str>,
+    ) -> Result<Vec<IsolatedMarginFeeData>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(level) = vip_level {
+            params.push(("vipLevel", level.to_string()));
+        }
+        if let Some(s) = symbol {
+            params.push(("symbol", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_ISOLATED_MARGIN_DATA, &params_ref)
+            .await
+    }
+
+    // Liquidation.
+
+    /// Get the orders executed to force liquidate the margin accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `isolated_symbol` - Isolated margin symbol (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number (default 1)
+    /// * `size` - Page size (default 10, max 100)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let records = client.margin()
+    ///     .force_liquidation_records(None, None, None, None, Some(100))
+    ///     .await?;
+    /// for record in records.rows {
+    ///     println!("{} {} @ {}", record.symbol, record.executed_qty, record.avg_price);
+    /// }
+    /// ```
+    pub async fn force_liquidation_records(
+        &self,
+        isolated_symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<ForceLiquidationRecord>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(s) = isolated_symbol {
+            params.push(("isolatedSymbol", s.to_string()));
+        }
+        if let Some(st) = start_time {
+            params.push(("startTime", st.to_string()));
+        }
+        if let Some(et) = end_time {
+            params.push(("endTime", et.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_FORCE_LIQUIDATION_REC, &params_ref)
+            .await
+    }
+
+    /// Liquidate a margin account manually, repaying its liabilities with
+    /// its assets.
+    ///
+    /// # Arguments
+    ///
+    /// * `isolated_symbol` - Isolated margin symbol to liquidate, or `None`
+    ///   for the cross margin account
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let repaid = client.margin().manual_liquidation(Some("BTCUSDT")).await?;
+    /// for liability in repaid {
+    ///     println!("{}: {}", liability.liability_asset, liability.liability_qty);
+    /// }
+    /// ```
+    pub async fn manual_liquidation(
+        &self,
+        isolated_symbol: Option<&str>,
+    ) -> Result<Vec<ManualLiquidationResult>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        match isolated_symbol {
+            Some(s) => {
+                params.push(("type", "ISOLATED".to_string()));
+                params.push(("symbol", s.to_string()));
+            }
+            None => params.push(("type", "MARGIN".to_string())),
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_MARGIN_MANUAL_LIQUIDATION, &params_ref)
+            .await
+    }
+
     // Transfer.
 
     /// Execute a cross-margin transfer between spot and margin accounts.
@@ -299,34 +543,46 @@ impl Margin {
 
     // Borrow/Repay.
 
-    /// Apply for a margin loan.
+    /// Borrow or repay a margin loan.
     ///
     /// # Arguments
     ///
-    /// * `asset` - Asset to borrow
-    /// * `amount` - Amount to borrow
+    /// * `asset` - Asset to borrow or repay
+    /// * `amount` - Amount to borrow or repay
+    /// * `borrow_type` - Whether to borrow or repay
     /// * `is_isolated` - Whether this is isolated margin
     /// * `symbol` - Symbol for isolated margin (required if is_isolated is true)
     ///
     /// # Example
     ///
     /// ```rust,ignore
+    /// use binance_api_client::BorrowRepayType;
+    ///
     /// // Borrow 0.1 BTC on cross margin
-    /// let result = client.margin().loan("BTC", "0.1", false, None).await?;
-    /// println!("Loan transaction ID: {}", result.tran_id);
+    /// let result = client.margin()
+    ///     .borrow_repay("BTC", "0.1", BorrowRepayType::Borrow, false, None)
+    ///     .await?;
+    /// println!("Borrow transaction ID: {}", result.tran_id);
     /// ```
-    pub async fn loan(
+    pub async fn borrow_repay(
         &self,
         asset: &str,
         amount: &str,
+        borrow_type: BorrowRepayType,
         is_isolated: bool,
         symbol: Option<&str>,
     ) -> Result<TransactionId> {
-        let mut params: Vec<(&str, String)> =
-            vec![("asset", asset.to_string()), ("amount", amount.to_string())];
+        let mut params: Vec<(&str, String)> = vec![
+            ("asset", asset.to_string()),
+            ("amount", amount.to_string()),
+            ("type", borrow_type.to_string()),
+            (
+                "isIsolated",
+                if is_isolated { "TRUE" } else { "FALSE" }.to_string(),
+            ),
+        ];
 
         if is_isolated {
-            params.push(("isIsolated", "TRUE".to_string()));
             if let Some(s) = symbol {
                 params.push(("symbol", s.to_string()));
             }
@@ -334,55 +590,126 @@ impl Margin {
 
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
         self.client
-            .post_signed(SAPI_V1_MARGIN_LOAN, &params_ref)
+            .post_signed(SAPI_V1_MARGIN_BORROW_REPAY, &params_ref)
             .await
     }
 
-    /// Repay a margin loan.
+    /// Get the amount of each asset available to borrow on the platform.
+    ///
+    /// A borrow can fail even below the account's
+    /// [`max_borrowable`](Self::max_borrowable) amount when the platform
+    /// inventory runs out.
     ///
     /// # Arguments
     ///
-    /// * `asset` - Asset to repay
-    /// * `amount` - Amount to repay
-    /// * `is_isolated` - Whether this is isolated margin
-    /// * `symbol` - Symbol for isolated margin (required if is_isolated is true)
+    /// * `is_isolated` - Whether to query the isolated margin inventory
     ///
     /// # Example
     ///
     /// ```rust,ignore
-    /// // Repay 0.1 BTC on cross margin
-    /// let result = client.margin().repay("BTC", "0.1", false, None).await?;
-    /// println!("Repay transaction ID: {}", result.tran_id);
+    /// let inventory = client.margin().available_inventory(false).await?;
+    /// if inventory.assets.get("BTC").copied().unwrap_or(0.0) >= 0.1 {
+    ///     client.margin()
+    ///         .borrow_repay("BTC", "0.1", BorrowRepayType::Borrow, false, None)
+    ///         .await?;
+    /// }
     /// ```
-    pub async fn repay(
+    pub async fn available_inventory(&self, is_isolated: bool) -> Result<MarginAvailableInventory> {
+        let params: Vec<(&str, String)> = vec![(
+            "type",
+            if is_isolated { "ISOLATED" } else { "MARGIN" }.to_string(),
+        )];
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_AVAILABLE_INVENTORY, &params_ref)
+            .await
+    }
+
+    /// Get the interest rate of assets for the next hour.
+    ///
+    /// # Arguments
+    ///
+    /// * `assets` - Assets to query (at most 20)
+    /// * `is_isolated` - Whether to query isolated margin rates
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let rates = client.margin()
+    ///     .next_hourly_interest_rate(&["BTC", "USDT"], false)
+    ///     .await?;
+    /// for rate in rates {
+    ///     println!("{}: {}", rate.asset, rate.next_hourly_interest_rate);
+    /// }
+    /// ```
+    pub async fn next_hourly_interest_rate(
+        &self,
+        assets: &[&str],
+        is_isolated: bool,
+    ) -> Result<Vec<NextHourlyInterestRate>> {
+        let params: Vec<(&str, String)> = vec![
+            ("assets", assets.join(",")),
+            (
+                "isIsolated",
+                if is_isolated { "TRUE" } else { "FALSE" }.to_string(),
+            ),
+        ];
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_NEXT_HOURLY_INTEREST_RATE, &params_ref)
+            .await
+    }
+
+    /// Apply for a margin loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Asset to borrow
+    /// * `amount` - Amount to borrow
+    /// * `is_isolated` - Whether this is isolated margin
+    /// * `symbol` - Symbol for isolated margin (required if is_isolated is true)
+    #[deprecated(note = "use `borrow_repay` with `BorrowRepayType::Borrow`")]
+    pub async fn loan(
         &self,
         asset: &str,
         amount: &str,
         is_isolated: bool,
         symbol: Option<&str>,
     ) -> Result<TransactionId> {
-        let mut params: Vec<(&str, String)> =
-            vec![("asset", asset.to_string()), ("amount", amount.to_string())];
-
-        if is_isolated {
-            params.push(("isIsolated", "TRUE".to_string()));
-            if let Some(s) = symbol {
-                params.push(("symbol", s.to_string()));
-            }
-        }
+        self.borrow_repay(asset, amount, BorrowRepayType::Borrow, is_isolated, symbol)
+            .await
+    }
 
-        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-        self.client
-            .post_signed(SAPI_V1_MARGIN_REPAY, &params_ref)
+    /// Repay a margin loan.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Asset to repay
+    /// * `amount` - Amount to repay
+    /// * `is_isolated` - Whether this is isolated margin
+    /// * `symbol` - Symbol for isolated margin (required if is_isolated is true)
+    #[deprecated(note = "use `borrow_repay` with `BorrowRepayType::Repay`")]
+    pub async fn repay(
+        &self,
+        asset: &str,
+        amount: &str,
+        is_isolated: bool,
+        symbol: Option<&str>,
+    ) -> Result<TransactionId> {
+        self.borrow_repay(asset, amount, BorrowRepayType::Repay, is_isolated, symbol)
             .await
     }
 
-    /// Get loan records.
+    /// Get borrow or repay records.
     ///
     /// # Arguments
     ///
-    /// * `asset` - Asset to query
+    /// * `borrow_type` - Whether to query borrows or repayments
+    /// * `asset` - Asset to query (optional)
     /// * `isolated_symbol` - Isolated margin symbol (optional)
+    /// * `tx_id` - Transaction ID (optional)
     /// * `start_time` - Start timestamp (optional)
     /// * `end_time` - End timestamp (optional)
     /// * `current` - Page number (default 1)
@@ -391,27 +718,47 @@ impl Margin {
     /// # Example
     ///
     /// ```rust,ignore
+    /// use binance_api_client::BorrowRepayType;
+    ///
     /// let records = client.margin()
-    ///     .loan_records("BTC", None, None, None, None, Some(20))
+    ///     .borrow_repay_records(
+    ///         BorrowRepayType::Borrow,
+    ///         Some("BTC"),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         Some(20),
+    ///     )
     ///     .await?;
     /// for record in records.rows {
-    ///     println!("Loan: {} {} at {}", record.principal, record.asset, record.timestamp);
+    ///     println!("Borrowed: {} {} at {}", record.principal, record.asset, record.timestamp);
     /// }
     /// ```
-    pub async fn loan_records(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn borrow_repay_records(
         &self,
-        asset: &str,
+        borrow_type: BorrowRepayType,
+        asset: Option<&str>,
         isolated_symbol: Option<&str>,
+        tx_id: Option<u64>,
         start_time: Option<u64>,
         end_time: Option<u64>,
         current: Option<u32>,
         size: Option<u32>,
-    ) -> Result<RecordsQueryResult<LoanRecord>> {
-        let mut params: Vec<(&str, String)> = vec![("asset", asset.to_string())];
+    ) -> Result<RecordsQueryResult<BorrowRepayRecord>> {
+        let mut params: Vec<(&str, String)> = vec![("type", borrow_type.to_string())];
 
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
         if let Some(s) = isolated_symbol {
             params.push(("isolatedSymbol", s.to_string()));
         }
+        if let Some(id) = tx_id {
+            params.push(("txId", id.to_string()));
+        }
         if let Some(st) = start_time {
             params.push(("startTime", st.to_string()));
         }
@@ -427,10 +774,48 @@ impl Margin {
 
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
         self.client
-            .get_signed(SAPI_V1_MARGIN_LOAN, &params_ref)
+            .get_signed(SAPI_V1_MARGIN_BORROW_REPAY, &params_ref)
             .await
     }
 
+    /// Get loan records.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Asset to query
+    /// * `isolated_symbol` - Isolated margin symbol (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number (default 1)
+    /// * `size` - Page size (default 10, max 100)
+    #[deprecated(note = "use `borrow_repay_records` with `BorrowRepayType::Borrow`")]
+    pub async fn loan_records(
+        &self,
+        asset: &str,
+        isolated_symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<LoanRecord>> {
+        let records = self
+            .borrow_repay_records(
+                BorrowRepayType::Borrow,
+                Some(asset),
+                isolated_symbol,
+                None,
+                start_time,
+                end_time,
+                current,
+                size,
+            )
+            .await?;
+        Ok(RecordsQueryResult {
+            total: records.total,
+            rows: records.rows.into_iter().map(LoanRecord::from).collect(),
+        })
+    }
+
     /// Get repay records.
     ///
     /// # Arguments
@@ -441,6 +826,7 @@ impl Margin {
     /// * `end_time` - End timestamp (optional)
     /// * `current` - Page number (default 1)
     /// * `size` - Page size (default 10, max 100)
+    #[deprecated(note = "use `borrow_repay_records` with `BorrowRepayType::Repay`")]
     pub async fn repay_records(
         &self,
         asset: &str,
@@ -450,28 +836,22 @@ impl Margin {
         current: Option<u32>,
         size: Option<u32>,
     ) -> Result<RecordsQueryResult<RepayRecord>> {
-        let mut params: Vec<(&str, String)> = vec![("asset", asset.to_string())];
-
-        if let Some(s) = isolated_symbol {
-            params.push(("isolatedSymbol", s.to_string()));
-        }
-        if let Some(st) = start_time {
-            params.push(("startTime", st.to_string()));
-        }
-        if let Some(et) = end_time {
-            params.push(("endTime", et.to_string()));
-        }
-        if let Some(c) = current {
-            params.push(("current", c.to_string()));
-        }
-        if let Some(s) = size {
-            params.push(("size", s.to_string()));
-        }
-
-        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-        self.client
-            .get_signed(SAPI_V1_MARGIN_REPAY, &params_ref)
-            .await
+        let records = self
+            .borrow_repay_records(
+                BorrowRepayType::Repay,
+                Some(asset),
+                isolated_symbol,
+                None,
+                start_time,
+                end_time,
+                current,
+                size,
+            )
+            .await?;
+        Ok(RecordsQueryResult {
+            total: records.total,
+            rows: records.rows.into_iter().map(RepayRecord::from).collect(),
+        })
     }
 
     // Trading.
@@ -551,15 +931,7 @@ impl Margin {
             params.push(("newClientOrderId", id.to_string()));
         }
         if let Some(se) = side_effect_type {
-            params.push((
-                "sideEffectType",
-                match se {
-                    SideEffectType::NoSideEffect => "NO_SIDE_EFFECT",
-                    SideEffectType::MarginBuy => "MARGIN_BUY",
-                    SideEffectType::AutoRepay => "AUTO_REPAY",
-                }
-                .to_string(),
-            ));
+            params.push(("sideEffectType", se.to_string()));
         }
         if let Some(isolated) = is_isolated {
             params.push((
@@ -574,6 +946,47 @@ impl Margin {
             .await
     }
 
+    /// Place a margin order built with `OrderBuilder`.
+    ///
+    /// Fails without sending a request if the order sets a spot-only field:
+    /// trailing delta, strategy ID or type, or a pegged price.
+    ///
+    /// # Arguments
+    ///
+    /// * `order` - Order to place
+    /// * `is_isolated` - Whether to place the order on the isolated margin account
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{OrderBuilder, OrderSide, OrderType, SideEffectType};
+    ///
+    /// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Market)
+    ///     .quantity("0.001")
+    ///     .side_effect_type(SideEffectType::MarginBuy)
+    ///     .build();
+    ///
+    /// let result = client.margin().place_order(&order, false).await?;
+    /// ```
+    pub async fn place_order(
+        &self,
+        order: &NewOrder,
+        is_isolated: bool,
+    ) -> Result<MarginOrderResult> {
+        let mut params = order.to_margin_params()?;
+        if is_isolated {
+            params.push(("isIsolated".to_string(), "TRUE".to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+            .collect();
+        self.client
+            .post_signed(SAPI_V1_MARGIN_ORDER, &params_ref)
+            .await
+    }
+
     /// Cancel a margin order.
     ///
     /// # Arguments
@@ -763,6 +1176,46 @@ impl Margin {
             .await
     }
 
+    /// Get every margin order for a symbol, paginating automatically.
+    ///
+    /// Orders are fetched in pages of 500 by advancing `orderId` past the
+    /// last order received, oldest first. The stream ends after the most
+    /// recent order, or at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `order_id` - Order ID to start from (defaults to the first order)
+    /// * `is_isolated` - Whether isolated margin
+    pub fn all_orders_paginated(
+        &self,
+        symbol: &str,
+        order_id: Option<u64>,
+        is_isolated: Option<bool>,
+    ) -> impl Stream<Item = Result<MarginOrderState>> + Send + 'static {
+        let margin = self.clone();
+        let symbol = symbol.to_string();
+        let fetch = move |order_id| {
+            let margin = margin.clone();
+            let symbol = symbol.clone();
+            async move {
+                margin
+                    .all_orders(
+                        &symbol,
+                        Some(order_id),
+                        None,
+                        None,
+                        Some(MAX_ORDERS_LIMIT),
+                        is_isolated,
+                    )
+                    .await
+            }
+        };
+        pagination::by_id(order_id.unwrap_or(0), MAX_ORDERS_LIMIT, fetch, |o| {
+            o.order_id
+        })
+    }
+
     /// Get margin trades.
     ///
     /// # Arguments
@@ -815,6 +1268,57 @@ impl Margin {
             .await
     }
 
+    /// Get the full margin trade history for a symbol, paginating automatically.
+    ///
+    /// Trades are fetched in pages of 1000 by advancing `fromId` past the
+    /// last trade received, oldest first. The stream ends after the most
+    /// recent trade, or at the first error.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `from_id` - Trade ID to start from (defaults to the first trade)
+    /// * `is_isolated` - Whether isolated margin
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let trades: Vec<_> = client
+    ///     .margin()
+    ///     .my_trades_paginated("BTCUSDT", None, None)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn my_trades_paginated(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        is_isolated: Option<bool>,
+    ) -> impl Stream<Item = Result<MarginTrade>> + Send + 'static {
+        let margin = self.clone();
+        let symbol = symbol.to_string();
+        let fetch = move |from_id| {
+            let margin = margin.clone();
+            let symbol = symbol.clone();
+            async move {
+                margin
+                    .my_trades(
+                        &symbol,
+                        None,
+                        None,
+                        None,
+                        Some(from_id),
+                        Some(MAX_TRADES_LIMIT),
+                        is_isolated,
+                    )
+                    .await
+            }
+        };
+        pagination::by_id(from_id.unwrap_or(0), MAX_TRADES_LIMIT, fetch, |t| t.id)
+    }
+
     // Interest.
 
     /// Get interest history.
@@ -921,6 +1425,26 @@ impl Margin {
         self.client.get_signed(SAPI_V1_MARGIN_ALL_PAIRS, &[]).await
     }
 
+    /// Get isolated margin pair details.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    pub async fn isolated_pair(&self, symbol: &str) -> Result<IsolatedMarginPair> {
+        let params: Vec<(&str, String)> = vec![("symbol", symbol.to_string())];
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_ISOLATED_PAIR, &params_ref)
+            .await
+    }
+
+    /// Get all isolated margin pairs.
+    pub async fn all_isolated_pairs(&self) -> Result<Vec<IsolatedMarginPair>> {
+        self.client
+            .get_signed(SAPI_V1_MARGIN_ISOLATED_ALL_PAIRS, &[])
+            .await
+    }
+
     /// Get margin asset info.
     ///
     /// # Arguments
@@ -952,6 +1476,106 @@ impl Margin {
             .await
     }
 
+    /// Adjust the maximum leverage of the cross margin account.
+    ///
+    /// Selecting 10x switches the account to Cross Margin Pro; 3x and 5x use
+    /// Cross Margin Classic.
+    ///
+    /// # Arguments
+    ///
+    /// * `leverage` - New maximum leverage
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::MarginMaxLeverage;
+    ///
+    /// let result = client.margin().set_max_leverage(MarginMaxLeverage::X5).await?;
+    /// assert!(result.success);
+    /// ```
+    pub async fn set_max_leverage(&self, leverage: MarginMaxLeverage) -> Result<MaxLeverageResult> {
+        let params: Vec<(&str, String)> = vec![("maxLeverage", leverage.to_string())];
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_MARGIN_MAX_LEVERAGE, &params_ref)
+            .await
+    }
+
+    // Small Liability Exchange.
+
+    /// Get the cross margin liabilities small enough to exchange for BNB.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let assets = client.margin().small_liability_assets().await?;
+    /// let names: Vec<&str> = assets.iter().map(|a| a.asset.as_str()).collect();
+    /// client.margin().small_liability_exchange(&names).await?;
+    /// ```
+    pub async fn small_liability_assets(&self) -> Result<Vec<SmallLiabilityAsset>> {
+        self.client
+            .get_signed(SAPI_V1_MARGIN_SMALL_LIABILITY, &[])
+            .await
+    }
+
+    /// Repay small cross margin liabilities with BNB.
+    ///
+    /// # Arguments
+    ///
+    /// * `assets` - Assets whose liabilities to repay (at most 10)
+    pub async fn small_liability_exchange(&self, assets: &[&str]) -> Result<()> {
+        let params: Vec<(&str, String)> = vec![("assetNames", assets.join(","))];
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let _: serde_json::Value = self
+            .client
+            .post_signed(SAPI_V1_MARGIN_SMALL_LIABILITY, &params_ref)
+            .await?;
+        Ok(())
+    }
+
+    /// Get small liability exchange history.
+    ///
+    /// # Arguments
+    ///
+    /// * `current` - Page number, starting at 1
+    /// * `size` - Page size (max 100)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let history = client.margin()
+    ///     .small_liability_exchange_history(1, 100, None, None)
+    ///     .await?;
+    /// for record in history.rows {
+    ///     println!("{} {} -> {} {}", record.amount, record.asset,
+    ///         record.target_amount, record.target_asset);
+    /// }
+    /// ```
+    pub async fn small_liability_exchange_history(
+        &self,
+        current: u32,
+        size: u32,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<RecordsQueryResult<SmallLiabilityExchangeRecord>> {
+        let mut params: Vec<(&str, String)> =
+            vec![("current", current.to_string()), ("size", size.to_string())];
+
+        if let Some(st) = start_time {
+            params.push(("startTime", st.to_string()));
+        }
+        if let Some(et) = end_time {
+            params.push(("endTime", et.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_MARGIN_SMALL_LIABILITY_HISTORY, &params_ref)
+            .await
+    }
+
     // BNB Burn.
 
     /// Get BNB burn status for spot trading and margin interest.
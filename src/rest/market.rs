@@ -3,16 +3,19 @@
 //! This module provides access to public market data endpoints that don't
 //! require authentication.
 
+use std::time::Duration;
+
+use futures::{Stream, TryStreamExt, stream};
 use serde_json::Value;
 
 use crate::Result;
 use crate::client::Client;
 use crate::models::{
-    AggTrade, AveragePrice, BookTicker, ExchangeInfo, Kline, OrderBook, RollingWindowTicker,
-    RollingWindowTickerMini, ServerTime, Ticker24h, TickerPrice, Trade, TradingDayTicker,
-    TradingDayTickerMini,
+    AggTrade, AveragePrice, BookTicker, ExchangeInfo, Kline, MarketSnapshot, OrderBook,
+    RollingWindowTicker, RollingWindowTickerMini, ServerTime, Ticker24h, Ticker24hMini,
+    TickerPrice, Trade, TradingDayTicker, TradingDayTickerMini,
 };
-use crate::types::{KlineInterval, SymbolStatus, TickerType};
+use crate::types::{DepthLimit, KlineInterval, SymbolPermission, SymbolStatus, TickerType};
 
 // API endpoints
 const API_V3_PING: &str = "/api/v3/ping";
@@ -31,6 +34,30 @@ const API_V3_TICKER_PRICE: &str = "/api/v3/ticker/price";
 const API_V3_TICKER_BOOK_TICKER: &str = "/api/v3/ticker/bookTicker";
 const API_V3_TICKER: &str = "/api/v3/ticker";
 
+/// Largest order book depth returned by the API.
+const MAX_DEPTH_LIMIT: u16 = 5000;
+
+/// Order book levels included in a market snapshot.
+const SNAPSHOT_DEPTH_LIMIT: DepthLimit = DepthLimit::Levels5;
+
+/// Recent trades included in a market snapshot.
+const SNAPSHOT_TRADES_LIMIT: u16 = 10;
+
+/// Largest number of klines returned by one request.
+const MAX_KLINES_LIMIT: u16 = 1000;
+
+/// Largest number of aggregate trades returned by one request.
+const MAX_AGG_TRADES_LIMIT: u16 = 1000;
+
+/// Longest time window of an aggregate trades request.
+const AGG_TRADES_WINDOW_MS: u64 = 60 * 60 * 1000;
+
+/// Delay before retrying a page rejected by the rate limiter.
+const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Number of times a page is retried after a rate limit error.
+const MAX_RATE_LIMIT_RETRIES: u32 = 3;
+
 /// Market data API client.
 ///
 /// Provides access to public market data endpoints.
@@ -99,18 +126,47 @@ impl Market {
     /// let info = client.market().exchange_info_for_symbols(&["BTCUSDT", "ETHUSDT"]).await?;
     /// ```
     pub async fn exchange_info_for_symbols(&self, symbols: &[&str]) -> Result<ExchangeInfo> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let query = format!("symbols={}", urlencoding::encode(&symbols_json));
+        let query = format!("symbols={}", symbols_param(symbols));
         self.client.get(API_V3_EXCHANGE_INFO, Some(&query)).await
     }
 
+    /// Get exchange information filtered by symbols, permissions or status.
+    ///
+    /// Filtering on the server is faster and smaller than pulling the full
+    /// exchange info for every symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Query options
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{ExchangeInfoParams, SymbolPermission, SymbolStatus};
+    ///
+    /// let client = Binance::new_unauthenticated()?;
+    /// let params = ExchangeInfoParams::new()
+    ///     .permissions(&[SymbolPermission::Margin])
+    ///     .symbol_status(SymbolStatus::Trading)
+    ///     .show_permission_sets(false);
+    /// let info = client.market().exchange_info_with(&params).await?;
+    /// ```
+    pub async fn exchange_info_with(&self, params: &ExchangeInfoParams) -> Result<ExchangeInfo> {
+        let params = params.to_params();
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_with_params(API_V3_EXCHANGE_INFO, &params_ref)
+            .await
+    }
+
     /// Get order book depth.
     ///
     /// # Arguments
     ///
     /// * `symbol` - Trading pair symbol (e.g., "BTCUSDT")
-    /// * `limit` - Number of entries to return. Valid limits: 5, 10, 20, 50, 100, 500, 1000, 5000.
-    ///   Default is 100; max is 5000.
+    /// * `limit` - Number of entries to return, from 1 to 5000 (default 100).
+    ///   Larger values are truncated to 5000. The request weight grows with
+    ///   the limit; see [`DepthLimit::weight_for`].
     ///
     /// # Example
     ///
@@ -124,11 +180,60 @@ impl Market {
     pub async fn depth(&self, symbol: &str, limit: Option<u16>) -> Result<OrderBook> {
         let mut query = format!("symbol={}", symbol);
         if let Some(l) = limit {
-            query.push_str(&format!("&limit={}", l));
+            query.push_str(&format!("&limit={}", l.clamp(1, MAX_DEPTH_LIMIT)));
         }
         self.client.get(API_V3_DEPTH, Some(&query)).await
     }
 
+    /// Get order book depth with one of the standard limits.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `limit` - Number of levels, which also sets the request weight
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::DepthLimit;
+    ///
+    /// let client = Binance::new_unauthenticated()?;
+    /// let limit = DepthLimit::Levels5000;
+    /// println!("Fetching {} levels (weight {})", limit, limit.weight());
+    /// let depth = client.market().depth_with_limit("BTCUSDT", limit).await?;
+    /// ```
+    pub async fn depth_with_limit(&self, symbol: &str, limit: DepthLimit) -> Result<OrderBook> {
+        self.depth(symbol, Some(limit.levels())).await
+    }
+
+    /// Get at least `levels` levels of the order book for the lowest
+    /// request weight.
+    ///
+    /// The smallest valid limit covering `levels` is requested (see
+    /// [`DepthLimit::cheapest_for`]) and each side is truncated to `levels`.
+    /// Returns the order book and the request weight it cost, from 5 for up
+    /// to 100 levels to 250 for more than 1000.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `levels` - Number of levels needed on each side (at most 5000)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new_unauthenticated()?;
+    /// let (depth, weight) = client.market().depth_auto("BTCUSDT", 150).await?;
+    /// println!("{} bids for weight {}", depth.bids.len(), weight);
+    /// ```
+    pub async fn depth_auto(&self, symbol: &str, levels: u16) -> Result<(OrderBook, u32)> {
+        let limit = DepthLimit::cheapest_for(levels);
+        let mut depth = self.depth_with_limit(symbol, limit).await?;
+        depth.bids.truncate(levels as usize);
+        depth.asks.truncate(levels as usize);
+        Ok((depth, limit.weight()))
+    }
+
     /// Get recent trades.
     ///
     /// # Arguments
@@ -152,7 +257,9 @@ impl Market {
 
     /// Get older/historical trades.
     ///
-    /// This endpoint requires an API key but not a signature.
+    /// This endpoint requires an API key but not a signature, so a client
+    /// created with [`Binance::with_api_key`](crate::Binance::with_api_key)
+    /// can call it.
     ///
     /// # Arguments
     ///
@@ -163,7 +270,7 @@ impl Market {
     /// # Example
     ///
     /// ```rust,ignore
-    /// let client = Binance::new("api_key", "secret_key")?;
+    /// let client = Binance::with_api_key(Config::default(), "api_key")?;
     /// let trades = client.market().historical_trades("BTCUSDT", Some(12345), Some(100)).await?;
     /// ```
     pub async fn historical_trades(
@@ -228,6 +335,114 @@ impl Market {
         self.client.get(API_V3_AGG_TRADES, Some(&query)).await
     }
 
+    /// Get all aggregate trades between two times, paginating automatically.
+    ///
+    /// The first trade is found by searching one-hour windows from
+    /// `start_time` (the longest window the API accepts), then pages of 1000
+    /// trades are requested by advancing `fromId` past the last trade
+    /// received. The stream ends at the last trade before `end_time`. A page
+    /// rejected by the rate limiter is retried after a backoff; any other
+    /// error ends the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `start_time` - Start time in milliseconds
+    /// * `end_time` - End time in milliseconds (inclusive)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    ///
+    /// let client = Binance::new_unauthenticated()?;
+    /// let trades: Vec<_> = client
+    ///     .market()
+    ///     .agg_trades_range("BTCUSDT", 1_700_000_000_000, 1_700_086_400_000)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn agg_trades_range(
+        &self,
+        symbol: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<AggTrade>> + Send + 'static {
+        let market = self.clone();
+        let symbol = symbol.to_string();
+
+        let pages = stream::try_unfold(Some(AggTradeCursor::Time(start_time)), move |cursor| {
+            let market = market.clone();
+            let symbol = symbol.clone();
+            async move {
+                let Some(cursor) = cursor else {
+                    return Result::Ok(None);
+                };
+                let (page, next) = match cursor {
+                    AggTradeCursor::Time(start) => {
+                        let end = start.saturating_add(AGG_TRADES_WINDOW_MS - 1).min(end_time);
+                        let page = market
+                            .agg_trades_page(&symbol, None, Some((start, end)))
+                            .await?;
+                        let next = match page.last() {
+                            Some(last) => Some(AggTradeCursor::Id(last.agg_trade_id + 1)),
+                            None if end < end_time => Some(AggTradeCursor::Time(end + 1)),
+                            None => None,
+                        };
+                        (page, next)
+                    }
+                    AggTradeCursor::Id(from_id) => {
+                        let page = market.agg_trades_page(&symbol, Some(from_id), None).await?;
+                        let next = match page.last() {
+                            Some(last)
+                                if page.len() == MAX_AGG_TRADES_LIMIT as usize
+                                    && last.timestamp <= end_time =>
+                            {
+                                Some(AggTradeCursor::Id(last.agg_trade_id + 1))
+                            }
+                            _ => None,
+                        };
+                        (page, next)
+                    }
+                };
+                Ok(Some((page, next)))
+            }
+        });
+
+        pages
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+            .try_take_while(move |trade| std::future::ready(Ok(trade.timestamp <= end_time)))
+    }
+
+    // Fetch one page of aggregate trades, retrying after rate limit errors.
+    async fn agg_trades_page(
+        &self,
+        symbol: &str,
+        from_id: Option<u64>,
+        window: Option<(u64, u64)>,
+    ) -> Result<Vec<AggTrade>> {
+        let mut retries = 0;
+        loop {
+            let result = self
+                .agg_trades(
+                    symbol,
+                    from_id,
+                    window.map(|(start, _)| start),
+                    window.map(|(_, end)| end),
+                    Some(MAX_AGG_TRADES_LIMIT),
+                )
+                .await;
+            match result {
+                Err(e) if e.is_rate_limit() && retries < MAX_RATE_LIMIT_RETRIES => {
+                    retries += 1;
+                    tokio::time::sleep(RATE_LIMIT_BACKOFF * retries).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Get kline/candlestick data.
     ///
     /// # Arguments
@@ -257,21 +472,170 @@ impl Market {
         end_time: Option<u64>,
         limit: Option<u16>,
     ) -> Result<Vec<Kline>> {
-        let mut query = format!("symbol={}&interval={}", symbol, interval);
-        if let Some(start) = start_time {
-            query.push_str(&format!("&startTime={}", start));
-        }
-        if let Some(end) = end_time {
-            query.push_str(&format!("&endTime={}", end));
-        }
-        if let Some(l) = limit {
-            query.push_str(&format!("&limit={}", l));
-        }
+        self.fetch_klines(
+            API_V3_KLINES,
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            limit,
+            None,
+        )
+        .await
+    }
 
-        // Klines come as arrays, need to parse manually
-        let raw: Vec<Vec<Value>> = self.client.get(API_V3_KLINES, Some(&query)).await?;
+    /// Get kline/candlestick data with intervals aligned to a time zone.
+    ///
+    /// Times are still given and returned in UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `interval` - Kline interval
+    /// * `start_time` - Start time in milliseconds
+    /// * `end_time` - End time in milliseconds
+    /// * `limit` - Default 500; max 1000
+    /// * `time_zone` - UTC offset in hours or hours and minutes, from
+    ///   -12:00 to +14:00 (e.g., "8", "-1:00", "+05:45")
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::KlineInterval;
+    ///
+    /// let client = Binance::new_unauthenticated()?;
+    /// let klines = client
+    ///     .market()
+    ///     .klines_in_time_zone("BTCUSDT", KlineInterval::Days1, None, None, Some(7), "+08:00")
+    ///     .await?;
+    /// ```
+    pub async fn klines_in_time_zone(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        time_zone: &str,
+    ) -> Result<Vec<Kline>> {
+        self.fetch_klines(
+            API_V3_KLINES,
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            limit,
+            Some(time_zone),
+        )
+        .await
+    }
 
-        Ok(parse_klines(raw))
+    /// Get all klines between two times, paginating automatically.
+    ///
+    /// Pages of 1000 candles are requested by advancing `startTime` past the
+    /// last candle received. Candles repeated at page boundaries are dropped.
+    /// A page rejected by the rate limiter is retried after a backoff; any
+    /// other error ends the stream.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `interval` - Kline interval
+    /// * `start_time` - Start time in milliseconds
+    /// * `end_time` - End time in milliseconds (defaults to now)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::KlineInterval;
+    /// use futures::TryStreamExt;
+    ///
+    /// let client = Binance::new_unauthenticated()?;
+    /// let klines: Vec<_> = client
+    ///     .market()
+    ///     .klines_paginated("BTCUSDT", KlineInterval::Minutes1, 1_700_000_000_000, None)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn klines_paginated(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: Option<u64>,
+    ) -> impl Stream<Item = Result<Kline>> + Send + 'static {
+        self.paginate_klines(API_V3_KLINES, symbol, interval, start_time, end_time)
+    }
+
+    fn paginate_klines(
+        &self,
+        endpoint: &'static str,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: Option<u64>,
+    ) -> impl Stream<Item = Result<Kline>> + Send + 'static {
+        let market = self.clone();
+        let symbol = symbol.to_string();
+
+        let pages = stream::try_unfold(Some(start_time), move |next_start| {
+            let market = market.clone();
+            let symbol = symbol.clone();
+            async move {
+                let Some(start) = next_start else {
+                    return Result::Ok(None);
+                };
+                let page = market
+                    .klines_page(endpoint, &symbol, interval, start, end_time)
+                    .await?;
+                let next_start = next_page_start(&page, end_time);
+                Ok(Some((page, next_start)))
+            }
+        });
+
+        let mut last_open_time = None;
+        pages
+            .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+            .try_flatten()
+            .try_filter(move |kline| {
+                let is_new = last_open_time.is_none_or(|t| kline.open_time > t);
+                if is_new {
+                    last_open_time = Some(kline.open_time);
+                }
+                std::future::ready(is_new)
+            })
+    }
+
+    // Fetch one page of klines, retrying after rate limit errors.
+    async fn klines_page(
+        &self,
+        endpoint: &str,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: Option<u64>,
+    ) -> Result<Vec<Kline>> {
+        let mut retries = 0;
+        loop {
+            let result = self
+                .fetch_klines(
+                    endpoint,
+                    symbol,
+                    interval,
+                    Some(start_time),
+                    end_time,
+                    Some(MAX_KLINES_LIMIT),
+                    None,
+                )
+                .await;
+            match result {
+                Err(e) if e.is_rate_limit() && retries < MAX_RATE_LIMIT_RETRIES => {
+                    retries += 1;
+                    tokio::time::sleep(RATE_LIMIT_BACKOFF * retries).await;
+                }
+                result => return result,
+            }
+        }
     }
 
     /// Get UI optimized kline/candlestick data.
@@ -304,6 +668,71 @@ impl Market {
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u16>,
+    ) -> Result<Vec<Kline>> {
+        self.fetch_klines(
+            API_V3_UI_KLINES,
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            limit,
+            None,
+        )
+        .await
+    }
+
+    /// Get UI optimized kline/candlestick data with intervals aligned to a
+    /// time zone.
+    ///
+    /// See [`klines_in_time_zone`](Self::klines_in_time_zone) for the
+    /// `time_zone` format.
+    pub async fn ui_klines_in_time_zone(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        time_zone: &str,
+    ) -> Result<Vec<Kline>> {
+        self.fetch_klines(
+            API_V3_UI_KLINES,
+            symbol,
+            interval,
+            start_time,
+            end_time,
+            limit,
+            Some(time_zone),
+        )
+        .await
+    }
+
+    /// Get all UI optimized klines between two times, paginating
+    /// automatically.
+    ///
+    /// Pages are requested the same way as
+    /// [`klines_paginated`](Self::klines_paginated).
+    pub fn ui_klines_paginated(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: u64,
+        end_time: Option<u64>,
+    ) -> impl Stream<Item = Result<Kline>> + Send + 'static {
+        self.paginate_klines(API_V3_UI_KLINES, symbol, interval, start_time, end_time)
+    }
+
+    // Fetch klines from `endpoint`, which returns them as arrays.
+    #[allow(clippy::too_many_arguments)]
+    async fn fetch_klines(
+        &self,
+        endpoint: &str,
+        symbol: &str,
+        interval: KlineInterval,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u16>,
+        time_zone: Option<&str>,
     ) -> Result<Vec<Kline>> {
         let mut query = format!("symbol={}&interval={}", symbol, interval);
         if let Some(start) = start_time {
@@ -315,8 +744,12 @@ impl Market {
         if let Some(l) = limit {
             query.push_str(&format!("&limit={}", l));
         }
+        if let Some(tz) = time_zone {
+            query.push_str(&format!("&timeZone={}", urlencoding::encode(tz)));
+        }
 
-        let raw: Vec<Vec<Value>> = self.client.get(API_V3_UI_KLINES, Some(&query)).await?;
+        // Klines come as arrays, need to parse manually
+        let raw: Vec<Vec<Value>> = self.client.get(endpoint, Some(&query)).await?;
 
         Ok(parse_klines(raw))
     }
@@ -332,7 +765,7 @@ impl Market {
     /// ```rust,ignore
     /// let client = Binance::new_unauthenticated()?;
     /// let avg = client.market().avg_price("BTCUSDT").await?;
-    /// println!("Average price over {} mins: {}", avg.mins, avg.price);
+    /// println!("Average price over {:?}: {}", avg.window(), avg.price);
     /// ```
     pub async fn avg_price(&self, symbol: &str) -> Result<AveragePrice> {
         let query = format!("symbol={}", symbol);
@@ -369,6 +802,30 @@ impl Market {
         self.client.get(API_V3_TICKER_24HR, None).await
     }
 
+    /// Get 24hr ticker price change statistics (MINI) for a symbol.
+    ///
+    /// The MINI response omits the price change, weighted average and
+    /// best bid/ask fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    pub async fn ticker_24h_mini(&self, symbol: &str) -> Result<Ticker24hMini> {
+        let ticker_type = TickerType::Mini.to_string();
+        let params = [("symbol", symbol), ("type", ticker_type.as_str())];
+        self.client
+            .get_with_params(API_V3_TICKER_24HR, &params)
+            .await
+    }
+
+    /// Get 24hr ticker price change statistics (MINI) for all symbols.
+    pub async fn ticker_24h_mini_all(&self) -> Result<Vec<Ticker24hMini>> {
+        let ticker_type = TickerType::Mini.to_string();
+        self.client
+            .get_with_params(API_V3_TICKER_24HR, &[("type", ticker_type.as_str())])
+            .await
+    }
+
     /// Get trading day ticker statistics (FULL).
     ///
     /// # Arguments
@@ -422,15 +879,22 @@ impl Market {
     }
 
     /// Get trading day ticker statistics (FULL) for multiple symbols.
+    ///
+    /// The request weight grows with the number of symbols, up to 100
+    /// symbols per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - List of symbols
+    /// * `time_zone` - Optional timezone (e.g., "0" or "-1:00")
+    /// * `symbol_status` - Optional symbol trading status filter
     pub async fn trading_day_tickers(
         &self,
         symbols: &[&str],
         time_zone: Option<&str>,
         symbol_status: Option<SymbolStatus>,
     ) -> Result<Vec<TradingDayTicker>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let mut params: Vec<(&str, String)> =
-            vec![("symbols", urlencoding::encode(&symbols_json).into_owned())];
+        let mut params: Vec<(&str, String)> = vec![("symbols", symbols_param(symbols))];
 
         if let Some(tz) = time_zone {
             params.push(("timeZone", tz.to_string()));
@@ -446,15 +910,22 @@ impl Market {
     }
 
     /// Get trading day ticker statistics (MINI) for multiple symbols.
+    ///
+    /// The request weight grows with the number of symbols, up to 100
+    /// symbols per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - List of symbols
+    /// * `time_zone` - Optional timezone (e.g., "0" or "-1:00")
+    /// * `symbol_status` - Optional symbol trading status filter
     pub async fn trading_day_tickers_mini(
         &self,
         symbols: &[&str],
         time_zone: Option<&str>,
         symbol_status: Option<SymbolStatus>,
     ) -> Result<Vec<TradingDayTickerMini>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let mut params: Vec<(&str, String)> =
-            vec![("symbols", urlencoding::encode(&symbols_json).into_owned())];
+        let mut params: Vec<(&str, String)> = vec![("symbols", symbols_param(symbols))];
 
         params.push(("type", TickerType::Mini.to_string()));
 
@@ -524,15 +995,32 @@ impl Market {
     }
 
     /// Get rolling window ticker statistics (FULL) for multiple symbols.
+    ///
+    /// The request weight grows with the number of symbols, up to 100
+    /// symbols per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - List of symbols
+    /// * `window_size` - Optional window size (e.g., "1d", "15m")
+    /// * `symbol_status` - Optional symbol trading status filter
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new_unauthenticated()?;
+    /// let tickers = client
+    ///     .market()
+    ///     .rolling_window_tickers(&["BTCUSDT", "ETHUSDT"], Some("4h"), None)
+    ///     .await?;
+    /// ```
     pub async fn rolling_window_tickers(
         &self,
         symbols: &[&str],
         window_size: Option<&str>,
         symbol_status: Option<SymbolStatus>,
     ) -> Result<Vec<RollingWindowTicker>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let mut params: Vec<(&str, String)> =
-            vec![("symbols", urlencoding::encode(&symbols_json).into_owned())];
+        let mut params: Vec<(&str, String)> = vec![("symbols", symbols_param(symbols))];
 
         if let Some(window) = window_size {
             params.push(("windowSize", window.to_string()));
@@ -548,15 +1036,22 @@ impl Market {
     }
 
     /// Get rolling window ticker statistics (MINI) for multiple symbols.
+    ///
+    /// The request weight grows with the number of symbols, up to 100
+    /// symbols per request.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbols` - List of symbols
+    /// * `window_size` - Optional window size (e.g., "1d", "15m")
+    /// * `symbol_status` - Optional symbol trading status filter
     pub async fn rolling_window_tickers_mini(
         &self,
         symbols: &[&str],
         window_size: Option<&str>,
         symbol_status: Option<SymbolStatus>,
     ) -> Result<Vec<RollingWindowTickerMini>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let mut params: Vec<(&str, String)> =
-            vec![("symbols", urlencoding::encode(&symbols_json).into_owned())];
+        let mut params: Vec<(&str, String)> = vec![("symbols", symbols_param(symbols))];
 
         params.push(("type", TickerType::Mini.to_string()));
 
@@ -608,6 +1103,9 @@ impl Market {
 
     /// Get latest prices for specific symbols.
     ///
+    /// Costs less request weight than [`prices`](Self::prices) and takes one
+    /// request instead of one per symbol.
+    ///
     /// # Arguments
     ///
     /// * `symbols` - List of symbols
@@ -619,8 +1117,7 @@ impl Market {
     /// let prices = client.market().prices_for(&["BTCUSDT", "ETHUSDT"]).await?;
     /// ```
     pub async fn prices_for(&self, symbols: &[&str]) -> Result<Vec<TickerPrice>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let query = format!("symbols={}", urlencoding::encode(&symbols_json));
+        let query = format!("symbols={}", symbols_param(symbols));
         self.client.get(API_V3_TICKER_PRICE, Some(&query)).await
     }
 
@@ -645,6 +1142,47 @@ impl Market {
             .await
     }
 
+    /// Get a combined snapshot of a symbol's market data.
+    ///
+    /// Fetches the last price, 24hr statistics, best bid/ask, the 10 most
+    /// recent trades and the top 5 order book levels concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let client = Binance::new_unauthenticated()?;
+    /// let snapshot = client.market().snapshot("BTCUSDT").await?;
+    /// println!(
+    ///     "{}: {} ({}% 24h), spread {}",
+    ///     snapshot.symbol,
+    ///     snapshot.price,
+    ///     snapshot.ticker_24h.price_change_percent,
+    ///     snapshot.spread()
+    /// );
+    /// ```
+    pub async fn snapshot(&self, symbol: &str) -> Result<MarketSnapshot> {
+        let (price, ticker_24h, book_ticker, recent_trades, depth) = tokio::try_join!(
+            self.price(symbol),
+            self.ticker_24h(symbol),
+            self.book_ticker(symbol),
+            self.trades(symbol, Some(SNAPSHOT_TRADES_LIMIT)),
+            self.depth_with_limit(symbol, SNAPSHOT_DEPTH_LIMIT),
+        )?;
+
+        Ok(MarketSnapshot {
+            symbol: price.symbol,
+            price: price.price,
+            ticker_24h,
+            book_ticker,
+            recent_trades,
+            depth,
+        })
+    }
+
     /// Get best price/qty on the order book for all symbols.
     ///
     /// # Example
@@ -659,6 +1197,9 @@ impl Market {
 
     /// Get best price/qty on the order book for specific symbols.
     ///
+    /// Costs less request weight than [`book_tickers`](Self::book_tickers) and takes one
+    /// request instead of one per symbol.
+    ///
     /// # Arguments
     ///
     /// * `symbols` - List of symbols
@@ -670,14 +1211,97 @@ impl Market {
     /// let tickers = client.market().book_tickers_for(&["BTCUSDT", "ETHUSDT"]).await?;
     /// ```
     pub async fn book_tickers_for(&self, symbols: &[&str]) -> Result<Vec<BookTicker>> {
-        let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
-        let query = format!("symbols={}", urlencoding::encode(&symbols_json));
+        let query = format!("symbols={}", symbols_param(symbols));
         self.client
             .get(API_V3_TICKER_BOOK_TICKER, Some(&query))
             .await
     }
 }
 
+// Position of the next aggregate trades page.
+#[derive(Debug, Clone, Copy)]
+enum AggTradeCursor {
+    // Search the hour starting at this time.
+    Time(u64),
+    // Continue from this aggregate trade ID.
+    Id(u64),
+}
+
+/// Query options for [`Market::exchange_info_with`].
+///
+/// Symbols cannot be combined with permissions or a symbol status.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExchangeInfoParams {
+    symbols: Vec<String>,
+    permissions: Vec<SymbolPermission>,
+    symbol_status: Option<SymbolStatus>,
+    show_permission_sets: Option<bool>,
+}
+
+impl ExchangeInfoParams {
+    /// Create options that return every symbol.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return these symbols.
+    pub fn symbols(mut self, symbols: &[&str]) -> Self {
+        self.symbols = symbols.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// Only return symbols with any of these permissions.
+    pub fn permissions(mut self, permissions: &[SymbolPermission]) -> Self {
+        self.permissions = permissions.to_vec();
+        self
+    }
+
+    /// Only return symbols with this trading status.
+    pub fn symbol_status(mut self, status: SymbolStatus) -> Self {
+        self.symbol_status = Some(status);
+        self
+    }
+
+    /// Set whether to include the permission sets of each symbol
+    /// (default true).
+    pub fn show_permission_sets(mut self, show: bool) -> Self {
+        self.show_permission_sets = Some(show);
+        self
+    }
+
+    pub(crate) fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if !self.symbols.is_empty() {
+            let symbols: Vec<&str> = self.symbols.iter().map(String::as_str).collect();
+            params.push(("symbols", symbols_param(&symbols)));
+        }
+        if !self.permissions.is_empty() {
+            let permissions: Vec<String> = self.permissions.iter().map(|p| p.to_string()).collect();
+            let permissions_json = serde_json::to_string(&permissions).unwrap_or_default();
+            params.push((
+                "permissions",
+                urlencoding::encode(&permissions_json).into_owned(),
+            ));
+        }
+        if let Some(status) = self.symbol_status {
+            params.push(("symbolStatus", status.to_string()));
+        }
+        if let Some(show) = self.show_permission_sets {
+            params.push(("showPermissionSets", show.to_string()));
+        }
+
+        params
+    }
+}
+
+/// Encode a symbol list as the URL-encoded JSON array the `symbols`
+/// parameter takes, e.g. `["BTCUSDT","ETHUSDT"]`.
+fn symbols_param(symbols: &[&str]) -> String {
+    let symbols_json = serde_json::to_string(symbols).unwrap_or_default();
+    urlencoding::encode(&symbols_json).into_owned()
+}
+
 /// Parse a serde_json::Value as f64, handling both strings and numbers.
 fn parse_value_as_f64(value: &Value) -> f64 {
     match value {
@@ -705,6 +1329,15 @@ fn parse_klines(raw: Vec<Vec<Value>>) -> Vec<Kline> {
         .collect()
 }
 
+// Start time of the page after `page`, or `None` if `page` was the last one.
+fn next_page_start(page: &[Kline], end_time: Option<u64>) -> Option<u64> {
+    if page.len() < MAX_KLINES_LIMIT as usize {
+        return None;
+    }
+    let next = page.last()?.open_time as u64 + 1;
+    end_time.is_none_or(|end| next <= end).then_some(next)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -726,4 +1359,39 @@ mod tests {
         let value = Value::Null;
         assert_eq!(parse_value_as_f64(&value), 0.0);
     }
+
+    #[test]
+    fn test_next_page_start() {
+        let page: Vec<Kline> = (0..MAX_KLINES_LIMIT as i64)
+            .map(|i| Kline {
+                open_time: i * 60_000,
+                close_time: i * 60_000 + 59_999,
+                ..Default::default()
+            })
+            .collect();
+        let last_open = 999 * 60_000;
+
+        assert_eq!(next_page_start(&page, None), Some(last_open + 1));
+        assert_eq!(next_page_start(&page, Some(last_open)), None);
+        assert_eq!(next_page_start(&page[..10], None), None);
+    }
+
+    #[test]
+    fn test_exchange_info_params() {
+        assert!(ExchangeInfoParams::new().to_params().is_empty());
+
+        let params = ExchangeInfoParams::new()
+            .permissions(&[SymbolPermission::Spot, SymbolPermission::Margin])
+            .symbol_status(SymbolStatus::Trading)
+            .show_permission_sets(false)
+            .to_params();
+        assert_eq!(
+            params,
+            vec![
+                ("permissions", "%5B%22SPOT%22%2C%22MARGIN%22%5D".to_string()),
+                ("symbolStatus", "TRADING".to_string()),
+                ("showPermissionSets", "false".to_string()),
+            ]
+        );
+    }
 }
@@ -4,17 +4,37 @@
 //! organized by category.
 
 pub mod account;
+pub mod algo;
+#[cfg(feature = "broker")]
+pub mod broker;
+pub mod earn;
+pub mod fiat;
+#[cfg(feature = "institutional-loan")]
+pub mod institutional_loan;
+pub mod loan;
 pub mod margin;
 pub mod market;
+mod pagination;
+pub mod staking;
 pub mod userstream;
 pub mod wallet;
 
 pub use account::{
-    Account, CancelReplaceOrder, CancelReplaceOrderBuilder, NewOcoOrder, NewOpoOrder,
-    NewOpocoOrder, NewOrder, NewOtoOrder, NewOtocoOrder, OcoOrderBuilder, OpoOrderBuilder,
-    OpocoOrderBuilder, OrderBuilder, OtoOrderBuilder, OtocoOrderBuilder,
+    Account, CancelOutcome, CancelReplaceOrder, CancelReplaceOrderBuilder, NewOcoOrder,
+    NewOpoOrder, NewOpocoOrder, NewOrder, NewOtoOrder, NewOtocoOrder, OcoOrderBuilder,
+    OpoOrderBuilder, OpocoOrderBuilder, OrderBuilder, OtoOrderBuilder, OtocoOrderBuilder,
+    QuoteRefresh, QuoteUpdate,
 };
+pub use algo::{Algo, AlgoOrderBuilder, NewAlgoOrder};
+#[cfg(feature = "broker")]
+pub use broker::Broker;
+pub use earn::Earn;
+pub use fiat::Fiat;
+#[cfg(feature = "institutional-loan")]
+pub use institutional_loan::InstitutionalLoan;
+pub use loan::Loan;
 pub use margin::Margin;
-pub use market::Market;
+pub use market::{ExchangeInfoParams, Market};
+pub use staking::Staking;
 pub use userstream::UserStream;
-pub use wallet::Wallet;
+pub use wallet::{NewWithdraw, Wallet, WithdrawBuilder};
@@ -0,0 +1,116 @@
+//! Cursor pagination shared by the history endpoints.
+
+use std::future::Future;
+
+use futures::{Stream, TryStreamExt, stream};
+
+use crate::Result;
+
+/// Page through an endpoint whose results are ordered by an ID cursor.
+///
+/// `fetch` gets up to `limit` records with an ID of at least its argument.
+/// Pages are requested from `start` until one comes back short.
+pub(crate) fn by_id<T, F, Fut>(
+    start: u64,
+    limit: u32,
+    fetch: F,
+    id: fn(&T) -> u64,
+) -> impl Stream<Item = Result<T>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Fn(u64) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    let pages = stream::try_unfold(Some(start), move |cursor| {
+        let page = cursor.map(&fetch);
+        async move {
+            let Some(page) = page else {
+                return Result::Ok(None);
+            };
+            let page = page.await?;
+            let next = if page.len() < limit as usize {
+                None
+            } else {
+                page.last().map(|record| id(record) + 1)
+            };
+            Ok(Some((page, next)))
+        }
+    });
+
+    pages
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+/// Page through an endpoint with numbered pages.
+///
+/// `fetch` gets page `current` (starting at 1) of up to `size` records.
+/// Pages are requested until one comes back short.
+pub(crate) fn by_page<T, F, Fut>(
+    size: u32,
+    fetch: F,
+) -> impl Stream<Item = Result<T>> + Send + 'static
+where
+    T: Send + 'static,
+    F: Fn(u32) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Vec<T>>> + Send + 'static,
+{
+    let pages = stream::try_unfold(Some(1), move |current| {
+        let page = current.map(|c| (c, fetch(c)));
+        async move {
+            let Some((current, page)) = page else {
+                return Result::Ok(None);
+            };
+            let page = page.await?;
+            let next = if page.len() < size as usize {
+                None
+            } else {
+                Some(current + 1)
+            };
+            Ok(Some((page, next)))
+        }
+    });
+
+    pages
+        .map_ok(|page| stream::iter(page.into_iter().map(Ok)))
+        .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_by_id() {
+        let records: Vec<u64> = (10..35).collect();
+        let fetch = move |from: u64| {
+            let page: Vec<u64> = records
+                .iter()
+                .copied()
+                .filter(|id| *id >= from)
+                .take(10)
+                .collect();
+            async move { Ok(page) }
+        };
+
+        let ids: Vec<u64> = by_id(0, 10, fetch, |id| *id).try_collect().await.unwrap();
+        assert_eq!(ids, (10..35).collect::<Vec<u64>>());
+    }
+
+    #[tokio::test]
+    async fn test_by_page() {
+        let records: Vec<u64> = (0..25).collect();
+        let fetch = move |current: u32| {
+            let page: Vec<u64> = records
+                .iter()
+                .copied()
+                .skip((current as usize - 1) * 10)
+                .take(10)
+                .collect();
+            async move { Ok(page) }
+        };
+
+        let ids: Vec<u64> = by_page(10, fetch).try_collect().await.unwrap();
+        assert_eq!(ids, (0..25).collect::<Vec<u64>>());
+    }
+}
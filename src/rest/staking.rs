@@ -0,0 +1,381 @@
+//! Staking API endpoints (SAPI).
+//!
+//! This module provides access to Binance staking SAPI endpoints for:
+//! - ETH staking (stake, redeem, account, quota)
+//! - WBETH wrapping
+//! - SOL staking (stake, redeem, account, quota)
+//! - Staking, redemption and rewards history
+
+use crate::client::Client;
+use crate::error::Result;
+use crate::models::margin::RecordsQueryResult;
+use crate::models::staking::{
+    EthRedeemResult, EthRedemptionRecord, EthRewardRecord, EthStakeResult, EthStakingAccount,
+    EthStakingQuota, EthStakingRecord, SolRedeemResult, SolRedemptionRecord, SolRewardRecord,
+    SolStakeResult, SolStakingAccount, SolStakingQuota, SolStakingRecord, WbethWrapRecord,
+    WbethWrapResult,
+};
+
+// SAPI endpoints.
+const SAPI_V2_ETH_STAKING_STAKE: &str = "/sapi/v2/eth-staking/eth/stake";
+const SAPI_V1_ETH_STAKING_REDEEM: &str = "/sapi/v1/eth-staking/eth/redeem";
+const SAPI_V2_ETH_STAKING_ACCOUNT: &str = "/sapi/v2/eth-staking/account";
+const SAPI_V1_ETH_STAKING_QUOTA: &str = "/sapi/v1/eth-staking/eth/quota";
+const SAPI_V1_ETH_STAKING_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/stakingHistory";
+const SAPI_V1_ETH_REDEMPTION_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/redemptionHistory";
+const SAPI_V1_ETH_REWARDS_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/rewardsHistory";
+const SAPI_V1_WBETH_WRAP: &str = "/sapi/v1/eth-staking/wbeth/wrap";
+const SAPI_V1_WBETH_WRAP_HISTORY: &str = "/sapi/v1/eth-staking/wbeth/history/wrapHistory";
+const SAPI_V1_WBETH_UNWRAP_HISTORY: &str = "/sapi/v1/eth-staking/wbeth/history/unwrapHistory";
+const SAPI_V1_SOL_STAKING_STAKE: &str = "/sapi/v1/sol-staking/sol/stake";
+const SAPI_V1_SOL_STAKING_REDEEM: &str = "/sapi/v1/sol-staking/sol/redeem";
+const SAPI_V1_SOL_STAKING_ACCOUNT: &str = "/sapi/v1/sol-staking/account";
+const SAPI_V1_SOL_STAKING_QUOTA: &str = "/sapi/v1/sol-staking/sol/quota";
+const SAPI_V1_SOL_STAKING_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/stakingHistory";
+const SAPI_V1_SOL_REDEMPTION_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/redemptionHistory";
+const SAPI_V1_SOL_REWARDS_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/bnsolRewardsHistory";
+
+/// Staking API client.
+///
+/// Provides access to Binance ETH and SOL staking SAPI endpoints.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Binance::new("api_key", "secret_key")?;
+///
+/// // Stake ETH for WBETH
+/// let result = client.staking().stake_eth("0.5").await?;
+/// println!("Received {} WBETH", result.wbeth_amount);
+///
+/// // Check SOL staking holdings
+/// let account = client.staking().sol_account().await?;
+/// println!("Holding {} SOL", account.holding_in_sol);
+/// ```
+#[derive(Clone)]
+pub struct Staking {
+    pub(crate) client: Client,
+}
+
+impl Staking {
+    /// Create a new Staking API client.
+    pub(crate) fn new(client: Client) -> Self {
+        Self { client }
+    }
+
+    // ETH Staking.
+
+    /// Stake ETH and receive WBETH.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of ETH to stake
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.staking().stake_eth("1.0").await?;
+    /// println!("Conversion ratio: {}", result.conversion_ratio);
+    /// ```
+    pub async fn stake_eth(&self, amount: &str) -> Result<EthStakeResult> {
+        let params = [("amount", amount)];
+        self.client
+            .post_signed(SAPI_V2_ETH_STAKING_STAKE, &params)
+            .await
+    }
+
+    /// Redeem BETH or WBETH for ETH.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount to redeem
+    /// * `asset` - Asset to redeem, "BETH" or "WBETH" (defaults to BETH)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.staking().redeem_eth("1.0", Some("WBETH")).await?;
+    /// println!("ETH arriving at {}", result.arrival_time);
+    /// ```
+    pub async fn redeem_eth(&self, amount: &str, asset: Option<&str>) -> Result<EthRedeemResult> {
+        let mut params: Vec<(&str, String)> = vec![("amount", amount.to_string())];
+
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_ETH_STAKING_REDEEM, &params_ref)
+            .await
+    }
+
+    /// Get the ETH staking account.
+    pub async fn eth_account(&self) -> Result<EthStakingAccount> {
+        self.client
+            .get_signed(SAPI_V2_ETH_STAKING_ACCOUNT, &[])
+            .await
+    }
+
+    /// Get the remaining personal ETH staking and redemption quota.
+    pub async fn eth_quota(&self) -> Result<EthStakingQuota> {
+        self.client.get_signed(SAPI_V1_ETH_STAKING_QUOTA, &[]).await
+    }
+
+    /// Get ETH staking history.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number, starting at 1 (optional)
+    /// * `size` - Page size, max 100 (optional)
+    pub async fn eth_staking_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<EthStakingRecord>> {
+        self.history(
+            SAPI_V1_ETH_STAKING_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    /// Get ETH redemption history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn eth_redemption_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<EthRedemptionRecord>> {
+        self.history(
+            SAPI_V1_ETH_REDEMPTION_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    /// Get BETH rewards distribution history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn eth_rewards_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<EthRewardRecord>> {
+        self.history(
+            SAPI_V1_ETH_REWARDS_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    // WBETH.
+
+    /// Wrap BETH into WBETH.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of BETH to wrap
+    pub async fn wrap_beth(&self, amount: &str) -> Result<WbethWrapResult> {
+        let params = [("amount", amount)];
+        self.client.post_signed(SAPI_V1_WBETH_WRAP, &params).await
+    }
+
+    /// Unwrap WBETH by redeeming it for ETH.
+    ///
+    /// Binance no longer offers a direct WBETH to BETH conversion, so this
+    /// is equivalent to [`redeem_eth`](Self::redeem_eth) with the WBETH asset.
+    pub async fn unwrap_wbeth(&self, amount: &str) -> Result<EthRedeemResult> {
+        self.redeem_eth(amount, Some("WBETH")).await
+    }
+
+    /// Get WBETH wrap history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn wbeth_wrap_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<WbethWrapRecord>> {
+        self.history(
+            SAPI_V1_WBETH_WRAP_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    /// Get WBETH unwrap history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn wbeth_unwrap_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<WbethWrapRecord>> {
+        self.history(
+            SAPI_V1_WBETH_UNWRAP_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    // SOL Staking.
+
+    /// Stake SOL and receive BNSOL.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of SOL to stake
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.staking().stake_sol("10.0").await?;
+    /// println!("Received {} BNSOL", result.bnsol_amount);
+    /// ```
+    pub async fn stake_sol(&self, amount: &str) -> Result<SolStakeResult> {
+        let params = [("amount", amount)];
+        self.client
+            .post_signed(SAPI_V1_SOL_STAKING_STAKE, &params)
+            .await
+    }
+
+    /// Redeem BNSOL for SOL.
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of BNSOL to redeem
+    pub async fn redeem_sol(&self, amount: &str) -> Result<SolRedeemResult> {
+        let params = [("amount", amount)];
+        self.client
+            .post_signed(SAPI_V1_SOL_STAKING_REDEEM, &params)
+            .await
+    }
+
+    /// Get the SOL staking account.
+    pub async fn sol_account(&self) -> Result<SolStakingAccount> {
+        self.client
+            .get_signed(SAPI_V1_SOL_STAKING_ACCOUNT, &[])
+            .await
+    }
+
+    /// Get the SOL staking quota and product details.
+    pub async fn sol_quota(&self) -> Result<SolStakingQuota> {
+        self.client.get_signed(SAPI_V1_SOL_STAKING_QUOTA, &[]).await
+    }
+
+    /// Get SOL staking history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn sol_staking_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<SolStakingRecord>> {
+        self.history(
+            SAPI_V1_SOL_STAKING_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    /// Get SOL redemption history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn sol_redemption_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<SolRedemptionRecord>> {
+        self.history(
+            SAPI_V1_SOL_REDEMPTION_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    /// Get BNSOL rewards history.
+    ///
+    /// See [`eth_staking_history`](Self::eth_staking_history) for the arguments.
+    pub async fn sol_rewards_history(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<SolRewardRecord>> {
+        self.history(
+            SAPI_V1_SOL_REWARDS_HISTORY,
+            start_time,
+            end_time,
+            current,
+            size,
+        )
+        .await
+    }
+
+    async fn history<T: serde::de::DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<RecordsQueryResult<T>> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client.get_signed(endpoint, &params_ref).await
+    }
+}
@@ -5,16 +5,24 @@
 //! - Coin information
 //! - Deposit/withdrawal operations
 //! - Asset management
+//! - Dust conversion
+//! - Asset dividends
 //! - Universal transfers
 
+use futures::Stream;
+
 use crate::client::Client;
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::models::wallet::{
     AccountSnapshot, AccountSnapshotType, AccountStatus, ApiKeyPermissions, ApiTradingStatus,
-    AssetDetail, CoinInfo, DepositAddress, DepositRecord, FundingAsset, SystemStatus, TradeFee,
-    TransferHistory, TransferResponse, UniversalTransferType, WalletBalance, WithdrawRecord,
-    WithdrawResponse,
+    AssetDetail, AssetDividendRecords, CoinInfo, ConvertAccountType, ConvertTransferHistory,
+    ConvertibleCoins, DepositAddress, DepositAddressEntry, DepositCreditResult, DepositRecord,
+    DustAssets, DustLog, DustTransferResult, FundingAsset, SystemStatus, TradeFee, TransferHistory,
+    TransferRecord, TransferResponse, UniversalTransferType, WalletBalance, WithdrawAddress,
+    WithdrawRecord, WithdrawResponse, WithdrawWalletType,
 };
+use crate::rest::pagination;
+use crate::types::QtyParam;
 
 // SAPI endpoints.
 const SAPI_V1_SYSTEM_STATUS: &str = "/sapi/v1/system/status";
@@ -22,11 +30,25 @@ const SAPI_V1_CAPITAL_CONFIG_GETALL: &str = "/sapi/v1/capital/config/getall";
 const SAPI_V1_ACCOUNT_SNAPSHOT: &str = "/sapi/v1/accountSnapshot";
 const SAPI_V1_CAPITAL_DEPOSIT_HISREC: &str = "/sapi/v1/capital/deposit/hisrec";
 const SAPI_V1_CAPITAL_DEPOSIT_ADDRESS: &str = "/sapi/v1/capital/deposit/address";
+const SAPI_V1_CAPITAL_DEPOSIT_ADDRESS_LIST: &str = "/sapi/v1/capital/deposit/address/list";
+const SAPI_V1_CAPITAL_DEPOSIT_CREDIT_APPLY: &str = "/sapi/v1/capital/deposit/credit-apply";
 const SAPI_V1_CAPITAL_WITHDRAW_APPLY: &str = "/sapi/v1/capital/withdraw/apply";
 const SAPI_V1_CAPITAL_WITHDRAW_HISTORY: &str = "/sapi/v1/capital/withdraw/history";
+const SAPI_V1_CAPITAL_WITHDRAW_ADDRESS_LIST: &str = "/sapi/v1/capital/withdraw/address/list";
+const SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLE_COINS: &str =
+    "/sapi/v1/capital/contract/convertible-coins";
 const SAPI_V1_ASSET_ASSET_DETAIL: &str = "/sapi/v1/asset/assetDetail";
 const SAPI_V1_ASSET_TRADE_FEE: &str = "/sapi/v1/asset/tradeFee";
+const SAPI_V1_ASSET_DUST: &str = "/sapi/v1/asset/dust";
+const SAPI_V1_ASSET_DUST_BTC: &str = "/sapi/v1/asset/dust-btc";
+const SAPI_V1_ASSET_DRIBBLET: &str = "/sapi/v1/asset/dribblet";
+const SAPI_V1_ASSET_ASSET_DIVIDEND: &str = "/sapi/v1/asset/assetDividend";
+const SAPI_V1_ASSET_CONVERT_TRANSFER_QUERY_BY_PAGE: &str =
+    "/sapi/v1/asset/convert-transfer/queryByPage";
 const SAPI_V1_ASSET_TRANSFER: &str = "/sapi/v1/asset/transfer";
+
+/// Maximum page size for universal transfer history.
+const MAX_TRANSFER_PAGE_SIZE: u32 = 100;
 const SAPI_V1_ASSET_GET_FUNDING_ASSET: &str = "/sapi/v1/asset/get-funding-asset";
 const SAPI_V1_ASSET_WALLET_BALANCE: &str = "/sapi/v1/asset/wallet/balance";
 const SAPI_V1_ACCOUNT_STATUS: &str = "/sapi/v1/account/status";
@@ -196,6 +218,86 @@ impl Wallet {
             .await
     }
 
+    /// Get the deposit addresses of a coin on every network.
+    ///
+    /// # Arguments
+    ///
+    /// * `coin` - Coin symbol (e.g., "USDT")
+    /// * `network` - Only return the address on this network (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let addresses = client.wallet().deposit_address_list("USDT", None).await?;
+    /// for entry in addresses {
+    ///     println!("{}: {} (default: {})", entry.coin, entry.address, entry.is_default == 1);
+    /// }
+    /// ```
+    pub async fn deposit_address_list(
+        &self,
+        coin: &str,
+        network: Option<&str>,
+    ) -> Result<Vec<DepositAddressEntry>> {
+        let mut params: Vec<(&str, String)> = vec![("coin", coin.to_string())];
+
+        if let Some(n) = network {
+            params.push(("network", n.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_CAPITAL_DEPOSIT_ADDRESS_LIST, &params_ref)
+            .await
+    }
+
+    /// Credit a deposit that is pending because of a suspended network or
+    /// a missing memo (one-click arrival).
+    ///
+    /// Identify the deposit by `deposit_id` or `tx_id`.
+    ///
+    /// # Arguments
+    ///
+    /// * `deposit_id` - Deposit record ID (optional)
+    /// * `tx_id` - Deposit transaction ID (optional)
+    /// * `sub_account_id` - Sub-account ID (optional)
+    /// * `sub_user_id` - Sub-user ID (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.wallet()
+    ///     .deposit_credit(None, Some("0xabc..."), None, None)
+    ///     .await?;
+    /// println!("Credited: {}", result.data);
+    /// ```
+    pub async fn deposit_credit(
+        &self,
+        deposit_id: Option<u64>,
+        tx_id: Option<&str>,
+        sub_account_id: Option<u64>,
+        sub_user_id: Option<u64>,
+    ) -> Result<DepositCreditResult> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(id) = deposit_id {
+            params.push(("depositId", id.to_string()));
+        }
+        if let Some(id) = tx_id {
+            params.push(("txId", id.to_string()));
+        }
+        if let Some(id) = sub_account_id {
+            params.push(("subAccountId", id.to_string()));
+        }
+        if let Some(id) = sub_user_id {
+            params.push(("subUserId", id.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .post_signed(SAPI_V1_CAPITAL_DEPOSIT_CREDIT_APPLY, &params_ref)
+            .await
+    }
+
     /// Get deposit history.
     ///
     /// # Arguments
@@ -266,6 +368,13 @@ impl Wallet {
     /// * `address_tag` - Secondary address identifier (memo/tag, optional)
     /// * `withdraw_order_id` - Client ID for the withdrawal (optional)
     ///
+    /// Use [`WithdrawBuilder`] and [`submit_withdraw`](Self::submit_withdraw)
+    /// for the remaining withdrawal parameters.
+    ///
+    /// If a [`WithdrawPolicy`](crate::WithdrawPolicy) is attached to the client,
+    /// withdrawals outside the policy fail with [`Error::WithdrawRefused`](crate::Error::WithdrawRefused)
+    /// without being sent.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -283,28 +392,124 @@ impl Wallet {
         address_tag: Option<&str>,
         withdraw_order_id: Option<&str>,
     ) -> Result<WithdrawResponse> {
-        let mut params: Vec<(&str, String)> = vec![
-            ("coin", coin.to_string()),
-            ("address", address.to_string()),
-            ("amount", amount.to_string()),
-        ];
-
+        let mut builder = WithdrawBuilder::new(coin, address, amount);
         if let Some(n) = network {
-            params.push(("network", n.to_string()));
+            builder = builder.network(n);
         }
         if let Some(tag) = address_tag {
-            params.push(("addressTag", tag.to_string()));
+            builder = builder.address_tag(tag);
         }
         if let Some(id) = withdraw_order_id {
-            params.push(("withdrawOrderId", id.to_string()));
+            builder = builder.withdraw_order_id(id);
         }
+        self.submit_withdraw(&builder.build()).await
+    }
 
+    /// Submit a withdrawal request built with [`WithdrawBuilder`].
+    ///
+    /// If a [`WithdrawPolicy`](crate::WithdrawPolicy) is attached to the client,
+    /// withdrawals outside the policy fail with [`Error::WithdrawRefused`](crate::Error::WithdrawRefused)
+    /// without being sent.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::{WithdrawBuilder, WithdrawWalletType};
+    ///
+    /// let request = WithdrawBuilder::new("USDT", "0x1234...", "100.0")
+    ///     .network("ETH")
+    ///     .withdraw_order_id("treasury-42")
+    ///     .wallet_type(WithdrawWalletType::Funding)
+    ///     .build();
+    /// let response = client.wallet().submit_withdraw(&request).await?;
+    /// ```
+    pub async fn submit_withdraw(&self, request: &NewWithdraw) -> Result<WithdrawResponse> {
+        let reserved = match self.client.withdraw_policy() {
+            Some(policy) => Some(policy.reserve(
+                &request.coin,
+                &request.address,
+                &request.amount,
+                request.network.as_deref(),
+                request.address_tag.as_deref(),
+            )?),
+            None => None,
+        };
+
+        let params = request.to_params();
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
-        self.client
+        let result = self
+            .client
             .post_signed(SAPI_V1_CAPITAL_WITHDRAW_APPLY, &params_ref)
+            .await;
+
+        // Only a rejected request frees the reserved amount: after a timeout
+        // or server error the withdrawal may still have been accepted
+        if let (Err(e), Some(policy), Some((day, amount))) =
+            (&result, self.client.withdraw_policy(), reserved)
+        {
+            if is_rejection(e) {
+                policy.release(&request.coin, day, amount);
+            }
+        }
+        result
+    }
+
+    /// Get the addresses saved in the withdrawal address book.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let addresses = client.wallet().withdraw_addresses().await?;
+    /// for address in addresses.iter().filter(|a| a.white_status) {
+    ///     println!("{} ({} on {}): {}", address.name, address.coin, address.network, address.address);
+    /// }
+    /// ```
+    pub async fn withdraw_addresses(&self) -> Result<Vec<WithdrawAddress>> {
+        self.client
+            .get_signed(SAPI_V1_CAPITAL_WITHDRAW_ADDRESS_LIST, &[])
+            .await
+    }
+
+    /// Get the stablecoins converted to BUSD on deposit and withdrawal.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let convertible = client.wallet().convertible_coins().await?;
+    /// if convertible.convert_enabled {
+    ///     println!("Auto-converted: {:?}", convertible.coins);
+    /// }
+    /// ```
+    pub async fn convertible_coins(&self) -> Result<ConvertibleCoins> {
+        self.client
+            .get_signed(SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLE_COINS, &[])
             .await
     }
 
+    /// Switch BUSD auto-conversion on or off for a stablecoin.
+    ///
+    /// # Arguments
+    ///
+    /// * `coin` - Stablecoin symbol (e.g., "USDC")
+    /// * `enable` - Whether deposits and withdrawals of the coin are converted
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// client.wallet().set_convertible_coin("USDC", false).await?;
+    /// ```
+    pub async fn set_convertible_coin(&self, coin: &str, enable: bool) -> Result<()> {
+        let params = [
+            ("coin", coin),
+            ("enable", if enable { "true" } else { "false" }),
+        ];
+        let _: serde_json::Value = self
+            .client
+            .post_signed(SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLE_COINS, &params)
+            .await?;
+        Ok(())
+    }
+
     /// Get withdrawal history.
     ///
     /// # Arguments
@@ -422,6 +627,170 @@ impl Wallet {
             .await
     }
 
+    // Dust Conversion.
+
+    /// Convert small balances to BNB.
+    ///
+    /// # Arguments
+    ///
+    /// * `assets` - Assets to convert
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let result = client.wallet().dust_transfer(&["ADA", "TRX"]).await?;
+    /// for item in &result.transfer_result {
+    ///     println!("{} {} -> {} BNB", item.amount, item.from_asset, item.transfered_amount);
+    /// }
+    /// ```
+    pub async fn dust_transfer(&self, assets: &[&str]) -> Result<DustTransferResult> {
+        let params: Vec<(&str, &str)> = assets.iter().map(|a| ("asset", *a)).collect();
+        self.client.post_signed(SAPI_V1_ASSET_DUST, &params).await
+    }
+
+    /// Get dust conversion history.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    pub async fn dust_log(
+        &self,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> Result<DustLog> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_ASSET_DRIBBLET, &params_ref)
+            .await
+    }
+
+    /// Get assets that can be converted to BNB with [`dust_transfer`](Self::dust_transfer).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let dust = client.wallet().dust_assets().await?;
+    /// let assets: Vec<&str> = dust.details.iter().map(|d| d.asset.as_str()).collect();
+    /// client.wallet().dust_transfer(&assets).await?;
+    /// ```
+    pub async fn dust_assets(&self) -> Result<DustAssets> {
+        self.client.post_signed(SAPI_V1_ASSET_DUST_BTC, &[]).await
+    }
+
+    // Asset Dividends.
+
+    /// Get asset dividend (distribution) records, newest first.
+    ///
+    /// # Arguments
+    ///
+    /// * `asset` - Filter by asset (optional)
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional, max 180 days after start)
+    /// * `limit` - Max records, default 20, max 500 (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let dividends = client.wallet().asset_dividend_record(Some("BNB"), None, None, None).await?;
+    /// for record in dividends.rows {
+    ///     println!("{} {} ({})", record.amount, record.asset, record.en_info);
+    /// }
+    /// ```
+    pub async fn asset_dividend_record(
+        &self,
+        asset: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<AssetDividendRecords> {
+        let mut params: Vec<(&str, String)> = vec![];
+
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
+        if let Some(s) = start_time {
+            params.push(("startTime", s.to_string()));
+        }
+        if let Some(e) = end_time {
+            params.push(("endTime", e.to_string()));
+        }
+        if let Some(l) = limit {
+            params.push(("limit", l.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_ASSET_ASSET_DIVIDEND, &params_ref)
+            .await
+    }
+
+    /// Get BUSD/stablecoin conversion history.
+    ///
+    /// The time window may not exceed 30 days.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_time` - Start timestamp
+    /// * `end_time` - End timestamp
+    /// * `asset` - Filter by asset converted from or to (optional)
+    /// * `account_type` - Filter by account (optional)
+    /// * `current` - Page number (default 1)
+    /// * `size` - Page size (default 10, max 100)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let history = client.wallet()
+    ///     .convert_transfer_history(start, end, Some("USDC"), None, None, Some(100))
+    ///     .await?;
+    /// for record in history.rows {
+    ///     println!("{} {} -> {} {}", record.deducted_amount, record.deducted_asset,
+    ///         record.target_amount, record.target_asset);
+    /// }
+    /// ```
+    pub async fn convert_transfer_history(
+        &self,
+        start_time: u64,
+        end_time: u64,
+        asset: Option<&str>,
+        account_type: Option<ConvertAccountType>,
+        current: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<ConvertTransferHistory> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("startTime", start_time.to_string()),
+            ("endTime", end_time.to_string()),
+        ];
+
+        if let Some(a) = asset {
+            params.push(("asset", a.to_string()));
+        }
+        if let Some(t) = account_type {
+            params.push(("accountType", t.as_str().to_string()));
+        }
+        if let Some(c) = current {
+            params.push(("current", c.to_string()));
+        }
+        if let Some(s) = size {
+            params.push(("size", s.to_string()));
+        }
+
+        let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        self.client
+            .get_signed(SAPI_V1_ASSET_CONVERT_TRANSFER_QUERY_BY_PAGE, &params_ref)
+            .await
+    }
+
     // Universal Transfer.
 
     /// Execute a universal transfer between accounts.
@@ -458,10 +827,8 @@ impl Wallet {
         from_symbol: Option<&str>,
         to_symbol: Option<&str>,
     ) -> Result<TransferResponse> {
-        let type_str = transfer_type.as_str().to_string();
-
         let mut params: Vec<(&str, String)> = vec![
-            ("type", type_str),
+            ("type", transfer_type.to_string()),
             ("asset", asset.to_string()),
             ("amount", amount.to_string()),
         ];
@@ -506,9 +873,61 @@ impl Wallet {
         current: Option<u32>,
         size: Option<u32>,
     ) -> Result<TransferHistory> {
-        let type_str = transfer_type.as_str().to_string();
+        self.universal_transfer_history(
+            transfer_type,
+            start_time,
+            end_time,
+            current,
+            size,
+            None,
+            None,
+        )
+        .await
+    }
 
-        let mut params: Vec<(&str, String)> = vec![("type", type_str)];
+    /// Get universal transfer history, including isolated margin transfers.
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer_type` - Type of transfer to query
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    /// * `current` - Page number (default 1)
+    /// * `size` - Page size (default 10, max 100)
+    /// * `from_symbol` - Source isolated margin symbol (required for
+    ///   `ISOLATEDMARGIN_MARGIN` and `ISOLATEDMARGIN_ISOLATEDMARGIN`)
+    /// * `to_symbol` - Target isolated margin symbol (required for
+    ///   `MARGIN_ISOLATEDMARGIN` and `ISOLATEDMARGIN_ISOLATEDMARGIN`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use binance_api_client::UniversalTransferType;
+    ///
+    /// let history = client.wallet()
+    ///     .universal_transfer_history(
+    ///         UniversalTransferType::MarginIsolatedMargin,
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         Some(100),
+    ///         None,
+    ///         Some("BTCUSDT"),
+    ///     )
+    ///     .await?;
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn universal_transfer_history(
+        &self,
+        transfer_type: UniversalTransferType,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        current: Option<u32>,
+        size: Option<u32>,
+        from_symbol: Option<&str>,
+        to_symbol: Option<&str>,
+    ) -> Result<TransferHistory> {
+        let mut params: Vec<(&str, String)> = vec![("type", transfer_type.to_string())];
 
         if let Some(st) = start_time {
             params.push(("startTime", st.to_string()));
@@ -522,6 +941,12 @@ impl Wallet {
         if let Some(s) = size {
             params.push(("size", s.to_string()));
         }
+        if let Some(from) = from_symbol {
+            params.push(("fromSymbol", from.to_string()));
+        }
+        if let Some(to) = to_symbol {
+            params.push(("toSymbol", to.to_string()));
+        }
 
         let params_ref: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
         self.client
@@ -529,6 +954,55 @@ impl Wallet {
             .await
     }
 
+    /// Get every universal transfer of a type, paginating automatically.
+    ///
+    /// Transfers are fetched in pages of 100 until a short page comes back.
+    /// The API only returns the last 6 months and limits each query to a
+    /// 7 day window, so pass `start_time` and `end_time` for older records.
+    ///
+    /// # Arguments
+    ///
+    /// * `transfer_type` - Type of transfer to query
+    /// * `start_time` - Start timestamp (optional)
+    /// * `end_time` - End timestamp (optional)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use futures::TryStreamExt;
+    /// use binance_api_client::UniversalTransferType;
+    ///
+    /// let transfers: Vec<_> = client
+    ///     .wallet()
+    ///     .universal_transfer_history_paginated(UniversalTransferType::MainFunding, None, None)
+    ///     .try_collect()
+    ///     .await?;
+    /// ```
+    pub fn universal_transfer_history_paginated(
+        &self,
+        transfer_type: UniversalTransferType,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+    ) -> impl Stream<Item = Result<TransferRecord>> + Send + 'static {
+        let wallet = self.clone();
+        let fetch = move |current| {
+            let wallet = wallet.clone();
+            async move {
+                wallet
+                    .transfer_history(
+                        transfer_type,
+                        start_time,
+                        end_time,
+                        Some(current),
+                        Some(MAX_TRANSFER_PAGE_SIZE),
+                    )
+                    .await
+                    .map(|history| history.rows)
+            }
+        };
+        pagination::by_page(MAX_TRANSFER_PAGE_SIZE, fetch)
+    }
+
     // Wallet Balances.
 
     /// Get funding wallet balance.
@@ -607,6 +1081,9 @@ impl Wallet {
     /// if status.data.is_locked {
     ///     println!("Trading is locked!");
     /// }
+    /// for (symbol, indicator) in status.data.indicators_near_trigger(0.8) {
+    ///     println!("{} {}: {} of {}", symbol, indicator.indicator, indicator.value, indicator.trigger_value);
+    /// }
     /// ```
     pub async fn api_trading_status(&self) -> Result<ApiTradingStatus> {
         self.client
@@ -629,3 +1106,245 @@ impl Wallet {
             .await
     }
 }
+
+/// Builder for withdrawal requests.
+#[derive(Debug, Clone)]
+pub struct WithdrawBuilder {
+    coin: String,
+    address: String,
+    amount: String,
+    network: Option<String>,
+    address_tag: Option<String>,
+    withdraw_order_id: Option<String>,
+    transaction_fee_flag: Option<bool>,
+    name: Option<String>,
+    wallet_type: Option<WithdrawWalletType>,
+}
+
+impl WithdrawBuilder {
+    /// Create a new withdrawal builder.
+    ///
+    /// # Arguments
+    ///
+    /// * `coin` - Coin symbol
+    /// * `address` - Withdrawal address
+    /// * `amount` - Amount to withdraw
+    pub fn new(coin: &str, address: &str, amount: impl Into<QtyParam>) -> Self {
+        Self {
+            coin: coin.to_string(),
+            address: address.to_string(),
+            amount: amount.into().into(),
+            network: None,
+            address_tag: None,
+            withdraw_order_id: None,
+            transaction_fee_flag: None,
+            name: None,
+            wallet_type: None,
+        }
+    }
+
+    /// Set the network (defaults to the coin's default network).
+    pub fn network(mut self, network: &str) -> Self {
+        self.network = Some(network.to_string());
+        self
+    }
+
+    /// Set the secondary address identifier (memo/tag).
+    pub fn address_tag(mut self, tag: &str) -> Self {
+        self.address_tag = Some(tag.to_string());
+        self
+    }
+
+    /// Set a client ID for the withdrawal.
+    pub fn withdraw_order_id(mut self, id: &str) -> Self {
+        self.withdraw_order_id = Some(id.to_string());
+        self
+    }
+
+    /// Charge the fee to the destination instead of the source for
+    /// internal transfers.
+    pub fn transaction_fee_flag(mut self, flag: bool) -> Self {
+        self.transaction_fee_flag = Some(flag);
+        self
+    }
+
+    /// Set a label for the address, saved to the address book.
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the wallet to withdraw from (defaults to the account's
+    /// withdrawal wallet setting).
+    pub fn wallet_type(mut self, wallet_type: WithdrawWalletType) -> Self {
+        self.wallet_type = Some(wallet_type);
+        self
+    }
+
+    /// Build the withdrawal request.
+    pub fn build(self) -> NewWithdraw {
+        NewWithdraw {
+            coin: self.coin,
+            address: self.address,
+            amount: self.amount,
+            network: self.network,
+            address_tag: self.address_tag,
+            withdraw_order_id: self.withdraw_order_id,
+            transaction_fee_flag: self.transaction_fee_flag,
+            name: self.name,
+            wallet_type: self.wallet_type,
+        }
+    }
+}
+
+/// Withdrawal request parameters.
+#[derive(Debug, Clone)]
+pub struct NewWithdraw {
+    coin: String,
+    address: String,
+    amount: String,
+    network: Option<String>,
+    address_tag: Option<String>,
+    withdraw_order_id: Option<String>,
+    transaction_fee_flag: Option<bool>,
+    name: Option<String>,
+    wallet_type: Option<WithdrawWalletType>,
+}
+
+impl NewWithdraw {
+    pub(crate) fn to_params(&self) -> Vec<(&'static str, String)> {
+        let mut params: Vec<(&str, String)> = vec![
+            ("coin", self.coin.clone()),
+            ("address", self.address.clone()),
+            ("amount", self.amount.clone()),
+        ];
+
+        if let Some(ref n) = self.network {
+            params.push(("network", n.clone()));
+        }
+        if let Some(ref tag) = self.address_tag {
+            params.push(("addressTag", tag.clone()));
+        }
+        if let Some(ref id) = self.withdraw_order_id {
+            params.push(("withdrawOrderId", id.clone()));
+        }
+        if let Some(flag) = self.transaction_fee_flag {
+            params.push(("transactionFeeFlag", flag.to_string()));
+        }
+        if let Some(ref name) = self.name {
+            params.push(("name", urlencoding::encode(name).into_owned()));
+        }
+        if let Some(wallet_type) = self.wallet_type {
+            params.push(("walletType", (wallet_type as u8).to_string()));
+        }
+
+        params
+    }
+}
+
+// Check whether the exchange definitively rejected a request, as opposed to
+// a failure where the request may still have been executed.
+fn is_rejection(error: &Error) -> bool {
+    match error.inner() {
+        // -1006 and -1007 mean the execution status is unknown
+        Error::Api { code, .. } if *code < 0 => !matches!(code, -1006 | -1007),
+        Error::Api { code, .. } => (400..500).contains(code),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_withdraw_builder_params() {
+        let request = WithdrawBuilder::new("USDT", "0xabc", "100.0")
+            .network("ETH")
+            .transaction_fee_flag(true)
+            .name("cold storage")
+            .wallet_type(WithdrawWalletType::Funding)
+            .build();
+
+        assert_eq!(
+            request.to_params(),
+            vec![
+                ("coin", "USDT".to_string()),
+                ("address", "0xabc".to_string()),
+                ("amount", "100.0".to_string()),
+                ("network", "ETH".to_string()),
+                ("transactionFeeFlag", "true".to_string()),
+                ("name", "cold%20storage".to_string()),
+                ("walletType", "1".to_string()),
+            ]
+        );
+    }
+
+    // Serve every request with the same HTTP response.
+    async fn mock_server(status: &'static str, body: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        url
+    }
+
+    async fn withdraw_with_response(status: &'static str, body: &'static str) -> (Result<()>, f64) {
+        let config = crate::Config::builder()
+            .rest_api_endpoint(mock_server(status, body).await)
+            .build();
+        let credentials = crate::Credentials::new("api_key", "secret_key");
+        let policy = crate::WithdrawPolicy::new()
+            .allow_address("USDT", "0xabc", Some("ETH"))
+            .daily_limit("USDT", 100.0);
+        let client = crate::Binance::with_credentials(config, credentials)
+            .unwrap()
+            .with_withdraw_policy(policy);
+
+        let request = WithdrawBuilder::new("USDT", "0xabc", "60.0")
+            .network("ETH")
+            .build();
+        let result = client.wallet().submit_withdraw(&request).await.map(|_| ());
+        let policy = client.client().withdraw_policy().unwrap();
+        (result, policy.used_today("USDT").unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_withdraw_reservation_on_failure() {
+        // Rejected by the exchange, the reservation is released
+        let (result, used) = withdraw_with_response(
+            "400 Bad Request",
+            r#"{"code": -4026, "msg": "Insufficient balance."}"#,
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(used, 0.0);
+
+        // Execution status unknown, the reservation is kept
+        let (result, used) = withdraw_with_response(
+            "400 Bad Request",
+            r#"{"code": -1007, "msg": "Timeout waiting for response from backend server."}"#,
+        )
+        .await;
+        assert!(result.is_err());
+        assert_eq!(used, 60.0);
+
+        // Server error, the reservation is kept
+        let (result, used) = withdraw_with_response("503 Service Unavailable", "").await;
+        assert!(result.is_err());
+        assert_eq!(used, 60.0);
+    }
+}
@@ -0,0 +1,254 @@
+//! Runtime placement of WebSocket read loops.
+//!
+//! By default, background tasks are spawned onto the ambient tokio runtime
+//! (the one calling into the client). Latency-sensitive users can move the
+//! WebSocket read loops onto a runtime of their own with [`RuntimeHints`],
+//! set through [`ConfigBuilder::runtime_hints`](crate::config::ConfigBuilder::runtime_hints).
+//!
+//! The constructors encode the recommended setups:
+//!
+//! - [`RuntimeHints::dedicated_reader`] starts a thread running a
+//!   current-thread runtime that does nothing but read sockets. This avoids
+//!   work-stealing and keeps message parsing on one warm core.
+//! - [`RuntimeHints::current_thread`] uses a current-thread runtime the
+//!   application already drives on its own thread.
+//! - [`RuntimeHints::multi_thread`] uses a separate multi-threaded runtime,
+//!   isolating the read loops from application work on the ambient runtime.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::{Handle, RuntimeFlavor};
+use tokio::task::JoinHandle;
+
+use crate::{Error, Result};
+
+/// Name of the thread started by [`RuntimeHints::dedicated_reader`].
+const DEDICATED_READER_THREAD: &str = "binance-ws-reader";
+
+/// Kind of runtime the WebSocket read loops run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RuntimeKind {
+    /// The runtime calling into the client.
+    #[default]
+    Ambient,
+    /// A dedicated thread started by the crate.
+    DedicatedReader,
+    /// A user-provided current-thread runtime.
+    CurrentThread,
+    /// A user-provided multi-threaded runtime.
+    MultiThread,
+}
+
+/// Where the crate spawns WebSocket read loops.
+///
+/// Two hints are equal if they are the same kind and share the same runtime
+/// (clones of one hint are equal).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{Binance, Config, RuntimeHints};
+///
+/// let config = Config::builder()
+///     .runtime_hints(RuntimeHints::dedicated_reader()?)
+///     .build();
+/// let client = Binance::with_config(config, None::<(&str, &str)>)?;
+///
+/// // The read loop of this stream runs on the "binance-ws-reader" thread
+/// let mut conn = client.websocket().connect_with_reconnect("btcusdt@bookTicker").await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeHints {
+    kind: RuntimeKind,
+    handle: Option<Arc<Handle>>,
+}
+
+impl RuntimeHints {
+    /// Spawn onto the ambient runtime (the default).
+    pub fn ambient() -> Self {
+        Self::default()
+    }
+
+    /// Start a dedicated thread running a current-thread runtime for the
+    /// WebSocket read loops.
+    ///
+    /// The thread lives for the rest of the process; create the hints once
+    /// and share them between clients.
+    pub fn dedicated_reader() -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .thread_name(DEDICATED_READER_THREAD)
+            .build()?;
+        let handle = runtime.handle().clone();
+
+        std::thread::Builder::new()
+            .name(DEDICATED_READER_THREAD.to_string())
+            .spawn(move || runtime.block_on(std::future::pending::<()>()))?;
+
+        Ok(Self {
+            kind: RuntimeKind::DedicatedReader,
+            handle: Some(Arc::new(handle)),
+        })
+    }
+
+    /// Use a current-thread runtime owned by the application.
+    ///
+    /// The runtime only makes progress while its thread is inside
+    /// `block_on`, so it should be driven from a thread of its own.
+    ///
+    /// Returns an error if `handle` is not a current-thread runtime.
+    pub fn current_thread(handle: Handle) -> Result<Self> {
+        Self::with_flavor(
+            handle,
+            RuntimeFlavor::CurrentThread,
+            RuntimeKind::CurrentThread,
+        )
+    }
+
+    /// Use a multi-threaded runtime owned by the application.
+    ///
+    /// Returns an error if `handle` is not a multi-threaded runtime.
+    pub fn multi_thread(handle: Handle) -> Result<Self> {
+        Self::with_flavor(handle, RuntimeFlavor::MultiThread, RuntimeKind::MultiThread)
+    }
+
+    fn with_flavor(handle: Handle, flavor: RuntimeFlavor, kind: RuntimeKind) -> Result<Self> {
+        if handle.runtime_flavor() != flavor {
+            return Err(Error::InvalidConfig(format!(
+                "expected a {:?} runtime, got {:?}",
+                flavor,
+                handle.runtime_flavor()
+            )));
+        }
+        Ok(Self {
+            kind,
+            handle: Some(Arc::new(handle)),
+        })
+    }
+
+    /// Get the kind of runtime the read loops run on.
+    pub fn kind(&self) -> RuntimeKind {
+        self.kind
+    }
+
+    /// Get the handle of the runtime, or `None` for the ambient runtime.
+    pub fn handle(&self) -> Option<&Handle> {
+        self.handle.as_deref()
+    }
+
+    /// Spawn a task onto the selected runtime.
+    pub(crate) fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        match &self.handle {
+            Some(handle) => handle.spawn(task),
+            None => tokio::spawn(task),
+        }
+    }
+
+    /// Run a future to completion on the selected runtime.
+    ///
+    /// Sockets opened inside `task` are registered with that runtime's reactor.
+    /// The task is aborted if the returned future is dropped, and fails with
+    /// [`Error::TaskFailed`] if it panics or the runtime shuts down.
+    pub(crate) async fn run<F>(&self, task: F) -> Result<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        if self.handle.is_none() {
+            return Ok(task.await);
+        }
+        let mut task = AbortOnDrop(self.spawn(task));
+        match (&mut task.0).await {
+            Ok(output) => Ok(output),
+            Err(e) if e.is_panic() => {
+                let panic = e.into_panic();
+                let message = panic
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| panic.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(Error::TaskFailed(format!("task panicked: {}", message)))
+            }
+            Err(_) => Err(Error::TaskFailed("task was cancelled".to_string())),
+        }
+    }
+}
+
+// Aborts a spawned task when dropped, so it does not outlive its caller.
+struct AbortOnDrop<T>(JoinHandle<T>);
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl PartialEq for RuntimeHints {
+    fn eq(&self, other: &Self) -> bool {
+        let same_runtime = match (&self.handle, &other.handle) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        };
+        self.kind == other.kind && same_runtime
+    }
+}
+
+impl Eq for RuntimeHints {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flavor_is_enforced() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let hints = RuntimeHints::current_thread(runtime.handle().clone()).unwrap();
+        assert_eq!(hints.kind(), RuntimeKind::CurrentThread);
+        assert_eq!(hints, hints.clone());
+        assert_ne!(hints, RuntimeHints::ambient());
+
+        assert!(matches!(
+            RuntimeHints::multi_thread(runtime.handle().clone()),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_dedicated_reader() {
+        let hints = RuntimeHints::dedicated_reader().unwrap();
+        assert_eq!(hints.kind(), RuntimeKind::DedicatedReader);
+
+        let thread = hints
+            .run(async { std::thread::current().name().map(str::to_string) })
+            .await
+            .unwrap();
+        assert_eq!(thread.as_deref(), Some(DEDICATED_READER_THREAD));
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_failed_tasks() {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .build()
+            .unwrap();
+        let hints = RuntimeHints::multi_thread(runtime.handle().clone()).unwrap();
+
+        let result = hints.run(async { panic!("boom") }).await;
+        assert!(matches!(result, Err(Error::TaskFailed(message)) if message.contains("boom")));
+
+        // The runtime shutting down cancels the task
+        let pending = tokio::spawn(async move { hints.run(std::future::pending::<()>()).await });
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        runtime.shutdown_background();
+        assert!(matches!(pending.await.unwrap(), Err(Error::TaskFailed(_))));
+    }
+}
@@ -0,0 +1,199 @@
+//! End-to-end checks for CI.
+//!
+//! Requires the `scenarios` feature.
+//!
+//! Each scenario exercises one part of the client against the live API and
+//! returns a [`ScenarioReport`], so downstream CI can verify credentials and
+//! connectivity with one call per check:
+//!
+//! ```rust,ignore
+//! use binance_api_client::{Binance, Config, scenarios};
+//!
+//! let client = Binance::with_config(Config::testnet(), Some((api_key, secret_key)))?;
+//!
+//! scenarios::place_and_cancel_order(&client, "BTCUSDT").await?;
+//! scenarios::depth_cache_sync(&client, "BTCUSDT").await?;
+//! let report = scenarios::run_user_stream(&client, Duration::from_secs(30)).await?;
+//! println!("{}: {} events", report.name, report.events);
+//! ```
+
+use std::time::{Duration, Instant};
+
+use tokio::time::timeout;
+
+use crate::config::TESTNET_REST_API_ENDPOINT;
+use crate::models::{Symbol, SymbolFilter};
+use crate::rest::{NewOrder, OrderBuilder};
+use crate::types::{OrderSide, OrderType, TimeInForce};
+use crate::ws::{DepthCacheConfig, DepthCacheManager, UserDataStreamManager};
+use crate::{Binance, Error, Result};
+
+/// Share of the best bid the probe order is placed at, far enough below the
+/// market not to fill.
+const PROBE_PRICE_RATIO: f64 = 0.9;
+
+/// Margin over the minimum notional for the probe order.
+const PROBE_NOTIONAL_MARGIN: f64 = 1.5;
+
+/// Outcome of a successful scenario.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioReport {
+    /// Scenario name.
+    pub name: &'static str,
+    /// Time the scenario took.
+    pub elapsed: Duration,
+    /// Number of events received, for streaming scenarios.
+    pub events: usize,
+}
+
+impl ScenarioReport {
+    fn new(name: &'static str, start: Instant, events: usize) -> Self {
+        Self {
+            name,
+            elapsed: start.elapsed(),
+            events,
+        }
+    }
+}
+
+/// Place a minimum size limit buy below the market and cancel it.
+///
+/// Only runs against the testnet; fails with [`Error::InvalidConfig`] for any
+/// other REST endpoint.
+pub async fn place_and_cancel_order(client: &Binance, symbol: &str) -> Result<ScenarioReport> {
+    let start = Instant::now();
+    if client.config().rest_api_endpoint != TESTNET_REST_API_ENDPOINT {
+        return Err(Error::InvalidConfig(
+            "scenario orders only run against the testnet".to_string(),
+        ));
+    }
+
+    let info = client.market().exchange_info_for_symbols(&[symbol]).await?;
+    let symbol_info = info
+        .symbols
+        .first()
+        .ok_or_else(|| Error::ScenarioFailed(format!("unknown symbol: {}", symbol)))?;
+    let ticker = client.market().book_ticker(symbol).await?;
+
+    let order = probe_order(symbol_info, ticker.bid_price);
+    order.validate(symbol_info, None)?;
+
+    let placed = client.account().create_order(&order).await?;
+    client
+        .account()
+        .cancel_order(symbol, Some(placed.order_id), None)
+        .await?;
+    Ok(ScenarioReport::new("place_and_cancel_order", start, 0))
+}
+
+/// Open a depth cache, wait for it to sync and check the book is not crossed.
+pub async fn depth_cache_sync(client: &Binance, symbol: &str) -> Result<ScenarioReport> {
+    let start = Instant::now();
+    let manager =
+        DepthCacheManager::new(client.clone(), symbol, DepthCacheConfig::default()).await?;
+    let result = async {
+        manager.wait_for_sync().await?;
+        let cache = manager.get_cache().await;
+        match (cache.best_bid(), cache.best_ask()) {
+            (Some((bid, _)), Some((ask, _))) if bid < ask => Ok(()),
+            (bid, ask) => Err(Error::ScenarioFailed(format!(
+                "depth cache for {} is not consistent: bid {:?}, ask {:?}",
+                symbol, bid, ask
+            ))),
+        }
+    }
+    .await;
+    manager.stop();
+    result.map(|()| ScenarioReport::new("depth_cache_sync", start, 0))
+}
+
+/// Run the user data stream for `duration` and count the events received.
+///
+/// Fails if the stream cannot be started, reports an error or ends early.
+pub async fn run_user_stream(client: &Binance, duration: Duration) -> Result<ScenarioReport> {
+    let start = Instant::now();
+    let mut manager = UserDataStreamManager::new(client.clone()).await?;
+    let mut events = 0;
+    let result = loop {
+        let remaining = duration.saturating_sub(start.elapsed());
+        match timeout(remaining, manager.next()).await {
+            Err(_) => break Ok(()),
+            Ok(Some(Ok(_))) => events += 1,
+            Ok(Some(Err(e))) => break Err(e),
+            Ok(None) => {
+                break Err(Error::WebSocket(
+                    tokio_tungstenite::tungstenite::Error::ConnectionClosed,
+                ));
+            }
+        }
+    };
+    manager.stop();
+    result.map(|()| ScenarioReport::new("run_user_stream", start, events))
+}
+
+// A limit buy below `bid` just above the symbol's minimum size.
+fn probe_order(symbol: &Symbol, bid: f64) -> NewOrder {
+    let price: f64 = symbol
+        .round_price(bid * PROBE_PRICE_RATIO)
+        .parse()
+        .unwrap_or_default();
+
+    let min_qty = match symbol.lot_size() {
+        Some(SymbolFilter::LotSize { min_qty, .. }) => *min_qty,
+        _ => 0.0,
+    };
+    let min_notional = match (symbol.notional(), symbol.min_notional()) {
+        (Some(SymbolFilter::Notional { min_notional, .. }), _)
+        | (_, Some(SymbolFilter::MinNotional { min_notional, .. })) => *min_notional,
+        _ => 0.0,
+    };
+    let quantity = if price > 0.0 {
+        (min_notional * PROBE_NOTIONAL_MARGIN / price).max(min_qty)
+    } else {
+        min_qty
+    };
+
+    OrderBuilder::new(&symbol.symbol, OrderSide::Buy, OrderType::Limit)
+        .price(symbol.round_price(price))
+        .quantity(symbol.round_qty(quantity))
+        .time_in_force(TimeInForce::GTC)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_probe_order() {
+        let symbol: Symbol = serde_json::from_value(serde_json::json!({
+            "symbol": "BTCUSDT", "status": "TRADING", "baseAsset": "BTC",
+            "baseAssetPrecision": 8, "quoteAsset": "USDT", "quotePrecision": 8,
+            "quoteAssetPrecision": 8, "orderTypes": ["LIMIT", "MARKET"],
+            "icebergAllowed": true, "ocoAllowed": true, "isSpotTradingAllowed": true,
+            "isMarginTradingAllowed": false, "permissions": ["SPOT"],
+            "filters": [
+                {"filterType": "PRICE_FILTER", "minPrice": "0.01", "maxPrice": "1000000.00",
+                 "tickSize": "0.01"},
+                {"filterType": "LOT_SIZE", "minQty": "0.00001", "maxQty": "9000.00",
+                 "stepSize": "0.00001"},
+                {"filterType": "NOTIONAL", "minNotional": "5.00", "applyMinToMarket": true,
+                 "maxNotional": "9000000.00", "applyMaxToMarket": false,
+                 "avgPriceMins": 5}
+            ]
+        }))
+        .unwrap();
+
+        let order = probe_order(&symbol, 50000.0);
+        let params = order.to_params();
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("price"), Some("45000.00"));
+        assert_eq!(get("quantity"), Some("0.00016"));
+        assert!(order.validate(&symbol, None).is_ok());
+    }
+}
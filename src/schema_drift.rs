@@ -0,0 +1,395 @@
+//! Detection of response fields the models do not cover.
+//!
+//! With [`ConfigBuilder::detect_schema_drift`] enabled, every successful REST
+//! response is parsed through a deserializer that records the fields the
+//! model ignored and enum values that fell back to an `Other`/`Unknown`
+//! variant. They are collected in a [`SchemaDriftReport`], available from
+//! [`Binance::schema_drift`] and logged at shutdown with the `tracing`
+//! feature.
+//!
+//! Fields inside untagged or flattened types are not checked.
+//!
+//! [`ConfigBuilder::detect_schema_drift`]: crate::ConfigBuilder::detect_schema_drift
+//! [`Binance::schema_drift`]: crate::Binance::schema_drift
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+use serde::de::value::BorrowedStrDeserializer;
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, EnumAccess, IntoDeserializer, MapAccess,
+    SeqAccess, VariantAccess, Visitor,
+};
+use serde_json::Value;
+
+/// Unknown fields and enum values seen in responses, per endpoint.
+///
+/// Field paths use `.` between object keys and `[]` for array elements, e.g.
+/// `balances[].newField`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaDriftReport {
+    /// Fields not covered by the response models.
+    pub unknown_fields: BTreeMap<String, BTreeSet<String>>,
+    /// Enum values not covered by the response models, as `path=value`.
+    pub unknown_values: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl SchemaDriftReport {
+    /// Check whether no drift was detected.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty() && self.unknown_values.is_empty()
+    }
+}
+
+/// Collects schema drift across the responses of a client.
+#[derive(Debug, Default)]
+pub struct SchemaDriftDetector {
+    report: Mutex<SchemaDriftReport>,
+}
+
+impl SchemaDriftDetector {
+    /// Create an empty detector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a raw response, recording the fields and enum values the model
+    /// did not cover.
+    ///
+    /// Nothing is recorded if the response fails to parse.
+    pub fn deserialize<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        raw: &Value,
+    ) -> serde_json::Result<T> {
+        let drift = RefCell::new(Drift::default());
+        let parsed = T::deserialize(Tracked {
+            value: raw,
+            path: String::new(),
+            drift: &drift,
+        })?;
+
+        let Drift { fields, values } = drift.into_inner();
+        if fields.is_empty() && values.is_empty() {
+            return Ok(parsed);
+        }
+
+        let mut report = self.report.lock().unwrap_or_else(|e| e.into_inner());
+        if !fields.is_empty() {
+            report
+                .unknown_fields
+                .entry(endpoint.to_string())
+                .or_default()
+                .extend(fields);
+        }
+        if !values.is_empty() {
+            report
+                .unknown_values
+                .entry(endpoint.to_string())
+                .or_default()
+                .extend(values);
+        }
+        Ok(parsed)
+    }
+
+    /// Get a snapshot of the drift collected so far.
+    pub fn report(&self) -> SchemaDriftReport {
+        self.report
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Log the collected drift as warnings.
+    pub fn log(&self) {
+        let report = self.report();
+        for (endpoint, fields) in &report.unknown_fields {
+            tracing::warn!(endpoint, ?fields, "Response fields not covered by models");
+        }
+        for (endpoint, values) in &report.unknown_values {
+            tracing::warn!(endpoint, ?values, "Enum values not covered by models");
+        }
+    }
+}
+
+// Drift found while parsing one response.
+#[derive(Default)]
+struct Drift {
+    fields: BTreeSet<String>,
+    values: BTreeSet<String>,
+}
+
+// Deserializer over a JSON value that records the paths of ignored fields
+// and of enum values not among the enum's variants.
+struct Tracked<'a, 'de> {
+    value: &'de Value,
+    path: String,
+    drift: &'a RefCell<Drift>,
+}
+
+impl<'a, 'de> Tracked<'a, 'de> {
+    fn child(&self, value: &'de Value, key: &str) -> Self {
+        let path = if self.path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", self.path, key)
+        };
+        Self {
+            value,
+            path,
+            drift: self.drift,
+        }
+    }
+}
+
+impl<'a, 'de> Deserializer<'de> for Tracked<'a, 'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(b) => visitor.visit_bool(*b),
+            Value::Number(n) => {
+                if let Some(n) = n.as_u64() {
+                    visitor.visit_u64(n)
+                } else if let Some(n) = n.as_i64() {
+                    visitor.visit_i64(n)
+                } else {
+                    visitor.visit_f64(n.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(s) => visitor.visit_borrowed_str(s),
+            Value::Array(items) => visitor.visit_seq(TrackedSeq {
+                items: items.iter(),
+                path: format!("{}[]", self.path),
+                drift: self.drift,
+            }),
+            Value::Object(map) => visitor.visit_map(TrackedMap {
+                entries: map.iter(),
+                value: None,
+                parent: self,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        match self.value {
+            Value::String(s) => {
+                if !variants.contains(&s.as_str()) {
+                    let value = format!("{}={}", self.path, s);
+                    self.drift.borrow_mut().values.insert(value);
+                }
+                visitor.visit_enum(s.as_str().into_deserializer())
+            }
+            Value::Object(map) if map.len() == 1 => {
+                let (variant, value) = map.iter().next().expect("one entry");
+                let value = self.child(value, variant);
+                visitor.visit_enum(TrackedEnum { variant, value })
+            }
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        // Null fields carry no information
+        if !self.value.is_null() {
+            self.drift.borrow_mut().fields.insert(self.path);
+        }
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+struct TrackedSeq<'a, 'de> {
+    items: std::slice::Iter<'de, Value>,
+    path: String,
+    drift: &'a RefCell<Drift>,
+}
+
+impl<'a, 'de> SeqAccess<'de> for TrackedSeq<'a, 'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> serde_json::Result<Option<T::Value>> {
+        match self.items.next() {
+            Some(value) => seed
+                .deserialize(Tracked {
+                    value,
+                    path: self.path.clone(),
+                    drift: self.drift,
+                })
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+struct TrackedMap<'a, 'de> {
+    entries: serde_json::map::Iter<'de>,
+    value: Option<(&'de String, &'de Value)>,
+    parent: Tracked<'a, 'de>,
+}
+
+impl<'a, 'de> MapAccess<'de> for TrackedMap<'a, 'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> serde_json::Result<Option<K::Value>> {
+        match self.entries.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(BorrowedStrDeserializer::new(key))
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> serde_json::Result<V::Value> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| de::Error::custom("value is missing"))?;
+        seed.deserialize(self.parent.child(value, key))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
+
+struct TrackedEnum<'a, 'de> {
+    variant: &'de str,
+    value: Tracked<'a, 'de>,
+}
+
+impl<'a, 'de> EnumAccess<'de> for TrackedEnum<'a, 'de> {
+    type Error = serde_json::Error;
+    type Variant = Tracked<'a, 'de>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> serde_json::Result<(V::Value, Self::Variant)> {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.variant))?;
+        Ok((variant, self.value))
+    }
+}
+
+impl<'a, 'de> VariantAccess<'de> for Tracked<'a, 'de> {
+    type Error = serde_json::Error;
+
+    fn unit_variant(self) -> serde_json::Result<()> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> serde_json::Result<T::Value> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AccountInfo;
+
+    #[test]
+    fn test_schema_drift_detector() {
+        let raw = serde_json::json!({
+            "makerCommission": 10, "takerCommission": 10, "buyerCommission": 0,
+            "sellerCommission": 0, "canTrade": true, "canWithdraw": true,
+            "canDeposit": true, "updateTime": 1, "accountType": "SPOT",
+            "balances": [{"asset": "BTC", "free": "1.0", "locked": "0.0", "staked": "0.5"}],
+            "permissions": ["SPOT", "NEW_WALLET"],
+            "newFlag": true,
+            "optional": null
+        });
+        let detector = SchemaDriftDetector::new();
+        let parsed: AccountInfo = detector.deserialize("/api/v3/account", &raw).unwrap();
+        assert_eq!(parsed.balances.len(), 1);
+
+        let report = detector.report();
+        let fields = &report.unknown_fields["/api/v3/account"];
+        assert!(fields.contains("newFlag"));
+        assert!(fields.contains("balances[].staked"));
+        assert!(!fields.contains("optional"));
+        assert_eq!(
+            report.unknown_values["/api/v3/account"],
+            BTreeSet::from(["permissions[]=NEW_WALLET".to_string()])
+        );
+
+        // Fields read under an alias are covered
+        #[derive(serde::Deserialize)]
+        struct Aliased {
+            #[serde(alias = "qty")]
+            _quantity: String,
+        }
+        let aliased = SchemaDriftDetector::new();
+        let _: Aliased = aliased
+            .deserialize("/test", &serde_json::json!({"qty": "1.0"}))
+            .unwrap();
+        assert!(aliased.report().is_empty());
+
+        let clean = SchemaDriftDetector::new();
+        let _: serde_json::Value = clean
+            .deserialize("/api/v3/ping", &serde_json::json!({"a": 1}))
+            .unwrap();
+        assert!(clean.report().is_empty());
+    }
+}
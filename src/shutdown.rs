@@ -0,0 +1,293 @@
+//! Graceful shutdown coordination.
+//!
+//! Every [`Client`](crate::Client) owns a [`ShutdownHandle`] that is shared
+//! with all of its clones and with the WebSocket helpers created from it.
+//! Background tasks (reconnecting sockets, depth cache managers, user data
+//! stream keep-alive loops) are spawned through the handle and watch it for
+//! the shutdown signal, and order requests register themselves while they
+//! are in flight. [`ShutdownHandle::shutdown`] stops accepting new orders,
+//! waits for in-flight orders to complete, lets background tasks close their
+//! connections and listen keys, and aborts anything still running when the
+//! timeout expires.
+//!
+//! Long-running loops can be spawned with
+//! [`ShutdownHandle::spawn_supervised`], which restarts them after a panic
+//! (see [`supervisor`](crate::supervisor)).
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::{Notify, broadcast, watch};
+use tokio::task::JoinHandle;
+use tokio::time::{Instant, timeout_at};
+
+use crate::runtime::RuntimeHints;
+use crate::supervisor::{self, RestartPolicy, TASK_EVENT_CAPACITY, TaskEvent};
+use crate::{Error, Result};
+
+/// Default time allowed for a graceful shutdown.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Outcome of a shutdown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ShutdownReport {
+    /// Background tasks that exited on their own.
+    pub tasks_completed: usize,
+    /// Background tasks that were aborted after the timeout.
+    pub tasks_aborted: usize,
+    /// Order requests still in flight when the timeout expired.
+    pub orders_pending: usize,
+}
+
+impl ShutdownReport {
+    /// Check if everything stopped before the timeout.
+    pub fn is_clean(&self) -> bool {
+        self.tasks_aborted == 0 && self.orders_pending == 0
+    }
+}
+
+#[derive(Debug)]
+struct Inner {
+    signal: watch::Sender<bool>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+    in_flight: AtomicUsize,
+    idle: Notify,
+    events: broadcast::Sender<TaskEvent>,
+}
+
+/// Shared shutdown signal and task tracker.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let client = Binance::from_env()?;
+/// let mut depth = DepthCacheManager::new(client.clone(), "BTCUSDT", Default::default()).await?;
+///
+/// // ...
+///
+/// let report = client.shutdown().await;
+/// assert!(report.is_clean());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    inner: Arc<Inner>,
+}
+
+impl Default for ShutdownHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownHandle {
+    /// Create a new shutdown handle.
+    pub fn new() -> Self {
+        let (signal, _) = watch::channel(false);
+        let (events, _) = broadcast::channel(TASK_EVENT_CAPACITY);
+        Self {
+            inner: Arc::new(Inner {
+                signal,
+                tasks: Mutex::new(Vec::new()),
+                in_flight: AtomicUsize::new(0),
+                idle: Notify::new(),
+                events,
+            }),
+        }
+    }
+
+    /// Check if shutdown has been requested.
+    pub fn is_shutdown(&self) -> bool {
+        *self.inner.signal.borrow()
+    }
+
+    /// Wait until shutdown is requested.
+    pub async fn cancelled(&self) {
+        let mut rx = self.inner.signal.subscribe();
+        let _ = rx.wait_for(|stopped| *stopped).await;
+    }
+
+    /// Spawn a background task that is awaited on shutdown.
+    ///
+    /// The task should watch [`cancelled`](Self::cancelled) and exit promptly,
+    /// otherwise it is aborted when the shutdown timeout expires.
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_on(&RuntimeHints::default(), task);
+    }
+
+    /// Spawn a background task onto the runtime selected by `runtime`.
+    pub(crate) fn spawn_on<F>(&self, runtime: &RuntimeHints, task: F)
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = runtime.spawn(task);
+        let mut tasks = self.inner.tasks.lock().unwrap_or_else(|e| e.into_inner());
+        tasks.retain(|h| !h.is_finished());
+        tasks.push(handle);
+    }
+
+    /// Spawn a background task that is restarted with backoff if it panics.
+    ///
+    /// `factory` is called to create the task future on every (re)start. The
+    /// task is not restarted once it returns normally, once `policy` runs out
+    /// of restarts, or after shutdown has been requested. Panics and restarts
+    /// are reported on [`task_events`](Self::task_events).
+    pub fn spawn_supervised<F, Fut>(&self, name: &str, policy: RestartPolicy, factory: F)
+    where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_supervised_on(&RuntimeHints::default(), name, policy, factory);
+    }
+
+    /// Spawn a supervised background task onto the runtime selected by `runtime`.
+    pub(crate) fn spawn_supervised_on<F, Fut>(
+        &self,
+        runtime: &RuntimeHints,
+        name: &str,
+        policy: RestartPolicy,
+        factory: F,
+    ) where
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        self.spawn_on(
+            runtime,
+            supervisor::supervise(
+                name.to_string(),
+                policy,
+                self.clone(),
+                self.inner.events.clone(),
+                factory,
+            ),
+        );
+    }
+
+    /// Subscribe to lifecycle events of supervised tasks.
+    pub fn task_events(&self) -> broadcast::Receiver<TaskEvent> {
+        self.inner.events.subscribe()
+    }
+
+    /// Get the number of background tasks still running.
+    pub fn active_tasks(&self) -> usize {
+        let tasks = self.inner.tasks.lock().unwrap_or_else(|e| e.into_inner());
+        tasks.iter().filter(|h| !h.is_finished()).count()
+    }
+
+    /// Get the number of order requests currently in flight.
+    pub fn in_flight_orders(&self) -> usize {
+        self.inner.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Register an order request. Fails once shutdown has been requested.
+    pub(crate) fn begin_order(&self) -> Result<InFlightOrder> {
+        self.inner.in_flight.fetch_add(1, Ordering::SeqCst);
+        let guard = InFlightOrder {
+            inner: self.inner.clone(),
+        };
+        if self.is_shutdown() {
+            return Err(Error::ShuttingDown);
+        }
+        Ok(guard)
+    }
+
+    /// Request shutdown and wait for in-flight orders and background tasks.
+    ///
+    /// New order requests are refused with [`Error::ShuttingDown`] as soon as
+    /// this is called. Tasks still running when `timeout` expires are aborted.
+    pub async fn shutdown(&self, timeout: Duration) -> ShutdownReport {
+        self.inner.signal.send_replace(true);
+        let deadline = Instant::now() + timeout;
+
+        let drained = timeout_at(deadline, self.wait_for_orders()).await.is_ok();
+
+        let tasks = {
+            let mut tasks = self.inner.tasks.lock().unwrap_or_else(|e| e.into_inner());
+            std::mem::take(&mut *tasks)
+        };
+
+        let mut report = ShutdownReport::default();
+        for mut handle in tasks {
+            match timeout_at(deadline, &mut handle).await {
+                Ok(_) => report.tasks_completed += 1,
+                Err(_) => {
+                    handle.abort();
+                    report.tasks_aborted += 1;
+                }
+            }
+        }
+
+        if !drained {
+            report.orders_pending = self.in_flight_orders();
+        }
+        report
+    }
+
+    async fn wait_for_orders(&self) {
+        loop {
+            let idle = self.inner.idle.notified();
+            if self.in_flight_orders() == 0 {
+                return;
+            }
+            idle.await;
+        }
+    }
+}
+
+/// Guard that marks an order request as in flight until dropped.
+#[derive(Debug)]
+pub(crate) struct InFlightOrder {
+    inner: Arc<Inner>,
+}
+
+impl Drop for InFlightOrder {
+    fn drop(&mut self) {
+        if self.inner.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.inner.idle.notify_waiters();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_stops_tasks() {
+        let shutdown = ShutdownHandle::new();
+
+        let watcher = shutdown.clone();
+        shutdown.spawn(async move { watcher.cancelled().await });
+        shutdown.spawn(std::future::pending());
+        assert_eq!(shutdown.active_tasks(), 2);
+
+        let report = shutdown.shutdown(Duration::from_millis(50)).await;
+        assert!(shutdown.is_shutdown());
+        assert_eq!(report.tasks_completed, 1);
+        assert_eq!(report.tasks_aborted, 1);
+        assert!(!report.is_clean());
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_orders() {
+        let shutdown = ShutdownHandle::new();
+        let order = shutdown.begin_order().unwrap();
+        assert_eq!(shutdown.in_flight_orders(), 1);
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            drop(order);
+        });
+
+        let report = shutdown.shutdown(Duration::from_secs(5)).await;
+        assert!(report.is_clean());
+        assert_eq!(shutdown.in_flight_orders(), 0);
+
+        assert!(matches!(shutdown.begin_order(), Err(Error::ShuttingDown)));
+        assert_eq!(shutdown.in_flight_orders(), 0);
+    }
+}
@@ -0,0 +1,550 @@
+//! Wallet statement export.
+//!
+//! [`StatementExporter`] pulls deposits, withdrawals, universal transfers,
+//! dust conversions and asset dividends over a date range, walking each
+//! endpoint's time window and pagination limits, and merges them into a
+//! single chronological statement that can be written as CSV.
+
+use std::io::Write;
+
+use crate::models::wallet::{
+    AssetDividendRecord, DepositRecord, DustLogEntry, TransferRecord, UniversalTransferType,
+    WithdrawRecord,
+};
+use crate::{Binance, Result};
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+// Longest time range accepted by each history endpoint.
+const DEPOSIT_WINDOW_MS: u64 = 90 * DAY_MS;
+const WITHDRAW_WINDOW_MS: u64 = 90 * DAY_MS;
+const TRANSFER_WINDOW_MS: u64 = 30 * DAY_MS;
+const DUST_WINDOW_MS: u64 = 90 * DAY_MS;
+const DIVIDEND_WINDOW_MS: u64 = 180 * DAY_MS;
+
+// Largest page size of each history endpoint.
+const DEPOSIT_PAGE_SIZE: u32 = 1000;
+const WITHDRAW_PAGE_SIZE: u32 = 1000;
+const TRANSFER_PAGE_SIZE: u32 = 100;
+const DIVIDEND_PAGE_SIZE: u32 = 500;
+
+/// CSV header written by [`write_csv`].
+pub const CSV_HEADER: &str = "time,timestamp,kind,asset,amount,fee,status,reference,description";
+
+/// Kind of statement entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatementEntryKind {
+    /// Crypto deposit.
+    Deposit,
+    /// Crypto withdrawal.
+    Withdrawal,
+    /// Universal transfer between wallets.
+    Transfer,
+    /// Dust conversion to BNB.
+    Dust,
+    /// Asset dividend or distribution.
+    Dividend,
+}
+
+impl std::fmt::Display for StatementEntryKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Deposit => "DEPOSIT",
+            Self::Withdrawal => "WITHDRAWAL",
+            Self::Transfer => "TRANSFER",
+            Self::Dust => "DUST",
+            Self::Dividend => "DIVIDEND",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A single line of a wallet statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatementEntry {
+    /// Time of the entry (milliseconds since the epoch).
+    pub time: u64,
+    /// Kind of entry.
+    pub kind: StatementEntryKind,
+    /// Asset.
+    pub asset: String,
+    /// Amount, negative when the asset left the account or was converted.
+    ///
+    /// Transfers move assets between wallets of the same account and are
+    /// always positive.
+    pub amount: f64,
+    /// Fee charged, in `asset`.
+    pub fee: f64,
+    /// Status reported by the endpoint.
+    pub status: String,
+    /// Transaction reference (transaction hash, transfer or dividend ID).
+    pub reference: String,
+    /// Human readable description.
+    pub description: String,
+}
+
+impl StatementEntry {
+    /// Build an entry from a deposit record.
+    pub fn from_deposit(record: &DepositRecord) -> Self {
+        Self {
+            time: record.insert_time,
+            kind: StatementEntryKind::Deposit,
+            asset: record.coin.clone(),
+            amount: record.amount,
+            fee: 0.0,
+            status: format!("{:?}", record.status),
+            reference: record.tx_id.clone(),
+            description: format!("{} deposit", record.network),
+        }
+    }
+
+    /// Build an entry from a withdrawal record.
+    pub fn from_withdrawal(record: &WithdrawRecord) -> Self {
+        Self {
+            time: parse_utc_datetime(&record.apply_time).unwrap_or_default(),
+            kind: StatementEntryKind::Withdrawal,
+            asset: record.coin.clone(),
+            amount: -record.amount,
+            fee: record.transaction_fee,
+            status: format!("{:?}", record.status),
+            reference: record.tx_id.clone().unwrap_or_else(|| record.id.clone()),
+            description: format!("{} withdrawal to {}", record.network, record.address),
+        }
+    }
+
+    /// Build an entry from a universal transfer record.
+    pub fn from_transfer(record: &TransferRecord) -> Self {
+        Self {
+            time: record.timestamp,
+            kind: StatementEntryKind::Transfer,
+            asset: record.asset.clone(),
+            amount: record.amount,
+            fee: 0.0,
+            status: record.status.clone(),
+            reference: record.tran_id.to_string(),
+            description: record.transfer_type.as_str().to_string(),
+        }
+    }
+
+    /// Build entries from a dust conversion.
+    ///
+    /// Each converted asset produces a debit of the asset and a credit of
+    /// BNB, with the service charge recorded as the BNB fee.
+    pub fn from_dust(entry: &DustLogEntry) -> Vec<Self> {
+        let mut entries = Vec::with_capacity(entry.user_asset_dribblet_details.len() * 2);
+        for detail in &entry.user_asset_dribblet_details {
+            let reference = detail.trans_id.to_string();
+            entries.push(Self {
+                time: detail.operate_time,
+                kind: StatementEntryKind::Dust,
+                asset: detail.from_asset.clone(),
+                amount: -detail.amount,
+                fee: 0.0,
+                status: String::new(),
+                reference: reference.clone(),
+                description: "converted to BNB".to_string(),
+            });
+            entries.push(Self {
+                time: detail.operate_time,
+                kind: StatementEntryKind::Dust,
+                asset: "BNB".to_string(),
+                amount: detail.transfered_amount,
+                fee: detail.service_charge_amount,
+                status: String::new(),
+                reference,
+                description: format!("converted from {}", detail.from_asset),
+            });
+        }
+        entries
+    }
+
+    /// Build an entry from an asset dividend record.
+    pub fn from_dividend(record: &AssetDividendRecord) -> Self {
+        Self {
+            time: record.div_time,
+            kind: StatementEntryKind::Dividend,
+            asset: record.asset.clone(),
+            amount: record.amount,
+            fee: 0.0,
+            status: String::new(),
+            reference: record.tran_id.to_string(),
+            description: record.en_info.clone(),
+        }
+    }
+}
+
+/// Exports wallet history as a single chronological statement.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::StatementExporter;
+/// use std::fs::File;
+///
+/// let exporter = StatementExporter::new(client.clone());
+///
+/// // Everything in 2024
+/// let file = File::create("statement-2024.csv")?;
+/// let rows = exporter.export_csv(1704067200000, 1735689600000, file).await?;
+/// println!("Wrote {} rows", rows);
+/// ```
+#[derive(Clone)]
+pub struct StatementExporter {
+    client: Binance,
+    transfer_types: Vec<UniversalTransferType>,
+}
+
+impl StatementExporter {
+    /// Create an exporter for the common spot, funding, margin and futures transfers.
+    pub fn new(client: Binance) -> Self {
+        Self {
+            client,
+            transfer_types: vec![
+                UniversalTransferType::MainFunding,
+                UniversalTransferType::FundingMain,
+                UniversalTransferType::MainMargin,
+                UniversalTransferType::MarginMain,
+                UniversalTransferType::MainUmfuture,
+                UniversalTransferType::UmfutureMain,
+            ],
+        }
+    }
+
+    /// Set the universal transfer types to include.
+    ///
+    /// Transfer history is queried per type, so each type adds requests.
+    pub fn transfer_types(mut self, transfer_types: Vec<UniversalTransferType>) -> Self {
+        self.transfer_types = transfer_types;
+        self
+    }
+
+    /// Fetch all entries between `start_time` and `end_time`, oldest first.
+    pub async fn entries(&self, start_time: u64, end_time: u64) -> Result<Vec<StatementEntry>> {
+        let mut entries = Vec::new();
+
+        for record in self.deposits(start_time, end_time).await? {
+            entries.push(StatementEntry::from_deposit(&record));
+        }
+        for record in self.withdrawals(start_time, end_time).await? {
+            entries.push(StatementEntry::from_withdrawal(&record));
+        }
+        for record in self.transfers(start_time, end_time).await? {
+            entries.push(StatementEntry::from_transfer(&record));
+        }
+        for entry in self.dust(start_time, end_time).await? {
+            entries.extend(StatementEntry::from_dust(&entry));
+        }
+        for record in self.dividends(start_time, end_time).await? {
+            entries.push(StatementEntry::from_dividend(&record));
+        }
+
+        entries.retain(|e| e.time >= start_time && e.time <= end_time);
+        entries.sort_by_key(|e| e.time);
+        Ok(entries)
+    }
+
+    /// Fetch all entries between `start_time` and `end_time` and write them as CSV.
+    ///
+    /// Returns the number of rows written, excluding the header.
+    pub async fn export_csv<W: Write>(
+        &self,
+        start_time: u64,
+        end_time: u64,
+        writer: W,
+    ) -> Result<usize> {
+        let entries = self.entries(start_time, end_time).await?;
+        write_csv(&entries, writer)?;
+        Ok(entries.len())
+    }
+
+    async fn deposits(&self, start_time: u64, end_time: u64) -> Result<Vec<DepositRecord>> {
+        let wallet = self.client.wallet();
+        let mut records = Vec::new();
+        for (start, end) in windows(start_time, end_time, DEPOSIT_WINDOW_MS) {
+            let mut offset = 0;
+            loop {
+                let page = wallet
+                    .deposit_history(
+                        None,
+                        None,
+                        Some(start),
+                        Some(end),
+                        Some(offset),
+                        Some(DEPOSIT_PAGE_SIZE),
+                    )
+                    .await?;
+                let len = page.len() as u32;
+                records.extend(page);
+                if len < DEPOSIT_PAGE_SIZE {
+                    break;
+                }
+                offset += len;
+            }
+        }
+        Ok(records)
+    }
+
+    async fn withdrawals(&self, start_time: u64, end_time: u64) -> Result<Vec<WithdrawRecord>> {
+        let wallet = self.client.wallet();
+        let mut records = Vec::new();
+        for (start, end) in windows(start_time, end_time, WITHDRAW_WINDOW_MS) {
+            let mut offset = 0;
+            loop {
+                let page = wallet
+                    .withdraw_history(
+                        None,
+                        None,
+                        None,
+                        Some(start),
+                        Some(end),
+                        Some(offset),
+                        Some(WITHDRAW_PAGE_SIZE),
+                    )
+                    .await?;
+                let len = page.len() as u32;
+                records.extend(page);
+                if len < WITHDRAW_PAGE_SIZE {
+                    break;
+                }
+                offset += len;
+            }
+        }
+        Ok(records)
+    }
+
+    async fn transfers(&self, start_time: u64, end_time: u64) -> Result<Vec<TransferRecord>> {
+        let wallet = self.client.wallet();
+        let mut records = Vec::new();
+        for &transfer_type in &self.transfer_types {
+            for (start, end) in windows(start_time, end_time, TRANSFER_WINDOW_MS) {
+                let mut current = 1;
+                loop {
+                    let page = wallet
+                        .transfer_history(
+                            transfer_type,
+                            Some(start),
+                            Some(end),
+                            Some(current),
+                            Some(TRANSFER_PAGE_SIZE),
+                        )
+                        .await?;
+                    let len = page.rows.len() as u32;
+                    records.extend(page.rows);
+                    if len < TRANSFER_PAGE_SIZE {
+                        break;
+                    }
+                    current += 1;
+                }
+            }
+        }
+        Ok(records)
+    }
+
+    async fn dust(&self, start_time: u64, end_time: u64) -> Result<Vec<DustLogEntry>> {
+        let wallet = self.client.wallet();
+        let mut entries = Vec::new();
+        for (start, end) in windows(start_time, end_time, DUST_WINDOW_MS) {
+            let log = wallet.dust_log(Some(start), Some(end)).await?;
+            entries.extend(log.user_asset_dribblets);
+        }
+        Ok(entries)
+    }
+
+    async fn dividends(&self, start_time: u64, end_time: u64) -> Result<Vec<AssetDividendRecord>> {
+        let wallet = self.client.wallet();
+        let mut records = Vec::new();
+        for (start, end) in windows(start_time, end_time, DIVIDEND_WINDOW_MS) {
+            // Records are returned newest first, so page backwards from the end.
+            let mut end = end;
+            loop {
+                let page = wallet
+                    .asset_dividend_record(None, Some(start), Some(end), Some(DIVIDEND_PAGE_SIZE))
+                    .await?;
+                let len = page.rows.len() as u32;
+                let oldest = page.rows.iter().map(|r| r.div_time).min();
+                records.extend(page.rows);
+                match oldest {
+                    Some(oldest) if len >= DIVIDEND_PAGE_SIZE && oldest > start => {
+                        end = oldest - 1;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        Ok(records)
+    }
+}
+
+/// Write statement entries as CSV, including the [`CSV_HEADER`] line.
+pub fn write_csv<W: Write>(entries: &[StatementEntry], mut writer: W) -> std::io::Result<()> {
+    writeln!(writer, "{}", CSV_HEADER)?;
+    for entry in entries {
+        writeln!(
+            writer,
+            "{},{},{},{},{},{},{},{},{}",
+            format_utc_datetime(entry.time),
+            entry.time,
+            entry.kind,
+            csv_field(&entry.asset),
+            entry.amount,
+            entry.fee,
+            csv_field(&entry.status),
+            csv_field(&entry.reference),
+            csv_field(&entry.description),
+        )?;
+    }
+    writer.flush()
+}
+
+// Split [start, end] into consecutive windows no longer than `span`.
+fn windows(start: u64, end: u64, span: u64) -> Vec<(u64, u64)> {
+    let mut windows = Vec::new();
+    let mut from = start;
+    while from <= end {
+        let to = from.saturating_add(span - 1).min(end);
+        windows.push((from, to));
+        if to == u64::MAX {
+            break;
+        }
+        from = to + 1;
+    }
+    windows
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Parse "YYYY-MM-DD HH:MM:SS" (UTC) into milliseconds since the epoch.
+fn parse_utc_datetime(value: &str) -> Option<u64> {
+    let (date, time) = value.trim().split_once(' ')?;
+    let mut date = date.split('-').map(|p| p.parse::<i64>());
+    let (year, month, day) = (date.next()?.ok()?, date.next()?.ok()?, date.next()?.ok()?);
+    let mut time = time.split(':').map(|p| p.parse::<i64>());
+    let (hour, minute, second) = (time.next()?.ok()?, time.next()?.ok()?, time.next()?.ok()?);
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + hour * 3_600 + minute * 60 + second;
+    u64::try_from(seconds).ok().map(|s| s * 1000)
+}
+
+// Format milliseconds since the epoch as an ISO 8601 UTC timestamp.
+fn format_utc_datetime(time: u64) -> String {
+    let seconds = (time / 1000) as i64;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let secs = seconds.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+        time % 1000
+    )
+}
+
+// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+// Proleptic Gregorian date for days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_windows() {
+        assert_eq!(windows(0, 9, 5), vec![(0, 4), (5, 9)]);
+        assert_eq!(windows(0, 10, 5), vec![(0, 4), (5, 9), (10, 10)]);
+        assert_eq!(windows(3, 3, 5), vec![(3, 3)]);
+        assert!(windows(5, 4, 5).is_empty());
+    }
+
+    #[test]
+    fn test_utc_datetime_round_trip() {
+        let time = parse_utc_datetime("2019-10-12 11:12:02").unwrap();
+        assert_eq!(time, 1570878722000);
+        assert_eq!(format_utc_datetime(time + 5), "2019-10-12T11:12:02.005Z");
+        assert_eq!(
+            format_utc_datetime(951782400000),
+            "2000-02-29T00:00:00.000Z"
+        );
+        assert_eq!(parse_utc_datetime("not a date"), None);
+    }
+
+    #[test]
+    fn test_write_csv() {
+        let withdrawal: WithdrawRecord = serde_json::from_str(
+            r#"{
+                "address": "0x94df8b352de7f46f64b01d3666bf6e936e44ce60",
+                "amount": "8.91000000",
+                "applyTime": "2019-10-12 11:12:02",
+                "coin": "USDT",
+                "id": "b6ae22b3aa844210a7041aee7589627c",
+                "withdrawOrderId": "WITHDRAWtest123",
+                "network": "ETH",
+                "transferType": 0,
+                "status": 6,
+                "transactionFee": "0.004",
+                "confirmNo": 3,
+                "info": "The address is not valid. Please confirm with the recipient",
+                "txId": "0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268"
+            }"#,
+        )
+        .unwrap();
+        let dividend = StatementEntry {
+            time: 1570878722001,
+            kind: StatementEntryKind::Dividend,
+            asset: "BNB".to_string(),
+            amount: 0.5,
+            fee: 0.0,
+            status: String::new(),
+            reference: "2968885920".to_string(),
+            description: "Launchpool, \"BNB Vault\"".to_string(),
+        };
+
+        let entries = vec![StatementEntry::from_withdrawal(&withdrawal), dividend];
+        let mut out = Vec::new();
+        write_csv(&entries, &mut out).unwrap();
+
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "2019-10-12T11:12:02.000Z,1570878722000,WITHDRAWAL,USDT,-8.91,0.004,Completed,\
+             0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268,\
+             ETH withdrawal to 0x94df8b352de7f46f64b01d3666bf6e936e44ce60"
+        );
+        assert_eq!(
+            lines[2],
+            "2019-10-12T11:12:02.001Z,1570878722001,DIVIDEND,BNB,0.5,0,,2968885920,\
+             \"Launchpool, \"\"BNB Vault\"\"\""
+        );
+    }
+}
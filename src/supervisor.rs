@@ -0,0 +1,243 @@
+//! Supervision of background tasks.
+//!
+//! Long-running loops (depth cache sync, user data stream keep-alive,
+//! reconnecting WebSocket readers) are spawned through
+//! [`ShutdownHandle::spawn_supervised`](crate::ShutdownHandle::spawn_supervised).
+//! If a loop panics, the supervisor catches the panic, waits with exponential
+//! backoff and starts the loop again. Panics and restarts are reported as
+//! [`TaskEvent`]s, which can be received from
+//! [`Binance::task_events`](crate::Binance::task_events).
+
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::time::{Duration, Instant};
+
+use futures::FutureExt;
+use tokio::sync::broadcast;
+use tokio::time::sleep;
+
+use crate::shutdown::ShutdownHandle;
+
+/// Capacity of the task event channel.
+pub(crate) const TASK_EVENT_CAPACITY: usize = 64;
+
+/// Restart behaviour for a supervised task.
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    /// Maximum number of consecutive restarts before giving up.
+    pub max_restarts: u32,
+    /// Delay before the first restart.
+    pub base_delay: Duration,
+    /// Maximum delay between restarts.
+    ///
+    /// A task that runs for longer than this before panicking is considered
+    /// healthy again and its backoff starts over.
+    pub max_delay: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 10,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// Backoff delay before the given restart attempt (starting at 1).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Lifecycle event of a supervised task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TaskEvent {
+    /// The task panicked.
+    Panicked {
+        /// Task name.
+        task: String,
+        /// Panic message.
+        message: String,
+    },
+    /// The task is being restarted after a panic.
+    Restarted {
+        /// Task name.
+        task: String,
+        /// Consecutive restart attempt, starting at 1.
+        attempt: u32,
+        /// Delay waited before restarting.
+        delay: Duration,
+    },
+    /// The task exceeded its restart budget and will not be restarted.
+    GaveUp {
+        /// Task name.
+        task: String,
+        /// Number of restarts attempted.
+        restarts: u32,
+    },
+}
+
+/// Run `factory` until it completes normally, restarting it after panics.
+pub(crate) async fn supervise<F, Fut>(
+    name: String,
+    policy: RestartPolicy,
+    shutdown: ShutdownHandle,
+    events: broadcast::Sender<TaskEvent>,
+    mut factory: F,
+) where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()>,
+{
+    let mut attempt = 0u32;
+
+    loop {
+        let started = Instant::now();
+        let task = factory();
+        #[cfg(feature = "tracing")]
+        let task = tracing::Instrument::instrument(
+            task,
+            tracing::info_span!("binance.task", task = %name, restart = attempt),
+        );
+        let Err(panic) = AssertUnwindSafe(task).catch_unwind().await else {
+            return;
+        };
+
+        let message = panic_message(panic.as_ref());
+        tracing::error!(task = %name, panic = %message, "Background task panicked");
+        let _ = events.send(TaskEvent::Panicked {
+            task: name.clone(),
+            message,
+        });
+
+        if started.elapsed() > policy.max_delay {
+            attempt = 0;
+        }
+        if attempt >= policy.max_restarts || shutdown.is_shutdown() {
+            let _ = events.send(TaskEvent::GaveUp {
+                task: name,
+                restarts: attempt,
+            });
+            return;
+        }
+        attempt += 1;
+
+        let delay = policy.delay(attempt);
+        tokio::select! {
+            _ = shutdown.cancelled() => return,
+            _ = sleep(delay) => {}
+        }
+
+        tracing::warn!(task = %name, attempt, "Restarting background task");
+        let _ = events.send(TaskEvent::Restarted {
+            task: name.clone(),
+            attempt,
+            delay,
+        });
+    }
+}
+
+fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = panic.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = panic.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_restart_policy_delay() {
+        let policy = RestartPolicy {
+            max_restarts: 5,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+        };
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(3), Duration::from_millis(400));
+        assert_eq!(policy.delay(4), Duration::from_millis(500));
+        assert_eq!(policy.delay(40), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_supervised_task_restarts_after_panic() {
+        let shutdown = ShutdownHandle::new();
+        let mut events = shutdown.task_events();
+        let runs = Arc::new(AtomicU32::new(0));
+
+        let counter = runs.clone();
+        let policy = RestartPolicy {
+            max_restarts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+        };
+        shutdown.spawn_supervised("flaky", policy, move || {
+            let counter = counter.clone();
+            async move {
+                if counter.fetch_add(1, Ordering::SeqCst) < 2 {
+                    panic!("boom");
+                }
+            }
+        });
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while shutdown.active_tasks() > 0 {
+                sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+
+        assert_eq!(
+            events.recv().await.unwrap(),
+            TaskEvent::Panicked {
+                task: "flaky".to_string(),
+                message: "boom".to_string(),
+            }
+        );
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            TaskEvent::Restarted { attempt: 1, .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_supervised_task_gives_up() {
+        let shutdown = ShutdownHandle::new();
+        let mut events = shutdown.task_events();
+        let policy = RestartPolicy {
+            max_restarts: 1,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(10),
+        };
+        shutdown.spawn_supervised("broken", policy, || async { panic!("always") });
+
+        let mut last = None;
+        while let Ok(event) = events.recv().await {
+            let done = matches!(event, TaskEvent::GaveUp { .. });
+            last = Some(event);
+            if done {
+                break;
+            }
+        }
+        assert_eq!(
+            last,
+            Some(TaskEvent::GaveUp {
+                task: "broken".to_string(),
+                restarts: 1,
+            })
+        );
+    }
+}
@@ -0,0 +1,378 @@
+//! Automatic sweeping of idle balances into Simple Earn.
+//!
+//! [`EarnSweeper`] moves spot (or funding wallet) balances above a reserve
+//! into Simple Earn flexible products, either once with
+//! [`sweep_once`](EarnSweeper::sweep_once) or periodically with
+//! [`start`](EarnSweeper::start). Before placing orders,
+//! [`ensure_spot_balance`](EarnSweeper::ensure_spot_balance) redeems enough
+//! from Earn to cover a required spot balance.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio::sync::mpsc;
+
+use crate::models::earn::EarnAccount;
+use crate::supervisor::RestartPolicy;
+use crate::{Binance, Error, Result};
+
+/// Smallest amount step accepted by Simple Earn.
+const AMOUNT_DECIMALS: i32 = 8;
+
+/// Sweep rule for a single asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepRule {
+    /// Asset to sweep.
+    pub asset: String,
+    /// Balance to keep in the source wallet.
+    pub reserve: f64,
+    /// Smallest amount worth sweeping.
+    pub min_amount: f64,
+    /// Wallet to sweep from (spot or funding).
+    pub source: EarnAccount,
+}
+
+impl SweepRule {
+    /// Sweep spot balance of `asset` above `reserve`.
+    pub fn new(asset: &str, reserve: f64) -> Self {
+        Self {
+            asset: asset.to_uppercase(),
+            reserve,
+            min_amount: 0.0,
+            source: EarnAccount::Spot,
+        }
+    }
+
+    /// Set the smallest amount worth sweeping.
+    pub fn min_amount(mut self, min_amount: f64) -> Self {
+        self.min_amount = min_amount;
+        self
+    }
+
+    /// Sweep from the funding wallet instead of the spot wallet.
+    pub fn from_funding(mut self) -> Self {
+        self.source = EarnAccount::Fund;
+        self
+    }
+
+    /// Get the amount to sweep given the free balance, if any.
+    pub fn sweep_amount(&self, free: f64) -> Option<f64> {
+        let amount = floor_amount(free - self.reserve);
+        (amount > 0.0 && amount >= self.min_amount).then_some(amount)
+    }
+}
+
+/// Outcome of a sweep or redemption.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SweepEvent {
+    /// A balance was moved into Earn.
+    Swept {
+        /// Asset.
+        asset: String,
+        /// Amount subscribed.
+        amount: f64,
+        /// Purchase ID.
+        purchase_id: u64,
+    },
+    /// A balance was redeemed from Earn to spot.
+    Redeemed {
+        /// Asset.
+        asset: String,
+        /// Amount redeemed.
+        amount: f64,
+        /// Redemption ID.
+        redeem_id: u64,
+    },
+    /// A sweep failed.
+    Failed {
+        /// Asset.
+        asset: String,
+        /// Error message.
+        error: String,
+    },
+}
+
+/// Moves idle balances into Simple Earn flexible products.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{EarnSweeper, SweepRule};
+/// use std::time::Duration;
+///
+/// let sweeper = EarnSweeper::new(client.clone())
+///     .rule(SweepRule::new("USDT", 500.0).min_amount(10.0))
+///     .rule(SweepRule::new("BTC", 0.01));
+///
+/// // Before buying, make sure 2000 USDT is available on spot
+/// sweeper.ensure_spot_balance("USDT", 2000.0).await?;
+///
+/// // Sweep every hour
+/// let mut scheduler = sweeper.start(Duration::from_secs(3600));
+/// while let Some(event) = scheduler.next().await {
+///     println!("{:?}", event);
+/// }
+/// ```
+#[derive(Clone)]
+pub struct EarnSweeper {
+    client: Binance,
+    rules: Vec<SweepRule>,
+}
+
+impl EarnSweeper {
+    /// Create a sweeper with no rules.
+    pub fn new(client: Binance) -> Self {
+        Self {
+            client,
+            rules: Vec::new(),
+        }
+    }
+
+    /// Add a sweep rule.
+    pub fn rule(mut self, rule: SweepRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// Get the configured rules.
+    pub fn rules(&self) -> &[SweepRule] {
+        &self.rules
+    }
+
+    /// Apply every rule once.
+    ///
+    /// Failures of individual rules are reported as [`SweepEvent::Failed`];
+    /// an error is returned only if balances could not be fetched.
+    pub async fn sweep_once(&self) -> Result<Vec<SweepEvent>> {
+        let spot = if self.rules.iter().any(|r| r.source != EarnAccount::Fund) {
+            Some(self.client.account().get_account().await?)
+        } else {
+            None
+        };
+
+        let mut events = Vec::new();
+        for rule in &self.rules {
+            let free = match rule.source {
+                EarnAccount::Fund => self.funding_free(&rule.asset).await?,
+                _ => spot
+                    .as_ref()
+                    .and_then(|a| a.balances.iter().find(|b| b.asset == rule.asset))
+                    .map(|b| b.free)
+                    .unwrap_or_default(),
+            };
+
+            let Some(amount) = rule.sweep_amount(free) else {
+                continue;
+            };
+
+            let event = match self.subscribe(rule, amount).await {
+                Ok(purchase_id) => SweepEvent::Swept {
+                    asset: rule.asset.clone(),
+                    amount,
+                    purchase_id,
+                },
+                Err(e) => SweepEvent::Failed {
+                    asset: rule.asset.clone(),
+                    error: e.to_string(),
+                },
+            };
+            events.push(event);
+        }
+
+        Ok(events)
+    }
+
+    /// Redeem from Earn so that at least `required` of `asset` is free on spot.
+    ///
+    /// Returns the redemption event, or `None` if the spot balance already
+    /// covers `required`. Flexible redemptions are usually credited immediately.
+    pub async fn ensure_spot_balance(
+        &self,
+        asset: &str,
+        required: f64,
+    ) -> Result<Option<SweepEvent>> {
+        let asset = asset.to_uppercase();
+        let account = self.client.account().get_account().await?;
+        let free = account
+            .balances
+            .iter()
+            .find(|b| b.asset == asset)
+            .map(|b| b.free)
+            .unwrap_or_default();
+
+        let Some(amount) = redeem_amount(required, free) else {
+            return Ok(None);
+        };
+
+        let product_id = self.product_id(&asset).await?;
+        let result = self
+            .client
+            .earn()
+            .redeem_flexible(
+                &product_id,
+                Some(&format_amount(amount)),
+                Some(EarnAccount::Spot),
+            )
+            .await?;
+
+        Ok(Some(SweepEvent::Redeemed {
+            asset,
+            amount,
+            redeem_id: result.redeem_id,
+        }))
+    }
+
+    /// Run [`sweep_once`](Self::sweep_once) every `interval` in the background.
+    ///
+    /// The task is restarted if it panics, and stops when the returned
+    /// scheduler is stopped or dropped or the client shuts down.
+    pub fn start(self, interval: Duration) -> SweepScheduler {
+        let (tx, rx) = mpsc::channel(100);
+        let is_stopped = Arc::new(AtomicBool::new(false));
+
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = self.client.shutdown_handle().clone();
+        let runtime = self.client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "earn-sweeper", policy, move || {
+            self.clone()
+                .sweep_loop(interval, is_stopped_clone.clone(), tx.clone())
+        });
+
+        SweepScheduler { rx, is_stopped }
+    }
+
+    async fn sweep_loop(
+        self,
+        interval: Duration,
+        is_stopped: Arc<AtomicBool>,
+        tx: mpsc::Sender<SweepEvent>,
+    ) {
+        let shutdown = self.client.shutdown_handle().clone();
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                // The scheduler was dropped
+                _ = tx.closed() => break,
+                _ = ticker.tick() => {}
+            }
+            if is_stopped.load(Ordering::SeqCst) {
+                break;
+            }
+
+            match self.sweep_once().await {
+                Ok(events) => {
+                    for event in events {
+                        if tx.send(event).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!("Earn sweep failed: {}", e),
+            }
+        }
+    }
+
+    async fn funding_free(&self, asset: &str) -> Result<f64> {
+        let assets = self
+            .client
+            .wallet()
+            .funding_wallet(Some(asset), None)
+            .await?;
+        Ok(assets
+            .iter()
+            .find(|a| a.asset == asset)
+            .map(|a| a.free)
+            .unwrap_or_default())
+    }
+
+    async fn product_id(&self, asset: &str) -> Result<String> {
+        let products = self
+            .client
+            .earn()
+            .flexible_products(Some(asset), None, None)
+            .await?;
+        products
+            .rows
+            .into_iter()
+            .find(|p| p.asset == asset)
+            .map(|p| p.product_id)
+            .ok_or_else(|| {
+                Error::InvalidConfig(format!("no Simple Earn flexible product for {}", asset))
+            })
+    }
+
+    async fn subscribe(&self, rule: &SweepRule, amount: f64) -> Result<u64> {
+        let product_id = self.product_id(&rule.asset).await?;
+        let result = self
+            .client
+            .earn()
+            .subscribe_flexible(&product_id, &format_amount(amount), Some(rule.source))
+            .await?;
+        Ok(result.purchase_id)
+    }
+}
+
+/// Handle to a background sweep task started with [`EarnSweeper::start`].
+pub struct SweepScheduler {
+    rx: mpsc::Receiver<SweepEvent>,
+    is_stopped: Arc<AtomicBool>,
+}
+
+impl SweepScheduler {
+    /// Receive the next sweep event.
+    pub async fn next(&mut self) -> Option<SweepEvent> {
+        self.rx.recv().await
+    }
+
+    /// Stop the background task before its next sweep.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+impl Drop for SweepScheduler {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+// Amount needed to bring `free` up to `required`, rounded up to the amount step.
+fn redeem_amount(required: f64, free: f64) -> Option<f64> {
+    let scale = 10f64.powi(AMOUNT_DECIMALS);
+    let shortfall = ((required - free) * scale).ceil() / scale;
+    (shortfall > 0.0).then_some(shortfall)
+}
+
+fn floor_amount(amount: f64) -> f64 {
+    let scale = 10f64.powi(AMOUNT_DECIMALS);
+    (amount * scale).floor() / scale
+}
+
+fn format_amount(amount: f64) -> String {
+    format!("{:.*}", AMOUNT_DECIMALS as usize, amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sweep_amount() {
+        let rule = SweepRule::new("usdt", 100.0).min_amount(10.0);
+        assert_eq!(rule.asset, "USDT");
+        assert_eq!(rule.sweep_amount(50.0), None);
+        assert_eq!(rule.sweep_amount(105.0), None);
+        assert_eq!(rule.sweep_amount(150.123456789), Some(50.12345678));
+    }
+
+    #[test]
+    fn test_redeem_amount() {
+        assert_eq!(redeem_amount(100.0, 150.0), None);
+        assert_eq!(redeem_amount(100.0, 100.0), None);
+        assert_eq!(redeem_amount(100.0, 99.999999991), Some(0.00000001));
+        assert_eq!(format_amount(0.5), "0.50000000");
+    }
+}
@@ -0,0 +1,268 @@
+//! Server time synchronization.
+//!
+//! Signed requests are rejected with error `-1021` when the `timestamp`
+//! parameter falls outside the receive window. This happens when the local
+//! clock drifts away from the Binance server clock. `TimeSync` keeps track
+//! of the measured offset so that it can be applied to every signed request.
+
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::Result;
+use crate::credentials::get_timestamp;
+
+const MILLIS_PER_HOUR: f64 = 3_600_000.0;
+
+/// Time after a failed synchronization before it is attempted again.
+pub const TIME_SYNC_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+/// Snapshot of the measured clock skew.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockSkew {
+    /// Offset (server time minus local time) in milliseconds.
+    pub offset_ms: i64,
+    /// Rate at which the offset changes, in milliseconds per hour.
+    ///
+    /// `None` until at least two synchronizations have been recorded.
+    pub drift_ms_per_hour: Option<f64>,
+    /// Local timestamp (in milliseconds) of the last synchronization.
+    pub last_sync_time: Option<u64>,
+}
+
+/// Tracks the offset between the local clock and the Binance server clock.
+///
+/// A `TimeSync` is shared by all clones of a [`Client`](crate::Client).
+/// When automatic synchronization is enabled, the first signed request
+/// measures the offset and starts a background task that refreshes it
+/// whenever the sync interval has elapsed, retrying failed synchronizations
+/// after [`TIME_SYNC_RETRY_DELAY`].
+#[derive(Debug)]
+pub struct TimeSync {
+    enabled: bool,
+    interval: Duration,
+    offset_ms: AtomicI64,
+    last_sync_ms: AtomicU64,
+    synced: AtomicBool,
+    drift_bits: AtomicU64,
+    has_drift: AtomicBool,
+    failed_at_ms: AtomicU64,
+    started: AtomicBool,
+}
+
+impl TimeSync {
+    /// Create a new time sync component.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to synchronize automatically before signed requests
+    /// * `interval` - Minimum time between automatic synchronizations
+    pub fn new(enabled: bool, interval: Duration) -> Self {
+        Self {
+            enabled,
+            interval,
+            offset_ms: AtomicI64::new(0),
+            last_sync_ms: AtomicU64::new(0),
+            synced: AtomicBool::new(false),
+            drift_bits: AtomicU64::new(0),
+            has_drift: AtomicBool::new(false),
+            failed_at_ms: AtomicU64::new(0),
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// Check if automatic synchronization is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Get the current offset (server time minus local time) in milliseconds.
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::SeqCst)
+    }
+
+    /// Check if the offset has been measured at least once.
+    pub fn is_synced(&self) -> bool {
+        self.synced.load(Ordering::SeqCst)
+    }
+
+    /// Get the local timestamp (in milliseconds) of the last synchronization.
+    pub fn last_sync_time(&self) -> Option<u64> {
+        self.is_synced()
+            .then(|| self.last_sync_ms.load(Ordering::SeqCst))
+    }
+
+    /// Get the rate at which the offset changes, in milliseconds per hour.
+    ///
+    /// A steadily growing drift usually points at a misbehaving NTP daemon
+    /// on the host. Returns `None` until two synchronizations have been
+    /// recorded.
+    pub fn drift_ms_per_hour(&self) -> Option<f64> {
+        self.has_drift
+            .load(Ordering::SeqCst)
+            .then(|| f64::from_bits(self.drift_bits.load(Ordering::SeqCst)))
+    }
+
+    /// Get a snapshot of the current clock skew.
+    pub fn skew(&self) -> ClockSkew {
+        ClockSkew {
+            offset_ms: self.offset_ms(),
+            drift_ms_per_hour: self.drift_ms_per_hour(),
+            last_sync_time: self.last_sync_time(),
+        }
+    }
+
+    /// Check if an automatic synchronization is due.
+    ///
+    /// Not due within [`TIME_SYNC_RETRY_DELAY`] of a failed synchronization.
+    pub fn needs_sync(&self, now_ms: u64) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let failed_at = self.failed_at_ms.load(Ordering::SeqCst);
+        if failed_at > 0 && now_ms.saturating_sub(failed_at) < retry_delay_ms() {
+            return false;
+        }
+        match self.last_sync_time() {
+            Some(last) => now_ms.saturating_sub(last) >= self.interval.as_millis() as u64,
+            None => true,
+        }
+    }
+
+    /// Record a server time measurement.
+    ///
+    /// The server time is compared against the midpoint of the local request
+    /// and response times to compensate for network latency.
+    ///
+    /// The offset and drift rate are also reported as tracing gauges
+    /// (`gauge.clock_offset_ms` and `gauge.clock_drift_ms_per_hour`).
+    ///
+    /// Returns the new offset in milliseconds.
+    pub fn record(&self, server_time: u64, request_ms: u64, response_ms: u64) -> i64 {
+        let local_midpoint = request_ms + response_ms.saturating_sub(request_ms) / 2;
+        let offset = server_time as i64 - local_midpoint as i64;
+
+        if let Some(last_sync) = self.last_sync_time() {
+            let elapsed_ms = response_ms.saturating_sub(last_sync);
+            if elapsed_ms > 0 {
+                let change = (offset - self.offset_ms()) as f64;
+                let drift = change * MILLIS_PER_HOUR / elapsed_ms as f64;
+                self.drift_bits.store(drift.to_bits(), Ordering::SeqCst);
+                self.has_drift.store(true, Ordering::SeqCst);
+            }
+        }
+
+        self.offset_ms.store(offset, Ordering::SeqCst);
+        self.last_sync_ms.store(response_ms, Ordering::SeqCst);
+        self.synced.store(true, Ordering::SeqCst);
+        self.failed_at_ms.store(0, Ordering::SeqCst);
+
+        tracing::debug!(
+            gauge.clock_offset_ms = offset,
+            gauge.clock_drift_ms_per_hour = self.drift_ms_per_hour().unwrap_or(0.0),
+            "Server time synchronized"
+        );
+
+        offset
+    }
+
+    /// Record a failed synchronization at `now_ms`, delaying the next one.
+    pub fn record_failure(&self, now_ms: u64) {
+        self.failed_at_ms.store(now_ms.max(1), Ordering::SeqCst);
+    }
+
+    // Mark the background task as started, returning false if it already was.
+    pub(crate) fn start_background(&self) -> bool {
+        !self.started.swap(true, Ordering::SeqCst)
+    }
+
+    /// Get the current timestamp adjusted by the measured offset.
+    pub fn timestamp(&self) -> Result<u64> {
+        let local = get_timestamp()?;
+        Ok(local.saturating_add_signed(self.offset_ms()))
+    }
+}
+
+fn retry_delay_ms() -> u64 {
+    TIME_SYNC_RETRY_DELAY.as_millis() as u64
+}
+
+impl Default for TimeSync {
+    fn default() -> Self {
+        Self::new(false, crate::config::DEFAULT_TIME_SYNC_INTERVAL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_offset() {
+        let sync = TimeSync::new(true, Duration::from_secs(60));
+        assert!(!sync.is_synced());
+        assert_eq!(sync.offset_ms(), 0);
+
+        // Request sent at 1000, response at 1100, server reports 1550.
+        let offset = sync.record(1550, 1000, 1100);
+        assert_eq!(offset, 500);
+        assert_eq!(sync.offset_ms(), 500);
+        assert!(sync.is_synced());
+        assert_eq!(sync.last_sync_time(), Some(1100));
+
+        // Server clock behind the local clock.
+        let offset = sync.record(900, 1000, 1100);
+        assert_eq!(offset, -150);
+    }
+
+    #[test]
+    fn test_drift_rate() {
+        let sync = TimeSync::new(true, Duration::from_secs(60));
+        sync.record(1_000, 1_000, 1_000);
+        assert_eq!(sync.drift_ms_per_hour(), None);
+
+        // Offset grows by 10ms over 30 minutes.
+        let half_hour = 1_800_000;
+        sync.record(1_000 + half_hour + 10, 1_000 + half_hour, 1_000 + half_hour);
+
+        let skew = sync.skew();
+        assert_eq!(skew.offset_ms, 10);
+        assert_eq!(skew.drift_ms_per_hour, Some(20.0));
+        assert_eq!(skew.last_sync_time, Some(1_000 + half_hour));
+    }
+
+    #[test]
+    fn test_needs_sync() {
+        let sync = TimeSync::new(true, Duration::from_secs(60));
+        assert!(sync.needs_sync(0));
+
+        sync.record(10_000, 10_000, 10_000);
+        assert!(!sync.needs_sync(10_000 + 59_999));
+        assert!(sync.needs_sync(10_000 + 60_000));
+
+        let disabled = TimeSync::new(false, Duration::from_secs(60));
+        assert!(!disabled.needs_sync(0));
+    }
+
+    #[test]
+    fn test_needs_sync_after_failure() {
+        let sync = TimeSync::new(true, Duration::from_secs(60));
+        sync.record_failure(10_000);
+        assert!(!sync.needs_sync(10_000 + retry_delay_ms() - 1));
+        assert!(sync.needs_sync(10_000 + retry_delay_ms()));
+
+        // A successful synchronization clears the failure
+        sync.record_failure(100_000);
+        sync.record(100_000, 100_000, 100_000);
+        assert!(sync.needs_sync(160_000));
+    }
+
+    #[test]
+    fn test_timestamp_applies_offset() {
+        let sync = TimeSync::default();
+        sync.record(0, 10_000_000, 10_000_000);
+        let local = get_timestamp().unwrap();
+        let adjusted = sync.timestamp().unwrap();
+        assert!(adjusted + 10_000_000 >= local);
+        assert!(adjusted < local);
+    }
+}
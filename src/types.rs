@@ -40,6 +40,22 @@ pub enum OrderType {
     Other,
 }
 
+impl std::fmt::Display for OrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Limit => "LIMIT",
+            Self::Market => "MARKET",
+            Self::StopLoss => "STOP_LOSS",
+            Self::StopLossLimit => "STOP_LOSS_LIMIT",
+            Self::TakeProfit => "TAKE_PROFIT",
+            Self::TakeProfitLimit => "TAKE_PROFIT_LIMIT",
+            Self::LimitMaker => "LIMIT_MAKER",
+            Self::Other => "OTHER",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Time in force - how long an order remains active.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum TimeInForce {
@@ -178,6 +194,107 @@ impl std::fmt::Display for KlineInterval {
     }
 }
 
+/// Number of price levels requested from the order book depth endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthLimit {
+    /// 5 levels
+    Levels5,
+    /// 10 levels
+    Levels10,
+    /// 20 levels
+    Levels20,
+    /// 50 levels
+    Levels50,
+    /// 100 levels (the API default)
+    #[default]
+    Levels100,
+    /// 500 levels
+    Levels500,
+    /// 1000 levels
+    Levels1000,
+    /// 5000 levels (the maximum)
+    Levels5000,
+}
+
+impl DepthLimit {
+    /// Get the number of levels.
+    pub fn levels(self) -> u16 {
+        match self {
+            Self::Levels5 => 5,
+            Self::Levels10 => 10,
+            Self::Levels20 => 20,
+            Self::Levels50 => 50,
+            Self::Levels100 => 100,
+            Self::Levels500 => 500,
+            Self::Levels1000 => 1000,
+            Self::Levels5000 => 5000,
+        }
+    }
+
+    /// Get the request weight of a depth request for this limit.
+    pub fn weight(self) -> u32 {
+        Self::weight_for(self.levels())
+    }
+
+    /// Get the smallest limit returning at least `levels` levels, which is
+    /// also the cheapest in request weight.
+    ///
+    /// Requests for more than 5000 levels get [`Levels5000`](Self::Levels5000).
+    pub fn cheapest_for(levels: u16) -> Self {
+        match levels {
+            0..=5 => Self::Levels5,
+            6..=10 => Self::Levels10,
+            11..=20 => Self::Levels20,
+            21..=50 => Self::Levels50,
+            51..=100 => Self::Levels100,
+            101..=500 => Self::Levels500,
+            501..=1000 => Self::Levels1000,
+            _ => Self::Levels5000,
+        }
+    }
+
+    /// Get the request weight of a depth request for any `limit`.
+    ///
+    /// Limits above 5000 are truncated to 5000 by the API.
+    pub fn weight_for(limit: u16) -> u32 {
+        match limit {
+            0..=100 => 5,
+            101..=500 => 25,
+            501..=1000 => 50,
+            _ => 250,
+        }
+    }
+}
+
+impl TryFrom<u16> for DepthLimit {
+    type Error = crate::Error;
+
+    fn try_from(levels: u16) -> crate::Result<Self> {
+        Ok(match levels {
+            5 => Self::Levels5,
+            10 => Self::Levels10,
+            20 => Self::Levels20,
+            50 => Self::Levels50,
+            100 => Self::Levels100,
+            500 => Self::Levels500,
+            1000 => Self::Levels1000,
+            5000 => Self::Levels5000,
+            _ => {
+                return Err(crate::Error::InvalidConfig(format!(
+                    "invalid depth limit {}",
+                    levels
+                )));
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for DepthLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.levels())
+    }
+}
+
 /// Ticker response type for market data endpoints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -198,6 +315,37 @@ impl std::fmt::Display for TickerType {
     }
 }
 
+/// Unit of the timestamps in WebSocket stream events.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TimeUnit {
+    /// Milliseconds since Unix epoch (the default).
+    #[default]
+    Millisecond,
+    /// Microseconds since Unix epoch.
+    Microsecond,
+}
+
+impl TimeUnit {
+    /// Convert a timestamp in this unit to microseconds.
+    pub fn to_micros(self, time: u64) -> u64 {
+        match self {
+            Self::Millisecond => time.saturating_mul(1000),
+            Self::Microsecond => time,
+        }
+    }
+}
+
+impl std::fmt::Display for TimeUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Millisecond => "MILLISECOND",
+            Self::Microsecond => "MICROSECOND",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Symbol status.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -253,6 +401,17 @@ pub enum SymbolPermission {
     Other,
 }
 
+impl std::fmt::Display for SymbolPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Spot => "SPOT",
+            Self::Margin => "MARGIN",
+            Self::Other => "OTHER",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 /// Account type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -270,6 +429,37 @@ pub enum AccountType {
     Other,
 }
 
+/// Wallet an order is routed to.
+///
+/// Used by [`Account::place_order`](crate::rest::Account::place_order) so the
+/// same strategy code can trade from the spot, cross margin or isolated margin
+/// account.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum AccountKind {
+    /// Spot account
+    #[default]
+    Spot,
+    /// Cross margin account
+    CrossMargin,
+    /// Isolated margin account for the given symbol
+    IsolatedMargin(String),
+}
+
+impl AccountKind {
+    /// Get the symbol permission required to trade from this account.
+    pub fn permission(&self) -> SymbolPermission {
+        match self {
+            Self::Spot => SymbolPermission::Spot,
+            Self::CrossMargin | Self::IsolatedMargin(_) => SymbolPermission::Margin,
+        }
+    }
+
+    /// Check if this is a margin account (cross or isolated).
+    pub fn is_margin(&self) -> bool {
+        !matches!(self, Self::Spot)
+    }
+}
+
 /// Rate limit type.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -312,7 +502,7 @@ pub enum OrderResponseType {
     Other,
 }
 
-/// OCO order status.
+/// Order list status type (OCO, OTO, OTOCO, OPO and OPOCO lists).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OcoStatus {
@@ -322,9 +512,12 @@ pub enum OcoStatus {
     ExecStarted,
     /// All done
     AllDone,
+    /// Unknown list status type
+    #[serde(other)]
+    Other,
 }
 
-/// OCO order status.
+/// Order list order status (OCO, OTO, OTOCO, OPO and OPOCO lists).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OcoOrderStatus {
@@ -334,9 +527,12 @@ pub enum OcoOrderStatus {
     AllDone,
     /// Rejected
     Reject,
+    /// Unknown list order status
+    #[serde(other)]
+    Other,
 }
 
-/// Contingency type for OCO orders.
+/// Contingency type for order lists.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContingencyType {
@@ -355,6 +551,19 @@ pub enum ContingencyType {
     Other,
 }
 
+/// Venue where an order was placed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkingFloor {
+    /// Placed on the exchange order book
+    Exchange,
+    /// Placed through Smart Order Routing
+    Sor,
+    /// Unknown working floor
+    #[serde(other)]
+    Other,
+}
+
 /// Cancel-replace mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -427,10 +636,197 @@ impl std::fmt::Display for OrderRateLimitExceededMode {
     }
 }
 
+/// Self-trade prevention mode, which decides what happens when an order
+/// would match another order of the same account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SelfTradePreventionMode {
+    /// No self-trade prevention.
+    None,
+    /// Expire the taker order.
+    ExpireTaker,
+    /// Expire the maker order.
+    ExpireMaker,
+    /// Expire both orders.
+    ExpireBoth,
+    /// Decrease both orders by the matched quantity.
+    Decrement,
+    /// Settle the match as a transfer between accounts of the same trade
+    /// group.
+    Transfer,
+}
+
+impl std::fmt::Display for SelfTradePreventionMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::None => "NONE",
+            Self::ExpireTaker => "EXPIRE_TAKER",
+            Self::ExpireMaker => "EXPIRE_MAKER",
+            Self::ExpireBoth => "EXPIRE_BOTH",
+            Self::Decrement => "DECREMENT",
+            Self::Transfer => "TRANSFER",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Price a pegged order follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PegPriceType {
+    /// Best price on the same side of the book as the order.
+    #[serde(rename = "PRIMARY_PEG")]
+    Primary,
+    /// Best price on the opposite side of the book.
+    #[serde(rename = "MARKET_PEG")]
+    Market,
+}
+
+impl std::fmt::Display for PegPriceType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Primary => "PRIMARY_PEG",
+            Self::Market => "MARKET_PEG",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// Unit of a pegged order's offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PegOffsetType {
+    /// Offset in price levels of the order book.
+    PriceLevel,
+}
+
+impl std::fmt::Display for PegOffsetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::PriceLevel => "PRICE_LEVEL",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+/// A quantity or price parameter for order builders.
+///
+/// Builders take `impl Into<QtyParam>`, so the value can be given as:
+///
+/// - a string, sent unchanged: `"0.001"`
+/// - an `f64` with an explicit number of decimals: `(0.001, 5)` or
+///   [`QtyParam::from_f64`]
+/// - a `rust_decimal::Decimal`, with the `decimal` feature
+///
+/// Numbers are always formatted in plain notation, never as `1e-5`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct QtyParam(String);
+
+impl QtyParam {
+    /// Format an `f64` with a fixed number of decimals.
+    pub fn from_f64(value: f64, decimals: usize) -> Self {
+        Self(format!("{:.*}", decimals, value))
+    }
+
+    /// Get the formatted value.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for QtyParam {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for QtyParam {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl From<String> for QtyParam {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&String> for QtyParam {
+    fn from(value: &String) -> Self {
+        Self(value.clone())
+    }
+}
+
+impl From<(f64, usize)> for QtyParam {
+    fn from((value, decimals): (f64, usize)) -> Self {
+        Self::from_f64(value, decimals)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<rust_decimal::Decimal> for QtyParam {
+    fn from(value: rust_decimal::Decimal) -> Self {
+        Self(value.to_string())
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl From<&rust_decimal::Decimal> for QtyParam {
+    fn from(value: &rust_decimal::Decimal) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<QtyParam> for String {
+    fn from(value: QtyParam) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stp_and_peg_display() {
+        for mode in [
+            SelfTradePreventionMode::None,
+            SelfTradePreventionMode::ExpireTaker,
+            SelfTradePreventionMode::ExpireMaker,
+            SelfTradePreventionMode::ExpireBoth,
+            SelfTradePreventionMode::Decrement,
+            SelfTradePreventionMode::Transfer,
+        ] {
+            let serialized = serde_json::to_string(&mode).unwrap();
+            assert_eq!(serialized, format!("\"{}\"", mode));
+        }
+        assert_eq!(PegPriceType::Market.to_string(), "MARKET_PEG");
+        assert_eq!(
+            serde_json::to_string(&PegPriceType::Market).unwrap(),
+            "\"MARKET_PEG\""
+        );
+        assert_eq!(PegOffsetType::PriceLevel.to_string(), "PRICE_LEVEL");
+    }
+
+    #[test]
+    fn test_qty_param() {
+        assert_eq!(QtyParam::from("0.001").as_str(), "0.001");
+        assert_eq!(QtyParam::from((0.00001, 8)).as_str(), "0.00001000");
+        assert_eq!(QtyParam::from_f64(1e-7, 8).as_str(), "0.00000010");
+        assert_eq!(QtyParam::from_f64(50000.0, 2).as_str(), "50000.00");
+        assert_eq!(
+            QtyParam::from_f64(1e21, 0).as_str(),
+            "1000000000000000000000"
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_qty_param_decimal() {
+        let value = rust_decimal::Decimal::from_scientific("1e-5").unwrap();
+        assert_eq!(QtyParam::from(value).as_str(), "0.00001");
+    }
+
     #[test]
     fn test_order_side_serde() {
         let buy: OrderSide = serde_json::from_str("\"BUY\"").unwrap();
@@ -499,4 +895,44 @@ mod tests {
         let serialized = serde_json::to_string(&KlineInterval::Minutes15).unwrap();
         assert_eq!(serialized, "\"15m\"");
     }
+
+    #[test]
+    fn test_time_unit() {
+        assert_eq!(TimeUnit::default(), TimeUnit::Millisecond);
+        assert_eq!(TimeUnit::Microsecond.to_string(), "MICROSECOND");
+        assert_eq!(
+            TimeUnit::Millisecond.to_micros(1_700_000_000_000),
+            1_700_000_000_000_000
+        );
+        assert_eq!(
+            TimeUnit::Microsecond.to_micros(1_700_000_000_000_000),
+            1_700_000_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_account_kind_permission() {
+        assert_eq!(AccountKind::Spot.permission(), SymbolPermission::Spot);
+        assert_eq!(
+            AccountKind::CrossMargin.permission(),
+            SymbolPermission::Margin
+        );
+        let isolated = AccountKind::IsolatedMargin("BTCUSDT".to_string());
+        assert_eq!(isolated.permission(), SymbolPermission::Margin);
+        assert!(isolated.is_margin());
+        assert!(!AccountKind::default().is_margin());
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        assert_eq!(DepthLimit::Levels5000.levels(), 5000);
+        assert_eq!(DepthLimit::Levels5000.weight(), 250);
+        assert_eq!(DepthLimit::Levels100.weight(), 5);
+        assert_eq!(DepthLimit::weight_for(101), 25);
+        assert_eq!(DepthLimit::try_from(1000).unwrap(), DepthLimit::Levels1000);
+        assert!(DepthLimit::try_from(7).is_err());
+        assert_eq!(DepthLimit::cheapest_for(50), DepthLimit::Levels50);
+        assert_eq!(DepthLimit::cheapest_for(150), DepthLimit::Levels500);
+        assert_eq!(DepthLimit::cheapest_for(6000), DepthLimit::Levels5000);
+    }
 }
@@ -0,0 +1,397 @@
+//! Client-side withdrawal policy.
+//!
+//! A `WithdrawPolicy` is an optional defense-in-depth layer on top of the
+//! API key restrictions configured on Binance. When attached to a client,
+//! [`Wallet::withdraw`](crate::rest::Wallet::withdraw) refuses any withdrawal
+//! to an address that has not been registered, or that would exceed the
+//! daily maximum for the coin, before the request is ever signed.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::Result;
+use crate::credentials::get_timestamp;
+use crate::error::Error;
+
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// A withdrawal destination allowed by a [`WithdrawPolicy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowedAddress {
+    /// Coin symbol (uppercase).
+    pub coin: String,
+    /// Destination address.
+    pub address: String,
+    /// Network the address may be used on. `None` allows any network.
+    pub network: Option<String>,
+    /// Required memo/tag. `None` allows any tag.
+    pub address_tag: Option<String>,
+}
+
+impl AllowedAddress {
+    fn matches(
+        &self,
+        coin: &str,
+        address: &str,
+        network: Option<&str>,
+        address_tag: Option<&str>,
+    ) -> bool {
+        if !self.coin.eq_ignore_ascii_case(coin) || self.address != address {
+            return false;
+        }
+        if let Some(allowed) = &self.network {
+            if !network.is_some_and(|n| allowed.eq_ignore_ascii_case(n)) {
+                return false;
+            }
+        }
+        if let Some(tag) = &self.address_tag {
+            if address_tag != Some(tag.as_str()) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Default)]
+struct DailyUsage {
+    day: u64,
+    amount: f64,
+}
+
+/// Whitelist of withdrawal destinations and daily limits.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{Binance, WithdrawPolicy};
+///
+/// let policy = WithdrawPolicy::new()
+///     .allow_address("USDT", "0x1234...", Some("ETH"))
+///     .daily_limit("USDT", 1000.0);
+///
+/// let client = Binance::new("api_key", "secret_key")?.with_withdraw_policy(policy);
+///
+/// // Refused locally: the address is not whitelisted.
+/// let result = client.wallet()
+///     .withdraw("USDT", "0xdead...", "100.0", Some("ETH"), None, None)
+///     .await;
+/// assert!(result.is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct WithdrawPolicy {
+    addresses: Vec<AllowedAddress>,
+    daily_limits: HashMap<String, f64>,
+    usage: Mutex<HashMap<String, DailyUsage>>,
+}
+
+impl WithdrawPolicy {
+    /// Create an empty policy. An empty policy refuses all withdrawals.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allow withdrawals of `coin` to `address`.
+    ///
+    /// # Arguments
+    ///
+    /// * `coin` - Coin symbol
+    /// * `address` - Destination address
+    /// * `network` - Network the address may be used on (`None` allows any)
+    pub fn allow_address(self, coin: &str, address: &str, network: Option<&str>) -> Self {
+        self.allow(AllowedAddress {
+            coin: coin.to_uppercase(),
+            address: address.to_string(),
+            network: network.map(|n| n.to_string()),
+            address_tag: None,
+        })
+    }
+
+    /// Allow withdrawals to a fully specified destination.
+    pub fn allow(mut self, address: AllowedAddress) -> Self {
+        self.addresses.push(address);
+        self
+    }
+
+    /// Set the maximum amount of `coin` that may be withdrawn per UTC day.
+    ///
+    /// Coins without a daily limit are only restricted by the address whitelist.
+    pub fn daily_limit(mut self, coin: &str, max_amount: f64) -> Self {
+        self.daily_limits.insert(coin.to_uppercase(), max_amount);
+        self
+    }
+
+    /// Get the allowed destinations.
+    pub fn addresses(&self) -> &[AllowedAddress] {
+        &self.addresses
+    }
+
+    /// Get the daily limit for a coin, if any.
+    pub fn daily_limit_for(&self, coin: &str) -> Option<f64> {
+        self.daily_limits.get(&coin.to_uppercase()).copied()
+    }
+
+    /// Get the amount of `coin` withdrawn through this policy today (UTC).
+    pub fn used_today(&self, coin: &str) -> Result<f64> {
+        let day = get_timestamp()? / MILLIS_PER_DAY;
+        let usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(usage
+            .get(&coin.to_uppercase())
+            .filter(|u| u.day == day)
+            .map_or(0.0, |u| u.amount))
+    }
+
+    /// Check a withdrawal against the policy without recording it.
+    pub fn check(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: &str,
+        network: Option<&str>,
+        address_tag: Option<&str>,
+    ) -> Result<()> {
+        let amount = self.check_destination(coin, address, amount, network, address_tag)?;
+        let used = self.used_today(coin)?;
+        self.check_limit(coin, used, amount)
+    }
+
+    /// Check a withdrawal and, if allowed, count it towards the daily limit.
+    ///
+    /// Returns the UTC day (days since Unix epoch) the amount was counted on
+    /// and the parsed amount, so it can be released if the request fails.
+    pub(crate) fn reserve(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: &str,
+        network: Option<&str>,
+        address_tag: Option<&str>,
+    ) -> Result<(u64, f64)> {
+        self.reserve_at(
+            coin,
+            address,
+            amount,
+            network,
+            address_tag,
+            get_timestamp()?,
+        )
+    }
+
+    fn reserve_at(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: &str,
+        network: Option<&str>,
+        address_tag: Option<&str>,
+        now_ms: u64,
+    ) -> Result<(u64, f64)> {
+        let amount = self.check_destination(coin, address, amount, network, address_tag)?;
+        let day = now_ms / MILLIS_PER_DAY;
+
+        let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        let entry = usage.entry(coin.to_uppercase()).or_default();
+        if entry.day != day {
+            *entry = DailyUsage { day, amount: 0.0 };
+        }
+        self.check_limit(coin, entry.amount, amount)?;
+        entry.amount += amount;
+        Ok((day, amount))
+    }
+
+    /// Undo a reservation made by [`reserve`](Self::reserve) on `day`.
+    ///
+    /// Does nothing once the usage was reset for a later day, which the
+    /// reservation never counted towards.
+    pub(crate) fn release(&self, coin: &str, day: u64, amount: f64) {
+        let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(entry) = usage.get_mut(&coin.to_uppercase()) {
+            if entry.day == day {
+                entry.amount = (entry.amount - amount).max(0.0);
+            }
+        }
+    }
+
+    fn check_destination(
+        &self,
+        coin: &str,
+        address: &str,
+        amount: &str,
+        network: Option<&str>,
+        address_tag: Option<&str>,
+    ) -> Result<f64> {
+        let amount: f64 = amount
+            .parse()
+            .ok()
+            .filter(|a: &f64| a.is_finite() && *a > 0.0)
+            .ok_or_else(|| Error::WithdrawRefused(format!("invalid amount {}", amount)))?;
+
+        if !self
+            .addresses
+            .iter()
+            .any(|a| a.matches(coin, address, network, address_tag))
+        {
+            return Err(Error::WithdrawRefused(format!(
+                "{} address {} (network {}) is not whitelisted",
+                coin.to_uppercase(),
+                address,
+                network.unwrap_or("default")
+            )));
+        }
+
+        Ok(amount)
+    }
+
+    fn check_limit(&self, coin: &str, used: f64, amount: f64) -> Result<()> {
+        if let Some(max) = self.daily_limit_for(coin) {
+            if used + amount > max {
+                return Err(Error::WithdrawRefused(format!(
+                    "{} daily limit of {} exceeded ({} already withdrawn today)",
+                    coin.to_uppercase(),
+                    max,
+                    used
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> WithdrawPolicy {
+        WithdrawPolicy::new()
+            .allow_address("usdt", "0xabc", Some("ETH"))
+            .allow(AllowedAddress {
+                coin: "XRP".to_string(),
+                address: "rXYZ".to_string(),
+                network: None,
+                address_tag: Some("42".to_string()),
+            })
+            .daily_limit("USDT", 100.0)
+    }
+
+    #[test]
+    fn test_whitelist() {
+        let policy = policy();
+        assert!(
+            policy
+                .check("USDT", "0xabc", "10", Some("eth"), None)
+                .is_ok()
+        );
+        assert!(
+            policy
+                .check("USDT", "0xabc", "10", Some("BSC"), None)
+                .is_err()
+        );
+        assert!(policy.check("USDT", "0xabc", "10", None, None).is_err());
+        assert!(
+            policy
+                .check("USDT", "0xdef", "10", Some("ETH"), None)
+                .is_err()
+        );
+        assert!(
+            policy
+                .check("BTC", "0xabc", "10", Some("ETH"), None)
+                .is_err()
+        );
+
+        assert!(policy.check("XRP", "rXYZ", "10", None, Some("42")).is_ok());
+        assert!(policy.check("XRP", "rXYZ", "10", None, Some("7")).is_err());
+
+        assert!(
+            policy
+                .check("USDT", "0xabc", "abc", Some("ETH"), None)
+                .is_err()
+        );
+        assert!(
+            WithdrawPolicy::new()
+                .check("USDT", "0xabc", "1", None, None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_daily_limit() {
+        let policy = policy();
+        let day = 20_000 * MILLIS_PER_DAY;
+
+        let (reserved_day, reserved) = policy
+            .reserve_at("USDT", "0xabc", "60", Some("ETH"), None, day)
+            .unwrap();
+        assert_eq!(reserved, 60.0);
+
+        let err = policy
+            .reserve_at("USDT", "0xabc", "50", Some("ETH"), None, day + 1)
+            .unwrap_err();
+        assert!(matches!(err, Error::WithdrawRefused(_)));
+
+        // Failed requests give the amount back.
+        policy.release("USDT", reserved_day, reserved);
+        assert!(
+            policy
+                .reserve_at("USDT", "0xabc", "100", Some("ETH"), None, day + 2)
+                .is_ok()
+        );
+
+        // The limit resets on the next UTC day.
+        assert!(
+            policy
+                .reserve_at(
+                    "USDT",
+                    "0xabc",
+                    "100",
+                    Some("ETH"),
+                    None,
+                    day + MILLIS_PER_DAY
+                )
+                .is_ok()
+        );
+
+        // Coins without a limit are unrestricted.
+        assert!(
+            policy
+                .reserve_at("XRP", "rXYZ", "1000000", None, Some("42"), day)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_release_after_midnight() {
+        let policy = policy();
+        let before_midnight = 20_001 * MILLIS_PER_DAY - 1;
+
+        let (reserved_day, reserved) = policy
+            .reserve_at("USDT", "0xabc", "60", Some("ETH"), None, before_midnight)
+            .unwrap();
+        // Another withdrawal resets the usage for the next day
+        policy
+            .reserve_at(
+                "USDT",
+                "0xabc",
+                "80",
+                Some("ETH"),
+                None,
+                before_midnight + 1,
+            )
+            .unwrap();
+
+        // The first withdrawal fails after midnight; the new day keeps its usage
+        policy.release("USDT", reserved_day, reserved);
+        assert_eq!(policy.usage.lock().unwrap()["USDT"].amount, 80.0);
+        assert!(
+            policy
+                .reserve_at(
+                    "USDT",
+                    "0xabc",
+                    "30",
+                    Some("ETH"),
+                    None,
+                    before_midnight + 2
+                )
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Order entry over the WebSocket API.
+
+use serde::de::DeserializeOwned;
+
+use crate::models::{CancelReplaceResponse, CanceledOpenOrder, OcoOrder, OrderFull};
+use crate::rest::{CancelReplaceOrder, NewOcoOrder, NewOrder};
+use crate::{Binance, Error, Result};
+
+use super::WebSocketConnection;
+
+// Parameters sent as JSON numbers rather than strings.
+const INTEGER_PARAMS: &[&str] = &[
+    "orderId",
+    "cancelOrderId",
+    "strategyId",
+    "strategyType",
+    "trailingDelta",
+    "pegOffsetValue",
+    "recvWindow",
+    "timestamp",
+];
+
+/// A logged in WebSocket API session for placing and canceling orders.
+///
+/// Requests take the same builder types as the REST
+/// [`Account`](crate::rest::Account) API, but skip the per-request TCP and
+/// TLS overhead. Like REST order requests, they are refused once the client
+/// is shutting down, waited for by graceful shutdown and recorded in the
+/// client's [`AuditLog`](crate::AuditLog). The session is not reconnected; connect again if a request
+/// fails with a WebSocket error.
+///
+/// **Requires an Ed25519 API key.**
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::ws::WsApiClient;
+///
+/// let mut api = WsApiClient::connect(&client).await?;
+///
+/// let order = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+///     .quantity("0.001")
+///     .price("50000.00")
+///     .time_in_force(TimeInForce::GTC)
+///     .build();
+/// let placed = api.place_order(&order).await?;
+///
+/// let replace = CancelReplaceOrderBuilder::new(
+///     "BTCUSDT",
+///     OrderSide::Buy,
+///     OrderType::Limit,
+///     CancelReplaceMode::StopOnFailure,
+/// )
+/// .cancel_order_id(placed.order_id)
+/// .quantity("0.001")
+/// .price("50010.00")
+/// .time_in_force(TimeInForce::GTC)
+/// .build();
+/// api.cancel_replace_order(&replace).await?;
+///
+/// api.cancel_all_orders("BTCUSDT").await?;
+/// ```
+pub struct WsApiClient {
+    client: Binance,
+    connection: WebSocketConnection,
+}
+
+impl WsApiClient {
+    /// Connect to the WebSocket API and log in with `session.logon`.
+    pub async fn connect(client: &Binance) -> Result<Self> {
+        let credentials = client
+            .client()
+            .credentials()
+            .ok_or(Error::AuthenticationRequired)?;
+        let timestamp = client.client().time_sync().timestamp()?;
+
+        let mut connection = client.websocket().connect_ws_api().await?;
+        connection.session_logon(credentials, timestamp).await?;
+        Ok(Self {
+            client: client.clone(),
+            connection,
+        })
+    }
+
+    /// Place an order (`order.place`).
+    pub async fn place_order(&mut self, order: &NewOrder) -> Result<OrderFull> {
+        self.send("order.place", order.to_params()).await
+    }
+
+    /// Cancel an order and place a new one (`order.cancelReplace`).
+    pub async fn cancel_replace_order(
+        &mut self,
+        request: &CancelReplaceOrder,
+    ) -> Result<CancelReplaceResponse> {
+        self.send("order.cancelReplace", request.to_params()).await
+    }
+
+    /// Place an OCO order list (`orderList.place.oco`).
+    ///
+    /// The limit leg is placed as a `LIMIT_MAKER` order and the stop leg as a
+    /// `STOP_LOSS_LIMIT` order, or `STOP_LOSS` without a stop limit price.
+    pub async fn place_oco(&mut self, order: &NewOcoOrder) -> Result<OcoOrder> {
+        self.send("orderList.place.oco", order.to_order_list_params())
+            .await
+    }
+
+    /// Cancel all open orders and order lists on a symbol
+    /// (`openOrders.cancelAll`).
+    pub async fn cancel_all_orders(&mut self, symbol: &str) -> Result<Vec<CanceledOpenOrder>> {
+        let params = vec![("symbol".to_string(), symbol.to_string())];
+        self.send("openOrders.cancelAll", params).await
+    }
+
+    /// Get the underlying connection.
+    pub fn connection(&mut self) -> &mut WebSocketConnection {
+        &mut self.connection
+    }
+
+    // Send an order request, tracked for shutdown and audited like REST
+    // order requests.
+    async fn send<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: Vec<(String, String)>,
+    ) -> Result<T> {
+        let client = self.client.client();
+        let _in_flight = client.shutdown_handle().begin_order()?;
+        let timestamp = client.time_sync().timestamp()?;
+        let params = request_params(params, timestamp, self.client.config().recv_window);
+        let result = self.connection.request(method, params.clone()).await;
+        if let Some(audit_log) = client.audit_log() {
+            audit_log.record_ws_api(method, &params, &result);
+        }
+        serde_json::from_value(result?).map_err(Error::Serialization)
+    }
+}
+
+impl std::fmt::Debug for WsApiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WsApiClient").finish_non_exhaustive()
+    }
+}
+
+// Build the params of a request in a logged in session, which needs no
+// API key or signature.
+fn request_params(
+    params: Vec<(String, String)>,
+    timestamp: u64,
+    recv_window: u64,
+) -> serde_json::Value {
+    let mut object = serde_json::Map::new();
+    for (key, value) in params {
+        let value = match value.parse::<i64>() {
+            Ok(number) if INTEGER_PARAMS.contains(&key.as_str()) => number.into(),
+            _ => value.into(),
+        };
+        object.insert(key, value);
+    }
+    if recv_window > 0 {
+        object.insert("recvWindow".to_string(), recv_window.into());
+    }
+    object.insert("timestamp".to_string(), timestamp.into());
+    serde_json::Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_params() {
+        let params = vec![
+            ("symbol".to_string(), "BTCUSDT".to_string()),
+            ("cancelOrderId".to_string(), "12345".to_string()),
+            ("quantity".to_string(), "1".to_string()),
+        ];
+        let params = request_params(params, 1_700_000_000_000, 5000);
+        assert_eq!(
+            params,
+            serde_json::json!({
+                "symbol": "BTCUSDT",
+                "cancelOrderId": 12345,
+                "quantity": "1",
+                "recvWindow": 5000,
+                "timestamp": 1_700_000_000_000u64,
+            })
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! Best bid/offer cache across many symbols from the book ticker streams.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc};
+use tokio::time::sleep;
+
+use crate::Result;
+use crate::credentials::get_timestamp;
+use crate::models::websocket::{BookTickerEvent, WebSocketEvent};
+use crate::supervisor::RestartPolicy;
+
+/// Best bid and offer of one symbol.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BestBidOffer {
+    /// Symbol.
+    pub symbol: String,
+    /// Best bid price.
+    pub bid_price: f64,
+    /// Best bid quantity.
+    pub bid_quantity: f64,
+    /// Best ask price.
+    pub ask_price: f64,
+    /// Best ask quantity.
+    pub ask_quantity: f64,
+    /// Order book update ID.
+    pub update_id: u64,
+    /// Local time the update was received (milliseconds).
+    pub updated_at: u64,
+}
+
+impl BestBidOffer {
+    /// Get the mid price.
+    pub fn mid_price(&self) -> f64 {
+        (self.bid_price + self.ask_price) / 2.0
+    }
+
+    /// Get the spread (ask - bid).
+    pub fn spread(&self) -> f64 {
+        self.ask_price - self.bid_price
+    }
+}
+
+// Best bid/offer per symbol.
+#[derive(Debug, Default)]
+struct BookTickers {
+    quotes: HashMap<String, BestBidOffer>,
+}
+
+impl BookTickers {
+    // Store an update, returning it if the best bid or ask price changed.
+    fn apply(&mut self, event: &BookTickerEvent, now: u64) -> Option<BestBidOffer> {
+        let quote = BestBidOffer {
+            symbol: event.symbol.clone(),
+            bid_price: event.bid_price,
+            bid_quantity: event.bid_quantity,
+            ask_price: event.ask_price,
+            ask_quantity: event.ask_quantity,
+            update_id: event.update_id,
+            updated_at: now,
+        };
+
+        let changed = match self.quotes.get(&event.symbol) {
+            Some(previous) if previous.update_id > event.update_id => return None,
+            Some(previous) => {
+                previous.bid_price != quote.bid_price || previous.ask_price != quote.ask_price
+            }
+            None => true,
+        };
+
+        self.quotes.insert(event.symbol.clone(), quote.clone());
+        changed.then_some(quote)
+    }
+}
+
+/// Keeps the best bid and offer of many symbols in memory.
+///
+/// The cache follows either the `<symbol>@bookTicker` streams of the given
+/// symbols over one combined connection, or `!bookTicker` for every symbol.
+/// Each update whose best bid or ask price differs from the previous one is
+/// sent to [`next_change`](Self::next_change); changes are dropped if they
+/// are not received fast enough.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::BookTickerCache;
+///
+/// let mut cache = BookTickerCache::new(client.clone(), &["BTCUSDT", "ETHUSDT"]).await?;
+///
+/// while let Some(quote) = cache.next_change().await {
+///     println!("{} {} / {}", quote.symbol, quote.bid_price, quote.ask_price);
+/// }
+///
+/// let quotes = cache.snapshot().await;
+/// ```
+pub struct BookTickerCache {
+    tickers: Arc<RwLock<BookTickers>>,
+    is_stopped: Arc<AtomicBool>,
+    change_rx: mpsc::Receiver<BestBidOffer>,
+}
+
+impl BookTickerCache {
+    /// Start following the book tickers of `symbols`.
+    ///
+    /// An empty `symbols` follows every symbol through `!bookTicker`.
+    pub async fn new(client: crate::Binance, symbols: &[&str]) -> Result<Self> {
+        let ws = client.websocket();
+        let streams: Vec<String> = if symbols.is_empty() {
+            vec![ws.all_book_ticker_stream()]
+        } else {
+            symbols.iter().map(|s| ws.book_ticker_stream(s)).collect()
+        };
+        let tickers = Arc::new(RwLock::new(BookTickers::default()));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let (change_tx, change_rx) = mpsc::channel(100);
+
+        let tickers_clone = tickers.clone();
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "book-ticker-cache", policy, move || {
+            Self::sync_loop(
+                client.clone(),
+                streams.clone(),
+                tickers_clone.clone(),
+                is_stopped_clone.clone(),
+                change_tx.clone(),
+            )
+        });
+
+        Ok(Self {
+            tickers,
+            is_stopped,
+            change_rx,
+        })
+    }
+
+    async fn sync_loop(
+        client: crate::Binance,
+        streams: Vec<String>,
+        tickers: Arc<RwLock<BookTickers>>,
+        is_stopped: Arc<AtomicBool>,
+        change_tx: mpsc::Sender<BestBidOffer>,
+    ) {
+        let ws = client.websocket();
+        let shutdown = client.client().shutdown_handle().clone();
+
+        loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            let mut conn = match ws.connect_combined_with_reconnect(&streams).await {
+                Ok(c) => c,
+                Err(_) => {
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            loop {
+                if is_stopped.load(Ordering::SeqCst) {
+                    conn.close().await;
+                    return;
+                }
+
+                let next = tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        conn.close().await;
+                        return;
+                    }
+                    next = conn.next() => next,
+                };
+
+                match next {
+                    Some(Ok(WebSocketEvent::BookTicker(ticker))) => {
+                        let now = get_timestamp().unwrap_or_default();
+                        if let Some(quote) = tickers.write().await.apply(&ticker, now) {
+                            let _ = change_tx.try_send(quote);
+                        }
+                    }
+                    Some(_) => continue,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Receive the next best bid or ask price change.
+    pub async fn next_change(&mut self) -> Option<BestBidOffer> {
+        self.change_rx.recv().await
+    }
+
+    /// Get the best bid and offer of a symbol.
+    pub async fn get(&self, symbol: &str) -> Option<BestBidOffer> {
+        self.tickers
+            .read()
+            .await
+            .quotes
+            .get(&symbol.to_uppercase())
+            .cloned()
+    }
+
+    /// Get the best bid and offer of every symbol seen so far, keyed by symbol.
+    pub async fn snapshot(&self) -> HashMap<String, BestBidOffer> {
+        self.tickers.read().await.quotes.clone()
+    }
+
+    /// Stop the book ticker cache.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(update_id: u64, bid: f64, bid_qty: f64, ask: f64) -> BookTickerEvent {
+        BookTickerEvent {
+            update_id,
+            symbol: "BTCUSDT".to_string(),
+            bid_price: bid,
+            bid_quantity: bid_qty,
+            ask_price: ask,
+            ask_quantity: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_book_ticker_changes() {
+        let mut tickers = BookTickers::default();
+        let quote = tickers.apply(&ticker(1, 100.0, 1.0, 101.0), 10).unwrap();
+        assert_eq!(quote.mid_price(), 100.5);
+        assert_eq!(quote.updated_at, 10);
+
+        // Quantity-only updates are stored without a change
+        assert!(tickers.apply(&ticker(2, 100.0, 2.0, 101.0), 20).is_none());
+        assert_eq!(tickers.quotes["BTCUSDT"].bid_quantity, 2.0);
+
+        assert!(tickers.apply(&ticker(3, 100.5, 2.0, 101.0), 30).is_some());
+
+        // Out-of-order updates are ignored
+        assert!(tickers.apply(&ticker(1, 99.0, 1.0, 101.0), 40).is_none());
+        assert_eq!(tickers.quotes["BTCUSDT"].bid_price, 100.5);
+    }
+}
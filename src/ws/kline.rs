@@ -0,0 +1,425 @@
+//! Local kline (candlestick) cache kept current from the kline stream.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc};
+use tokio::time::{sleep, timeout};
+
+use super::{Rotation, WS_TIMEOUT_SECS};
+use crate::Result;
+use crate::credentials::get_timestamp;
+use crate::models::Kline;
+use crate::models::websocket::{KlineData, WebSocketEvent};
+use crate::supervisor::RestartPolicy;
+use crate::types::KlineInterval;
+
+/// Largest number of klines returned by one `klines` request.
+const MAX_KLINES_PER_REQUEST: usize = 1000;
+
+/// Configuration for the kline cache manager.
+#[derive(Debug, Clone)]
+pub struct KlineCacheConfig {
+    /// Number of candles to keep, including the one still forming.
+    pub capacity: usize,
+}
+
+impl Default for KlineCacheConfig {
+    fn default() -> Self {
+        Self { capacity: 500 }
+    }
+}
+
+/// Candles for one symbol and interval, oldest first.
+///
+/// The newest candle may still be forming; it is replaced by every kline
+/// update until the stream marks it closed.
+#[derive(Debug, Clone)]
+pub struct KlineCache {
+    symbol: String,
+    interval: KlineInterval,
+    candles: VecDeque<Kline>,
+    capacity: usize,
+    // Whether the newest candle is still forming.
+    forming: bool,
+}
+
+impl KlineCache {
+    /// Create an empty cache.
+    pub fn new(symbol: &str, interval: KlineInterval, capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            symbol: symbol.to_uppercase(),
+            interval,
+            candles: VecDeque::with_capacity(capacity),
+            capacity,
+            forming: false,
+        }
+    }
+
+    /// Replace the cache contents with klines from the REST API.
+    ///
+    /// A candle whose close time is at or after `now_ms` is treated as still
+    /// forming. Returns the closed candles that are newer than the last
+    /// closed candle previously in the cache (all of them if it was empty).
+    pub fn seed(&mut self, klines: &[Kline], now_ms: u64) -> Vec<Kline> {
+        let last_closed = self.closed_candles().last().map(|k| k.open_time);
+
+        self.candles = klines.iter().cloned().collect();
+        self.forming = self
+            .candles
+            .back()
+            .is_some_and(|k| k.close_time >= now_ms as i64);
+        self.trim();
+
+        self.closed_candles()
+            .into_iter()
+            .filter(|k| last_closed.is_none_or(|t| k.open_time > t))
+            .collect()
+    }
+
+    /// Apply a kline stream update.
+    ///
+    /// Returns the candle if this update closed it.
+    pub fn apply(&mut self, data: &KlineData) -> Option<Kline> {
+        let kline = Kline::from(data);
+
+        match self.candles.back() {
+            Some(last) if kline.open_time < last.open_time => return None,
+            Some(last) if kline.open_time == last.open_time => {
+                if !self.forming {
+                    // Already closed
+                    return None;
+                }
+                self.candles.pop_back();
+            }
+            _ => {}
+        }
+
+        self.candles.push_back(kline.clone());
+        self.forming = !data.is_closed;
+        self.trim();
+
+        data.is_closed.then_some(kline)
+    }
+
+    /// Get the most recent `n` candles, oldest first, including the one still forming.
+    pub fn latest(&self, n: usize) -> Vec<Kline> {
+        let skip = self.candles.len().saturating_sub(n);
+        self.candles.iter().skip(skip).cloned().collect()
+    }
+
+    /// Get all closed candles, oldest first.
+    pub fn closed_candles(&self) -> Vec<Kline> {
+        let closed = self.candles.len() - usize::from(self.forming);
+        self.candles.iter().take(closed).cloned().collect()
+    }
+
+    /// Get the candle that is still forming, if any.
+    pub fn current(&self) -> Option<&Kline> {
+        self.candles.back().filter(|_| self.forming)
+    }
+
+    /// Get the symbol.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Get the interval.
+    pub fn interval(&self) -> KlineInterval {
+        self.interval
+    }
+
+    /// Get the number of cached candles.
+    pub fn len(&self) -> usize {
+        self.candles.len()
+    }
+
+    /// Check if the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.candles.is_empty()
+    }
+
+    fn trim(&mut self) {
+        while self.candles.len() > self.capacity {
+            self.candles.pop_front();
+        }
+    }
+}
+
+/// Keeps a local candle history current from the kline stream.
+///
+/// The cache is seeded from `market().klines()` and then updated from the
+/// `<symbol>@kline_<interval>` stream. After a reconnect it is seeded again
+/// so no candles are missed. Every candle that closes is sent to
+/// [`next_closed`](Self::next_closed); notifications are dropped if they are
+/// not received fast enough.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{KlineCacheConfig, KlineCacheManager, KlineInterval};
+///
+/// let mut manager = KlineCacheManager::new(
+///     client.clone(),
+///     "BTCUSDT",
+///     KlineInterval::Minutes1,
+///     KlineCacheConfig::default(),
+/// )
+/// .await?;
+///
+/// while let Some(candle) = manager.next_closed().await {
+///     let closes: Vec<f64> = manager.latest(20).await.iter().map(|k| k.close).collect();
+///     println!("{} closed at {}, last 20 closes: {:?}", candle.open_time, candle.close, closes);
+/// }
+/// ```
+pub struct KlineCacheManager {
+    cache: Arc<RwLock<KlineCache>>,
+    is_stopped: Arc<AtomicBool>,
+    closed_rx: mpsc::Receiver<Kline>,
+}
+
+impl KlineCacheManager {
+    /// Create a kline cache manager.
+    ///
+    /// The cache is seeded before this returns, so it is usable immediately.
+    pub async fn new(
+        client: crate::Binance,
+        symbol: &str,
+        interval: KlineInterval,
+        config: KlineCacheConfig,
+    ) -> Result<Self> {
+        let mut cache = KlineCache::new(symbol, interval, config.capacity);
+        let klines = Self::fetch(&client, &cache).await?;
+        cache.seed(&klines, get_timestamp()?);
+
+        let cache = Arc::new(RwLock::new(cache));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let (closed_tx, closed_rx) = mpsc::channel(100);
+
+        let cache_clone = cache.clone();
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "kline-cache", policy, move || {
+            Self::sync_loop(
+                client.clone(),
+                cache_clone.clone(),
+                is_stopped_clone.clone(),
+                closed_tx.clone(),
+            )
+        });
+
+        Ok(Self {
+            cache,
+            is_stopped,
+            closed_rx,
+        })
+    }
+
+    async fn fetch(client: &crate::Binance, cache: &KlineCache) -> Result<Vec<Kline>> {
+        let limit = cache.capacity.min(MAX_KLINES_PER_REQUEST) as u16;
+        client
+            .market()
+            .klines(cache.symbol(), cache.interval(), None, None, Some(limit))
+            .await
+    }
+
+    async fn sync_loop(
+        client: crate::Binance,
+        cache: Arc<RwLock<KlineCache>>,
+        is_stopped: Arc<AtomicBool>,
+        closed_tx: mpsc::Sender<Kline>,
+    ) {
+        let ws = client.websocket();
+        let (symbol, interval) = {
+            let cache = cache.read().await;
+            (cache.symbol().to_string(), cache.interval())
+        };
+        let stream = ws.kline_stream(&symbol, interval);
+        let shutdown = client.client().shutdown_handle().clone();
+        let mut reconnecting = false;
+
+        loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            let mut conn = match ws.connect(&stream).await {
+                Ok(c) => c,
+                Err(_) => {
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+            let mut rotation = Rotation::new(client.config().ws_rotate_after);
+
+            // Fill the gap left by the disconnect
+            if reconnecting {
+                let snapshot = cache.read().await.clone();
+                let klines = match Self::fetch(&client, &snapshot).await {
+                    Ok(k) => k,
+                    Err(_) => {
+                        sleep(Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+                let now = get_timestamp().unwrap_or_default();
+                for kline in cache.write().await.seed(&klines, now) {
+                    let _ = closed_tx.try_send(kline);
+                }
+            }
+            reconnecting = true;
+
+            loop {
+                if is_stopped.load(Ordering::SeqCst) {
+                    let _ = conn.close().await;
+                    return;
+                }
+
+                let next = tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        let _ = conn.close().await;
+                        return;
+                    }
+                    _ = rotation.due() => {
+                        // Switch to a new connection before Binance closes this one
+                        match ws.connect(&stream).await {
+                            Ok(new_conn) => {
+                                conn.rotate(new_conn).await;
+                                rotation.reset();
+                            }
+                            Err(_) => rotation.retry_later(),
+                        }
+                        continue;
+                    }
+                    next = timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()) => next,
+                };
+
+                match next {
+                    Ok(Some(Ok(WebSocketEvent::Kline(event)))) => {
+                        if let Some(closed) = cache.write().await.apply(&event.kline) {
+                            let _ = closed_tx.try_send(closed);
+                        }
+                    }
+                    Ok(Some(_)) => continue,
+                    // Connection closed or stale, reconnect
+                    Ok(None) | Err(_) => break,
+                }
+            }
+
+            // Brief delay before reconnecting
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Wait for the next candle to close.
+    pub async fn next_closed(&mut self) -> Option<Kline> {
+        self.closed_rx.recv().await
+    }
+
+    /// Get the most recent `n` candles, oldest first, including the one still forming.
+    pub async fn latest(&self, n: usize) -> Vec<Kline> {
+        self.cache.read().await.latest(n)
+    }
+
+    /// Get all closed candles, oldest first.
+    pub async fn closed_candles(&self) -> Vec<Kline> {
+        self.cache.read().await.closed_candles()
+    }
+
+    /// Get the candle that is still forming, if any.
+    pub async fn current(&self) -> Option<Kline> {
+        self.cache.read().await.current().cloned()
+    }
+
+    /// Get a copy of the whole cache.
+    pub async fn get_cache(&self) -> KlineCache {
+        self.cache.read().await.clone()
+    }
+
+    /// Stop the kline cache manager.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kline(open_time: i64, close: f64) -> Kline {
+        Kline {
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: 1.0,
+            close_time: open_time + 59_999,
+            quote_asset_volume: close,
+            number_of_trades: 1,
+            taker_buy_base_asset_volume: 0.5,
+            taker_buy_quote_asset_volume: close / 2.0,
+        }
+    }
+
+    fn update(open_time: i64, close: f64, is_closed: bool) -> KlineData {
+        let json = format!(
+            r#"{{"t":{},"T":{},"s":"BTCUSDT","i":"1m","f":1,"L":2,"o":"{c}","c":"{c}",
+                "h":"{c}","l":"{c}","v":"1","n":1,"x":{},"q":"1","V":"0.5","Q":"0.5","B":"0"}}"#,
+            open_time,
+            open_time + 59_999,
+            is_closed,
+            c = close
+        );
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn test_kline_cache_updates() {
+        let mut cache = KlineCache::new("btcusdt", KlineInterval::Minutes1, 3);
+        cache.seed(&[kline(0, 1.0), kline(60_000, 2.0)], 100_000);
+        assert_eq!(cache.closed_candles().len(), 1);
+        assert_eq!(cache.current().map(|k| k.close), Some(2.0));
+
+        // Updates replace the forming candle until it closes
+        assert_eq!(cache.apply(&update(60_000, 2.5, false)), None);
+        let closed = cache.apply(&update(60_000, 3.0, true)).unwrap();
+        assert_eq!(closed.close, 3.0);
+        assert!(cache.current().is_none());
+        assert_eq!(cache.apply(&update(60_000, 3.0, true)), None);
+
+        // New candles are appended and the oldest are dropped
+        cache.apply(&update(120_000, 4.0, false));
+        cache.apply(&update(180_000, 5.0, false));
+        assert_eq!(cache.len(), 3);
+        let closes: Vec<f64> = cache.latest(2).iter().map(|k| k.close).collect();
+        assert_eq!(closes, vec![4.0, 5.0]);
+
+        // Stale updates are ignored
+        assert_eq!(cache.apply(&update(0, 9.0, true)), None);
+    }
+
+    #[test]
+    fn test_kline_cache_reseed_reports_missed_closes() {
+        let mut cache = KlineCache::new("BTCUSDT", KlineInterval::Minutes1, 10);
+        cache.seed(&[kline(0, 1.0), kline(60_000, 2.0)], 100_000);
+
+        let missed = cache.seed(
+            &[
+                kline(0, 1.0),
+                kline(60_000, 2.0),
+                kline(120_000, 3.0),
+                kline(180_000, 4.0),
+            ],
+            200_000,
+        );
+        let opens: Vec<i64> = missed.iter().map(|k| k.open_time).collect();
+        assert_eq!(opens, vec![60_000, 120_000]);
+        assert_eq!(cache.current().map(|k| k.open_time), Some(180_000));
+    }
+}
@@ -5,8 +5,14 @@
 //!
 //! - Auto-reconnection with exponential backoff
 //! - Depth cache management (local order book)
+//! - Order books for many symbols over one connection
+//! - Kline cache management (local candle history)
 //! - User data stream keep-alive
 //! - Connection health monitoring
+//! - Best bid/offer cache across many symbols
+//! - Bid/ask spread monitoring
+//! - Multiplexing many streams over one connection
+//! - Reference-counted subscriptions with per-connection stream budgets
 //!
 //! # Example
 //!
@@ -37,25 +43,50 @@
 //! ```
 
 use futures::{Future, SinkExt, Stream, StreamExt};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::pin::Pin;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Weak};
 use std::task::{Context, Poll};
 use std::time::{Duration, Instant};
 use tokio::net::TcpStream;
-use tokio::sync::{Mutex, RwLock, mpsc};
-use tokio::time::{interval, sleep, timeout};
+use tokio::sync::{Mutex, RwLock, RwLockReadGuard, broadcast, mpsc, oneshot, watch};
+use tokio::time::{MissedTickBehavior, interval, interval_at, sleep, timeout};
 use tokio_tungstenite::{
-    MaybeTlsStream, WebSocketStream as TungsteniteStream, connect_async,
+    MaybeTlsStream, WebSocketStream as TungsteniteStream,
     tungstenite::{Bytes, Message},
 };
 
-use crate::config::Config;
+use crate::config::{Config, DEFAULT_WS_ROTATE_AFTER};
+use crate::credentials::{Credentials, SignatureType, get_timestamp};
+use crate::metrics::Metrics;
 use crate::models::OrderBook;
 use crate::models::websocket::{DepthEvent, WebSocketEvent};
-use crate::types::KlineInterval;
+use crate::runtime::RuntimeHints;
+use crate::shutdown::ShutdownHandle;
+use crate::supervisor::RestartPolicy;
+use crate::types::{KlineInterval, OrderSide, TimeUnit};
 use crate::{Error, Result};
+use reconcile::ExecutionTracker;
+
+mod api;
+mod book_ticker;
+mod kline;
+mod multi_depth;
+mod multiplex;
+mod proxy;
+mod reconcile;
+mod spread;
+mod subscription;
+
+pub use api::WsApiClient;
+pub use book_ticker::{BestBidOffer, BookTickerCache};
+pub use kline::{KlineCache, KlineCacheConfig, KlineCacheManager};
+pub use multi_depth::MultiDepthCacheManager;
+pub use multiplex::StreamMultiplexer;
+pub use reconcile::{UserDataReconciliation, UserDataStreamConfig};
+pub use spread::{SpreadAlert, SpreadMonitor, SpreadMonitorConfig, SpreadStats};
+pub use subscription::{Subscription, SubscriptionManager};
 
 // Constants.
 
@@ -74,22 +105,80 @@ const WS_TIMEOUT_SECS: u64 = 30;
 /// Interval for health check pings (in seconds).
 const HEALTH_CHECK_INTERVAL_SECS: u64 = 30;
 
+/// Time without any message before a connection is considered stale (in seconds).
+const MAX_IDLE_SECS: u64 = 60;
+
+/// Default capacity of a reconnecting connection's event channel.
+const EVENT_CHANNEL_CAPACITY: usize = 1000;
+
 /// User data stream keepalive interval (in seconds).
 /// Should be less than 60 minutes (the listen key expiry time).
 const USER_STREAM_KEEPALIVE_SECS: u64 = 30 * 60; // 30 minutes
 
+/// Capacity of the user data stream status event channel.
+const USER_STREAM_EVENT_CAPACITY: usize = 64;
+
+/// Capacity of the depth cache event channel.
+const DEPTH_CACHE_EVENT_CAPACITY: usize = 64;
+
+/// Capacity of the connection state change channel.
+const CONNECTION_STATE_CAPACITY: usize = 16;
+
+/// Delay before retrying a failed connection rotation (in seconds).
+const ROTATE_RETRY_SECS: u64 = 60;
+
 // WebSocket client.
 
 /// WebSocket client for connecting to Binance streams.
 #[derive(Clone)]
 pub struct WebSocketClient {
     config: Config,
+    shutdown: ShutdownHandle,
+    time_unit: Option<TimeUnit>,
 }
 
 impl WebSocketClient {
     /// Create a new WebSocket client.
-    pub(crate) fn new(config: Config) -> Self {
-        Self { config }
+    ///
+    /// Background tasks started by this client stop when `shutdown` is signalled.
+    pub(crate) fn new(config: Config, shutdown: ShutdownHandle) -> Self {
+        Self {
+            config,
+            shutdown,
+            time_unit: None,
+        }
+    }
+
+    /// Set the unit of event timestamps on stream connections (`timeUnit`).
+    ///
+    /// With [`TimeUnit::Microsecond`], every time field of the events
+    /// received, such as `event_time` and `trade_time`, is in microseconds.
+    /// Connections opened by the depth and kline cache managers are not
+    /// affected.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let ws = client.websocket().with_time_unit(TimeUnit::Microsecond);
+    /// let mut conn = ws.connect(&ws.trade_stream("btcusdt")).await?;
+    ///
+    /// while let Some(event) = conn.next_event().await {
+    ///     let event = event?;
+    ///     if let WebSocketEvent::Trade(trade) = event.event {
+    ///         let received_us = TimeUnit::Millisecond.to_micros(event.received_at);
+    ///         let latency_us = received_us.saturating_sub(trade.event_time);
+    ///         println!("{}us", latency_us);
+    ///     }
+    /// }
+    /// ```
+    pub fn with_time_unit(mut self, time_unit: TimeUnit) -> Self {
+        self.time_unit = Some(time_unit);
+        self
+    }
+
+    /// Get the unit of event timestamps on stream connections.
+    pub fn time_unit(&self) -> TimeUnit {
+        self.time_unit.unwrap_or_default()
     }
 
     /// Get the WebSocket endpoint URL.
@@ -97,6 +186,28 @@ impl WebSocketClient {
         &self.config.ws_endpoint
     }
 
+    // URL of a single stream connection.
+    fn stream_url(&self, stream: &str) -> String {
+        let url = format!("{}/ws/{}", self.config.ws_endpoint, stream);
+        match self.time_unit {
+            Some(time_unit) => format!("{}?timeUnit={}", url, time_unit),
+            None => url,
+        }
+    }
+
+    // URL of a combined stream connection.
+    fn combined_stream_url(&self, streams: &[String]) -> String {
+        let url = format!(
+            "{}/stream?streams={}",
+            self.config.ws_endpoint,
+            streams.join("/")
+        );
+        match self.time_unit {
+            Some(time_unit) => format!("{}&timeUnit={}", url, time_unit),
+            None => url,
+        }
+    }
+
     /// Connect to a single stream.
     ///
     /// # Arguments
@@ -111,8 +222,10 @@ impl WebSocketClient {
     /// let mut conn = ws.connect(&stream).await?;
     /// ```
     pub async fn connect(&self, stream: &str) -> Result<WebSocketConnection> {
-        let url = format!("{}/ws/{}", self.config.ws_endpoint, stream);
-        self.connect_url(&url).await
+        let url = self.stream_url(stream);
+        let mut conn = self.connect_url(&url).await?;
+        conn.stream = Some(stream.to_string());
+        Ok(conn)
     }
 
     /// Connect to multiple streams (combined stream).
@@ -132,11 +245,7 @@ impl WebSocketClient {
     /// let mut conn = ws.connect_combined(&streams).await?;
     /// ```
     pub async fn connect_combined(&self, streams: &[String]) -> Result<WebSocketConnection> {
-        let streams_param = streams.join("/");
-        let url = format!(
-            "{}/stream?streams={}",
-            self.config.ws_endpoint, streams_param
-        );
+        let url = self.combined_stream_url(streams);
         self.connect_url(&url).await
     }
 
@@ -153,7 +262,7 @@ impl WebSocketClient {
     /// let mut conn = client.websocket().connect_user_stream(&listen_key).await?;
     /// ```
     pub async fn connect_user_stream(&self, listen_key: &str) -> Result<WebSocketConnection> {
-        let url = format!("{}/ws/{}", self.config.ws_endpoint, listen_key);
+        let url = self.stream_url(listen_key);
         self.connect_url(&url).await
     }
 
@@ -179,8 +288,8 @@ impl WebSocketClient {
     /// }
     /// ```
     pub async fn connect_with_reconnect(&self, stream: &str) -> Result<ReconnectingWebSocket> {
-        let url = format!("{}/ws/{}", self.config.ws_endpoint, stream);
-        ReconnectingWebSocket::new(url, ReconnectConfig::default()).await
+        self.connect_with_reconnect_config(stream, self.reconnect_config())
+            .await
     }
 
     /// Connect to combined streams with auto-reconnection support.
@@ -188,16 +297,81 @@ impl WebSocketClient {
         &self,
         streams: &[String],
     ) -> Result<ReconnectingWebSocket> {
-        let streams_param = streams.join("/");
-        let url = format!(
-            "{}/stream?streams={}",
-            self.config.ws_endpoint, streams_param
-        );
-        ReconnectingWebSocket::new(url, ReconnectConfig::default()).await
+        self.connect_combined_with_reconnect_config(streams, self.reconnect_config())
+            .await
+    }
+
+    /// Connect with auto-reconnection support and custom settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Stream name (e.g., "!ticker@arr")
+    /// * `config` - Reconnection settings, usually based on
+    ///   [`reconnect_config`](Self::reconnect_config)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let config = ReconnectConfig {
+    ///     event_capacity: 100,
+    ///     overflow_policy: OverflowPolicy::DropOldest,
+    ///     ..ws.reconnect_config()
+    /// };
+    /// let mut conn = ws
+    ///     .connect_with_reconnect_config(&ws.all_ticker_stream(), config)
+    ///     .await?;
+    /// ```
+    pub async fn connect_with_reconnect_config(
+        &self,
+        stream: &str,
+        config: ReconnectConfig,
+    ) -> Result<ReconnectingWebSocket> {
+        let url = self.stream_url(stream);
+        ReconnectingWebSocket::with_runtime(
+            url,
+            config,
+            self.shutdown.clone(),
+            self.config.runtime_hints.clone(),
+        )
+        .await
+    }
+
+    /// Connect to combined streams with auto-reconnection support and
+    /// custom settings.
+    pub async fn connect_combined_with_reconnect_config(
+        &self,
+        streams: &[String],
+        config: ReconnectConfig,
+    ) -> Result<ReconnectingWebSocket> {
+        let url = self.combined_stream_url(streams);
+        ReconnectingWebSocket::with_runtime(
+            url,
+            config,
+            self.shutdown.clone(),
+            self.config.runtime_hints.clone(),
+        )
+        .await
+    }
+
+    /// Get the reconnection settings used by
+    /// [`connect_with_reconnect`](Self::connect_with_reconnect), taken from
+    /// the client configuration.
+    pub fn reconnect_config(&self) -> ReconnectConfig {
+        ReconnectConfig {
+            metrics: self.config.metrics.clone(),
+            proxy: self.config.proxy.clone(),
+            rotate_after: self.config.ws_rotate_after,
+            ..ReconnectConfig::default()
+        }
+    }
+
+    /// Connect to the WebSocket API.
+    pub(crate) async fn connect_ws_api(&self) -> Result<WebSocketConnection> {
+        self.connect_url(&self.config.ws_api_endpoint).await
     }
 
     async fn connect_url(&self, url: &str) -> Result<WebSocketConnection> {
-        let (ws_stream, _) = connect_async(url).await.map_err(Error::WebSocket)?;
+        let (ws_stream, _) = proxy::connect(url, self.config.proxy.as_deref()).await?;
         Ok(WebSocketConnection::new(ws_stream))
     }
 
@@ -224,6 +398,25 @@ impl WebSocketClient {
         format!("{}@kline_{}", symbol.to_lowercase(), interval)
     }
 
+    /// Get the kline/candlestick stream name for a symbol, with candles
+    /// aligned to a UTC offset instead of UTC.
+    ///
+    /// Stream: `<symbol>@kline_<interval>@<time_zone>`
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `interval` - Kline interval
+    /// * `time_zone` - UTC offset (e.g., "+08:00")
+    pub fn kline_stream_with_time_zone(
+        &self,
+        symbol: &str,
+        interval: KlineInterval,
+        time_zone: &str,
+    ) -> String {
+        format!("{}@{}", self.kline_stream(symbol, interval), time_zone)
+    }
+
     /// Get the mini ticker stream name for a symbol.
     ///
     /// Stream: `<symbol>@miniTicker`
@@ -252,6 +445,32 @@ impl WebSocketClient {
         "!ticker@arr".to_string()
     }
 
+    /// Get the rolling window ticker stream name for a symbol.
+    ///
+    /// Stream: `<symbol>@ticker_<window>`
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - Trading pair symbol
+    /// * `window_size` - Window size (1h, 4h, or 1d)
+    pub fn rolling_window_ticker_stream(&self, symbol: &str, window_size: &str) -> String {
+        format!("{}@ticker_{}", symbol.to_lowercase(), window_size)
+    }
+
+    /// Get the rolling window ticker stream for all symbols.
+    ///
+    /// Stream: `!ticker_<window>@arr`
+    pub fn all_rolling_window_ticker_stream(&self, window_size: &str) -> String {
+        format!("!ticker_{}@arr", window_size)
+    }
+
+    /// Get the average price stream name for a symbol.
+    ///
+    /// Stream: `<symbol>@avgPrice`
+    pub fn avg_price_stream(&self, symbol: &str) -> String {
+        format!("{}@avgPrice", symbol.to_lowercase())
+    }
+
     /// Get the book ticker stream name for a symbol.
     ///
     /// Stream: `<symbol>@bookTicker`
@@ -304,12 +523,36 @@ impl WebSocketClient {
 
 // Basic WebSocket connection.
 
+/// A WebSocket event together with the stream that produced it.
+#[derive(Debug, Clone)]
+pub struct StreamEvent {
+    /// Stream name (e.g., "btcusdt@aggTrade").
+    ///
+    /// Taken from the envelope of combined stream messages, or from the
+    /// `/ws/<stream>` URL the connection was opened for, which is the listen
+    /// key for user data streams. `None` if the URL names no stream, as on
+    /// WebSocket API connections.
+    pub stream: Option<String>,
+    /// Local time the message was received, in milliseconds since Unix epoch.
+    pub received_at: u64,
+    /// The event.
+    pub event: WebSocketEvent,
+}
+
 /// An active WebSocket connection.
 ///
 /// Use `next()` to receive events, or convert to a `Stream` for async iteration.
+///
+/// Streams can be added and removed on a live connection with `subscribe()`
+/// and `unsubscribe()`.
 pub struct WebSocketConnection {
     inner: TungsteniteStream<MaybeTlsStream<TcpStream>>,
     last_ping: Instant,
+    last_message: Instant,
+    stream: Option<String>,
+    next_request_id: u64,
+    // Events received while waiting for a subscription response.
+    pending: VecDeque<Result<StreamEvent>>,
 }
 
 impl WebSocketConnection {
@@ -317,6 +560,10 @@ impl WebSocketConnection {
         Self {
             inner: stream,
             last_ping: Instant::now(),
+            last_message: Instant::now(),
+            stream: None,
+            next_request_id: 1,
+            pending: VecDeque::new(),
         }
     }
 
@@ -324,21 +571,37 @@ impl WebSocketConnection {
     ///
     /// Returns `None` if the connection is closed.
     pub async fn next(&mut self) -> Option<Result<WebSocketEvent>> {
+        self.next_event()
+            .await
+            .map(|result| result.map(|ev| ev.event))
+    }
+
+    /// Receive the next WebSocket event with its stream name and receive time.
+    ///
+    /// Returns `None` if the connection is closed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut conn = ws.connect_combined(&streams).await?;
+    ///
+    /// while let Some(event) = conn.next_event().await {
+    ///     let event = event?;
+    ///     println!("{:?} at {}: {:?}", event.stream, event.received_at, event.event);
+    /// }
+    /// ```
+    pub async fn next_event(&mut self) -> Option<Result<StreamEvent>> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(event);
+        }
+
         loop {
-            match self.inner.next().await? {
+            match self.recv_message().await? {
                 Ok(Message::Text(text)) => {
-                    // Try to parse as a combined stream message first
-                    if let Ok(combined) = serde_json::from_str::<CombinedStreamMessage>(&text) {
-                        return Some(Ok(combined.data));
-                    }
-                    // Otherwise parse as a regular event
-                    return Some(serde_json::from_str(&text).map_err(Error::Serialization));
+                    return Some(self.parse_event(text.as_bytes()));
                 }
                 Ok(Message::Binary(data)) => {
-                    if let Ok(combined) = serde_json::from_slice::<CombinedStreamMessage>(&data) {
-                        return Some(Ok(combined.data));
-                    }
-                    return Some(serde_json::from_slice(&data).map_err(Error::Serialization));
+                    return Some(self.parse_event(&data));
                 }
                 Ok(Message::Ping(data)) => {
                     self.last_ping = Instant::now();
@@ -365,10 +628,74 @@ impl WebSocketConnection {
         }
     }
 
+    /// Wrap a stream connected to `url`, taking the stream name from
+    /// single-stream URLs (`.../ws/<stream>`).
+    fn for_url(stream: TungsteniteStream<MaybeTlsStream<TcpStream>>, url: &str) -> Self {
+        let mut conn = Self::new(stream);
+        conn.stream = stream_name_from_url(url);
+        conn
+    }
+
+    fn parse_event(&self, data: &[u8]) -> Result<StreamEvent> {
+        let received_at = get_timestamp().unwrap_or_default();
+
+        // Try to parse as a combined stream message first
+        if let Ok(combined) = serde_json::from_slice::<CombinedStreamMessage>(data) {
+            return Ok(StreamEvent {
+                stream: Some(combined.stream),
+                received_at,
+                event: combined.data,
+            });
+        }
+
+        // WebSocket API user data events are wrapped in an event envelope
+        if let Ok(message) = serde_json::from_slice::<WsApiEventMessage>(data) {
+            return Ok(StreamEvent {
+                stream: self.stream.clone(),
+                received_at,
+                event: message.event,
+            });
+        }
+
+        // Otherwise parse as a regular event
+        let event = serde_json::from_slice(data).map_err(Error::Serialization)?;
+        Ok(StreamEvent {
+            stream: self.stream.clone(),
+            received_at,
+            event,
+        })
+    }
+
+    /// Receive the next message as raw JSON, without parsing it into an event.
+    ///
+    /// Combined stream messages keep their `{"stream": ..., "data": ...}`
+    /// envelope. Events received while waiting for a `subscribe()` response
+    /// were already parsed and are returned re-serialized, without an envelope.
+    ///
+    /// Returns `None` if the connection is closed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut conn = ws.connect(&ws.trade_stream("btcusdt")).await?;
+    ///
+    /// while let Some(json) = conn.next_json().await {
+    ///     println!("{}", json?);
+    /// }
+    /// ```
+    pub async fn next_json(&mut self) -> Option<Result<serde_json::Value>> {
+        if let Some(event) = self.pending.pop_front() {
+            return Some(
+                event.and_then(|ev| serde_json::to_value(&ev.event).map_err(Error::Serialization)),
+            );
+        }
+        self.next_raw().await
+    }
+
     /// Receive the next raw message (for depth cache management).
     pub(crate) async fn next_raw(&mut self) -> Option<Result<serde_json::Value>> {
         loop {
-            match self.inner.next().await? {
+            match self.recv_message().await? {
                 Ok(Message::Text(text)) => {
                     return Some(serde_json::from_str(&text).map_err(Error::Serialization));
                 }
@@ -388,6 +715,131 @@ impl WebSocketConnection {
         }
     }
 
+    /// Subscribe to additional streams on this connection.
+    ///
+    /// Waits for Binance to acknowledge the request. Events received in the
+    /// meantime are kept and returned by the following `next()` calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - Stream names (e.g., from `WebSocketClient::trade_stream`)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut conn = ws.connect_combined(&[ws.trade_stream("btcusdt")]).await?;
+    ///
+    /// conn.subscribe(&[ws.trade_stream("ethusdt")]).await?;
+    /// conn.unsubscribe(&[ws.trade_stream("btcusdt")]).await?;
+    ///
+    /// println!("{:?}", conn.list_subscriptions().await?);
+    /// ```
+    pub async fn subscribe(&mut self, streams: &[String]) -> Result<()> {
+        self.request("SUBSCRIBE", serde_json::json!(streams))
+            .await
+            .map(|_| ())
+    }
+
+    /// Unsubscribe from streams on this connection.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - Stream names to remove
+    pub async fn unsubscribe(&mut self, streams: &[String]) -> Result<()> {
+        self.request("UNSUBSCRIBE", serde_json::json!(streams))
+            .await
+            .map(|_| ())
+    }
+
+    /// List the streams this connection is subscribed to.
+    ///
+    /// Streams in the connection URL are included.
+    pub async fn list_subscriptions(&mut self) -> Result<Vec<String>> {
+        let result = self
+            .request("LIST_SUBSCRIPTIONS", serde_json::Value::Null)
+            .await?;
+        serde_json::from_value(result).map_err(Error::Serialization)
+    }
+
+    /// Log in to a WebSocket API session with an Ed25519 key (`session.logon`).
+    ///
+    /// Later requests on the connection, such as `userDataStream.subscribe`,
+    /// are authenticated as this API key.
+    pub(crate) async fn session_logon(
+        &mut self,
+        credentials: &Credentials,
+        timestamp: u64,
+    ) -> Result<serde_json::Value> {
+        let params = session_logon_params(credentials, timestamp)?;
+        self.request("session.logon", params).await
+    }
+
+    /// Subscribe to the user data stream of the logged in session.
+    pub(crate) async fn subscribe_user_data(&mut self) -> Result<()> {
+        self.request("userDataStream.subscribe", serde_json::Value::Null)
+            .await
+            .map(|_| ())
+    }
+
+    // Send a request and wait for the response with its ID.
+    async fn request(
+        &mut self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let id = self.next_request_id;
+        self.next_request_id += 1;
+
+        let request = stream_request(method, params, id);
+        self.inner
+            .send(Message::Text(request.to_string().into()))
+            .await
+            .map_err(Error::WebSocket)?;
+
+        let wait = async {
+            loop {
+                let data = match self.recv_message().await {
+                    Some(Ok(Message::Text(text))) => Bytes::from(text),
+                    Some(Ok(Message::Binary(data))) => data,
+                    Some(Ok(Message::Ping(data))) => {
+                        self.last_ping = Instant::now();
+                        self.inner
+                            .send(Message::Pong(data))
+                            .await
+                            .map_err(Error::WebSocket)?;
+                        continue;
+                    }
+                    Some(Ok(Message::Pong(_))) | Some(Ok(Message::Frame(_))) => continue,
+                    Some(Ok(Message::Close(_))) | None => {
+                        return Err(Error::WebSocket(
+                            tokio_tungstenite::tungstenite::Error::ConnectionClosed,
+                        ));
+                    }
+                    Some(Err(e)) => return Err(Error::WebSocket(e)),
+                };
+
+                match serde_json::from_slice::<StreamResponse>(&data) {
+                    Ok(response) if response.id == Some(id) => return response.into_result(),
+                    // Responses to other requests are dropped.
+                    Ok(response) if response.id.is_some() => continue,
+                    _ => {
+                        let event = self.parse_event(&data);
+                        self.pending.push_back(event);
+                    }
+                }
+            }
+        };
+
+        timeout(Duration::from_secs(WS_TIMEOUT_SECS), wait)
+            .await
+            .map_err(|_| {
+                Error::Io(std::io::Error::new(
+                    std::io::ErrorKind::TimedOut,
+                    format!("no response to {} request {}", method, id),
+                ))
+            })?
+    }
+
     /// Send a ping message.
     pub async fn ping(&mut self) -> Result<()> {
         self.inner
@@ -406,12 +858,90 @@ impl WebSocketConnection {
         self.last_ping.elapsed()
     }
 
+    /// Get the time since any message, including pongs, was received.
+    pub fn time_since_last_message(&self) -> Duration {
+        self.last_message.elapsed()
+    }
+
+    // Receive the next frame, recording when it arrived.
+    async fn recv_message(
+        &mut self,
+    ) -> Option<std::result::Result<Message, tokio_tungstenite::tungstenite::Error>> {
+        let message = self.inner.next().await;
+        if let Some(Ok(_)) = message {
+            self.last_message = Instant::now();
+        }
+        message
+    }
+
+    /// Switch over to `new` and close this connection, keeping events
+    /// received but not yet returned.
+    pub(crate) async fn rotate(&mut self, new: WebSocketConnection) {
+        let mut old = std::mem::replace(self, new);
+        let mut pending = std::mem::take(&mut old.pending);
+        pending.append(&mut self.pending);
+        self.pending = pending;
+        let _ = old.close().await;
+    }
+
     /// Convert this connection into a `Stream` of events.
     pub fn into_stream(self) -> WebSocketEventStream {
         WebSocketEventStream { inner: self }
     }
 }
 
+// Build a request: a stream control request (`SUBSCRIBE`, `UNSUBSCRIBE`,
+// `LIST_SUBSCRIPTIONS`) or a WebSocket API request. Null params are omitted.
+fn stream_request(method: &str, params: serde_json::Value, id: u64) -> serde_json::Value {
+    let mut request = serde_json::json!({ "method": method, "id": id });
+    if !params.is_null() {
+        request["params"] = params;
+    }
+    request
+}
+
+// Build signed `session.logon` parameters. Only Ed25519 keys can log in.
+fn session_logon_params(credentials: &Credentials, timestamp: u64) -> Result<serde_json::Value> {
+    if credentials.signature_type() != SignatureType::Ed25519 {
+        return Err(Error::InvalidCredentials(
+            "session.logon requires an Ed25519 API key".to_string(),
+        ));
+    }
+
+    let payload = format!("apiKey={}&timestamp={}", credentials.api_key(), timestamp);
+    Ok(serde_json::json!({
+        "apiKey": credentials.api_key(),
+        "signature": credentials.sign(&payload),
+        "timestamp": timestamp,
+    }))
+}
+
+/// Response to a stream control or WebSocket API request.
+#[derive(serde::Deserialize)]
+struct StreamResponse {
+    id: Option<u64>,
+    #[serde(default)]
+    result: serde_json::Value,
+    #[serde(default)]
+    error: Option<crate::error::BinanceApiError>,
+}
+
+impl StreamResponse {
+    fn into_result(self) -> Result<serde_json::Value> {
+        match self.error {
+            Some(error) => Err(Error::from_binance_error(error)),
+            None => Ok(self.result),
+        }
+    }
+}
+
+// Get the stream name from a single-stream URL (`.../ws/<stream>`).
+fn stream_name_from_url(url: &str) -> Option<String> {
+    url.rsplit_once("/ws/")
+        .map(|(_, name)| name.split('?').next().unwrap_or_default().to_string())
+        .filter(|name| !name.is_empty())
+}
+
 /// A `Stream` wrapper for WebSocket events.
 pub struct WebSocketEventStream {
     inner: WebSocketConnection,
@@ -442,6 +972,22 @@ pub struct ReconnectConfig {
     pub health_check_enabled: bool,
     /// Interval for health check pings.
     pub health_check_interval: Duration,
+    /// Time without any message, including pongs, after which the
+    /// connection is considered stale and reconnected.
+    pub max_idle: Duration,
+    /// Recorder notified of reconnect attempts.
+    pub metrics: Metrics,
+    /// Proxy URL to connect through, if any.
+    pub proxy: Option<String>,
+    /// Whether to give up after `max_reconnects` failed attempts.
+    pub retry_mode: RetryMode,
+    /// Age at which the connection is replaced by a new one, ahead of
+    /// Binance's 24-hour disconnect. `None` disables rotation.
+    pub rotate_after: Option<Duration>,
+    /// Number of events buffered for the consumer.
+    pub event_capacity: usize,
+    /// What to do with events when the buffer is full.
+    pub overflow_policy: OverflowPolicy,
 }
 
 impl Default for ReconnectConfig {
@@ -452,7 +998,90 @@ impl Default for ReconnectConfig {
             base_delay: Duration::from_millis(BASE_RECONNECT_DELAY_MS),
             health_check_enabled: true,
             health_check_interval: Duration::from_secs(HEALTH_CHECK_INTERVAL_SECS),
+            max_idle: Duration::from_secs(MAX_IDLE_SECS),
+            metrics: Metrics::default(),
+            proxy: None,
+            retry_mode: RetryMode::Limited,
+            rotate_after: Some(DEFAULT_WS_ROTATE_AFTER),
+            event_capacity: EVENT_CHANNEL_CAPACITY,
+            overflow_policy: OverflowPolicy::Block,
+        }
+    }
+}
+
+impl ReconnectConfig {
+    /// Default configuration that never stops reconnecting.
+    pub fn infinite() -> Self {
+        Self {
+            retry_mode: RetryMode::Infinite,
+            ..Self::default()
+        }
+    }
+}
+
+/// How long a [`ReconnectingWebSocket`] keeps trying to reconnect.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RetryMode {
+    /// Close after `max_reconnects` consecutive failed attempts.
+    #[default]
+    Limited,
+    /// Keep reconnecting until closed, waiting at most `max_reconnect_delay`
+    /// between attempts.
+    Infinite,
+}
+
+/// What a [`ReconnectingWebSocket`] does with events its consumer is too
+/// slow to receive.
+///
+/// With `Block`, a full buffer stops the connection from being read, which
+/// also delays ping handling and can get a busy stream such as `!ticker@arr`
+/// disconnected. The dropping policies keep reading and count every dropped
+/// event in [`ReconnectingWebSocket::dropped_events`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// Wait for the consumer to make room.
+    #[default]
+    Block,
+    /// Discard the oldest buffered event to make room for the new one.
+    DropOldest,
+    /// Discard the new event.
+    DropNewest,
+}
+
+// Sending side of a reconnecting connection's event channel.
+#[derive(Debug, Clone)]
+struct EventSink {
+    tx: mpsc::Sender<Result<StreamEvent>>,
+    // Used to discard the oldest event; gone once the consumer is dropped.
+    rx: Weak<Mutex<mpsc::Receiver<Result<StreamEvent>>>>,
+    policy: OverflowPolicy,
+    dropped: Arc<AtomicU64>,
+}
+
+impl EventSink {
+    // Queue an event according to the overflow policy, returning false once
+    // the consumer is gone.
+    async fn deliver(&self, event: Result<StreamEvent>) -> bool {
+        let event = match self.policy {
+            OverflowPolicy::Block => return self.tx.send(event).await.is_ok(),
+            OverflowPolicy::DropOldest | OverflowPolicy::DropNewest => {
+                match self.tx.try_send(event) {
+                    Ok(()) => return true,
+                    Err(mpsc::error::TrySendError::Closed(_)) => return false,
+                    Err(mpsc::error::TrySendError::Full(event)) => event,
+                }
+            }
+        };
+
+        self.dropped.fetch_add(1, Ordering::Relaxed);
+        if self.policy == OverflowPolicy::DropOldest {
+            let Some(rx) = self.rx.upgrade() else {
+                return false;
+            };
+            let _ = rx.lock().await.try_recv();
+            let _ = self.tx.try_send(event);
         }
+        true
     }
 }
 
@@ -469,34 +1098,152 @@ pub enum ConnectionState {
     Closed,
 }
 
+// When a connection is due to be replaced ahead of Binance's 24-hour
+// disconnect.
+#[derive(Debug)]
+pub(crate) struct Rotation {
+    after: Option<Duration>,
+    due: Option<tokio::time::Instant>,
+}
+
+impl Rotation {
+    pub(crate) fn new(after: Option<Duration>) -> Self {
+        let mut rotation = Self { after, due: None };
+        rotation.reset();
+        rotation
+    }
+
+    // Start counting from a fresh connection.
+    pub(crate) fn reset(&mut self) {
+        self.due = self.after.map(|after| tokio::time::Instant::now() + after);
+    }
+
+    // Try again shortly after a failed rotation.
+    pub(crate) fn retry_later(&mut self) {
+        if self.after.is_some() {
+            self.due = Some(tokio::time::Instant::now() + Duration::from_secs(ROTATE_RETRY_SECS));
+        }
+    }
+
+    // Resolve once the connection is due to be replaced, or never without
+    // rotation.
+    pub(crate) async fn due(&self) {
+        match self.due {
+            Some(due) => tokio::time::sleep_until(due).await,
+            None => std::future::pending().await,
+        }
+    }
+}
+
+// Connection state shared with the read loop, announcing every change.
+#[derive(Debug)]
+struct SharedConnectionState {
+    state: RwLock<ConnectionState>,
+    events: broadcast::Sender<ConnectionState>,
+}
+
+impl SharedConnectionState {
+    fn new(state: ConnectionState) -> Self {
+        let (events, _) = broadcast::channel(CONNECTION_STATE_CAPACITY);
+        Self {
+            state: RwLock::new(state),
+            events,
+        }
+    }
+
+    async fn get(&self) -> ConnectionState {
+        *self.state.read().await
+    }
+
+    async fn set(&self, state: ConnectionState) {
+        let mut current = self.state.write().await;
+        if *current != state {
+            *current = state;
+            let _ = self.events.send(state);
+        }
+    }
+}
+
+// Subscription change sent to the read loop, which owns the connection.
+struct SubscriptionCommand {
+    subscribe: bool,
+    streams: Vec<String>,
+    reply: oneshot::Sender<Result<()>>,
+}
+
 /// A WebSocket connection with automatic reconnection support.
 ///
 /// This wrapper handles connection failures by automatically reconnecting
-/// with exponential backoff.
+/// with exponential backoff. Streams added with [`subscribe`](Self::subscribe)
+/// are subscribed again on every new connection.
+///
+/// Ahead of Binance's 24-hour disconnect, the connection is replaced after
+/// [`ReconnectConfig::rotate_after`]: the new connection is opened before the
+/// old one is closed, so no events are lost, though a few may be repeated.
 pub struct ReconnectingWebSocket {
     connection: Arc<Mutex<Option<WebSocketConnection>>>,
-    state: Arc<RwLock<ConnectionState>>,
+    state: Arc<SharedConnectionState>,
     reconnect_count: Arc<AtomicU64>,
     is_closed: Arc<AtomicBool>,
-    event_rx: mpsc::Receiver<Result<WebSocketEvent>>,
+    event_rx: Arc<Mutex<mpsc::Receiver<Result<StreamEvent>>>>,
+    dropped_events: Arc<AtomicU64>,
+    subscriptions: Arc<Mutex<Vec<String>>>,
+    command_tx: mpsc::Sender<SubscriptionCommand>,
+    health: Arc<ConnectionHealthMonitor>,
 }
 
 impl ReconnectingWebSocket {
     /// Create a new reconnecting WebSocket connection.
     pub async fn new(url: String, config: ReconnectConfig) -> Result<Self> {
-        let (event_tx, event_rx) = mpsc::channel(1000);
-        let connection = Arc::new(Mutex::new(None));
-        let state = Arc::new(RwLock::new(ConnectionState::Connecting));
-        let reconnect_count = Arc::new(AtomicU64::new(0));
-        let is_closed = Arc::new(AtomicBool::new(false));
+        Self::with_shutdown(url, config, ShutdownHandle::new()).await
+    }
+
+    /// Create a new reconnecting WebSocket connection that closes when
+    /// `shutdown` is signalled.
+    pub async fn with_shutdown(
+        url: String,
+        config: ReconnectConfig,
+        shutdown: ShutdownHandle,
+    ) -> Result<Self> {
+        Self::with_runtime(url, config, shutdown, RuntimeHints::default()).await
+    }
+
+    // Connect and run the read loop on the runtime selected by `runtime`.
+    async fn with_runtime(
+        url: String,
+        config: ReconnectConfig,
+        shutdown: ShutdownHandle,
+        runtime: RuntimeHints,
+    ) -> Result<Self> {
+        let (event_tx, event_rx) = mpsc::channel(config.event_capacity.max(1));
+        let event_rx = Arc::new(Mutex::new(event_rx));
+        let dropped_events = Arc::new(AtomicU64::new(0));
+        let sink = EventSink {
+            tx: event_tx,
+            rx: Arc::downgrade(&event_rx),
+            policy: config.overflow_policy,
+            dropped: dropped_events.clone(),
+        };
+        let connection = Arc::new(Mutex::new(None));
+        let state = Arc::new(SharedConnectionState::new(ConnectionState::Connecting));
+        let reconnect_count = Arc::new(AtomicU64::new(0));
+        let is_closed = Arc::new(AtomicBool::new(false));
+        let subscriptions = Arc::new(Mutex::new(Vec::new()));
+        let (command_tx, command_rx) = mpsc::channel(16);
+        let command_rx = Arc::new(Mutex::new(command_rx));
+        let health = Arc::new(ConnectionHealthMonitor::new(config.max_idle));
 
         // Perform initial connection
-        let (ws_stream, _) = connect_async(&url).await.map_err(Error::WebSocket)?;
+        let connect_url = url.clone();
+        let connect_proxy = config.proxy.clone();
+        let (ws_stream, _) = runtime
+            .run(async move { proxy::connect(&connect_url, connect_proxy.as_deref()).await })
+            .await??;
         {
             let mut conn = connection.lock().await;
-            *conn = Some(WebSocketConnection::new(ws_stream));
+            *conn = Some(WebSocketConnection::for_url(ws_stream, &url));
         }
-        *state.write().await = ConnectionState::Connected;
+        state.set(ConnectionState::Connected).await;
 
         let ws = Self {
             connection: connection.clone(),
@@ -504,34 +1251,60 @@ impl ReconnectingWebSocket {
             reconnect_count: reconnect_count.clone(),
             is_closed: is_closed.clone(),
             event_rx,
+            dropped_events,
+            subscriptions: subscriptions.clone(),
+            command_tx,
+            health: health.clone(),
         };
 
-        // Start the read loop in a background task
-        tokio::spawn(async move {
+        // Start the read loop in a supervised background task
+        let task_shutdown = shutdown.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "websocket-reader", policy, move || {
             Self::read_loop(
-                url,
-                config,
-                connection,
-                state,
-                reconnect_count,
-                is_closed,
-                event_tx,
+                url.clone(),
+                config.clone(),
+                connection.clone(),
+                state.clone(),
+                reconnect_count.clone(),
+                is_closed.clone(),
+                sink.clone(),
+                subscriptions.clone(),
+                command_rx.clone(),
+                health.clone(),
+                task_shutdown.clone(),
             )
-            .await;
         });
 
         Ok(ws)
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn read_loop(
         url: String,
         config: ReconnectConfig,
         connection: Arc<Mutex<Option<WebSocketConnection>>>,
-        state: Arc<RwLock<ConnectionState>>,
+        state: Arc<SharedConnectionState>,
         reconnect_count: Arc<AtomicU64>,
         is_closed: Arc<AtomicBool>,
-        event_tx: mpsc::Sender<Result<WebSocketEvent>>,
+        sink: EventSink,
+        subscriptions: Arc<Mutex<Vec<String>>>,
+        command_rx: Arc<Mutex<mpsc::Receiver<SubscriptionCommand>>>,
+        health: Arc<ConnectionHealthMonitor>,
+        shutdown: ShutdownHandle,
     ) {
+        let mut commands = command_rx.lock().await;
+        let mut rotation = Rotation::new(config.rotate_after);
+
+        // Without health check pings, only watch for idle connections
+        let period = if config.health_check_enabled {
+            config.health_check_interval
+        } else {
+            config.max_idle
+        };
+        let mut heartbeat = interval_at(tokio::time::Instant::now() + period, period);
+        heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
         loop {
             if is_closed.load(Ordering::SeqCst) {
                 break;
@@ -541,13 +1314,48 @@ impl ReconnectingWebSocket {
             let event = {
                 let mut conn_guard = connection.lock().await;
                 if let Some(ref mut conn) = *conn_guard {
-                    match timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()).await {
-                        Ok(Some(event)) => Some(event),
-                        Ok(None) => None, // Connection closed
-                        Err(_) => {
-                            // Timeout - connection might be stale
+                    let next = conn.next_event();
+                    tokio::select! {
+                        _ = shutdown.cancelled() => {
+                            let _ = conn.close().await;
+                            *conn_guard = None;
+                            is_closed.store(true, Ordering::SeqCst);
+                            break;
+                        }
+                        Some(command) = commands.recv() => {
+                            let result = Self::apply_command(conn, &command, &subscriptions).await;
+                            let _ = command.reply.send(result);
+                            continue;
+                        }
+                        _ = rotation.due() => {
+                            // Switch to a new connection before Binance closes this one
+                            match Self::open_connection(&url, &config, &subscriptions).await {
+                                Ok(new_conn) => {
+                                    conn.rotate(new_conn).await;
+                                    rotation.reset();
+                                    #[cfg(feature = "tracing")]
+                                    tracing::info!(url, "Rotated WebSocket connection");
+                                }
+                                Err(_) => rotation.retry_later(),
+                            }
+                            continue;
+                        }
+                        _ = heartbeat.tick() => {
+                            let idle = conn.time_since_last_message();
+                            health.record_activity_at(Instant::now() - idle).await;
+                            if health.is_healthy().await {
+                                if config.health_check_enabled {
+                                    let _ = conn.ping().await;
+                                }
+                                continue;
+                            }
+                            // No messages, not even pongs, for too long
+                            #[cfg(feature = "tracing")]
+                            tracing::warn!(url, idle_ms = idle.as_millis() as u64, "WebSocket connection is stale");
                             None
                         }
+                        // None when the connection closed
+                        result = next => result,
                     }
                 } else {
                     None
@@ -556,82 +1364,173 @@ impl ReconnectingWebSocket {
 
             match event {
                 Some(Ok(ev)) => {
-                    if event_tx.send(Ok(ev)).await.is_err() {
+                    if !sink.deliver(Ok(ev)).await {
                         // Receiver dropped, exit
                         break;
                     }
                 }
                 Some(Err(e)) => {
                     // Send error and attempt reconnect
-                    let _ = event_tx.send(Err(e)).await;
-                    Self::attempt_reconnect(
+                    sink.deliver(Err(e)).await;
+                    let reconnected = Self::attempt_reconnect(
                         &url,
                         &config,
                         &connection,
                         &state,
                         &reconnect_count,
                         &is_closed,
+                        &subscriptions,
+                        &shutdown,
                     )
                     .await;
+                    if reconnected {
+                        rotation.reset();
+                        health.record_activity().await;
+                    }
                 }
                 None => {
                     // Connection closed or timed out, attempt reconnect
-                    Self::attempt_reconnect(
+                    let reconnected = Self::attempt_reconnect(
                         &url,
                         &config,
                         &connection,
                         &state,
                         &reconnect_count,
                         &is_closed,
+                        &subscriptions,
+                        &shutdown,
                     )
                     .await;
+                    if reconnected {
+                        rotation.reset();
+                        health.record_activity().await;
+                    }
                 }
             }
         }
 
-        *state.write().await = ConnectionState::Closed;
+        // Fail pending and later subscription changes
+        commands.close();
+        while commands.try_recv().is_ok() {}
+
+        state.set(ConnectionState::Closed).await;
+    }
+
+    // Apply a subscription change and record it for later reconnects.
+    async fn apply_command(
+        conn: &mut WebSocketConnection,
+        command: &SubscriptionCommand,
+        subscriptions: &Mutex<Vec<String>>,
+    ) -> Result<()> {
+        if command.subscribe {
+            conn.subscribe(&command.streams).await?;
+            let mut subscriptions = subscriptions.lock().await;
+            for stream in &command.streams {
+                if !subscriptions.contains(stream) {
+                    subscriptions.push(stream.clone());
+                }
+            }
+        } else {
+            conn.unsubscribe(&command.streams).await?;
+            subscriptions
+                .lock()
+                .await
+                .retain(|stream| !command.streams.contains(stream));
+        }
+        Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn attempt_reconnect(
         url: &str,
         config: &ReconnectConfig,
         connection: &Arc<Mutex<Option<WebSocketConnection>>>,
-        state: &Arc<RwLock<ConnectionState>>,
+        state: &Arc<SharedConnectionState>,
         reconnect_count: &Arc<AtomicU64>,
         is_closed: &Arc<AtomicBool>,
-    ) {
+        subscriptions: &Mutex<Vec<String>>,
+        shutdown: &ShutdownHandle,
+    ) -> bool {
         if is_closed.load(Ordering::SeqCst) {
-            return;
+            return false;
         }
 
-        *state.write().await = ConnectionState::Reconnecting;
+        state.set(ConnectionState::Reconnecting).await;
 
         let count = reconnect_count.fetch_add(1, Ordering::SeqCst) + 1;
 
-        if count > config.max_reconnects as u64 {
+        if config.retry_mode == RetryMode::Limited && count > config.max_reconnects as u64 {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                url,
+                attempts = count - 1,
+                "Giving up reconnecting WebSocket"
+            );
             is_closed.store(true, Ordering::SeqCst);
-            *state.write().await = ConnectionState::Closed;
-            return;
+            state.set(ConnectionState::Closed).await;
+            return false;
         }
 
         // Calculate delay with exponential backoff and jitter
         let delay = Self::calculate_backoff_delay(count, config);
-        sleep(delay).await;
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            url,
+            attempt = count,
+            delay_ms = delay.as_millis() as u64,
+            "Reconnecting WebSocket"
+        );
+        tokio::select! {
+            _ = shutdown.cancelled() => {
+                is_closed.store(true, Ordering::SeqCst);
+                return false;
+            }
+            _ = sleep(delay) => {}
+        }
 
         // Attempt to reconnect
-        match connect_async(url).await {
-            Ok((ws_stream, _)) => {
+        match Self::open_connection(url, config, subscriptions).await {
+            Ok(new_conn) => {
                 let mut conn = connection.lock().await;
-                *conn = Some(WebSocketConnection::new(ws_stream));
-                *state.write().await = ConnectionState::Connected;
+                *conn = Some(new_conn);
+                state.set(ConnectionState::Connected).await;
                 reconnect_count.store(0, Ordering::SeqCst);
+                config
+                    .metrics
+                    .recorder()
+                    .record_ws_reconnect(url, count, true);
+                #[cfg(feature = "tracing")]
+                tracing::info!(url, attempt = count, "WebSocket reconnected");
+                true
             }
-            Err(_) => {
+            Err(_e) => {
                 // Will retry on next loop iteration
+                config
+                    .metrics
+                    .recorder()
+                    .record_ws_reconnect(url, count, false);
+                #[cfg(feature = "tracing")]
+                tracing::warn!(url, attempt = count, error = %_e, "WebSocket reconnect failed");
+                false
             }
         }
     }
 
+    // Open a new connection, restoring streams added with subscribe().
+    async fn open_connection(
+        url: &str,
+        config: &ReconnectConfig,
+        subscriptions: &Mutex<Vec<String>>,
+    ) -> Result<WebSocketConnection> {
+        let streams = subscriptions.lock().await.clone();
+        let (ws_stream, _) = proxy::connect(url, config.proxy.as_deref()).await?;
+        let mut conn = WebSocketConnection::for_url(ws_stream, url);
+        if !streams.is_empty() {
+            conn.subscribe(&streams).await?;
+        }
+        Ok(conn)
+    }
+
     fn calculate_backoff_delay(attempt: u64, config: &ReconnectConfig) -> Duration {
         let base_ms = config.base_delay.as_millis() as u64;
         let exp_delay = base_ms.saturating_mul(2u64.saturating_pow(attempt as u32));
@@ -647,12 +1546,92 @@ impl ReconnectingWebSocket {
 
     /// Receive the next WebSocket event.
     pub async fn next(&mut self) -> Option<Result<WebSocketEvent>> {
-        self.event_rx.recv().await
+        self.next_event()
+            .await
+            .map(|result| result.map(|ev| ev.event))
+    }
+
+    /// Receive the next WebSocket event with its stream name and receive time.
+    pub async fn next_event(&mut self) -> Option<Result<StreamEvent>> {
+        self.event_rx.lock().await.recv().await
+    }
+
+    /// Get the number of events dropped under the configured
+    /// [`OverflowPolicy`] because the consumer fell behind.
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped_events.load(Ordering::Relaxed)
     }
 
     /// Get the current connection state.
     pub async fn state(&self) -> ConnectionState {
-        *self.state.read().await
+        self.state.get().await
+    }
+
+    /// Get the monitor tracking when a message was last received.
+    ///
+    /// It is updated on every health check; the connection is reconnected
+    /// once it reports unhealthy.
+    pub fn health(&self) -> Arc<ConnectionHealthMonitor> {
+        self.health.clone()
+    }
+
+    /// Subscribe to connection state changes.
+    ///
+    /// Every transition, such as `Connected` to `Reconnecting`, is sent once.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut states = conn.state_changes();
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Ok(state) = states.recv().await {
+    ///         println!("WebSocket is now {:?}", state);
+    ///     }
+    /// });
+    /// ```
+    pub fn state_changes(&self) -> broadcast::Receiver<ConnectionState> {
+        self.state.events.subscribe()
+    }
+
+    /// Subscribe to additional streams.
+    ///
+    /// The streams are subscribed again after every reconnect.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - Stream names (e.g., from `WebSocketClient::trade_stream`)
+    pub async fn subscribe(&self, streams: &[String]) -> Result<()> {
+        self.send_command(true, streams).await
+    }
+
+    /// Unsubscribe from streams added with [`subscribe`](Self::subscribe).
+    ///
+    /// Streams in the connection URL are restored by the next reconnect.
+    ///
+    /// # Arguments
+    ///
+    /// * `streams` - Stream names to remove
+    pub async fn unsubscribe(&self, streams: &[String]) -> Result<()> {
+        self.send_command(false, streams).await
+    }
+
+    /// Get the streams added with [`subscribe`](Self::subscribe).
+    pub async fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().await.clone()
+    }
+
+    // Hand a subscription change to the read loop and wait for the response.
+    async fn send_command(&self, subscribe: bool, streams: &[String]) -> Result<()> {
+        let (reply, response) = oneshot::channel();
+        let command = SubscriptionCommand {
+            subscribe,
+            streams: streams.to_vec(),
+            reply,
+        };
+        let closed = || Error::WebSocket(tokio_tungstenite::tungstenite::Error::ConnectionClosed);
+        self.command_tx.send(command).await.map_err(|_| closed())?;
+        response.await.map_err(|_| closed())?
     }
 
     /// Get the number of reconnection attempts.
@@ -673,7 +1652,7 @@ impl ReconnectingWebSocket {
             let _ = c.close().await;
         }
         *conn = None;
-        *self.state.write().await = ConnectionState::Closed;
+        self.state.set(ConnectionState::Closed).await;
     }
 }
 
@@ -858,6 +1837,91 @@ impl DepthCache {
     pub fn total_ask_volume(&self) -> f64 {
         self.asks.values().sum()
     }
+
+    /// Get the quantity resting at a price on either side, or 0.
+    pub fn quantity_at_price(&self, price: f64) -> f64 {
+        let key = OrderedFloat(price);
+        self.bids
+            .get(&key)
+            .or_else(|| self.asks.get(&key))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Get the order book imbalance over the top `n_levels` of each side.
+    ///
+    /// Returns `(bid volume - ask volume) / (bid volume + ask volume)`, from
+    /// -1 (asks only) to 1 (bids only), or `None` if the book is empty.
+    pub fn imbalance(&self, n_levels: usize) -> Option<f64> {
+        let bid_volume: f64 = self.bids.values().rev().take(n_levels).sum();
+        let ask_volume: f64 = self.asks.values().take(n_levels).sum();
+        let total = bid_volume + ask_volume;
+        (total > 0.0).then(|| (bid_volume - ask_volume) / total)
+    }
+
+    /// Get the volume weighted average price of selling into the bids for
+    /// `depth_quote` of quote asset.
+    ///
+    /// Returns `None` if the bids are not deep enough.
+    pub fn vwap_bid(&self, depth_quote: f64) -> Option<f64> {
+        vwap_for_quote(self.bids.iter().rev().map(|(p, q)| (p.0, *q)), depth_quote)
+    }
+
+    /// Get the volume weighted average price of buying from the asks for
+    /// `depth_quote` of quote asset.
+    ///
+    /// Returns `None` if the asks are not deep enough.
+    pub fn vwap_ask(&self, depth_quote: f64) -> Option<f64> {
+        vwap_for_quote(self.asks.iter().map(|(p, q)| (p.0, *q)), depth_quote)
+    }
+
+    /// Get the average fill price of a market order for `quantity` of base
+    /// asset, walking the asks for a buy and the bids for a sell.
+    ///
+    /// Returns `None` if the book is not deep enough.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let cache = manager.get_cache().await;
+    /// if let (Some(fill), Some(mid)) = (cache.price_for_quantity(OrderSide::Buy, 2.0), cache.mid_price()) {
+    ///     println!("Slippage: {:.4}%", (fill - mid) / mid * 100.0);
+    /// }
+    /// ```
+    pub fn price_for_quantity(&self, side: OrderSide, quantity: f64) -> Option<f64> {
+        let levels: Box<dyn Iterator<Item = (f64, f64)>> = match side {
+            OrderSide::Buy => Box::new(self.asks.iter().map(|(p, q)| (p.0, *q))),
+            OrderSide::Sell => Box::new(self.bids.iter().rev().map(|(p, q)| (p.0, *q))),
+        };
+
+        let mut remaining = quantity;
+        let mut notional = 0.0;
+        for (price, available) in levels {
+            let filled = remaining.min(available);
+            notional += filled * price;
+            remaining -= filled;
+            if remaining <= 0.0 {
+                return Some(notional / quantity);
+            }
+        }
+        None
+    }
+}
+
+// Walk price levels until `quote` of notional is filled and return the
+// average price.
+fn vwap_for_quote(levels: impl Iterator<Item = (f64, f64)>, quote: f64) -> Option<f64> {
+    let mut remaining = quote;
+    let mut quantity = 0.0;
+    for (price, available) in levels {
+        let filled = remaining.min(price * available);
+        quantity += filled / price;
+        remaining -= filled;
+        if remaining <= 0.0 {
+            return Some(quote / quantity);
+        }
+    }
+    None
 }
 
 // Depth cache manager.
@@ -871,6 +1935,11 @@ pub struct DepthCacheConfig {
     pub fast_updates: bool,
     /// Optional refresh interval to re-fetch snapshot.
     pub refresh_interval: Option<Duration>,
+    /// Whether to send a copy of the cache to `next()` on every update.
+    ///
+    /// Disable when following updates through
+    /// [`DepthCacheManager::watch`] to avoid cloning large books.
+    pub send_updates: bool,
 }
 
 impl Default for DepthCacheConfig {
@@ -879,8 +1948,113 @@ impl Default for DepthCacheConfig {
             depth_limit: 1000,
             fast_updates: false,
             refresh_interval: None,
+            send_updates: true,
+        }
+    }
+}
+
+/// Health event of a [`DepthCacheManager`].
+///
+/// Times are local, in milliseconds since Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepthCacheEvent {
+    /// The cache was initialized from a snapshot and is receiving updates.
+    Synced {
+        /// Last update ID after applying the buffered events.
+        last_update_id: u64,
+        /// Time of the sync.
+        time: u64,
+        /// Number of syncs so far, including this one.
+        syncs: u64,
+    },
+    /// An update did not follow on from the cache, which will be resynced.
+    OutOfSync {
+        /// First update ID the cache expected.
+        expected: u64,
+        /// First update ID of the update received.
+        got: u64,
+        /// Time the gap was detected.
+        time: u64,
+        /// Number of gaps so far, including this one.
+        gaps: u64,
+    },
+    /// The snapshot was re-fetched on the configured refresh interval.
+    SnapshotRefreshed {
+        /// Last update ID of the new snapshot.
+        last_update_id: u64,
+        /// Time of the refresh.
+        time: u64,
+        /// Number of refreshes so far, including this one.
+        refreshes: u64,
+    },
+}
+
+/// Counters of a [`DepthCacheManager`]'s order book health.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DepthCacheStats {
+    /// Number of times the cache was synced from a snapshot.
+    pub syncs: u64,
+    /// Number of sequence gaps detected.
+    pub gaps: u64,
+    /// Number of periodic snapshot refreshes.
+    pub snapshot_refreshes: u64,
+    /// Time of the last sync (milliseconds).
+    pub last_synced_at: Option<u64>,
+    /// Time of the last sequence gap (milliseconds).
+    pub last_gap_at: Option<u64>,
+}
+
+// Health counters and event channel shared with the sync task.
+#[derive(Debug, Clone)]
+struct DepthCacheHealth {
+    stats: Arc<RwLock<DepthCacheStats>>,
+    events: broadcast::Sender<DepthCacheEvent>,
+}
+
+impl DepthCacheHealth {
+    fn new() -> Self {
+        let (events, _) = broadcast::channel(DEPTH_CACHE_EVENT_CAPACITY);
+        Self {
+            stats: Arc::new(RwLock::new(DepthCacheStats::default())),
+            events,
         }
     }
+
+    async fn synced(&self, last_update_id: u64) {
+        let time = get_timestamp().unwrap_or_default();
+        let mut stats = self.stats.write().await;
+        stats.syncs += 1;
+        stats.last_synced_at = Some(time);
+        let _ = self.events.send(DepthCacheEvent::Synced {
+            last_update_id,
+            time,
+            syncs: stats.syncs,
+        });
+    }
+
+    async fn out_of_sync(&self, expected: u64, got: u64) {
+        let time = get_timestamp().unwrap_or_default();
+        let mut stats = self.stats.write().await;
+        stats.gaps += 1;
+        stats.last_gap_at = Some(time);
+        let _ = self.events.send(DepthCacheEvent::OutOfSync {
+            expected,
+            got,
+            time,
+            gaps: stats.gaps,
+        });
+    }
+
+    async fn snapshot_refreshed(&self, last_update_id: u64) {
+        let time = get_timestamp().unwrap_or_default();
+        let mut stats = self.stats.write().await;
+        stats.snapshot_refreshes += 1;
+        let _ = self.events.send(DepthCacheEvent::SnapshotRefreshed {
+            last_update_id,
+            time,
+            refreshes: stats.snapshot_refreshes,
+        });
+    }
 }
 
 /// State of the depth cache manager.
@@ -936,6 +2110,8 @@ pub struct DepthCacheManager {
     state: Arc<RwLock<DepthCacheState>>,
     is_stopped: Arc<AtomicBool>,
     cache_rx: mpsc::Receiver<DepthCache>,
+    update_tx: watch::Sender<u64>,
+    health: DepthCacheHealth,
 }
 
 impl DepthCacheManager {
@@ -952,25 +2128,33 @@ impl DepthCacheManager {
         let state = Arc::new(RwLock::new(DepthCacheState::Initializing));
         let is_stopped = Arc::new(AtomicBool::new(false));
         let (cache_tx, cache_rx) = mpsc::channel(100);
+        let (update_tx, _) = watch::channel(0);
 
         // Clone for the background task
         let symbol_clone = symbol.clone();
         let cache_clone = cache.clone();
         let state_clone = state.clone();
         let is_stopped_clone = is_stopped.clone();
-
-        // Start the background sync task
-        tokio::spawn(async move {
+        let update_tx_clone = update_tx.clone();
+        let health = DepthCacheHealth::new();
+        let health_clone = health.clone();
+
+        // Start the supervised background sync task
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "depth-cache", policy, move || {
             Self::sync_loop(
-                client,
-                symbol_clone,
-                config,
-                cache_clone,
-                state_clone,
-                is_stopped_clone,
-                cache_tx,
+                client.clone(),
+                symbol_clone.clone(),
+                config.clone(),
+                cache_clone.clone(),
+                state_clone.clone(),
+                is_stopped_clone.clone(),
+                cache_tx.clone(),
+                update_tx_clone.clone(),
+                health_clone.clone(),
             )
-            .await;
         });
 
         Ok(Self {
@@ -979,9 +2163,12 @@ impl DepthCacheManager {
             state,
             is_stopped,
             cache_rx,
+            update_tx,
+            health,
         })
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn sync_loop(
         client: crate::Binance,
         symbol: String,
@@ -990,12 +2177,15 @@ impl DepthCacheManager {
         state: Arc<RwLock<DepthCacheState>>,
         is_stopped: Arc<AtomicBool>,
         cache_tx: mpsc::Sender<DepthCache>,
+        update_tx: watch::Sender<u64>,
+        health: DepthCacheHealth,
     ) {
         let ws = client.websocket();
         let stream = ws.diff_depth_stream(&symbol, config.fast_updates);
+        let shutdown = client.client().shutdown_handle().clone();
 
         loop {
-            if is_stopped.load(Ordering::SeqCst) {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
                 break;
             }
 
@@ -1010,6 +2200,7 @@ impl DepthCacheManager {
                     continue;
                 }
             };
+            let mut rotation = Rotation::new(client.config().ws_rotate_after);
 
             // Buffer some initial events
             let mut initial_events = Vec::new();
@@ -1056,7 +2247,11 @@ impl DepthCacheManager {
             // Send initial cache state
             {
                 let cache_guard = cache.read().await;
-                let _ = cache_tx.send(cache_guard.clone()).await;
+                health.synced(cache_guard.last_update_id).await;
+                update_tx.send_replace(cache_guard.last_update_id);
+                if config.send_updates {
+                    let _ = cache_tx.send(cache_guard.clone()).await;
+                }
             }
 
             // Main update loop
@@ -1077,21 +2272,51 @@ impl DepthCacheManager {
                         {
                             let mut cache_guard = cache.write().await;
                             cache_guard.initialize_from_snapshot(&snapshot);
+                            health.snapshot_refreshed(snapshot.last_update_id).await;
+                            update_tx.send_replace(cache_guard.last_update_id);
                         }
                         last_refresh = Instant::now();
                     }
                 }
 
-                match timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next_raw()).await {
+                let next = tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        let _ = conn.close().await;
+                        break;
+                    }
+                    _ = rotation.due() => {
+                        // Switch to a new connection before Binance closes this one
+                        match ws.connect(&stream).await {
+                            Ok(new_conn) => {
+                                conn.rotate(new_conn).await;
+                                rotation.reset();
+                            }
+                            Err(_) => rotation.retry_later(),
+                        }
+                        continue;
+                    }
+                    next = timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next_raw()) => next,
+                };
+
+                match next {
                     Ok(Some(Ok(raw))) => {
                         if let Ok(event) = serde_json::from_value::<DepthEvent>(raw) {
                             let mut cache_guard = cache.write().await;
+                            // Skip events already applied, as repeated after a rotation
+                            if event.final_update_id <= cache_guard.last_update_id {
+                                continue;
+                            }
                             if cache_guard.apply_update(&event) {
                                 // Successfully applied, send updated cache
-                                let _ = cache_tx.send(cache_guard.clone()).await;
+                                update_tx.send_replace(cache_guard.last_update_id);
+                                if config.send_updates {
+                                    let _ = cache_tx.send(cache_guard.clone()).await;
+                                }
                             } else {
                                 // Update failed (sequence gap), need to reinitialize
+                                let expected = cache_guard.last_update_id + 1;
                                 drop(cache_guard);
+                                health.out_of_sync(expected, event.first_update_id).await;
                                 *state.write().await = DepthCacheState::OutOfSync;
                                 break;
                             }
@@ -1149,10 +2374,62 @@ impl DepthCacheManager {
     }
 
     /// Receive the next cache update.
+    ///
+    /// Never returns an update when [`DepthCacheConfig::send_updates`] is
+    /// disabled.
     pub async fn next(&mut self) -> Option<DepthCache> {
         self.cache_rx.recv().await
     }
 
+    /// Get a handle that reads the cache in place and is notified of updates.
+    ///
+    /// Unlike [`next`](Self::next), updates are not copied: the handle is only
+    /// told the latest update ID and reads the book through a lock guard.
+    /// Handles can be cloned and outlive the manager.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut watch = manager.watch();
+    ///
+    /// while let Some((symbol, update_id)) = watch.changed().await {
+    ///     let cache = watch.read().await;
+    ///     println!("{} #{}: {:?}", symbol, update_id, cache.mid_price());
+    /// }
+    /// ```
+    pub fn watch(&self) -> DepthCacheWatch {
+        DepthCacheWatch {
+            symbol: self.symbol.clone(),
+            cache: self.cache.clone(),
+            update_rx: self.update_tx.subscribe(),
+        }
+    }
+
+    /// Subscribe to sync, sequence gap and snapshot refresh events.
+    ///
+    /// Events sent before subscribing are not received; use
+    /// [`stats`](Self::stats) for the totals.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut events = manager.events();
+    ///
+    /// while let Ok(event) = events.recv().await {
+    ///     if let DepthCacheEvent::OutOfSync { expected, got, gaps, .. } = event {
+    ///         eprintln!("gap #{}: expected {} got {}", gaps, expected, got);
+    ///     }
+    /// }
+    /// ```
+    pub fn events(&self) -> broadcast::Receiver<DepthCacheEvent> {
+        self.health.events.subscribe()
+    }
+
+    /// Get the sync, gap and refresh counters.
+    pub async fn stats(&self) -> DepthCacheStats {
+        self.health.stats.read().await.clone()
+    }
+
     /// Stop the depth cache manager.
     pub fn stop(&self) {
         self.is_stopped.store(true, Ordering::SeqCst);
@@ -1164,13 +2441,241 @@ impl DepthCacheManager {
     }
 }
 
+/// Read access to a [`DepthCacheManager`]'s cache with update notifications.
+///
+/// Created by [`DepthCacheManager::watch`]. Notifications only carry the
+/// symbol and last update ID; updates that arrive between two calls to
+/// [`changed`](Self::changed) are coalesced into one.
+#[derive(Debug, Clone)]
+pub struct DepthCacheWatch {
+    symbol: String,
+    cache: Arc<RwLock<DepthCache>>,
+    update_rx: watch::Receiver<u64>,
+}
+
+impl DepthCacheWatch {
+    /// Wait for the cache to change, returning the symbol and new last update ID.
+    ///
+    /// Returns `None` once the manager's background task has ended.
+    pub async fn changed(&mut self) -> Option<(String, u64)> {
+        self.update_rx.changed().await.ok()?;
+        let update_id = *self.update_rx.borrow_and_update();
+        Some((self.symbol.clone(), update_id))
+    }
+
+    /// Lock the cache for reading without copying it.
+    ///
+    /// Updates are held back while the guard is alive, so drop it promptly.
+    pub async fn read(&self) -> RwLockReadGuard<'_, DepthCache> {
+        self.cache.read().await
+    }
+
+    /// Get the last update ID announced to this handle.
+    pub fn last_update_id(&self) -> u64 {
+        *self.update_rx.borrow()
+    }
+
+    /// Get the symbol being tracked.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+}
+
 // User data stream manager.
 
+/// Status event of a [`UserDataStreamManager`].
+///
+/// While the stream is disconnected no execution reports are received, so
+/// order state may be stale until it reconnects.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserDataStreamEvent {
+    /// The stream connected for the first time.
+    Connected,
+    /// The connection was lost and is being re-established.
+    Disconnected {
+        /// Why the connection was lost, if known.
+        error: Option<String>,
+    },
+    /// The stream connected again after being disconnected.
+    Reconnected {
+        /// Number of reconnects so far, including this one.
+        reconnects: u64,
+    },
+    /// Refreshing the listen key failed.
+    KeepaliveFailed {
+        /// The keepalive error.
+        error: String,
+        /// Number of consecutive failures, including this one.
+        failures: u32,
+    },
+    /// A new listen key was started in place of the old one.
+    ListenKeyRotated(String),
+    /// Open orders and missed trades were fetched after reconnecting.
+    ///
+    /// Only sent when [`UserDataStreamConfig::reconcile`] is enabled.
+    Reconciled(UserDataReconciliation),
+    /// Fetching open orders or missed trades after reconnecting failed.
+    ReconciliationFailed {
+        /// The request error.
+        error: String,
+    },
+    /// The manager stopped and no more events will be received.
+    Stopped,
+}
+
+/// Connection and keepalive status of a [`UserDataStreamManager`].
+///
+/// Times are local, in milliseconds since Unix epoch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserDataStreamStatus {
+    /// Connection state.
+    pub state: ConnectionState,
+    /// Time of the last successful connection.
+    pub connected_at: Option<u64>,
+    /// Number of times the stream reconnected.
+    pub reconnects: u64,
+    /// Time of the last successful keepalive.
+    pub last_keepalive_at: Option<u64>,
+    /// Number of consecutive keepalive failures.
+    pub keepalive_failures: u32,
+    /// The last connection or keepalive error.
+    pub last_error: Option<String>,
+}
+
+impl UserDataStreamStatus {
+    fn new(state: ConnectionState) -> Self {
+        Self {
+            state,
+            connected_at: None,
+            reconnects: 0,
+            last_keepalive_at: None,
+            keepalive_failures: 0,
+            last_error: None,
+        }
+    }
+
+    /// Check if the stream is connected and receiving events.
+    pub fn is_connected(&self) -> bool {
+        self.state == ConnectionState::Connected
+    }
+}
+
+// Status and event channel shared with the background tasks.
+#[derive(Debug, Clone)]
+struct UserStreamHealth {
+    status: Arc<std::sync::Mutex<UserDataStreamStatus>>,
+    events: broadcast::Sender<UserDataStreamEvent>,
+}
+
+impl UserStreamHealth {
+    fn new(state: ConnectionState) -> Self {
+        let (events, _) = broadcast::channel(USER_STREAM_EVENT_CAPACITY);
+        Self {
+            status: Arc::new(std::sync::Mutex::new(UserDataStreamStatus::new(state))),
+            events,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, UserDataStreamStatus> {
+        self.status.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn status(&self) -> UserDataStreamStatus {
+        self.lock().clone()
+    }
+
+    // Record a connection, returning true if it is a reconnect.
+    fn connected(&self) -> bool {
+        let mut status = self.lock();
+        if status.state == ConnectionState::Closed {
+            return false;
+        }
+        let reconnected = status.connected_at.is_some();
+        let event = if reconnected {
+            status.reconnects += 1;
+            UserDataStreamEvent::Reconnected {
+                reconnects: status.reconnects,
+            }
+        } else {
+            UserDataStreamEvent::Connected
+        };
+        status.state = ConnectionState::Connected;
+        status.connected_at = Some(get_timestamp().unwrap_or_default());
+        let _ = self.events.send(event);
+        reconnected
+    }
+
+    fn disconnected(&self, error: Option<String>) {
+        let mut status = self.lock();
+        if error.is_some() {
+            status.last_error = error.clone();
+        }
+        if status.state == ConnectionState::Connected {
+            status.state = ConnectionState::Reconnecting;
+            let _ = self
+                .events
+                .send(UserDataStreamEvent::Disconnected { error });
+        }
+    }
+
+    fn keepalive_succeeded(&self) {
+        let mut status = self.lock();
+        status.last_keepalive_at = Some(get_timestamp().unwrap_or_default());
+        status.keepalive_failures = 0;
+    }
+
+    fn keepalive_failed(&self, error: String) {
+        let mut status = self.lock();
+        status.keepalive_failures += 1;
+        status.last_error = Some(error.clone());
+        let _ = self.events.send(UserDataStreamEvent::KeepaliveFailed {
+            error,
+            failures: status.keepalive_failures,
+        });
+    }
+
+    fn listen_key_rotated(&self, listen_key: String) {
+        let _ = self
+            .events
+            .send(UserDataStreamEvent::ListenKeyRotated(listen_key));
+    }
+
+    fn reconciled(&self, result: Result<UserDataReconciliation>) {
+        let event = match result {
+            Ok(reconciliation) => UserDataStreamEvent::Reconciled(reconciliation),
+            Err(e) => {
+                let error = e.to_string();
+                self.lock().last_error = Some(error.clone());
+                UserDataStreamEvent::ReconciliationFailed { error }
+            }
+        };
+        let _ = self.events.send(event);
+    }
+
+    fn stopped(&self) {
+        let mut status = self.lock();
+        if status.state != ConnectionState::Closed {
+            status.state = ConnectionState::Closed;
+            let _ = self.events.send(UserDataStreamEvent::Stopped);
+        }
+    }
+}
+
 /// Manages a user data stream with automatic keep-alive.
 ///
 /// This manager automatically refreshes the listen key every 30 minutes
 /// to prevent the stream from expiring (listen keys expire after 60 minutes).
 ///
+/// With an Ed25519 API key, [`new_ws_api`](Self::new_ws_api) receives the
+/// same events over the WebSocket API instead, without a listen key.
+///
+/// Connection and keepalive problems are reported through
+/// [`status`](Self::status) and [`events`](Self::events).
+///
+/// Execution reports repeated after a reconnect are dropped, and with
+/// [`UserDataStreamConfig::reconcile`] the open orders and any trades missed
+/// while disconnected are fetched after every reconnect.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -1188,6 +2693,9 @@ impl DepthCacheManager {
 ///         WebSocketEvent::AccountPosition(position) => {
 ///             println!("Account update: {:?}", position);
 ///         }
+///         WebSocketEvent::ListenKeyExpired(expired) => {
+///             println!("Listen key expired at {}", expired.event_time);
+///         }
 ///         _ => {}
 ///     }
 /// }
@@ -1196,6 +2704,7 @@ pub struct UserDataStreamManager {
     listen_key: Arc<RwLock<String>>,
     is_stopped: Arc<AtomicBool>,
     event_rx: mpsc::Receiver<Result<WebSocketEvent>>,
+    health: UserStreamHealth,
 }
 
 impl UserDataStreamManager {
@@ -1203,61 +2712,284 @@ impl UserDataStreamManager {
     ///
     /// This will start the listen key and begin receiving user data events.
     pub async fn new(client: crate::Binance) -> Result<Self> {
+        Self::with_config(client, UserDataStreamConfig::default()).await
+    }
+
+    /// Create a new user data stream manager with custom configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let config = UserDataStreamConfig {
+    ///     reconcile: true,
+    ///     reconcile_symbols: vec!["BTCUSDT".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let manager = UserDataStreamManager::with_config(client, config).await?;
+    ///
+    /// let mut events = manager.events();
+    /// while let Ok(event) = events.recv().await {
+    ///     if let UserDataStreamEvent::Reconciled(catch_up) = event {
+    ///         for trade in catch_up.missed_trades {
+    ///             println!("Missed fill: {:?}", trade);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn with_config(client: crate::Binance, config: UserDataStreamConfig) -> Result<Self> {
         // Get initial listen key
         let listen_key = client.user_stream().start().await?;
         let listen_key = Arc::new(RwLock::new(listen_key));
         let is_stopped = Arc::new(AtomicBool::new(false));
         let (event_tx, event_rx) = mpsc::channel(1000);
+        let health = UserStreamHealth::new(ConnectionState::Connecting);
+        let tracker = Arc::new(ExecutionTracker::new(config));
 
         // Clone for background tasks
         let listen_key_clone = listen_key.clone();
         let is_stopped_clone = is_stopped.clone();
         let client_clone = client.clone();
+        let health_clone = health.clone();
 
         // Start keep-alive task
-        tokio::spawn(async move {
-            Self::keepalive_loop(
-                client_clone.clone(),
-                listen_key_clone.clone(),
-                is_stopped_clone.clone(),
-            )
-            .await;
-        });
+        let shutdown = client.client().shutdown_handle().clone();
+        shutdown.spawn_supervised(
+            "user-stream-keepalive",
+            RestartPolicy::default(),
+            move || {
+                Self::keepalive_loop(
+                    client_clone.clone(),
+                    listen_key_clone.clone(),
+                    is_stopped_clone.clone(),
+                    health_clone.clone(),
+                )
+            },
+        );
 
         // Start WebSocket connection task
         let listen_key_ws = listen_key.clone();
         let is_stopped_ws = is_stopped.clone();
-
-        tokio::spawn(async move {
-            Self::connection_loop(client, listen_key_ws, is_stopped_ws, event_tx).await;
-        });
+        let health_ws = health.clone();
+        let tracker_ws = tracker.clone();
+
+        let runtime = client.config().runtime_hints.clone();
+        shutdown.spawn_supervised_on(
+            &runtime,
+            "user-stream-connection",
+            RestartPolicy::default(),
+            move || {
+                Self::connection_loop(
+                    client.clone(),
+                    listen_key_ws.clone(),
+                    is_stopped_ws.clone(),
+                    event_tx.clone(),
+                    health_ws.clone(),
+                    tracker_ws.clone(),
+                )
+            },
+        );
 
         Ok(Self {
             listen_key,
             is_stopped,
             event_rx,
+            health,
+        })
+    }
+
+    /// Create a user data stream manager on the WebSocket API.
+    ///
+    /// Logs in with `session.logon` and subscribes with
+    /// `userDataStream.subscribe`, logging in again after every reconnect.
+    /// No listen key is used, so there is nothing to keep alive.
+    ///
+    /// **Requires an Ed25519 API key.**
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let credentials = Credentials::with_ed25519_pem("api_key", &pem)?;
+    /// let client = Binance::with_credentials(Config::default(), credentials)?;
+    ///
+    /// let mut manager = UserDataStreamManager::new_ws_api(client).await?;
+    /// while let Some(event) = manager.next().await {
+    ///     println!("{:?}", event?);
+    /// }
+    /// ```
+    pub async fn new_ws_api(client: crate::Binance) -> Result<Self> {
+        Self::new_ws_api_with_config(client, UserDataStreamConfig::default()).await
+    }
+
+    /// Create a user data stream manager on the WebSocket API with custom
+    /// configuration.
+    ///
+    /// **Requires an Ed25519 API key.**
+    pub async fn new_ws_api_with_config(
+        client: crate::Binance,
+        config: UserDataStreamConfig,
+    ) -> Result<Self> {
+        // Log in once up front so bad credentials are reported to the caller
+        let conn = Self::ws_api_connect(&client).await?;
+        let connection = Arc::new(Mutex::new(Some(conn)));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let (event_tx, event_rx) = mpsc::channel(1000);
+        let health = UserStreamHealth::new(ConnectionState::Connecting);
+
+        let tracker = Arc::new(ExecutionTracker::new(config));
+
+        let is_stopped_ws = is_stopped.clone();
+        let health_ws = health.clone();
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        shutdown.spawn_supervised_on(
+            &runtime,
+            "user-stream-ws-api",
+            RestartPolicy::default(),
+            move || {
+                Self::ws_api_connection_loop(
+                    client.clone(),
+                    connection.clone(),
+                    is_stopped_ws.clone(),
+                    event_tx.clone(),
+                    health_ws.clone(),
+                    tracker.clone(),
+                )
+            },
+        );
+
+        Ok(Self {
+            listen_key: Arc::new(RwLock::new(String::new())),
+            is_stopped,
+            event_rx,
+            health,
         })
     }
 
+    // Connect to the WebSocket API, log in and subscribe to the user data stream.
+    async fn ws_api_connect(client: &crate::Binance) -> Result<WebSocketConnection> {
+        let credentials = client
+            .client()
+            .credentials()
+            .ok_or(Error::AuthenticationRequired)?;
+        let timestamp = client.client().time_sync().timestamp()?;
+
+        let mut conn = client.websocket().connect_ws_api().await?;
+        conn.session_logon(credentials, timestamp).await?;
+        conn.subscribe_user_data().await?;
+        Ok(conn)
+    }
+
+    async fn ws_api_connection_loop(
+        client: crate::Binance,
+        connection: Arc<Mutex<Option<WebSocketConnection>>>,
+        is_stopped: Arc<AtomicBool>,
+        event_tx: mpsc::Sender<Result<WebSocketEvent>>,
+        health: UserStreamHealth,
+        tracker: Arc<ExecutionTracker>,
+    ) {
+        let reconnect_config = ReconnectConfig::default();
+        let shutdown = client.client().shutdown_handle().clone();
+
+        'connect: loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            // The first connection is made by `new_ws_api`
+            let initial = connection.lock().await.take();
+            let conn = match initial {
+                Some(conn) => Ok(conn),
+                None => Self::ws_api_connect(&client).await,
+            };
+
+            match conn {
+                Ok(mut conn) => {
+                    if health.connected() && tracker.reconciles() {
+                        health.reconciled(tracker.reconcile(&client).await);
+                    }
+                    loop {
+                        if is_stopped.load(Ordering::SeqCst) {
+                            let _ = conn.close().await;
+                            break 'connect;
+                        }
+
+                        let next = tokio::select! {
+                            _ = shutdown.cancelled() => {
+                                let _ = conn.close().await;
+                                break 'connect;
+                            }
+                            next = timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()) => next,
+                        };
+
+                        match next {
+                            Ok(Some(event)) => {
+                                if event.as_ref().is_ok_and(|event| !tracker.accept(event)) {
+                                    // Already received before reconnecting
+                                    continue;
+                                }
+                                let terminated =
+                                    matches!(event, Ok(WebSocketEvent::EventStreamTerminated(_)));
+                                if event_tx.send(event).await.is_err() {
+                                    // Receiver dropped
+                                    break 'connect;
+                                }
+                                if terminated {
+                                    // No more events on this session, log in again
+                                    let _ = conn.close().await;
+                                    health.disconnected(Some("event stream terminated".into()));
+                                    break;
+                                }
+                            }
+                            // Connection closed, log in again
+                            Ok(None) => {
+                                health.disconnected(None);
+                                break;
+                            }
+                            // Timeout, continue
+                            Err(_) => continue,
+                        }
+                    }
+                }
+                Err(e) => {
+                    // Connection or logon failed, wait before retry
+                    health.disconnected(Some(e.to_string()));
+                    sleep(reconnect_config.base_delay).await;
+                }
+            }
+
+            // Brief delay before reconnecting
+            sleep(Duration::from_millis(100)).await;
+        }
+
+        health.stopped();
+    }
+
     async fn keepalive_loop(
         client: crate::Binance,
         listen_key: Arc<RwLock<String>>,
         is_stopped: Arc<AtomicBool>,
+        health: UserStreamHealth,
     ) {
         let mut interval_timer = interval(Duration::from_secs(USER_STREAM_KEEPALIVE_SECS));
+        let shutdown = client.client().shutdown_handle().clone();
 
         loop {
-            interval_timer.tick().await;
+            tokio::select! {
+                _ = shutdown.cancelled() => break,
+                _ = interval_timer.tick() => {}
+            }
 
             if is_stopped.load(Ordering::SeqCst) {
                 break;
             }
 
             let key = listen_key.read().await.clone();
-            if client.user_stream().keepalive(&key).await.is_err() {
-                // If keepalive fails, try to get a new listen key
-                if let Ok(new_key) = client.user_stream().start().await {
-                    *listen_key.write().await = new_key;
+            match client.user_stream().keepalive(&key).await {
+                Ok(()) => health.keepalive_succeeded(),
+                Err(e) => {
+                    health.keepalive_failed(e.to_string());
+                    // If keepalive fails, try to get a new listen key
+                    Self::rotate_listen_key(&client, &listen_key, &health).await;
                 }
             }
         }
@@ -1267,16 +2999,34 @@ impl UserDataStreamManager {
         let _ = client.user_stream().close(&key).await;
     }
 
+    // Start a new listen key in place of the current one.
+    async fn rotate_listen_key(
+        client: &crate::Binance,
+        listen_key: &RwLock<String>,
+        health: &UserStreamHealth,
+    ) {
+        if let Ok(new_key) = client.user_stream().start().await {
+            let mut key = listen_key.write().await;
+            if *key != new_key {
+                *key = new_key.clone();
+                health.listen_key_rotated(new_key);
+            }
+        }
+    }
+
     async fn connection_loop(
         client: crate::Binance,
         listen_key: Arc<RwLock<String>>,
         is_stopped: Arc<AtomicBool>,
         event_tx: mpsc::Sender<Result<WebSocketEvent>>,
+        health: UserStreamHealth,
+        tracker: Arc<ExecutionTracker>,
     ) {
         let reconnect_config = ReconnectConfig::default();
+        let shutdown = client.client().shutdown_handle().clone();
 
-        loop {
-            if is_stopped.load(Ordering::SeqCst) {
+        'connect: loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
                 break;
             }
 
@@ -1285,20 +3035,52 @@ impl UserDataStreamManager {
 
             match ws.connect_user_stream(&key).await {
                 Ok(mut conn) => {
+                    if health.connected() && tracker.reconciles() {
+                        health.reconciled(tracker.reconcile(&client).await);
+                    }
                     loop {
                         if is_stopped.load(Ordering::SeqCst) {
+                            break 'connect;
+                        }
+
+                        // The keepalive task replaced the listen key
+                        if *listen_key.read().await != key {
+                            let _ = conn.close().await;
+                            health.disconnected(Some("listen key rotated".into()));
                             break;
                         }
 
-                        match timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()).await {
+                        let next = tokio::select! {
+                            _ = shutdown.cancelled() => {
+                                let _ = conn.close().await;
+                                break 'connect;
+                            }
+                            next = timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()) => next,
+                        };
+
+                        match next {
                             Ok(Some(event)) => {
+                                if event.as_ref().is_ok_and(|event| !tracker.accept(event)) {
+                                    // Already received before reconnecting
+                                    continue;
+                                }
+                                let expired =
+                                    matches!(event, Ok(WebSocketEvent::ListenKeyExpired(_)));
                                 if event_tx.send(event).await.is_err() {
                                     // Receiver dropped
-                                    return;
+                                    break 'connect;
+                                }
+                                if expired {
+                                    // No more events on this key, start a new one
+                                    let _ = conn.close().await;
+                                    health.disconnected(Some("listen key expired".into()));
+                                    Self::rotate_listen_key(&client, &listen_key, &health).await;
+                                    break;
                                 }
                             }
                             Ok(None) => {
                                 // Connection closed
+                                health.disconnected(None);
                                 break;
                             }
                             Err(_) => {
@@ -1308,8 +3090,9 @@ impl UserDataStreamManager {
                         }
                     }
                 }
-                Err(_) => {
+                Err(e) => {
                     // Connection failed, wait before retry
+                    health.disconnected(Some(e.to_string()));
                     sleep(reconnect_config.base_delay).await;
                 }
             }
@@ -1317,6 +3100,8 @@ impl UserDataStreamManager {
             // Brief delay before reconnecting
             sleep(Duration::from_millis(100)).await;
         }
+
+        health.stopped();
     }
 
     /// Receive the next user data event.
@@ -1325,13 +3110,51 @@ impl UserDataStreamManager {
     }
 
     /// Get the current listen key.
+    ///
+    /// Empty for streams started with [`new_ws_api`](Self::new_ws_api).
     pub async fn listen_key(&self) -> String {
         self.listen_key.read().await.clone()
     }
 
+    /// Get the connection and keepalive status.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if !manager.status().is_connected() {
+    ///     // Execution reports may be missed, hold off placing orders
+    /// }
+    /// ```
+    pub fn status(&self) -> UserDataStreamStatus {
+        self.health.status()
+    }
+
+    /// Subscribe to connection and keepalive status events.
+    ///
+    /// Only events sent after subscribing are received.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut events = manager.events();
+    /// tokio::spawn(async move {
+    ///     while let Ok(event) = events.recv().await {
+    ///         match event {
+    ///             UserDataStreamEvent::Disconnected { .. } => pause_trading(),
+    ///             UserDataStreamEvent::Reconnected { .. } => resume_trading(),
+    ///             _ => {}
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    pub fn events(&self) -> broadcast::Receiver<UserDataStreamEvent> {
+        self.health.events.subscribe()
+    }
+
     /// Stop the user data stream manager.
     pub fn stop(&self) {
         self.is_stopped.store(true, Ordering::SeqCst);
+        self.health.stopped();
     }
 
     /// Check if the manager is stopped.
@@ -1372,6 +3195,14 @@ impl ConnectionHealthMonitor {
         self.is_healthy.store(true, Ordering::SeqCst);
     }
 
+    // Record activity that happened at `at`, unless newer activity is known.
+    pub(crate) async fn record_activity_at(&self, at: Instant) {
+        let mut last_activity = self.last_activity.write().await;
+        if at > *last_activity {
+            *last_activity = at;
+        }
+    }
+
     /// Check if the connection is healthy.
     pub async fn is_healthy(&self) -> bool {
         let last = *self.last_activity.read().await;
@@ -1406,11 +3237,16 @@ impl ConnectionHealthMonitor {
 /// Combined stream message wrapper.
 #[derive(serde::Deserialize)]
 struct CombinedStreamMessage {
-    #[allow(dead_code)]
     stream: String,
     data: WebSocketEvent,
 }
 
+/// WebSocket API event wrapper (`{"subscriptionId": 0, "event": {...}}`).
+#[derive(serde::Deserialize)]
+struct WsApiEventMessage {
+    event: WebSocketEvent,
+}
+
 // Tests.
 
 #[cfg(test)]
@@ -1420,7 +3256,7 @@ mod tests {
     #[test]
     fn test_stream_names() {
         let config = Config::default();
-        let ws = WebSocketClient::new(config);
+        let ws = WebSocketClient::new(config, ShutdownHandle::new());
 
         assert_eq!(ws.agg_trade_stream("BTCUSDT"), "btcusdt@aggTrade");
         assert_eq!(ws.trade_stream("BTCUSDT"), "btcusdt@trade");
@@ -1433,12 +3269,168 @@ mod tests {
         assert_eq!(ws.all_mini_ticker_stream(), "!miniTicker@arr");
         assert_eq!(ws.all_ticker_stream(), "!ticker@arr");
         assert_eq!(ws.all_book_ticker_stream(), "!bookTicker");
+        assert_eq!(ws.avg_price_stream("BTCUSDT"), "btcusdt@avgPrice");
+        assert_eq!(
+            ws.rolling_window_ticker_stream("BTCUSDT", "4h"),
+            "btcusdt@ticker_4h"
+        );
+        assert_eq!(ws.all_rolling_window_ticker_stream("1d"), "!ticker_1d@arr");
+        assert_eq!(
+            ws.kline_stream_with_time_zone("BTCUSDT", KlineInterval::Days1, "+08:00"),
+            "btcusdt@kline_1d@+08:00"
+        );
+    }
+
+    #[test]
+    fn test_stream_name_from_url() {
+        assert_eq!(
+            stream_name_from_url("wss://stream.binance.com:9443/ws/btcusdt@aggTrade"),
+            Some("btcusdt@aggTrade".to_string())
+        );
+        assert_eq!(
+            stream_name_from_url("wss://stream.binance.com:9443/stream?streams=a/b"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stream_urls_with_time_unit() {
+        let config = Config::default();
+        let ws = WebSocketClient::new(config, ShutdownHandle::new());
+        let endpoint = ws.endpoint().to_string();
+        let streams = vec!["a".to_string(), "b".to_string()];
+
+        assert_eq!(ws.time_unit(), TimeUnit::Millisecond);
+        assert_eq!(ws.stream_url("a"), format!("{}/ws/a", endpoint));
+        assert_eq!(
+            ws.combined_stream_url(&streams),
+            format!("{}/stream?streams=a/b", endpoint)
+        );
+
+        let ws = ws.with_time_unit(TimeUnit::Microsecond);
+        assert_eq!(ws.time_unit(), TimeUnit::Microsecond);
+        assert_eq!(
+            ws.stream_url("a"),
+            format!("{}/ws/a?timeUnit=MICROSECOND", endpoint)
+        );
+        assert_eq!(
+            ws.combined_stream_url(&streams),
+            format!("{}/stream?streams=a/b&timeUnit=MICROSECOND", endpoint)
+        );
+        assert_eq!(
+            stream_name_from_url(&ws.stream_url("btcusdt@trade")),
+            Some("btcusdt@trade".to_string())
+        );
+    }
+
+    #[test]
+    fn test_user_stream_health() {
+        let health = UserStreamHealth::new(ConnectionState::Connecting);
+        let mut events = health.events.subscribe();
+        assert!(!health.status().is_connected());
+
+        health.connected();
+        assert_eq!(events.try_recv().unwrap(), UserDataStreamEvent::Connected);
+        assert!(health.status().is_connected());
+
+        health.disconnected(Some("connection reset".to_string()));
+        assert_eq!(
+            events.try_recv().unwrap(),
+            UserDataStreamEvent::Disconnected {
+                error: Some("connection reset".to_string())
+            }
+        );
+        // Failed attempts while reconnecting are only recorded
+        health.disconnected(Some("timed out".to_string()));
+        assert!(events.try_recv().is_err());
+        assert_eq!(health.status().state, ConnectionState::Reconnecting);
+        assert_eq!(health.status().last_error.as_deref(), Some("timed out"));
+
+        health.connected();
+        assert_eq!(
+            events.try_recv().unwrap(),
+            UserDataStreamEvent::Reconnected { reconnects: 1 }
+        );
+
+        health.keepalive_failed("invalid listen key".to_string());
+        health.keepalive_failed("invalid listen key".to_string());
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            UserDataStreamEvent::KeepaliveFailed { failures: 1, .. }
+        ));
+        assert!(matches!(
+            events.try_recv().unwrap(),
+            UserDataStreamEvent::KeepaliveFailed { failures: 2, .. }
+        ));
+        health.listen_key_rotated("new_key".to_string());
+        assert_eq!(
+            events.try_recv().unwrap(),
+            UserDataStreamEvent::ListenKeyRotated("new_key".to_string())
+        );
+        health.keepalive_succeeded();
+        assert_eq!(health.status().keepalive_failures, 0);
+        assert!(health.status().last_keepalive_at.is_some());
+
+        // Stopped is reported once and the state no longer changes
+        health.stopped();
+        health.stopped();
+        health.connected();
+        assert_eq!(events.try_recv().unwrap(), UserDataStreamEvent::Stopped);
+        assert!(events.try_recv().is_err());
+        assert_eq!(health.status().state, ConnectionState::Closed);
+    }
+
+    #[test]
+    fn test_session_logon_params() {
+        let hmac = Credentials::new("api_key", "secret_key");
+        assert!(matches!(
+            session_logon_params(&hmac, 1),
+            Err(Error::InvalidCredentials(_))
+        ));
+
+        let seed = [7u8; 32];
+        let ed25519 = Credentials::with_ed25519_key("api_key", &seed).unwrap();
+        let params = session_logon_params(&ed25519, 1649729878532).unwrap();
+        assert_eq!(params["apiKey"], "api_key");
+        assert_eq!(params["timestamp"], 1649729878532u64);
+        assert_eq!(
+            params["signature"],
+            ed25519.sign("apiKey=api_key&timestamp=1649729878532")
+        );
+    }
+
+    #[test]
+    fn test_stream_request_and_response() {
+        let request = stream_request("SUBSCRIBE", serde_json::json!(["btcusdt@trade"]), 3);
+        assert_eq!(
+            request,
+            serde_json::json!({ "method": "SUBSCRIBE", "params": ["btcusdt@trade"], "id": 3 })
+        );
+        let request = stream_request("LIST_SUBSCRIPTIONS", serde_json::Value::Null, 4);
+        assert!(request.get("params").is_none());
+
+        let response: StreamResponse =
+            serde_json::from_str(r#"{"result":["btcusdt@trade"],"id":4}"#).unwrap();
+        assert_eq!(response.id, Some(4));
+        assert_eq!(
+            response.into_result().unwrap(),
+            serde_json::json!(["btcusdt@trade"])
+        );
+
+        let response: StreamResponse = serde_json::from_str(
+            r#"{"error":{"code":2,"msg":"Invalid request: unknown variant"},"id":5}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            response.into_result(),
+            Err(Error::Api { code: 2, .. })
+        ));
     }
 
     #[test]
     fn test_depth_stream_names() {
         let config = Config::default();
-        let ws = WebSocketClient::new(config);
+        let ws = WebSocketClient::new(config, ShutdownHandle::new());
 
         assert_eq!(
             ws.partial_depth_stream("BTCUSDT", 10, false),
@@ -1468,6 +3460,36 @@ mod tests {
         assert_eq!(cache.mid_price(), Some(50000.5));
     }
 
+    #[test]
+    fn test_depth_cache_analytics() {
+        let mut cache = DepthCache::new("BTCUSDT");
+        cache.bids.insert(OrderedFloat(100.0), 1.0);
+        cache.bids.insert(OrderedFloat(99.0), 3.0);
+        cache.asks.insert(OrderedFloat(101.0), 1.0);
+        cache.asks.insert(OrderedFloat(102.0), 1.0);
+
+        assert_eq!(cache.quantity_at_price(99.0), 3.0);
+        assert_eq!(cache.quantity_at_price(102.0), 1.0);
+        assert_eq!(cache.quantity_at_price(98.0), 0.0);
+
+        assert_eq!(cache.imbalance(1), Some(0.0));
+        assert_eq!(cache.imbalance(2), Some(2.0 / 6.0));
+        assert_eq!(DepthCache::new("BTCUSDT").imbalance(5), None);
+
+        // 1.5 BTC: 1 at 101 and 0.5 at 102.
+        assert_eq!(
+            cache.price_for_quantity(OrderSide::Buy, 1.5),
+            Some((101.0 + 51.0) / 1.5)
+        );
+        assert_eq!(cache.price_for_quantity(OrderSide::Sell, 1.0), Some(100.0));
+        assert_eq!(cache.price_for_quantity(OrderSide::Buy, 3.0), None);
+
+        // 199 USDT: 100 at 100 and 99 at 99.
+        assert_eq!(cache.vwap_bid(199.0), Some(199.0 / 2.0));
+        assert_eq!(cache.vwap_ask(101.0), Some(101.0));
+        assert_eq!(cache.vwap_ask(1000.0), None);
+    }
+
     #[test]
     fn test_reconnect_config_default() {
         let config = ReconnectConfig::default();
@@ -1499,6 +3521,71 @@ mod tests {
         assert_ne!(DepthCacheState::Synced, DepthCacheState::OutOfSync);
     }
 
+    #[tokio::test]
+    async fn test_depth_cache_watch() {
+        let cache = Arc::new(RwLock::new(DepthCache::new("BTCUSDT")));
+        let (update_tx, update_rx) = watch::channel(0);
+        let mut watch = DepthCacheWatch {
+            symbol: "BTCUSDT".to_string(),
+            cache: cache.clone(),
+            update_rx,
+        };
+
+        // Updates between reads are coalesced into the latest one
+        cache.write().await.last_update_id = 7;
+        update_tx.send_replace(5);
+        update_tx.send_replace(7);
+        assert_eq!(watch.changed().await, Some(("BTCUSDT".to_string(), 7)));
+        assert_eq!(watch.read().await.last_update_id, 7);
+        assert_eq!(watch.last_update_id(), 7);
+
+        drop(update_tx);
+        assert_eq!(watch.changed().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_depth_cache_health() {
+        let health = DepthCacheHealth::new();
+        let mut events = health.events.subscribe();
+
+        health.synced(10).await;
+        health.out_of_sync(11, 15).await;
+        health.synced(20).await;
+        health.snapshot_refreshed(30).await;
+
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            DepthCacheEvent::Synced {
+                last_update_id: 10,
+                syncs: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            DepthCacheEvent::OutOfSync {
+                expected: 11,
+                got: 15,
+                gaps: 1,
+                ..
+            }
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            DepthCacheEvent::Synced { syncs: 2, .. }
+        ));
+        assert!(matches!(
+            events.recv().await.unwrap(),
+            DepthCacheEvent::SnapshotRefreshed { refreshes: 1, .. }
+        ));
+
+        let stats = health.stats.read().await.clone();
+        assert_eq!(stats.syncs, 2);
+        assert_eq!(stats.gaps, 1);
+        assert_eq!(stats.snapshot_refreshes, 1);
+        assert!(stats.last_gap_at.is_some());
+    }
+
     #[test]
     fn test_ordered_float() {
         let a = OrderedFloat(1.0);
@@ -1507,6 +3594,148 @@ mod tests {
         assert_eq!(a, OrderedFloat(1.0));
     }
 
+    #[tokio::test]
+    async fn test_reconnect_resubscribes() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws/btcusdt@trade", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            for _ in 0..2 {
+                let (socket, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                let Some(Ok(Message::Text(request))) = ws.next().await else {
+                    panic!("expected a subscribe request");
+                };
+                let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+                let response = serde_json::json!({ "result": null, "id": request["id"] });
+                ws.send(Message::Text(response.to_string().into()))
+                    .await
+                    .unwrap();
+                requests.push(request);
+                let _ = ws.close(None).await;
+            }
+            requests
+        });
+
+        let config = ReconnectConfig {
+            base_delay: Duration::from_millis(1),
+            ..ReconnectConfig::infinite()
+        };
+        let conn = ReconnectingWebSocket::new(url, config).await.unwrap();
+        let mut states = conn.state_changes();
+        let streams = vec!["ethusdt@trade".to_string()];
+        conn.subscribe(&streams).await.unwrap();
+        assert_eq!(conn.subscriptions().await, streams);
+
+        assert_eq!(states.recv().await.unwrap(), ConnectionState::Reconnecting);
+        assert_eq!(states.recv().await.unwrap(), ConnectionState::Connected);
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0]["params"], serde_json::json!(streams));
+        assert_eq!(requests[1]["method"], "SUBSCRIBE");
+        assert_eq!(requests[1]["params"], serde_json::json!(streams));
+        conn.close().await;
+    }
+
+    #[tokio::test]
+    async fn test_stale_connection_reconnects() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws/btcusdt@trade", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            // Never read, so pings go unanswered
+            let mut connections = Vec::new();
+            for _ in 0..2 {
+                let (socket, _) = listener.accept().await.unwrap();
+                connections.push(tokio_tungstenite::accept_async(socket).await.unwrap());
+            }
+            connections
+        });
+
+        let config = ReconnectConfig {
+            base_delay: Duration::from_millis(1),
+            health_check_interval: Duration::from_millis(20),
+            max_idle: Duration::from_millis(50),
+            ..ReconnectConfig::default()
+        };
+        let conn = ReconnectingWebSocket::new(url, config).await.unwrap();
+        let mut states = conn.state_changes();
+
+        assert_eq!(states.recv().await.unwrap(), ConnectionState::Reconnecting);
+        assert_eq!(states.recv().await.unwrap(), ConnectionState::Connected);
+        assert!(conn.health().is_healthy().await);
+
+        let _connections = server.await.unwrap();
+        conn.close().await;
+    }
+
+    fn stream_event(id: u64) -> Result<StreamEvent> {
+        let event = serde_json::json!({
+            "e": "trade", "E": id, "s": "BTCUSDT", "t": id, "p": "1.0", "q": "1.0",
+            "b": 1, "a": 2, "T": id, "m": false, "M": true
+        });
+        Ok(StreamEvent {
+            stream: None,
+            received_at: 0,
+            event: serde_json::from_value(event).unwrap(),
+        })
+    }
+
+    fn event_time(event: Option<Result<StreamEvent>>) -> u64 {
+        match event.unwrap().unwrap().event {
+            WebSocketEvent::Trade(trade) => trade.event_time,
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_event_sink_overflow() {
+        for (policy, expected) in [
+            (OverflowPolicy::DropOldest, [2, 3]),
+            (OverflowPolicy::DropNewest, [1, 2]),
+        ] {
+            let (tx, rx) = mpsc::channel(2);
+            let rx = Arc::new(Mutex::new(rx));
+            let sink = EventSink {
+                tx,
+                rx: Arc::downgrade(&rx),
+                policy,
+                dropped: Arc::new(AtomicU64::new(0)),
+            };
+
+            for id in 1..=3 {
+                assert!(sink.deliver(stream_event(id)).await);
+            }
+            assert_eq!(sink.dropped.load(Ordering::Relaxed), 1);
+
+            let mut rx = rx.lock().await;
+            assert_eq!(event_time(rx.recv().await), expected[0]);
+            assert_eq!(event_time(rx.recv().await), expected[1]);
+            assert!(rx.try_recv().is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rotation() {
+        let mut rotation = Rotation::new(Some(Duration::from_millis(10)));
+        timeout(Duration::from_secs(1), rotation.due())
+            .await
+            .unwrap();
+
+        rotation.retry_later();
+        assert!(
+            timeout(Duration::from_millis(20), rotation.due())
+                .await
+                .is_err()
+        );
+
+        let rotation = Rotation::new(None);
+        assert!(
+            timeout(Duration::from_millis(20), rotation.due())
+                .await
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_backoff_delay() {
         let config = ReconnectConfig::default();
@@ -0,0 +1,506 @@
+//! Local order books for many symbols over one combined depth stream.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use tokio::sync::{RwLock, mpsc};
+use tokio::time::{sleep, timeout};
+
+use super::{DepthCache, DepthCacheConfig, DepthCacheState, Rotation, WS_TIMEOUT_SECS};
+use crate::models::OrderBook;
+use crate::models::websocket::{DepthEvent, WebSocketEvent};
+use crate::supervisor::RestartPolicy;
+use crate::{Error, Result};
+
+// Result of feeding a diff event or snapshot to a book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BookUpdate {
+    // The book changed and is synced.
+    Applied,
+    // The book is waiting for a snapshot and buffered the event.
+    Buffered,
+    // The book lost its sequence and needs a new snapshot.
+    Resync,
+    // The event was stale or for an unknown symbol.
+    Ignored,
+}
+
+// One order book and its sync progress.
+#[derive(Debug)]
+struct DepthBook {
+    cache: DepthCache,
+    state: DepthCacheState,
+    // Diff events received while waiting for a snapshot.
+    buffer: Vec<DepthEvent>,
+}
+
+// Order books keyed by uppercase symbol.
+#[derive(Debug, Default)]
+struct DepthBooks {
+    books: HashMap<String, DepthBook>,
+}
+
+impl DepthBooks {
+    fn new(symbols: &[String]) -> Self {
+        let books = symbols
+            .iter()
+            .map(|symbol| {
+                let book = DepthBook {
+                    cache: DepthCache::new(symbol),
+                    state: DepthCacheState::Initializing,
+                    buffer: Vec::new(),
+                };
+                (symbol.clone(), book)
+            })
+            .collect();
+        Self { books }
+    }
+
+    // Make every book wait for a fresh snapshot, as after a reconnect.
+    fn reset(&mut self) {
+        for book in self.books.values_mut() {
+            book.state = DepthCacheState::Initializing;
+            book.buffer.clear();
+        }
+    }
+
+    // Apply a diff event, or buffer it while the book waits for a snapshot.
+    fn apply_event(&mut self, event: DepthEvent) -> BookUpdate {
+        let Some(book) = self.books.get_mut(&event.symbol) else {
+            return BookUpdate::Ignored;
+        };
+
+        if book.state != DepthCacheState::Synced {
+            book.buffer.push(event);
+            return BookUpdate::Buffered;
+        }
+
+        if event.final_update_id <= book.cache.last_update_id {
+            return BookUpdate::Ignored;
+        }
+
+        if book.cache.apply_update(&event) {
+            BookUpdate::Applied
+        } else {
+            // Keep the event, it may follow on from the next snapshot
+            book.state = DepthCacheState::OutOfSync;
+            book.buffer.push(event);
+            BookUpdate::Resync
+        }
+    }
+
+    // Initialize a book from a snapshot and replay its buffered events.
+    fn apply_snapshot(&mut self, symbol: &str, snapshot: &OrderBook) -> BookUpdate {
+        let Some(book) = self.books.get_mut(symbol) else {
+            return BookUpdate::Ignored;
+        };
+
+        // A refresh of a synced book must not move it backwards
+        if book.state == DepthCacheState::Synced {
+            if snapshot.last_update_id < book.cache.last_update_id {
+                return BookUpdate::Ignored;
+            }
+            book.cache.initialize_from_snapshot(snapshot);
+            return BookUpdate::Applied;
+        }
+
+        book.cache.initialize_from_snapshot(snapshot);
+        for event in std::mem::take(&mut book.buffer) {
+            if event.final_update_id <= book.cache.last_update_id {
+                continue;
+            }
+            if !book.cache.apply_update(&event) {
+                book.state = DepthCacheState::OutOfSync;
+                return BookUpdate::Resync;
+            }
+        }
+
+        book.state = DepthCacheState::Synced;
+        BookUpdate::Applied
+    }
+
+    fn set_state(&mut self, state: DepthCacheState) {
+        for book in self.books.values_mut() {
+            book.state = state;
+        }
+    }
+}
+
+/// Maintains local order books for many symbols over one connection.
+///
+/// All `<symbol>@depth` streams are followed over a single combined
+/// connection, and REST snapshots are fetched one at a time from a shared
+/// queue, so tracking many books costs one socket instead of one per symbol.
+/// A book that loses its update sequence is queued for a new snapshot while
+/// the others keep updating.
+///
+/// Every applied update sends the updated book to [`next`](Self::next);
+/// updates are dropped if they are not received fast enough.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{DepthCacheConfig, MultiDepthCacheManager};
+///
+/// let symbols = ["BTCUSDT", "ETHUSDT", "BNBUSDT"];
+/// let mut manager =
+///     MultiDepthCacheManager::new(client.clone(), &symbols, DepthCacheConfig::default()).await?;
+///
+/// manager.wait_for_sync().await?;
+///
+/// while let Some(cache) = manager.next().await {
+///     println!("{} mid price: {:?}", cache.symbol, cache.mid_price());
+/// }
+/// ```
+pub struct MultiDepthCacheManager {
+    symbols: Vec<String>,
+    books: Arc<RwLock<DepthBooks>>,
+    is_stopped: Arc<AtomicBool>,
+    cache_rx: mpsc::Receiver<DepthCache>,
+}
+
+impl MultiDepthCacheManager {
+    /// Create a new manager for `symbols`.
+    ///
+    /// This will start the combined WebSocket connection and begin syncing
+    /// every order book.
+    pub async fn new(
+        client: crate::Binance,
+        symbols: &[&str],
+        config: DepthCacheConfig,
+    ) -> Result<Self> {
+        if symbols.is_empty() {
+            return Err(Error::InvalidConfig(
+                "At least one symbol is required".to_string(),
+            ));
+        }
+
+        let symbols: Vec<String> = symbols.iter().map(|s| s.to_uppercase()).collect();
+        let books = Arc::new(RwLock::new(DepthBooks::new(&symbols)));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let (cache_tx, cache_rx) = mpsc::channel(100);
+
+        let symbols_clone = symbols.clone();
+        let books_clone = books.clone();
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "multi-depth-cache", policy, move || {
+            Self::sync_loop(
+                client.clone(),
+                symbols_clone.clone(),
+                config.clone(),
+                books_clone.clone(),
+                is_stopped_clone.clone(),
+                cache_tx.clone(),
+            )
+        });
+
+        Ok(Self {
+            symbols,
+            books,
+            is_stopped,
+            cache_rx,
+        })
+    }
+
+    async fn sync_loop(
+        client: crate::Binance,
+        symbols: Vec<String>,
+        config: DepthCacheConfig,
+        books: Arc<RwLock<DepthBooks>>,
+        is_stopped: Arc<AtomicBool>,
+        cache_tx: mpsc::Sender<DepthCache>,
+    ) {
+        let ws = client.websocket();
+        let streams: Vec<String> = symbols
+            .iter()
+            .map(|s| ws.diff_depth_stream(s, config.fast_updates))
+            .collect();
+        let shutdown = client.client().shutdown_handle().clone();
+
+        loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            books.write().await.reset();
+
+            let mut conn = match ws.connect_combined(&streams).await {
+                Ok(c) => c,
+                Err(_) => {
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+            let mut rotation = Rotation::new(client.config().ws_rotate_after);
+
+            // Snapshots are fetched one at a time while events are buffered
+            let (queue_tx, queue_rx) = mpsc::unbounded_channel();
+            let (snapshot_tx, mut snapshot_rx) = mpsc::unbounded_channel();
+            let fetcher = client.config().runtime_hints.spawn(Self::fetch_snapshots(
+                client.clone(),
+                config.depth_limit,
+                queue_rx,
+                snapshot_tx,
+            ));
+            for symbol in &symbols {
+                let _ = queue_tx.send(symbol.clone());
+            }
+
+            let mut last_refresh = Instant::now();
+            loop {
+                if is_stopped.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Some(refresh_interval) = config.refresh_interval {
+                    if last_refresh.elapsed() >= refresh_interval {
+                        for symbol in &symbols {
+                            let _ = queue_tx.send(symbol.clone());
+                        }
+                        last_refresh = Instant::now();
+                    }
+                }
+
+                tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        let _ = conn.close().await;
+                        break;
+                    }
+                    _ = rotation.due() => {
+                        // Repeated events are ignored as stale by synced books
+                        match ws.connect_combined(&streams).await {
+                            Ok(new_conn) => {
+                                conn.rotate(new_conn).await;
+                                rotation.reset();
+                            }
+                            Err(_) => rotation.retry_later(),
+                        }
+                    }
+                    Some((symbol, snapshot)) = snapshot_rx.recv() => {
+                        let mut guard = books.write().await;
+                        match guard.apply_snapshot(&symbol, &snapshot) {
+                            BookUpdate::Applied => {
+                                let _ = cache_tx.try_send(guard.books[&symbol].cache.clone());
+                            }
+                            BookUpdate::Resync => {
+                                let _ = queue_tx.send(symbol);
+                            }
+                            BookUpdate::Buffered | BookUpdate::Ignored => {}
+                        }
+                    }
+                    next = timeout(Duration::from_secs(WS_TIMEOUT_SECS), conn.next()) => {
+                        match next {
+                            Ok(Some(Ok(WebSocketEvent::Depth(event)))) => {
+                                let symbol = event.symbol.clone();
+                                let mut guard = books.write().await;
+                                match guard.apply_event(event) {
+                                    BookUpdate::Applied => {
+                                        let cache = guard.books[&symbol].cache.clone();
+                                        let _ = cache_tx.try_send(cache);
+                                    }
+                                    BookUpdate::Resync => {
+                                        let _ = queue_tx.send(symbol);
+                                    }
+                                    BookUpdate::Buffered | BookUpdate::Ignored => {}
+                                }
+                            }
+                            Ok(Some(_)) => continue,
+                            // Connection error or timeout, reconnect
+                            Ok(None) | Err(_) => break,
+                        }
+                    }
+                }
+            }
+
+            fetcher.abort();
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            // Brief delay before reconnecting
+            sleep(Duration::from_millis(100)).await;
+        }
+
+        books.write().await.set_state(DepthCacheState::Stopped);
+    }
+
+    // Fetch queued snapshots one at a time, retrying failed requests.
+    async fn fetch_snapshots(
+        client: crate::Binance,
+        depth_limit: u32,
+        mut queue_rx: mpsc::UnboundedReceiver<String>,
+        snapshot_tx: mpsc::UnboundedSender<(String, OrderBook)>,
+    ) {
+        while let Some(symbol) = queue_rx.recv().await {
+            loop {
+                match client
+                    .market()
+                    .depth(&symbol, Some(depth_limit as u16))
+                    .await
+                {
+                    Ok(snapshot) => {
+                        if snapshot_tx.send((symbol, snapshot)).is_err() {
+                            return;
+                        }
+                        break;
+                    }
+                    Err(_) => sleep(Duration::from_secs(1)).await,
+                }
+            }
+        }
+    }
+
+    /// Wait for every order book to be synchronized.
+    pub async fn wait_for_sync(&self) -> Result<()> {
+        let timeout_duration = Duration::from_secs(30);
+        let start = Instant::now();
+
+        loop {
+            {
+                let books = self.books.read().await;
+                let states = || books.books.values().map(|book| book.state);
+                if states().all(|state| state == DepthCacheState::Synced) {
+                    return Ok(());
+                }
+                if states().any(|state| state == DepthCacheState::Stopped) {
+                    return Err(Error::InvalidCredentials(
+                        "Depth cache manager stopped".to_string(),
+                    ));
+                }
+            }
+
+            if start.elapsed() > timeout_duration {
+                return Err(Error::InvalidCredentials(
+                    "Timeout waiting for depth cache sync".to_string(),
+                ));
+            }
+            sleep(Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Get the current depth cache of a symbol.
+    pub async fn get_cache(&self, symbol: &str) -> Option<DepthCache> {
+        self.books
+            .read()
+            .await
+            .books
+            .get(&symbol.to_uppercase())
+            .map(|book| book.cache.clone())
+    }
+
+    /// Get the current state of a symbol's order book.
+    pub async fn state(&self, symbol: &str) -> Option<DepthCacheState> {
+        self.books
+            .read()
+            .await
+            .books
+            .get(&symbol.to_uppercase())
+            .map(|book| book.state)
+    }
+
+    /// Receive the next order book update of any symbol.
+    pub async fn next(&mut self) -> Option<DepthCache> {
+        self.cache_rx.recv().await
+    }
+
+    /// Stop the manager.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+
+    /// Get the symbols being tracked.
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::OrderBookEntry;
+    use crate::models::websocket::DepthLevel;
+
+    fn event(symbol: &str, first: u64, last: u64, bid: f64) -> DepthEvent {
+        DepthEvent {
+            event_time: last,
+            symbol: symbol.to_string(),
+            first_update_id: first,
+            final_update_id: last,
+            bids: vec![DepthLevel {
+                price: bid,
+                quantity: 1.0,
+            }],
+            asks: vec![],
+        }
+    }
+
+    fn snapshot(last_update_id: u64) -> OrderBook {
+        OrderBook {
+            last_update_id,
+            bids: vec![OrderBookEntry {
+                price: 100.0,
+                quantity: 1.0,
+            }],
+            asks: vec![OrderBookEntry {
+                price: 101.0,
+                quantity: 1.0,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_multi_depth_books_sync() {
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+        let mut books = DepthBooks::new(&symbols);
+
+        // Events are buffered until the snapshot arrives
+        let update = books.apply_event(event("BTCUSDT", 9, 10, 99.0));
+        assert_eq!(update, BookUpdate::Buffered);
+        let update = books.apply_event(event("BTCUSDT", 11, 12, 99.5));
+        assert_eq!(update, BookUpdate::Buffered);
+        let update = books.apply_event(event("XRPUSDT", 1, 2, 1.0));
+        assert_eq!(update, BookUpdate::Ignored);
+
+        // Stale buffered events are skipped, newer ones replayed
+        let update = books.apply_snapshot("BTCUSDT", &snapshot(10));
+        assert_eq!(update, BookUpdate::Applied);
+        let btc = &books.books["BTCUSDT"];
+        assert_eq!(btc.state, DepthCacheState::Synced);
+        assert_eq!(btc.cache.last_update_id, 12);
+        assert_eq!(btc.cache.best_bid(), Some((100.0, 1.0)));
+        assert_eq!(books.books["ETHUSDT"].state, DepthCacheState::Initializing);
+
+        let update = books.apply_event(event("BTCUSDT", 13, 14, 99.0));
+        assert_eq!(update, BookUpdate::Applied);
+
+        // A gap only resyncs the affected book
+        let update = books.apply_event(event("BTCUSDT", 20, 21, 99.0));
+        assert_eq!(update, BookUpdate::Resync);
+        assert_eq!(books.books["BTCUSDT"].state, DepthCacheState::OutOfSync);
+        let update = books.apply_snapshot("BTCUSDT", &snapshot(19));
+        assert_eq!(update, BookUpdate::Applied);
+        assert_eq!(books.books["BTCUSDT"].cache.last_update_id, 21);
+
+        // A snapshot older than the buffered events needs another one
+        books.apply_event(event("ETHUSDT", 30, 31, 99.0));
+        let update = books.apply_snapshot("ETHUSDT", &snapshot(20));
+        assert_eq!(update, BookUpdate::Resync);
+
+        // Refreshing a synced book never moves it backwards
+        let update = books.apply_snapshot("BTCUSDT", &snapshot(5));
+        assert_eq!(update, BookUpdate::Ignored);
+
+        books.reset();
+        assert!(
+            books
+                .books
+                .values()
+                .all(|book| book.state == DepthCacheState::Initializing)
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Many streams over one combined-stream connection.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tokio::time::{sleep, timeout};
+
+use super::{
+    ReconnectConfig, ReconnectingWebSocket, StreamEvent, WS_TIMEOUT_SECS, WebSocketClient,
+    WebSocketConnection,
+};
+use crate::models::websocket::WebSocketEvent;
+use crate::{Error, Result};
+
+/// Capacity of each per-stream channel.
+const STREAM_CHANNEL_CAPACITY: usize = 100;
+
+enum Command {
+    Subscribe {
+        streams: Vec<String>,
+        reply: oneshot::Sender<Result<HashMap<String, mpsc::Receiver<WebSocketEvent>>>>,
+    },
+    Unsubscribe {
+        streams: Vec<String>,
+        reply: oneshot::Sender<Result<()>>,
+    },
+}
+
+// Per-stream channels, keyed by stream name.
+type Routes = HashMap<String, mpsc::Sender<WebSocketEvent>>;
+
+/// Demultiplexes a single combined-stream connection into per-stream channels.
+///
+/// Streams are added and removed on the live connection with `SUBSCRIBE` and
+/// `UNSUBSCRIBE` requests, so tracking many symbols needs one socket rather
+/// than one per stream. The connection is opened on the first subscription
+/// and re-established with all current streams if it drops.
+///
+/// Each stream gets its own channel. Events are dropped for a stream whose
+/// receiver is not keeping up, and dropping a receiver unsubscribes the stream
+/// when its next event arrives. Binance allows up to 1024 streams per connection.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::StreamMultiplexer;
+///
+/// let ws = client.websocket();
+/// let mux = StreamMultiplexer::new(ws.clone());
+///
+/// let mut btc = mux.subscribe(&ws.trade_stream("btcusdt")).await?;
+/// let mut tickers = mux
+///     .subscribe_many(&[ws.book_ticker_stream("ethusdt"), ws.book_ticker_stream("bnbusdt")])
+///     .await?;
+///
+/// tokio::spawn(async move {
+///     while let Some(event) = btc.recv().await {
+///         println!("{:?}", event);
+///     }
+/// });
+/// ```
+pub struct StreamMultiplexer {
+    commands: mpsc::Sender<Command>,
+    streams: Arc<RwLock<Vec<String>>>,
+    handle: JoinHandle<()>,
+}
+
+impl StreamMultiplexer {
+    /// Create a multiplexer on the given WebSocket client.
+    ///
+    /// Must be called from within a tokio runtime unless the client's
+    /// `runtime_hints` select another one. The background task stops
+    /// when the client is shut down or the multiplexer is dropped.
+    pub fn new(ws: WebSocketClient) -> Self {
+        Self::with_reconnect(ws, ReconnectConfig::default())
+    }
+
+    /// Create a multiplexer with a custom reconnection backoff.
+    ///
+    /// Only `base_delay` and `max_reconnect_delay` are used; reconnection is
+    /// retried for as long as there are subscribed streams.
+    pub fn with_reconnect(ws: WebSocketClient, config: ReconnectConfig) -> Self {
+        let (commands, command_rx) = mpsc::channel(100);
+        let streams = Arc::new(RwLock::new(Vec::new()));
+
+        let runtime = ws.config.runtime_hints.clone();
+        let handle = runtime.spawn(Self::run(ws, config, command_rx, streams.clone()));
+
+        Self {
+            commands,
+            streams,
+            handle,
+        }
+    }
+
+    /// Subscribe to a stream and get the receiver for its events.
+    ///
+    /// Subscribing to a stream that already has a receiver replaces it.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Stream name (e.g., from `WebSocketClient::trade_stream`)
+    pub async fn subscribe(&self, stream: &str) -> Result<mpsc::Receiver<WebSocketEvent>> {
+        let mut receivers = self.subscribe_many(&[stream.to_string()]).await?;
+        receivers.remove(stream).ok_or_else(closed)
+    }
+
+    /// Subscribe to several streams in one request.
+    ///
+    /// Returns a receiver per stream, keyed by stream name.
+    pub async fn subscribe_many(
+        &self,
+        streams: &[String],
+    ) -> Result<HashMap<String, mpsc::Receiver<WebSocketEvent>>> {
+        let (reply, rx) = oneshot::channel();
+        let command = Command::Subscribe {
+            streams: streams.to_vec(),
+            reply,
+        };
+        self.commands.send(command).await.map_err(|_| closed())?;
+        rx.await.map_err(|_| closed())?
+    }
+
+    /// Unsubscribe from streams, closing their receivers.
+    pub async fn unsubscribe(&self, streams: &[String]) -> Result<()> {
+        let (reply, rx) = oneshot::channel();
+        let command = Command::Unsubscribe {
+            streams: streams.to_vec(),
+            reply,
+        };
+        self.commands.send(command).await.map_err(|_| closed())?;
+        rx.await.map_err(|_| closed())?
+    }
+
+    /// Get the currently subscribed stream names.
+    pub async fn streams(&self) -> Vec<String> {
+        self.streams.read().await.clone()
+    }
+
+    /// Stop the multiplexer, closing the connection and all receivers.
+    pub fn stop(&self) {
+        self.handle.abort();
+    }
+
+    async fn run(
+        ws: WebSocketClient,
+        config: ReconnectConfig,
+        mut commands: mpsc::Receiver<Command>,
+        streams: Arc<RwLock<Vec<String>>>,
+    ) {
+        let shutdown = ws.shutdown.clone();
+        let mut routes = Routes::new();
+        let mut conn: Option<WebSocketConnection> = None;
+        let mut attempt = 0;
+
+        loop {
+            // Re-establish a dropped connection while there is something to receive.
+            if conn.is_none() && !routes.is_empty() {
+                let names: Vec<String> = routes.keys().cloned().collect();
+                match ws.connect_combined(&names).await {
+                    Ok(c) => {
+                        conn = Some(c);
+                        attempt = 0;
+                    }
+                    Err(_) => {
+                        attempt += 1;
+                        let delay =
+                            ReconnectingWebSocket::calculate_backoff_delay(attempt, &config);
+                        tokio::select! {
+                            _ = shutdown.cancelled() => return,
+                            _ = sleep(delay) => continue,
+                        }
+                    }
+                }
+            }
+
+            let next = async {
+                match conn.as_mut() {
+                    Some(c) => timeout(Duration::from_secs(WS_TIMEOUT_SECS), c.next_event()).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            let action = tokio::select! {
+                _ = shutdown.cancelled() => None,
+                command = commands.recv() => command.map(Action::Command),
+                next = next => Some(Action::Event(next)),
+            };
+
+            match action {
+                None => {
+                    if let Some(mut c) = conn.take() {
+                        let _ = c.close().await;
+                    }
+                    return;
+                }
+                Some(Action::Command(command)) => {
+                    Self::handle_command(&ws, &mut conn, &mut routes, command).await;
+                }
+                Some(Action::Event(Ok(Some(Ok(event))))) => {
+                    let closed = dispatch(&mut routes, event);
+                    if let Some(c) = conn.as_mut().filter(|_| !closed.is_empty()) {
+                        if c.unsubscribe(&closed).await.is_err() {
+                            conn = None;
+                        }
+                    }
+                }
+                // Messages that are not stream events are skipped.
+                Some(Action::Event(Ok(Some(Err(_))))) => continue,
+                // Closed by the server.
+                Some(Action::Event(Ok(None))) => conn = None,
+                // Nothing received within the timeout.
+                Some(Action::Event(Err(_))) => continue,
+            }
+
+            let mut names: Vec<String> = routes.keys().cloned().collect();
+            names.sort();
+            *streams.write().await = names;
+        }
+    }
+
+    async fn handle_command(
+        ws: &WebSocketClient,
+        conn: &mut Option<WebSocketConnection>,
+        routes: &mut Routes,
+        command: Command,
+    ) {
+        match command {
+            Command::Subscribe { streams, reply } => {
+                let new: Vec<String> = streams
+                    .iter()
+                    .filter(|s| !routes.contains_key(*s))
+                    .cloned()
+                    .collect();
+
+                let result = match conn {
+                    _ if new.is_empty() => Ok(()),
+                    Some(c) => c.subscribe(&new).await,
+                    None => {
+                        let mut names: Vec<String> = routes.keys().cloned().collect();
+                        names.extend(new);
+                        ws.connect_combined(&names).await.map(|c| *conn = Some(c))
+                    }
+                };
+
+                let _ = reply.send(result.map(|()| {
+                    streams
+                        .into_iter()
+                        .map(|stream| {
+                            let (tx, rx) = mpsc::channel(STREAM_CHANNEL_CAPACITY);
+                            routes.insert(stream.clone(), tx);
+                            (stream, rx)
+                        })
+                        .collect()
+                }));
+            }
+            Command::Unsubscribe { streams, reply } => {
+                let existing: Vec<String> = streams
+                    .into_iter()
+                    .filter(|s| routes.remove(s).is_some())
+                    .collect();
+
+                let result = match conn {
+                    Some(c) if !existing.is_empty() => c.unsubscribe(&existing).await,
+                    _ => Ok(()),
+                };
+                let _ = reply.send(result);
+            }
+        }
+    }
+}
+
+impl Drop for StreamMultiplexer {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+enum Action {
+    Command(Command),
+    Event(std::result::Result<Option<Result<StreamEvent>>, tokio::time::error::Elapsed>),
+}
+
+// Send an event to its stream's channel, returning streams whose receiver was dropped.
+fn dispatch(routes: &mut Routes, event: StreamEvent) -> Vec<String> {
+    let Some(stream) = event.stream else {
+        return Vec::new();
+    };
+    let Some(tx) = routes.get(&stream) else {
+        return Vec::new();
+    };
+
+    match tx.try_send(event.event) {
+        Err(mpsc::error::TrySendError::Closed(_)) => {
+            routes.remove(&stream);
+            vec![stream]
+        }
+        // Full channels drop the event.
+        _ => Vec::new(),
+    }
+}
+
+fn closed() -> Error {
+    Error::WebSocket(tokio_tungstenite::tungstenite::Error::AlreadyClosed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade_event(stream: &str) -> StreamEvent {
+        let json = r#"{
+            "e": "trade", "E": 1, "s": "BTCUSDT", "t": 1, "p": "1.0", "q": "1.0",
+            "b": 1, "a": 2, "T": 1, "m": true, "M": true
+        }"#;
+        let event = serde_json::from_str(json).unwrap();
+        StreamEvent {
+            stream: Some(stream.to_string()),
+            received_at: 0,
+            event,
+        }
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let mut routes = Routes::new();
+        let (btc_tx, mut btc_rx) = mpsc::channel(1);
+        let (eth_tx, eth_rx) = mpsc::channel(1);
+        routes.insert("btcusdt@trade".to_string(), btc_tx);
+        routes.insert("ethusdt@trade".to_string(), eth_tx);
+        drop(eth_rx);
+
+        assert!(dispatch(&mut routes, trade_event("btcusdt@trade")).is_empty());
+        assert!(btc_rx.try_recv().is_ok());
+
+        // Unknown streams are ignored
+        assert!(dispatch(&mut routes, trade_event("bnbusdt@trade")).is_empty());
+
+        // A dropped receiver is removed and reported
+        assert_eq!(
+            dispatch(&mut routes, trade_event("ethusdt@trade")),
+            vec!["ethusdt@trade".to_string()]
+        );
+        assert!(!routes.contains_key("ethusdt@trade"));
+    }
+}
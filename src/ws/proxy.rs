@@ -0,0 +1,256 @@
+//! WebSocket connections through HTTP and SOCKS5 proxies.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::error::{Error as WsError, UrlError};
+use tokio_tungstenite::tungstenite::handshake::client::Response;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, client_async, connect_async};
+use url::Url;
+
+use crate::{Error, Result};
+
+/// Longest proxy response header accepted for an HTTP `CONNECT`.
+const MAX_CONNECT_RESPONSE: usize = 8 * 1024;
+
+type Stream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Open a WebSocket connection to `url`, tunnelled through `proxy` if set.
+///
+/// Supported proxy schemes are `http`, `socks5` (target resolved locally)
+/// and `socks5h` (target resolved by the proxy). Credentials are taken from
+/// the proxy URL.
+pub(crate) async fn connect(url: &str, proxy: Option<&str>) -> Result<(Stream, Response)> {
+    let Some(proxy) = proxy else {
+        return connect_async(url).await.map_err(Error::WebSocket);
+    };
+
+    let target = Url::parse(url)?;
+    // Match `connect_async`, which is built without a TLS backend.
+    if target.scheme() == "wss" {
+        return Err(Error::WebSocket(WsError::Url(
+            UrlError::TlsFeatureNotEnabled,
+        )));
+    }
+    let host = target
+        .host_str()
+        .ok_or_else(|| Error::InvalidConfig(format!("URL has no host: {}", url)))?
+        .to_string();
+    let port = target
+        .port_or_known_default()
+        .ok_or_else(|| Error::InvalidConfig(format!("URL has no port: {}", url)))?;
+
+    let proxy = Url::parse(proxy)?;
+    let proxy_host = proxy
+        .host_str()
+        .ok_or_else(|| Error::InvalidConfig("proxy URL has no host".to_string()))?;
+    let credentials = proxy
+        .password()
+        .map(|password| (decode(proxy.username()), decode(password)));
+
+    let stream = match proxy.scheme() {
+        "http" => {
+            let proxy_port = proxy.port().unwrap_or(80);
+            let mut stream = TcpStream::connect((proxy_host, proxy_port)).await?;
+            http_connect(&mut stream, &host, port, credentials).await?;
+            stream
+        }
+        scheme @ ("socks5" | "socks5h") => {
+            let proxy_port = proxy.port().unwrap_or(1080);
+            let mut stream = TcpStream::connect((proxy_host, proxy_port)).await?;
+            socks5_connect(&mut stream, &host, port, scheme == "socks5h", credentials).await?;
+            stream
+        }
+        scheme => {
+            return Err(Error::InvalidConfig(format!(
+                "unsupported proxy scheme: {}",
+                scheme
+            )));
+        }
+    };
+
+    client_async(url, MaybeTlsStream::Plain(stream))
+        .await
+        .map_err(Error::WebSocket)
+}
+
+fn decode(value: &str) -> String {
+    urlencoding::decode(value)
+        .map(|v| v.into_owned())
+        .unwrap_or_else(|_| value.to_string())
+}
+
+fn proxy_error(message: impl Into<String>) -> Error {
+    Error::Io(std::io::Error::other(message.into()))
+}
+
+// Length prefix of a SOCKS5 field, which holds at most 255 bytes.
+fn socks5_len(field: &str, value: &str) -> Result<u8> {
+    u8::try_from(value.len())
+        .map_err(|_| Error::InvalidConfig(format!("SOCKS5 {} is longer than 255 bytes", field)))
+}
+
+async fn http_connect(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+    credentials: Option<(String, String)>,
+) -> Result<()> {
+    let mut request = format!(
+        "CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n",
+        host = host,
+        port = port
+    );
+    if let Some((user, password)) = credentials {
+        let token = BASE64.encode(format!("{}:{}", user, password));
+        request.push_str(&format!("Proxy-Authorization: Basic {}\r\n", token));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read the response header one byte at a time so nothing past it is
+    // consumed from the tunnel.
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE {
+            return Err(proxy_error("proxy response header too long"));
+        }
+        let byte = stream.read_u8().await?;
+        response.push(byte);
+    }
+
+    let response = String::from_utf8_lossy(&response);
+    let status_line = response.lines().next().unwrap_or_default();
+    let status = status_line.split_whitespace().nth(1);
+    if status != Some("200") {
+        return Err(proxy_error(format!(
+            "proxy CONNECT failed: {}",
+            status_line
+        )));
+    }
+    Ok(())
+}
+
+async fn socks5_connect(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+    remote_dns: bool,
+    credentials: Option<(String, String)>,
+) -> Result<()> {
+    // Greeting: offer username/password authentication only if configured.
+    let method = if credentials.is_some() { 0x02 } else { 0x00 };
+    stream.write_all(&[0x05, 0x01, method]).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != 0x05 || reply[1] != method {
+        return Err(proxy_error(
+            "SOCKS5 proxy rejected the authentication method",
+        ));
+    }
+
+    if let Some((user, password)) = credentials {
+        let mut auth = vec![0x01, socks5_len("username", &user)?];
+        auth.extend_from_slice(user.as_bytes());
+        auth.push(socks5_len("password", &password)?);
+        auth.extend_from_slice(password.as_bytes());
+        stream.write_all(&auth).await?;
+        stream.read_exact(&mut reply).await?;
+        if reply[1] != 0x00 {
+            return Err(proxy_error("SOCKS5 proxy authentication failed"));
+        }
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    if remote_dns {
+        request.push(0x03);
+        request.push(socks5_len("hostname", host)?);
+        request.extend_from_slice(host.as_bytes());
+    } else {
+        let addr = tokio::net::lookup_host((host, port))
+            .await?
+            .next()
+            .ok_or_else(|| proxy_error(format!("could not resolve {}", host)))?;
+        match addr.ip() {
+            std::net::IpAddr::V4(ip) => {
+                request.push(0x01);
+                request.extend_from_slice(&ip.octets());
+            }
+            std::net::IpAddr::V6(ip) => {
+                request.push(0x04);
+                request.extend_from_slice(&ip.octets());
+            }
+        }
+    }
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut header = [0u8; 4];
+    stream.read_exact(&mut header).await?;
+    if header[1] != 0x00 {
+        return Err(proxy_error(format!(
+            "SOCKS5 proxy could not connect (reply {})",
+            header[1]
+        )));
+    }
+
+    // Skip the bound address and port.
+    let address_len = match header[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await? as usize,
+        other => {
+            return Err(proxy_error(format!(
+                "invalid SOCKS5 address type {}",
+                other
+            )));
+        }
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_http_connect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let proxy = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = Vec::new();
+            while !request.ends_with(b"\r\n\r\n") {
+                request.push(socket.read_u8().await.unwrap());
+            }
+            socket
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8(request).unwrap()
+        });
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let credentials = Some(("user".to_string(), "pass".to_string()));
+        http_connect(&mut stream, "stream.binance.com", 9443, credentials)
+            .await
+            .unwrap();
+
+        let request = proxy.await.unwrap();
+        assert!(request.starts_with("CONNECT stream.binance.com:9443 HTTP/1.1\r\n"));
+        assert!(request.contains("Proxy-Authorization: Basic dXNlcjpwYXNz\r\n"));
+    }
+
+    #[test]
+    fn test_socks5_len() {
+        assert_eq!(socks5_len("username", &"a".repeat(255)).unwrap(), 255);
+        assert!(matches!(
+            socks5_len("username", &"a".repeat(256)),
+            Err(Error::InvalidConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,287 @@
+//! Execution report deduplication and catch-up after user data stream
+//! reconnects.
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::{Mutex, MutexGuard};
+
+use futures::TryStreamExt;
+
+use crate::Result;
+use crate::credentials::get_timestamp;
+use crate::models::websocket::WebSocketEvent;
+use crate::models::{Order, UserTrade};
+
+/// Time before the last received event from which trades are fetched when
+/// reconciling (in milliseconds).
+const RECONCILE_MARGIN_MS: u64 = 5_000;
+
+/// Largest number of trades returned by one `my_trades` request.
+const MAX_TRADES_PER_REQUEST: u32 = 1000;
+
+/// Configuration for the user data stream manager.
+#[derive(Debug, Clone)]
+pub struct UserDataStreamConfig {
+    /// Whether to fetch open orders and recent trades after every reconnect
+    /// and report them as [`UserDataStreamEvent::Reconciled`].
+    ///
+    /// [`UserDataStreamEvent::Reconciled`]: super::UserDataStreamEvent::Reconciled
+    pub reconcile: bool,
+    /// Symbols whose trades are always checked when reconciling, in addition
+    /// to symbols seen on the stream and symbols with open orders.
+    pub reconcile_symbols: Vec<String>,
+    /// Whether to drop execution reports that were already received.
+    pub dedupe: bool,
+    /// Number of executions remembered for deduplication.
+    pub dedupe_capacity: usize,
+}
+
+impl Default for UserDataStreamConfig {
+    fn default() -> Self {
+        Self {
+            reconcile: false,
+            reconcile_symbols: Vec::new(),
+            dedupe: true,
+            dedupe_capacity: 10_000,
+        }
+    }
+}
+
+/// Account state fetched after a user data stream reconnect.
+///
+/// Missed trades are reported together rather than as synthetic execution
+/// reports: the REST trade records lack the order state an execution report
+/// carries (status, cumulative quantity, and so on), so they are applied
+/// alongside `open_orders`, which already reflects them. Their trade IDs are
+/// remembered, so execution reports for them arriving later are dropped.
+///
+/// Times are local, in milliseconds since Unix epoch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserDataReconciliation {
+    /// Time of the reconciliation.
+    pub time: u64,
+    /// Start of the period checked for missed trades.
+    pub since: u64,
+    /// All open orders after reconnecting.
+    pub open_orders: Vec<Order>,
+    /// Trades made since `since` that were not received on the stream.
+    pub missed_trades: Vec<UserTrade>,
+}
+
+// Keys seen most recently, forgetting the oldest past the capacity.
+#[derive(Debug)]
+struct SeenKeys<K> {
+    keys: HashSet<K>,
+    order: VecDeque<K>,
+    capacity: usize,
+}
+
+impl<K: Copy + Eq + Hash> SeenKeys<K> {
+    fn new(capacity: usize) -> Self {
+        Self {
+            keys: HashSet::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    // Record a key, returning false if it was already seen.
+    fn insert(&mut self, key: K) -> bool {
+        if !self.keys.insert(key) {
+            return false;
+        }
+        self.order.push_back(key);
+        while self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.keys.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug)]
+struct TrackerState {
+    // (order ID, execution ID) of execution reports
+    executions: SeenKeys<(u64, u64)>,
+    // (order ID, trade ID) of trades, from execution reports and catch-up
+    trades: SeenKeys<(u64, u64)>,
+    symbols: BTreeSet<String>,
+    last_event_at: u64,
+}
+
+// Deduplicates execution reports and finds trades missed while disconnected.
+#[derive(Debug)]
+pub(crate) struct ExecutionTracker {
+    config: UserDataStreamConfig,
+    state: Mutex<TrackerState>,
+}
+
+impl ExecutionTracker {
+    pub(crate) fn new(config: UserDataStreamConfig) -> Self {
+        let capacity = config.dedupe_capacity.max(1);
+        let state = TrackerState {
+            executions: SeenKeys::new(capacity),
+            trades: SeenKeys::new(capacity),
+            symbols: BTreeSet::new(),
+            last_event_at: get_timestamp().unwrap_or_default(),
+        };
+        Self {
+            config,
+            state: Mutex::new(state),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TrackerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub(crate) fn reconciles(&self) -> bool {
+        self.config.reconcile
+    }
+
+    // Record an event, returning false if it is a duplicate to be dropped.
+    pub(crate) fn accept(&self, event: &WebSocketEvent) -> bool {
+        let mut state = self.lock();
+        state.last_event_at = get_timestamp().unwrap_or_default();
+
+        let WebSocketEvent::ExecutionReport(report) = event else {
+            return true;
+        };
+        if !state.symbols.contains(&report.symbol) {
+            state.symbols.insert(report.symbol.clone());
+        }
+
+        let new_execution = state
+            .executions
+            .insert((report.order_id, report.execution_id()));
+        // A trade ID of -1 means the report is not for a trade
+        let new_trade = report.trade_id < 0
+            || state
+                .trades
+                .insert((report.order_id, report.trade_id as u64));
+        !self.config.dedupe || (new_execution && new_trade)
+    }
+
+    // Fetch open orders and the trades not received since the last event.
+    pub(crate) async fn reconcile(
+        &self,
+        client: &crate::Binance,
+    ) -> Result<UserDataReconciliation> {
+        let (since, mut symbols) = {
+            let state = self.lock();
+            let since = state.last_event_at.saturating_sub(RECONCILE_MARGIN_MS);
+            (since, state.symbols.clone())
+        };
+        symbols.extend(
+            self.config
+                .reconcile_symbols
+                .iter()
+                .map(|s| s.to_uppercase()),
+        );
+
+        let account = client.account();
+        let open_orders = account.open_orders(None).await?;
+        symbols.extend(open_orders.iter().map(|o| o.symbol.clone()));
+
+        let mut missed_trades = Vec::new();
+        for symbol in &symbols {
+            let mut trades = account
+                .my_trades(
+                    symbol,
+                    None,
+                    Some(since),
+                    None,
+                    Some(MAX_TRADES_PER_REQUEST),
+                )
+                .await?;
+            if trades.len() == MAX_TRADES_PER_REQUEST as usize {
+                // Later pages are fetched by trade ID, which cannot be
+                // combined with a start time
+                let from_id = trades.last().map(|trade| trade.id + 1);
+                let rest: Vec<UserTrade> = account
+                    .my_trades_paginated(symbol, from_id)
+                    .try_collect()
+                    .await?;
+                trades.extend(rest);
+            }
+            let mut state = self.lock();
+            missed_trades.extend(
+                trades
+                    .into_iter()
+                    .filter(|trade| state.trades.insert((trade.order_id, trade.id))),
+            );
+        }
+        missed_trades.sort_by_key(|trade| (trade.time, trade.id));
+
+        Ok(UserDataReconciliation {
+            time: get_timestamp().unwrap_or_default(),
+            since,
+            open_orders,
+            missed_trades,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execution_report(order_id: u64, execution_id: u64, trade_id: i64) -> WebSocketEvent {
+        let json = serde_json::json!({
+            "e": "executionReport", "E": 1, "s": "BTCUSDT", "c": "c", "S": "BUY",
+            "o": "LIMIT", "f": "GTC", "q": "1", "p": "1", "P": "0", "F": "0",
+            "g": -1, "C": "", "x": if trade_id < 0 { "NEW" } else { "TRADE" },
+            "X": if trade_id < 0 { "NEW" } else { "FILLED" }, "r": "NONE",
+            "i": order_id, "l": "0", "z": "0", "L": "0", "n": "0", "N": null,
+            "T": 1, "t": trade_id, "I": execution_id, "w": true, "m": false,
+            "M": false, "O": 1, "Z": "0", "Y": "0", "Q": "0"
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_execution_tracker_dedupe() {
+        let tracker = ExecutionTracker::new(UserDataStreamConfig::default());
+
+        assert!(tracker.accept(&execution_report(1, 10, -1)));
+        assert!(tracker.accept(&execution_report(1, 11, 100)));
+        // Repeated after a reconnect
+        assert!(!tracker.accept(&execution_report(1, 10, -1)));
+        assert!(!tracker.accept(&execution_report(1, 11, 100)));
+        // Same execution ID on another order
+        assert!(tracker.accept(&execution_report(2, 10, -1)));
+
+        // Trades found while reconciling are not reported again
+        tracker.lock().trades.insert((3, 300));
+        assert!(!tracker.accept(&execution_report(3, 30, 300)));
+
+        assert!(tracker.lock().symbols.contains("BTCUSDT"));
+    }
+
+    #[test]
+    fn test_execution_tracker_without_dedupe() {
+        let config = UserDataStreamConfig {
+            dedupe: false,
+            ..Default::default()
+        };
+        let tracker = ExecutionTracker::new(config);
+
+        assert!(tracker.accept(&execution_report(1, 10, 100)));
+        assert!(tracker.accept(&execution_report(1, 10, 100)));
+        // Trades are still recorded for reconciliation
+        assert!(!tracker.lock().trades.insert((1, 100)));
+    }
+
+    #[test]
+    fn test_seen_keys_capacity() {
+        let mut seen = SeenKeys::new(2);
+        assert!(seen.insert(1));
+        assert!(seen.insert(2));
+        assert!(!seen.insert(1));
+        assert!(seen.insert(3));
+        // The oldest key was forgotten
+        assert!(seen.insert(1));
+        assert!(!seen.insert(3));
+    }
+}
@@ -0,0 +1,397 @@
+//! Bid/ask spread monitoring on the book ticker stream.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use tokio::sync::{RwLock, mpsc};
+use tokio::time::sleep;
+
+use crate::Result;
+use crate::models::websocket::{BookTickerEvent, WebSocketEvent};
+use crate::supervisor::RestartPolicy;
+
+/// Configuration for the spread monitor.
+#[derive(Debug, Clone)]
+pub struct SpreadMonitorConfig {
+    /// Number of recent spreads used for rolling statistics.
+    pub window: usize,
+    /// Spread (in basis points of the mid price) above which an alert is raised.
+    pub threshold_bps: f64,
+    /// Per-symbol thresholds overriding `threshold_bps`.
+    pub symbol_thresholds: HashMap<String, f64>,
+}
+
+impl Default for SpreadMonitorConfig {
+    fn default() -> Self {
+        Self {
+            window: 100,
+            threshold_bps: 10.0,
+            symbol_thresholds: HashMap::new(),
+        }
+    }
+}
+
+impl SpreadMonitorConfig {
+    /// Set the alert threshold for a single symbol.
+    pub fn symbol_threshold(mut self, symbol: &str, threshold_bps: f64) -> Self {
+        self.symbol_thresholds
+            .insert(symbol.to_uppercase(), threshold_bps);
+        self
+    }
+
+    fn threshold_for(&self, symbol: &str) -> f64 {
+        self.symbol_thresholds
+            .get(symbol)
+            .copied()
+            .unwrap_or(self.threshold_bps)
+    }
+}
+
+/// Rolling spread statistics for a symbol, in basis points of the mid price.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpreadStats {
+    /// Symbol.
+    pub symbol: String,
+    /// Most recent spread.
+    pub last_bps: f64,
+    /// Mean spread over the window.
+    pub mean_bps: f64,
+    /// Minimum spread over the window.
+    pub min_bps: f64,
+    /// Maximum spread over the window.
+    pub max_bps: f64,
+    /// Standard deviation of the spread over the window.
+    pub std_dev_bps: f64,
+    /// Number of samples in the window.
+    pub samples: usize,
+}
+
+/// Spread alert emitted by [`SpreadMonitor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpreadAlert {
+    /// The spread rose above the symbol's threshold.
+    Widened {
+        /// Threshold that was crossed, in basis points.
+        threshold_bps: f64,
+        /// Statistics at the time of the alert.
+        stats: SpreadStats,
+    },
+    /// The spread fell back below the symbol's threshold.
+    Normalized {
+        /// Threshold that was crossed, in basis points.
+        threshold_bps: f64,
+        /// Statistics at the time of the alert.
+        stats: SpreadStats,
+    },
+}
+
+impl SpreadAlert {
+    /// Get the symbol this alert is for.
+    pub fn symbol(&self) -> &str {
+        match self {
+            Self::Widened { stats, .. } | Self::Normalized { stats, .. } => &stats.symbol,
+        }
+    }
+}
+
+// Rolling window of spreads for one symbol.
+#[derive(Debug, Clone)]
+struct SpreadWindow {
+    samples: VecDeque<f64>,
+    capacity: usize,
+    widened: bool,
+}
+
+impl SpreadWindow {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            widened: false,
+        }
+    }
+
+    fn push(&mut self, spread_bps: f64) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(spread_bps);
+    }
+
+    fn stats(&self, symbol: &str) -> SpreadStats {
+        let n = self.samples.len() as f64;
+        let mean = self.samples.iter().sum::<f64>() / n;
+        let variance = self.samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        SpreadStats {
+            symbol: symbol.to_string(),
+            last_bps: self.samples.back().copied().unwrap_or_default(),
+            mean_bps: mean,
+            min_bps: self.samples.iter().copied().fold(f64::INFINITY, f64::min),
+            max_bps: self
+                .samples
+                .iter()
+                .copied()
+                .fold(f64::NEG_INFINITY, f64::max),
+            std_dev_bps: variance.sqrt(),
+            samples: self.samples.len(),
+        }
+    }
+}
+
+// Spread state for all monitored symbols.
+#[derive(Debug)]
+struct SpreadTracker {
+    config: SpreadMonitorConfig,
+    windows: HashMap<String, SpreadWindow>,
+}
+
+impl SpreadTracker {
+    fn new(config: SpreadMonitorConfig) -> Self {
+        Self {
+            config,
+            windows: HashMap::new(),
+        }
+    }
+
+    fn update(&mut self, ticker: &BookTickerEvent) -> Option<SpreadAlert> {
+        if ticker.bid_price <= 0.0 || ticker.ask_price <= 0.0 {
+            return None;
+        }
+        let mid = (ticker.bid_price + ticker.ask_price) / 2.0;
+        let spread_bps = (ticker.ask_price - ticker.bid_price) / mid * 10_000.0;
+
+        let threshold_bps = self.config.threshold_for(&ticker.symbol);
+        let window = self
+            .windows
+            .entry(ticker.symbol.clone())
+            .or_insert_with(|| SpreadWindow::new(self.config.window));
+        window.push(spread_bps);
+
+        let widened = spread_bps > threshold_bps;
+        if widened == window.widened {
+            return None;
+        }
+        window.widened = widened;
+
+        let stats = window.stats(&ticker.symbol);
+        Some(if widened {
+            SpreadAlert::Widened {
+                threshold_bps,
+                stats,
+            }
+        } else {
+            SpreadAlert::Normalized {
+                threshold_bps,
+                stats,
+            }
+        })
+    }
+
+    fn stats(&self, symbol: &str) -> Option<SpreadStats> {
+        self.windows.get(symbol).map(|w| w.stats(symbol))
+    }
+}
+
+/// Tracks bid/ask spreads for a set of symbols from the book ticker stream.
+///
+/// Rolling spread statistics are kept for every symbol, and a
+/// [`SpreadAlert`] is emitted whenever a spread crosses its threshold in
+/// either direction. Alerts are dropped if they are not received fast enough.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::{SpreadAlert, SpreadMonitor, SpreadMonitorConfig};
+///
+/// let config = SpreadMonitorConfig::default().symbol_threshold("ETHUSDT", 5.0);
+/// let mut monitor = SpreadMonitor::new(client.clone(), &["BTCUSDT", "ETHUSDT"], config).await?;
+///
+/// while let Some(alert) = monitor.next().await {
+///     if let SpreadAlert::Widened { stats, .. } = alert {
+///         println!("{} spread {:.1} bps", stats.symbol, stats.last_bps);
+///     }
+/// }
+/// ```
+pub struct SpreadMonitor {
+    tracker: Arc<RwLock<SpreadTracker>>,
+    is_stopped: Arc<AtomicBool>,
+    alert_rx: mpsc::Receiver<SpreadAlert>,
+}
+
+impl SpreadMonitor {
+    /// Start monitoring the spreads of `symbols`.
+    pub async fn new(
+        client: crate::Binance,
+        symbols: &[&str],
+        config: SpreadMonitorConfig,
+    ) -> Result<Self> {
+        let ws = client.websocket();
+        let streams: Vec<String> = symbols.iter().map(|s| ws.book_ticker_stream(s)).collect();
+        let tracker = Arc::new(RwLock::new(SpreadTracker::new(config)));
+        let is_stopped = Arc::new(AtomicBool::new(false));
+        let (alert_tx, alert_rx) = mpsc::channel(100);
+
+        let tracker_clone = tracker.clone();
+        let is_stopped_clone = is_stopped.clone();
+        let shutdown = client.client().shutdown_handle().clone();
+        let runtime = client.config().runtime_hints.clone();
+        let policy = RestartPolicy::default();
+        shutdown.spawn_supervised_on(&runtime, "spread-monitor", policy, move || {
+            Self::monitor_loop(
+                client.clone(),
+                streams.clone(),
+                tracker_clone.clone(),
+                is_stopped_clone.clone(),
+                alert_tx.clone(),
+            )
+        });
+
+        Ok(Self {
+            tracker,
+            is_stopped,
+            alert_rx,
+        })
+    }
+
+    async fn monitor_loop(
+        client: crate::Binance,
+        streams: Vec<String>,
+        tracker: Arc<RwLock<SpreadTracker>>,
+        is_stopped: Arc<AtomicBool>,
+        alert_tx: mpsc::Sender<SpreadAlert>,
+    ) {
+        let ws = client.websocket();
+        let shutdown = client.client().shutdown_handle().clone();
+
+        loop {
+            if is_stopped.load(Ordering::SeqCst) || shutdown.is_shutdown() {
+                break;
+            }
+
+            let mut conn = match ws.connect_combined_with_reconnect(&streams).await {
+                Ok(c) => c,
+                Err(_) => {
+                    sleep(Duration::from_secs(1)).await;
+                    continue;
+                }
+            };
+
+            loop {
+                if is_stopped.load(Ordering::SeqCst) {
+                    conn.close().await;
+                    return;
+                }
+
+                let next = tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        conn.close().await;
+                        return;
+                    }
+                    next = conn.next() => next,
+                };
+
+                match next {
+                    Some(Ok(WebSocketEvent::BookTicker(ticker))) => {
+                        if let Some(alert) = tracker.write().await.update(&ticker) {
+                            let _ = alert_tx.try_send(alert);
+                        }
+                    }
+                    Some(_) => continue,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Receive the next spread alert.
+    pub async fn next(&mut self) -> Option<SpreadAlert> {
+        self.alert_rx.recv().await
+    }
+
+    /// Get the rolling spread statistics for a symbol.
+    pub async fn stats(&self, symbol: &str) -> Option<SpreadStats> {
+        self.tracker.read().await.stats(&symbol.to_uppercase())
+    }
+
+    /// Get the rolling spread statistics for all symbols seen so far.
+    pub async fn all_stats(&self) -> Vec<SpreadStats> {
+        let tracker = self.tracker.read().await;
+        tracker
+            .windows
+            .iter()
+            .map(|(symbol, window)| window.stats(symbol))
+            .collect()
+    }
+
+    /// Stop the spread monitor.
+    pub fn stop(&self) {
+        self.is_stopped.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(symbol: &str, bid: f64, ask: f64) -> BookTickerEvent {
+        BookTickerEvent {
+            update_id: 1,
+            symbol: symbol.to_string(),
+            bid_price: bid,
+            bid_quantity: 1.0,
+            ask_price: ask,
+            ask_quantity: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_spread_alerts() {
+        let config = SpreadMonitorConfig {
+            window: 3,
+            ..Default::default()
+        }
+        .symbol_threshold("ethusdt", 50.0);
+        let mut tracker = SpreadTracker::new(config);
+
+        // 2 bps: below the default 10 bps threshold
+        assert_eq!(tracker.update(&ticker("BTCUSDT", 99.99, 100.01)), None);
+
+        // 20 bps: widened
+        let alert = tracker.update(&ticker("BTCUSDT", 99.9, 100.1)).unwrap();
+        assert!(
+            matches!(alert, SpreadAlert::Widened { threshold_bps, .. } if threshold_bps == 10.0)
+        );
+        assert_eq!(alert.symbol(), "BTCUSDT");
+
+        // Still wide: no repeated alert
+        assert_eq!(tracker.update(&ticker("BTCUSDT", 99.9, 100.1)), None);
+
+        // Back to 2 bps: normalized
+        let alert = tracker.update(&ticker("BTCUSDT", 99.99, 100.01)).unwrap();
+        assert!(matches!(alert, SpreadAlert::Normalized { .. }));
+
+        // 20 bps is fine for ETHUSDT
+        assert_eq!(tracker.update(&ticker("ETHUSDT", 99.9, 100.1)), None);
+    }
+
+    #[test]
+    fn test_spread_stats_window() {
+        let mut tracker = SpreadTracker::new(SpreadMonitorConfig {
+            window: 2,
+            ..Default::default()
+        });
+        tracker.update(&ticker("BTCUSDT", 99.5, 100.5));
+        tracker.update(&ticker("BTCUSDT", 99.9, 100.1));
+        tracker.update(&ticker("BTCUSDT", 99.99, 100.01));
+
+        let stats = tracker.stats("BTCUSDT").unwrap();
+        assert_eq!(stats.samples, 2);
+        assert!((stats.max_bps - 20.0).abs() < 1e-6);
+        assert!((stats.min_bps - 2.0).abs() < 1e-6);
+        assert!((stats.mean_bps - 11.0).abs() < 1e-6);
+        assert!((stats.last_bps - 2.0).abs() < 1e-6);
+        assert!(tracker.stats("ETHUSDT").is_none());
+    }
+}
@@ -0,0 +1,470 @@
+//! Reference-counted stream subscriptions spread over budgeted connections.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use super::{StreamMultiplexer, WebSocketClient};
+use crate::Result;
+use crate::models::websocket::WebSocketEvent;
+
+/// Streams Binance allows on one connection.
+const MAX_STREAMS_PER_CONNECTION: usize = 1024;
+
+/// Capacity of each stream's consumer channel.
+const CONSUMER_CHANNEL_CAPACITY: usize = 100;
+
+// A connection and the number of streams placed on it.
+struct Slot<C> {
+    id: u64,
+    connection: C,
+    streams: usize,
+}
+
+struct Entry {
+    // Distinguishes this subscription from later ones to the same stream.
+    generation: u64,
+    connection: u64,
+    tx: broadcast::Sender<WebSocketEvent>,
+    consumers: usize,
+    forward: Option<JoinHandle<()>>,
+}
+
+// Outcome of registering a consumer.
+enum Acquired<C> {
+    // The stream is already subscribed upstream, or being subscribed.
+    Shared {
+        generation: u64,
+        rx: broadcast::Receiver<WebSocketEvent>,
+    },
+    // The stream must be subscribed on `connection`.
+    New {
+        generation: u64,
+        rx: broadcast::Receiver<WebSocketEvent>,
+        tx: broadcast::Sender<WebSocketEvent>,
+        connection: C,
+    },
+}
+
+// Outcome of releasing a consumer.
+enum Released<C> {
+    // Other consumers remain, or the subscription is already gone.
+    Kept,
+    // The stream must be unsubscribed from `connection`.
+    Unsubscribe(C),
+    // The stream was the last one on its connection, which was dropped.
+    Closed,
+}
+
+// Consumer counts and connection packing, kept apart from the network I/O
+// so the lock is never held across an await.
+struct Registry<C> {
+    budget: usize,
+    connections: Vec<Slot<C>>,
+    streams: HashMap<String, Entry>,
+    next_id: u64,
+}
+
+impl<C: Clone> Registry<C> {
+    fn new(budget: usize) -> Self {
+        Self {
+            budget,
+            connections: Vec::new(),
+            streams: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    // Register a consumer, placing the stream on a connection with room for
+    // it if nobody consumes it yet. `open` creates a connection when all are
+    // full.
+    fn acquire(&mut self, stream: &str, open: impl FnOnce() -> C) -> Acquired<C> {
+        if let Some(entry) = self.streams.get_mut(stream) {
+            entry.consumers += 1;
+            return Acquired::Shared {
+                generation: entry.generation,
+                rx: entry.tx.subscribe(),
+            };
+        }
+
+        let index = match self
+            .connections
+            .iter()
+            .position(|c| c.streams < self.budget)
+        {
+            Some(index) => index,
+            None => {
+                let id = self.next_id();
+                self.connections.push(Slot {
+                    id,
+                    connection: open(),
+                    streams: 0,
+                });
+                self.connections.len() - 1
+            }
+        };
+        let generation = self.next_id();
+        let slot = &mut self.connections[index];
+        slot.streams += 1;
+
+        let (tx, rx) = broadcast::channel(CONSUMER_CHANNEL_CAPACITY);
+        self.streams.insert(
+            stream.to_string(),
+            Entry {
+                generation,
+                connection: slot.id,
+                tx: tx.clone(),
+                consumers: 1,
+                forward: None,
+            },
+        );
+        Acquired::New {
+            generation,
+            rx,
+            tx,
+            connection: slot.connection.clone(),
+        }
+    }
+
+    // Attach the task forwarding upstream events, aborting it if every
+    // consumer left while the stream was being subscribed.
+    fn attach(&mut self, stream: &str, generation: u64, forward: JoinHandle<()>) {
+        match self.streams.get_mut(stream) {
+            Some(entry) if entry.generation == generation => entry.forward = Some(forward),
+            _ => forward.abort(),
+        }
+    }
+
+    // Drop one consumer, removing the stream if it was the last one.
+    fn release(&mut self, stream: &str, generation: u64) -> Released<C> {
+        let Some(entry) = self.streams.get_mut(stream) else {
+            return Released::Kept;
+        };
+        if entry.generation != generation {
+            return Released::Kept;
+        }
+        entry.consumers -= 1;
+        if entry.consumers > 0 {
+            return Released::Kept;
+        }
+        self.remove(stream, generation)
+    }
+
+    // Remove a stream regardless of its consumers.
+    fn remove(&mut self, stream: &str, generation: u64) -> Released<C> {
+        match self.streams.get(stream) {
+            Some(entry) if entry.generation == generation => {}
+            _ => return Released::Kept,
+        }
+        let Some(entry) = self.streams.remove(stream) else {
+            return Released::Kept;
+        };
+        if let Some(forward) = entry.forward {
+            forward.abort();
+        }
+
+        let Some(index) = self
+            .connections
+            .iter()
+            .position(|c| c.id == entry.connection)
+        else {
+            return Released::Kept;
+        };
+        let slot = &mut self.connections[index];
+        slot.streams -= 1;
+        if slot.streams == 0 {
+            self.connections.remove(index);
+            Released::Closed
+        } else {
+            Released::Unsubscribe(slot.connection.clone())
+        }
+    }
+}
+
+/// Subscribes to streams on demand and releases them when unused.
+///
+/// A stream is subscribed when its first consumer registers with
+/// [`subscribe`](Self::subscribe) and unsubscribed once every
+/// [`Subscription`] for it has been dropped. Consumers of the same stream
+/// share one upstream subscription.
+///
+/// Streams are packed onto connections holding at most `budget` streams
+/// each; a new connection is opened when all are full, and a connection is
+/// closed when its last stream is released.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use binance_api_client::SubscriptionManager;
+///
+/// let ws = client.websocket();
+/// let manager = SubscriptionManager::with_budget(ws.clone(), 200);
+///
+/// let mut a = manager.subscribe(&ws.trade_stream("btcusdt")).await?;
+/// let mut b = manager.subscribe(&ws.trade_stream("btcusdt")).await?;
+/// assert_eq!(manager.active_streams().await.len(), 1);
+///
+/// while let Some(event) = a.recv().await {
+///     println!("{:?}", event);
+/// }
+///
+/// // Unsubscribed upstream once both are dropped
+/// drop(a);
+/// drop(b);
+/// ```
+#[derive(Clone)]
+pub struct SubscriptionManager {
+    ws: WebSocketClient,
+    state: Arc<Mutex<Registry<Arc<StreamMultiplexer>>>>,
+}
+
+impl SubscriptionManager {
+    /// Create a manager that packs up to 1024 streams on each connection.
+    pub fn new(ws: WebSocketClient) -> Self {
+        Self::with_budget(ws, MAX_STREAMS_PER_CONNECTION)
+    }
+
+    /// Create a manager that packs up to `budget` streams on each connection.
+    ///
+    /// The budget is clamped to between 1 and 1024.
+    pub fn with_budget(ws: WebSocketClient, budget: usize) -> Self {
+        Self {
+            ws,
+            state: Arc::new(Mutex::new(Registry::new(
+                budget.clamp(1, MAX_STREAMS_PER_CONNECTION),
+            ))),
+        }
+    }
+
+    /// Register a consumer for a stream, subscribing to it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - Stream name (e.g., from `WebSocketClient::trade_stream`)
+    pub async fn subscribe(&self, stream: &str) -> Result<Subscription> {
+        let acquired = self
+            .state()
+            .acquire(stream, || Arc::new(StreamMultiplexer::new(self.ws.clone())));
+        let (generation, rx, tx, connection) = match acquired {
+            Acquired::Shared { generation, rx } => {
+                return Ok(self.subscription(stream, generation, rx));
+            }
+            Acquired::New {
+                generation,
+                rx,
+                tx,
+                connection,
+            } => (generation, rx, tx, connection),
+        };
+
+        let mut upstream = match connection.subscribe(stream).await {
+            Ok(upstream) => upstream,
+            Err(e) => {
+                self.state().remove(stream, generation);
+                return Err(e);
+            }
+        };
+
+        let forward = self.ws.config.runtime_hints.spawn(async move {
+            while let Some(event) = upstream.recv().await {
+                let _ = tx.send(event);
+            }
+        });
+        self.state().attach(stream, generation, forward);
+        Ok(self.subscription(stream, generation, rx))
+    }
+
+    /// Get the streams that have at least one consumer, sorted by name.
+    pub async fn active_streams(&self) -> Vec<String> {
+        let mut streams: Vec<String> = self.state().streams.keys().cloned().collect();
+        streams.sort();
+        streams
+    }
+
+    /// Get the number of consumers registered for a stream.
+    pub async fn consumers(&self, stream: &str) -> usize {
+        self.state().streams.get(stream).map_or(0, |e| e.consumers)
+    }
+
+    /// Get the number of open connections.
+    pub async fn connection_count(&self) -> usize {
+        self.state().connections.len()
+    }
+
+    fn state(&self) -> MutexGuard<'_, Registry<Arc<StreamMultiplexer>>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn subscription(
+        &self,
+        stream: &str,
+        generation: u64,
+        rx: broadcast::Receiver<WebSocketEvent>,
+    ) -> Subscription {
+        Subscription {
+            stream: stream.to_string(),
+            generation,
+            rx,
+            manager: self.clone(),
+        }
+    }
+}
+
+/// A consumer's handle to a stream managed by a [`SubscriptionManager`].
+///
+/// Dropping the last subscription of a stream unsubscribes it.
+pub struct Subscription {
+    stream: String,
+    generation: u64,
+    rx: broadcast::Receiver<WebSocketEvent>,
+    manager: SubscriptionManager,
+}
+
+impl Subscription {
+    /// Get the stream name.
+    pub fn stream(&self) -> &str {
+        &self.stream
+    }
+
+    /// Receive the next event.
+    ///
+    /// Events are skipped for a consumer that falls more than 100 events
+    /// behind. Returns `None` once the client is shut down.
+    pub async fn recv(&mut self) -> Option<WebSocketEvent> {
+        let shutdown = self.manager.ws.shutdown.clone();
+        loop {
+            let next = tokio::select! {
+                _ = shutdown.cancelled() => return None,
+                next = self.rx.recv() => next,
+            };
+            match next {
+                Ok(event) => return Some(event),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::debug!("{} consumer skipped {} events", self.stream, skipped);
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        let released = self.manager.state().release(&self.stream, self.generation);
+        let Released::Unsubscribe(connection) = released else {
+            // Dropping the multiplexer of a closed connection needs no I/O.
+            return;
+        };
+
+        let runtime = self.manager.ws.config.runtime_hints.clone();
+        // Nothing to unsubscribe from once the runtime is gone.
+        if runtime.handle().is_none() && tokio::runtime::Handle::try_current().is_err() {
+            return;
+        }
+        let stream = std::mem::take(&mut self.stream);
+        runtime.spawn(async move {
+            if let Err(e) = connection.unsubscribe(&[stream]).await {
+                tracing::warn!("Failed to unsubscribe: {}", e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_stream(acquired: Acquired<u32>) -> (u64, u32) {
+        match acquired {
+            Acquired::New {
+                generation,
+                connection,
+                ..
+            } => (generation, connection),
+            Acquired::Shared { .. } => panic!("expected a new upstream subscription"),
+        }
+    }
+
+    #[test]
+    fn test_consumers_share_subscription() {
+        let mut registry = Registry::new(10);
+        let (generation, _) = new_stream(registry.acquire("btcusdt@trade", || 1));
+
+        match registry.acquire("btcusdt@trade", || 2) {
+            Acquired::Shared {
+                generation: shared, ..
+            } => assert_eq!(shared, generation),
+            Acquired::New { .. } => panic!("expected the subscription to be shared"),
+        }
+        assert_eq!(registry.streams["btcusdt@trade"].consumers, 2);
+        assert_eq!(registry.connections.len(), 1);
+
+        assert!(matches!(
+            registry.release("btcusdt@trade", generation),
+            Released::Kept
+        ));
+        assert_eq!(registry.streams["btcusdt@trade"].consumers, 1);
+    }
+
+    #[test]
+    fn test_opens_connection_when_budget_full() {
+        let mut registry = Registry::new(2);
+        let mut opened = 0;
+        let mut open = || {
+            opened += 1;
+            opened
+        };
+
+        assert_eq!(new_stream(registry.acquire("a", &mut open)).1, 1);
+        assert_eq!(new_stream(registry.acquire("b", &mut open)).1, 1);
+        assert_eq!(new_stream(registry.acquire("c", &mut open)).1, 2);
+        assert_eq!(registry.connections.len(), 2);
+    }
+
+    #[test]
+    fn test_unsubscribes_after_last_consumer() {
+        let mut registry = Registry::new(10);
+        let (a, _) = new_stream(registry.acquire("a", || 1));
+        registry.acquire("a", || 1);
+        new_stream(registry.acquire("b", || 1));
+
+        assert!(matches!(registry.release("a", a), Released::Kept));
+        assert!(matches!(registry.release("a", a), Released::Unsubscribe(1)));
+        assert!(!registry.streams.contains_key("a"));
+        assert_eq!(registry.connections[0].streams, 1);
+    }
+
+    #[test]
+    fn test_closes_empty_connection() {
+        let mut registry = Registry::new(2);
+        let (a, _) = new_stream(registry.acquire("a", || 1));
+        new_stream(registry.acquire("b", || 1));
+        let (c, _) = new_stream(registry.acquire("c", || 2));
+
+        assert!(matches!(registry.release("c", c), Released::Closed));
+        assert_eq!(registry.connections.len(), 1);
+        assert_eq!(registry.connections[0].connection, 1);
+
+        assert!(matches!(registry.release("a", a), Released::Unsubscribe(1)));
+        assert_eq!(registry.connections.len(), 1);
+    }
+
+    #[test]
+    fn test_stale_release_is_ignored() {
+        let mut registry = Registry::new(10);
+        let (old, _) = new_stream(registry.acquire("a", || 1));
+        registry.remove("a", old);
+        let (new, _) = new_stream(registry.acquire("a", || 1));
+
+        assert!(matches!(registry.release("a", old), Released::Kept));
+        assert!(matches!(registry.release("a", new), Released::Closed));
+    }
+}
@@ -2,8 +2,13 @@
 //!
 //! These tests use wiremock to mock HTTP responses from the Binance API.
 
-use binance_api_client::{Binance, Config, KlineInterval};
-use wiremock::matchers::{method, path, query_param};
+use std::time::Duration;
+
+use binance_api_client::{
+    Binance, Config, KlineInterval, LatencyProbe, OrderBuilder, OrderSide, OrderType, TimeInForce,
+};
+use futures::TryStreamExt;
+use wiremock::matchers::{header, method, path, query_param};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 /// Helper to create a test client with a mock server
@@ -54,6 +59,91 @@ async fn test_server_time() {
     assert_eq!(time.server_time, 1704067200000);
 }
 
+#[tokio::test]
+async fn test_sync_time() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("server_time.json")))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let offset = client.sync_time().await.unwrap();
+
+    // The mocked server time is in the past, so the local clock is ahead.
+    assert!(offset < 0);
+    assert_eq!(client.client().time_sync().offset_ms(), offset);
+    assert!(client.client().time_sync().is_synced());
+}
+
+#[tokio::test]
+async fn test_auto_time_sync() {
+    let mock_server = MockServer::start().await;
+
+    // Concurrent signed requests share the first synchronization
+    Mock::given(method("GET"))
+        .and(path("/api/v3/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("server_time.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/openOrders"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("[]"))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let config = Config::builder()
+        .rest_api_endpoint(mock_server.uri())
+        .auto_time_sync(true)
+        .build();
+    let client = Binance::with_config(config, Some(("api_key", "secret_key"))).unwrap();
+
+    let account = client.account();
+    let requests = (0..3).map(|_| account.open_orders(None));
+    for result in futures::future::join_all(requests).await {
+        assert!(result.unwrap().is_empty());
+    }
+    assert!(client.client().time_sync().offset_ms() < 0);
+    client.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_latency_probe_rest() {
+    let fast = MockServer::start().await;
+    let down = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ping"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("ping.json")))
+        .mount(&fast)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ping"))
+        .respond_with(ResponseTemplate::new(503))
+        .mount(&down)
+        .await;
+
+    let probe = LatencyProbe::new()
+        .rest_endpoints([down.uri(), fast.uri()])
+        .ws_endpoints(Vec::<String>::new())
+        .samples(2);
+    let report = probe.probe().await.unwrap();
+
+    assert_eq!(report.rest.len(), 2);
+    assert_eq!(report.rest[0].failures, 2);
+    assert!(!report.rest[0].is_reachable());
+    assert_eq!(report.fastest_rest().unwrap().endpoint, fast.uri());
+
+    let mut config = Config::default();
+    report.apply(&mut config);
+    assert_eq!(config.rest_api_endpoint, fast.uri());
+}
+
 #[tokio::test]
 async fn test_exchange_info() {
     let mock_server = MockServer::start().await;
@@ -197,6 +287,7 @@ async fn test_avg_price() {
     let avg = result.unwrap();
     assert_eq!(avg.mins, 5);
     assert_eq!(avg.price, 50125.1234);
+    assert_eq!(avg.close_time, Some(1704067500000));
 }
 
 #[tokio::test]
@@ -288,3 +379,359 @@ async fn test_book_ticker() {
     assert_eq!(ticker.ask_price, 50001.0);
     assert_eq!(ticker.ask_qty, 0.75);
 }
+
+#[tokio::test]
+async fn test_prices_for() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ticker/price"))
+        .and(query_param("symbols", r#"["BTCUSDT","ETHUSDT"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("ticker_prices.json")))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let prices = client
+        .market()
+        .prices_for(&["BTCUSDT", "ETHUSDT"])
+        .await
+        .unwrap();
+
+    assert_eq!(prices.len(), 2);
+    assert_eq!(prices[1].symbol, "ETHUSDT");
+    assert_eq!(prices[1].price, 3000.0);
+}
+
+#[tokio::test]
+async fn test_book_tickers_for() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ticker/bookTicker"))
+        .and(query_param("symbols", r#"["BTCUSDT","ETHUSDT"]"#))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("book_tickers.json")))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let tickers = client
+        .market()
+        .book_tickers_for(&["BTCUSDT", "ETHUSDT"])
+        .await
+        .unwrap();
+
+    assert_eq!(tickers.len(), 2);
+    assert_eq!(tickers[1].symbol, "ETHUSDT");
+    assert_eq!(tickers[1].ask_price, 3000.1);
+}
+
+#[tokio::test]
+async fn test_rolling_window_tickers_symbols_param() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ticker"))
+        .and(query_param("symbols", r#"["BTCUSDT","ETHUSDT"]"#))
+        .and(query_param("windowSize", "4h"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(load_mock("rolling_window_tickers.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let tickers = client
+        .market()
+        .rolling_window_tickers(&["BTCUSDT", "ETHUSDT"], Some("4h"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(tickers.len(), 2);
+    assert_eq!(tickers[0].symbol, "BTCUSDT");
+    assert_eq!(tickers[1].symbol, "ETHUSDT");
+}
+
+#[tokio::test]
+async fn test_trading_day_tickers_symbols_param() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/ticker/tradingDay"))
+        .and(query_param("symbols", r#"["BTCUSDT","ETHUSDT"]"#))
+        .and(query_param("timeZone", "-1:00"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(load_mock("rolling_window_tickers.json")),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let tickers = client
+        .market()
+        .trading_day_tickers(&["BTCUSDT", "ETHUSDT"], Some("-1:00"), None)
+        .await
+        .unwrap();
+
+    assert_eq!(tickers.len(), 2);
+    assert_eq!(tickers[1].last_price, 1101.2);
+}
+
+#[tokio::test]
+async fn test_exchange_info_cache_auto_refresh() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("exchange_info.json")))
+        .expect(2..)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let cache = client.exchange_info_cache();
+    let refresher = cache.auto_refresh(Duration::from_millis(50));
+
+    let first = loop {
+        if let Some(loaded_at) = cache.loaded_at().await {
+            break loaded_at;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+    assert!(cache.symbols().await.contains_key("BTCUSDT"));
+
+    tokio::time::sleep(Duration::from_millis(120)).await;
+    assert!(cache.loaded_at().await.unwrap() > first);
+    refresher.stop();
+}
+
+#[tokio::test]
+async fn test_exchange_info_cache_lookup() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("exchange_info.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let cache = client.exchange_info_cache();
+    assert!(cache.get("BTCUSDT").await.is_none());
+
+    // Fetched on the first lookup, then served from the cache
+    let symbol = cache.symbol("btcusdt").await.unwrap();
+    assert_eq!(symbol.symbol, "BTCUSDT");
+    let cached = cache.symbol("BTCUSDT").await.unwrap();
+    assert_eq!(cached.symbol, "BTCUSDT");
+    assert!(cache.get("btcusdt").await.is_some());
+    assert_eq!(cache.len().await, 1);
+}
+
+#[tokio::test]
+async fn test_exchange_info_cache_unknown_symbol() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("exchange_info.json")))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let cache = client.exchange_info_cache();
+
+    let error = cache.symbol("ETHUSDT").await.unwrap_err();
+    assert!(error.is_invalid_symbol());
+    assert!(cache.is_empty().await);
+}
+
+#[tokio::test]
+async fn test_exchange_info_cache_validate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("exchange_info.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Only the market order needs the average price
+    Mock::given(method("GET"))
+        .and(path("/api/v3/avgPrice"))
+        .and(query_param("symbol", "BTCUSDT"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("avg_price.json")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let cache = client.exchange_info_cache();
+
+    let limit = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+        .quantity("0.001")
+        .price("50000.00")
+        .time_in_force(TimeInForce::GTC)
+        .build();
+    cache.validate(&limit).await.unwrap();
+
+    let off_tick = OrderBuilder::new("BTCUSDT", OrderSide::Buy, OrderType::Limit)
+        .quantity("0.001")
+        .price("50000.001")
+        .time_in_force(TimeInForce::GTC)
+        .build();
+    assert!(cache.validate(&off_tick).await.is_err());
+
+    let market = OrderBuilder::new("BTCUSDT", OrderSide::Sell, OrderType::Market)
+        .quantity("0.001")
+        .build();
+    cache.validate(&market).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_exchange_info_cache_ttl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("exchange_info.json")))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/exchangeInfo"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_string(r#"{"code":-1000,"msg":"An unknown error occurred."}"#),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let cache = client.exchange_info_cache().ttl(Duration::from_millis(100));
+    cache.symbol("BTCUSDT").await.unwrap();
+    let first = cache.loaded_at().await.unwrap();
+
+    // Concurrent lookups after the TTL share one reload
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    let lookups = (0..5).map(|_| cache.symbol("BTCUSDT"));
+    for result in futures::future::join_all(lookups).await {
+        result.unwrap();
+    }
+    assert!(cache.loaded_at().await.unwrap() > first);
+
+    // A failed reload keeps the stale symbols and is not retried at once
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(cache.symbol("BTCUSDT").await.unwrap().symbol, "BTCUSDT");
+    assert_eq!(cache.symbol("BTCUSDT").await.unwrap().symbol, "BTCUSDT");
+}
+
+#[tokio::test]
+async fn test_ui_klines_in_time_zone() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/uiKlines"))
+        .and(query_param("symbol", "BTCUSDT"))
+        .and(query_param("interval", "1d"))
+        .and(query_param("timeZone", "+08:00"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("klines.json")))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let klines = client
+        .market()
+        .ui_klines_in_time_zone("BTCUSDT", KlineInterval::Days1, None, None, None, "+08:00")
+        .await
+        .unwrap();
+
+    assert_eq!(klines.len(), 2);
+    assert_eq!(klines[0].open, 50000.0);
+}
+
+#[tokio::test]
+async fn test_historical_trades_with_api_key() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v3/historicalTrades"))
+        .and(query_param("fromId", "28457"))
+        .and(header("X-MBX-APIKEY", "api_key"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(load_mock("trades.json")))
+        .mount(&mock_server)
+        .await;
+
+    let config = Config::builder()
+        .rest_api_endpoint(mock_server.uri())
+        .build();
+    let client = Binance::with_api_key(config, "api_key").unwrap();
+    let trades = client
+        .market()
+        .historical_trades("BTCUSDT", Some(28457), Some(10))
+        .await
+        .unwrap();
+    assert!(!trades.is_empty());
+
+    let unauthenticated = test_client(&mock_server).await;
+    let result = unauthenticated
+        .market()
+        .historical_trades("BTCUSDT", Some(28457), Some(10))
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_agg_trades_range() {
+    let mock_server = MockServer::start().await;
+    let hour = 3_600_000u64;
+    let start = 1_704_060_000_000u64;
+    let trade = |id: u64, time: u64| {
+        serde_json::json!({
+            "a": id, "p": "50000.0", "q": "0.01", "f": id, "l": id,
+            "T": time, "m": true, "M": true
+        })
+    };
+
+    // Nothing in the first hour, two trades in the second.
+    Mock::given(method("GET"))
+        .and(path("/api/v3/aggTrades"))
+        .and(query_param("startTime", start.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/aggTrades"))
+        .and(query_param("startTime", (start + hour).to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            trade(10, start + hour + 1),
+            trade(11, start + hour + 2),
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v3/aggTrades"))
+        .and(query_param("fromId", "12"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            trade(12, start + hour + 3),
+            trade(13, start + 3 * hour),
+        ])))
+        .mount(&mock_server)
+        .await;
+
+    let client = test_client(&mock_server).await;
+    let trades: Vec<_> = client
+        .market()
+        .agg_trades_range("BTCUSDT", start, start + 2 * hour)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = trades.iter().map(|t| t.agg_trade_id).collect();
+    assert_eq!(ids, vec![10, 11, 12]);
+}
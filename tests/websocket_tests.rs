@@ -190,6 +190,72 @@ fn test_parse_ticker_event() {
     }
 }
 
+#[test]
+fn test_parse_rolling_window_ticker_event() {
+    let json = r#"{
+        "e": "1hTicker",
+        "E": 1704067200000,
+        "s": "BTCUSDT",
+        "p": "500.00000000",
+        "P": "1.010",
+        "o": "49500.00000000",
+        "h": "51000.00000000",
+        "l": "49000.00000000",
+        "c": "50000.00000000",
+        "w": "50100.00000000",
+        "v": "1000.00000000",
+        "q": "50125000.00000000",
+        "O": 1704063600000,
+        "C": 1704067200000,
+        "F": 100,
+        "L": 200,
+        "n": 101
+    }"#;
+
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.event_type(), Some("1hTicker"));
+
+    match &event {
+        WebSocketEvent::RollingWindowTicker(ticker) => {
+            assert_eq!(ticker.window_size(), "1h");
+            assert_eq!(ticker.symbol, "BTCUSDT");
+            assert_eq!(ticker.last_price, 50000.0);
+            assert_eq!(ticker.weighted_avg_price, 50100.0);
+            assert_eq!(ticker.number_of_trades, 101);
+        }
+        _ => panic!("Expected RollingWindowTicker event"),
+    }
+
+    // The event type survives a round trip
+    let value = serde_json::to_value(&event).unwrap();
+    assert_eq!(value["e"], "1hTicker");
+}
+
+#[test]
+fn test_parse_avg_price_event() {
+    let json = r#"{
+        "e": "avgPrice",
+        "E": 1693907033000,
+        "s": "BTCUSDT",
+        "i": "5m",
+        "w": "25776.86000000",
+        "T": 1693907032213
+    }"#;
+
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.event_type(), Some("avgPrice"));
+
+    match event {
+        WebSocketEvent::AvgPrice(avg) => {
+            assert_eq!(avg.symbol, "BTCUSDT");
+            assert_eq!(avg.interval, "5m");
+            assert_eq!(avg.price, 25776.86);
+            assert_eq!(avg.last_trade_time, 1693907032213);
+        }
+        _ => panic!("Expected AvgPrice event"),
+    }
+}
+
 #[test]
 fn test_parse_book_ticker_event() {
     let json = r#"{
@@ -348,6 +414,45 @@ fn test_parse_balance_update_event() {
     }
 }
 
+#[test]
+fn test_parse_user_stream_lifecycle_events() {
+    let json = r#"{
+        "e": "listenKeyExpired",
+        "E": 1699596037418,
+        "listenKey": "OfYGbUzi3PraNagEkdKuFwUHn48brFsItTdsuiIXrucEvD0rhRXZ7I6URWfE8YE8"
+    }"#;
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+    match event {
+        WebSocketEvent::ListenKeyExpired(expired) => {
+            assert_eq!(expired.event_time, 1699596037418);
+            assert!(expired.listen_key.starts_with("OfYGbUzi"));
+        }
+        _ => panic!("Expected ListenKeyExpired event"),
+    }
+
+    let json = r#"{
+        "e": "externalLockUpdate",
+        "E": 1581557507324,
+        "a": "NEO",
+        "d": "10.00000000",
+        "T": 1581557507268
+    }"#;
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+    match event {
+        WebSocketEvent::ExternalLockUpdate(update) => {
+            assert_eq!(update.asset, "NEO");
+            assert_eq!(update.delta, 10.0);
+            assert_eq!(update.transaction_time, 1581557507268);
+        }
+        _ => panic!("Expected ExternalLockUpdate event"),
+    }
+
+    let json = r#"{"e": "eventStreamTerminated", "E": 1728973001334}"#;
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+    assert_eq!(event.event_type(), Some("eventStreamTerminated"));
+    assert!(matches!(event, WebSocketEvent::EventStreamTerminated(_)));
+}
+
 #[test]
 fn test_parse_execution_report_event() {
     let json = r#"{
@@ -398,3 +503,36 @@ fn test_parse_execution_report_event() {
         _ => panic!("Expected ExecutionReport event"),
     }
 }
+
+#[test]
+fn test_parse_unknown_event() {
+    let json = r#"{
+        "e": "someNewEvent",
+        "E": 1704067200000,
+        "s": "BTCUSDT",
+        "x": "1.5"
+    }"#;
+
+    let event: WebSocketEvent = serde_json::from_str(json).unwrap();
+
+    assert_eq!(event.event_type(), Some("someNewEvent"));
+    match event {
+        WebSocketEvent::Unknown(value) => {
+            assert_eq!(value["s"], "BTCUSDT");
+            assert_eq!(value["x"], "1.5");
+        }
+        _ => panic!("Expected Unknown event"),
+    }
+
+    // Messages without an event type are kept too
+    let event: WebSocketEvent = serde_json::from_str(r#"{"lastUpdateId": 160}"#).unwrap();
+    assert!(matches!(event, WebSocketEvent::Unknown(_)));
+    assert_eq!(event.event_type(), None);
+}
+
+#[test]
+fn test_parse_malformed_known_event() {
+    // Known event types are still parsed strictly
+    let json = r#"{"e": "trade", "E": 1704067200000, "s": "BTCUSDT"}"#;
+    assert!(serde_json::from_str::<WebSocketEvent>(json).is_err());
+}